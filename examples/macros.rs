@@ -93,6 +93,15 @@ fn main() {
             message: "You also get a free 2L beverage",
             choices: ["Pepsi", "7up", "Coke"],
         },
+        OrderSelect {
+            name: "delivery_order",
+            message: "Which items should be delivered first?",
+            when: |answers: &requestty::Answers| {
+                answers["to_be_delivered"].as_bool().unwrap()
+            },
+            // Separators cannot be used in `OrderSelect` choices
+            choices: ["Pizza", "Beverage"],
+        },
         Input {
             name: "comments",
             message: "Any comments on your purchase experience?",
@@ -0,0 +1,85 @@
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+pub(crate) fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => fields.named,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`FromAnswers` can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            let ident = field.ident.expect("fields are named");
+            let name = match answer_name(&field.attrs)? {
+                Some(name) => name,
+                None => syn::LitStr::new(&ident.to_string(), ident.span()),
+            };
+
+            Ok(quote_spanned! { field.ty.span() =>
+                #ident: ::requestty::__private::take_answer(answers, #name)?
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ident = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::requestty::FromAnswers for #ident #ty_generics #where_clause {
+            fn from_answers(
+                answers: &::requestty::Answers,
+            ) -> ::std::result::Result<Self, ::requestty::FromAnswersError> {
+                ::std::result::Result::Ok(Self { #(#fields),* })
+            }
+        }
+    })
+}
+
+/// Gets the name given by `#[answer(name = "...")]`, if any.
+fn answer_name(attrs: &[syn::Attribute]) -> syn::Result<Option<syn::LitStr>> {
+    let mut name = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("answer")) {
+        let list = match attr.parse_meta()? {
+            syn::Meta::List(list) => list,
+            meta => {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "expected `#[answer(name = \"...\")]`",
+                ))
+            }
+        };
+
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                })) if path.is_ident("name") => {
+                    if name.is_some() {
+                        return Err(syn::Error::new(path.span(), "duplicate option `name`"));
+                    }
+                    name = Some(lit);
+                }
+                nested => {
+                    return Err(syn::Error::new(
+                        nested.span(),
+                        "unknown option, expected `name = \"...\"`",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(name)
+}
@@ -72,6 +72,60 @@ pub(crate) fn check_non_dup<T>(ident: &syn::Ident, item: &Option<T>) -> syn::Res
     }
 }
 
+/// Appends a "did you mean" suggestion to `msg` if one of the `candidates` is close enough to
+/// `ident`.
+pub(crate) fn with_suggestion<'a>(
+    mut msg: String,
+    ident: &syn::Ident,
+    candidates: impl Iterator<Item = &'a str>,
+) -> String {
+    let ident = ident.to_string();
+    // Similar to the threshold rustc uses for its suggestions
+    let max_dist = std::cmp::max(ident.len(), 3) / 3;
+
+    let suggestion = candidates
+        .map(|candidate| (edit_distance(&ident, candidate), candidate))
+        .filter(|&(dist, _)| dist <= max_dist)
+        .min_by_key(|&(dist, _)| dist);
+
+    if let Some((_, suggestion)) = suggestion {
+        msg.push_str(", did you mean `");
+        msg.push_str(suggestion);
+        msg.push_str("`?");
+    }
+
+    msg
+}
+
+/// The levenshtein distance between `a` and `b`, ignoring case and underscores.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let normalize = |s: &str| -> Vec<char> {
+        s.chars()
+            .filter(|&c| c != '_')
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+
+    let a = normalize(a);
+    let b = normalize(b);
+
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+    let mut row = vec![0; b.len() + 1];
+
+    for (i, a_c) in a.iter().enumerate() {
+        row[0] = i + 1;
+
+        for (j, b_c) in b.iter().enumerate() {
+            let substitution = prev_row[j] + (a_c != b_c) as usize;
+            row[j + 1] = substitution.min(prev_row[j + 1] + 1).min(row[j] + 1);
+        }
+
+        std::mem::swap(&mut row, &mut prev_row);
+    }
+
+    prev_row[b.len()]
+}
+
 #[allow(clippy::large_enum_variant)]
 pub(crate) enum Choices {
     Array(syn::punctuated::Punctuated<Choice, Token![,]>),
@@ -1,5 +1,5 @@
-//! This crate is the declaration of the [`questions!`] macro. It should not be used directly. Use
-//! [`requestty`] instead.
+//! This crate is the declaration of the [`questions!`] macro and the [`FromAnswers`] derive. It
+//! should not be used directly. Use [`requestty`] instead.
 //!
 //! [`requestty`]: https://crates.io/crates/requestty
 
@@ -19,6 +19,7 @@ macro_rules! iter {
     };
 }
 
+mod from_answers;
 mod helpers;
 mod question;
 
@@ -39,6 +40,15 @@ pub fn questions(item: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro_derive(FromAnswers, attributes(answer))]
+pub fn derive_from_answers(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as syn::DeriveInput);
+
+    from_answers::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 struct Questions {
     inline: bool,
     questions: syn::punctuated::Punctuated<Question, Token![,]>,
@@ -19,6 +19,7 @@ bitflags::bitflags! {
         const EDITOR         = 0b001_0000_0000;
         const ON_ESC         = 0b010_0000_0000;
         const PROMPT         = 0b100_0000_0000;
+        const PLUGIN         = 0b1000_0000_0000;
     }
 }
 
@@ -36,6 +37,7 @@ pub(crate) enum QuestionKind {
     Password,
     Editor,
     Custom,
+    Plugin,
 }
 
 impl QuestionKind {
@@ -53,6 +55,7 @@ impl QuestionKind {
             QuestionKind::Password => "password",
             QuestionKind::Editor => "editor",
             QuestionKind::Custom => "custom",
+            QuestionKind::Plugin => "plugin",
         }
     }
 
@@ -113,46 +116,42 @@ impl QuestionKind {
                     | BuilderMethods::ON_ESC
             }
             QuestionKind::Custom => BuilderMethods::PROMPT,
+            QuestionKind::Plugin => BuilderMethods::PLUGIN,
         }
     }
 }
 
+const KINDS: [(&str, QuestionKind); 13] = [
+    ("Input", QuestionKind::Input),
+    ("Int", QuestionKind::Int),
+    ("Float", QuestionKind::Float),
+    ("Confirm", QuestionKind::Confirm),
+    ("Select", QuestionKind::Select),
+    ("RawSelect", QuestionKind::RawSelect),
+    ("Expand", QuestionKind::Expand),
+    ("MultiSelect", QuestionKind::MultiSelect),
+    ("OrderSelect", QuestionKind::OrderSelect),
+    ("Password", QuestionKind::Password),
+    ("Editor", QuestionKind::Editor),
+    ("Custom", QuestionKind::Custom),
+    ("Plugin", QuestionKind::Plugin),
+];
+
 impl Parse for QuestionKind {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let ident = input.parse::<syn::Ident>()?;
 
-        let kind = if ident == "Input" {
-            QuestionKind::Input
-        } else if ident == "Int" {
-            QuestionKind::Int
-        } else if ident == "Float" {
-            QuestionKind::Float
-        } else if ident == "Confirm" {
-            QuestionKind::Confirm
-        } else if ident == "Select" {
-            QuestionKind::Select
-        } else if ident == "RawSelect" {
-            QuestionKind::RawSelect
-        } else if ident == "Expand" {
-            QuestionKind::Expand
-        } else if ident == "MultiSelect" {
-            QuestionKind::MultiSelect
-        } else if ident == "OrderSelect" {
-            QuestionKind::OrderSelect
-        } else if ident == "Password" {
-            QuestionKind::Password
-        } else if ident == "Editor" {
-            QuestionKind::Editor
-        } else if ident == "Custom" {
-            QuestionKind::Custom
-        } else {
-            return Err(syn::Error::new(
+        match KINDS.iter().find(|(name, _)| ident == name) {
+            Some(&(_, kind)) => Ok(kind),
+            None => Err(syn::Error::new(
                 ident.span(),
-                format!("unknown question kind {}", ident),
-            ));
-        };
-
-        Ok(kind)
+                with_suggestion(
+                    format!("unknown question kind {}", ident),
+                    &ident,
+                    KINDS.iter().map(|&(name, _)| name),
+                ),
+            )),
+        }
     }
 }
 
@@ -167,6 +166,9 @@ pub(crate) struct QuestionOpts {
     pub(crate) message: Option<syn::Expr>,
     pub(crate) when: Option<syn::Expr>,
     pub(crate) ask_if_answered: Option<syn::Expr>,
+    pub(crate) theme: Option<syn::Expr>,
+    pub(crate) before_ask: Option<syn::Expr>,
+    pub(crate) after_ask: Option<syn::Expr>,
     pub(crate) on_esc: Option<syn::Expr>,
 
     pub(crate) default: Option<syn::Expr>,
@@ -188,42 +190,65 @@ pub(crate) struct QuestionOpts {
     pub(crate) prompt: Option<syn::Expr>,
 }
 
+/// Options which are allowed for all kinds.
+const COMMON_OPTIONS: [&str; 7] = [
+    "name",
+    "message",
+    "when",
+    "ask_if_answered",
+    "theme",
+    "before_ask",
+    "after_ask",
+];
+
+/// Options which are only allowed for some kinds, along with the builder method they require.
+const OPTIONS: [(&str, BuilderMethods); 15] = [
+    ("default", BuilderMethods::DEFAULT),
+    ("transform", BuilderMethods::TRANSFORM),
+    ("validate", BuilderMethods::VAL_FIL),
+    ("filter", BuilderMethods::VAL_FIL),
+    ("validate_on_key", BuilderMethods::VAL_KEY),
+    ("auto_complete", BuilderMethods::AUTO_COMPLETE),
+    ("choices", BuilderMethods::CHOICES),
+    ("page_size", BuilderMethods::LOOP_PAGE_SIZE),
+    ("should_loop", BuilderMethods::LOOP_PAGE_SIZE),
+    ("mask", BuilderMethods::MASK),
+    ("editor", BuilderMethods::EDITOR),
+    ("extension", BuilderMethods::EDITOR),
+    ("on_esc", BuilderMethods::ON_ESC),
+    ("prompt", BuilderMethods::PROMPT),
+    ("plugin", BuilderMethods::PLUGIN),
+];
+
 fn check_allowed(ident: &syn::Ident, kind: QuestionKind) -> syn::Result<()> {
     // default options which are always there
-    if ident == "name" || ident == "message" || ident == "when" || ident == "ask_if_answered" {
+    if COMMON_OPTIONS.iter().any(|option| ident == option) {
         return Ok(());
     }
 
-    let builder_method = if ident == "default" {
-        BuilderMethods::DEFAULT
-    } else if ident == "transform" {
-        BuilderMethods::TRANSFORM
-    } else if ident == "validate" || ident == "filter" {
-        BuilderMethods::VAL_FIL
-    } else if ident == "validate_on_key" {
-        BuilderMethods::VAL_KEY
-    } else if ident == "auto_complete" {
-        BuilderMethods::AUTO_COMPLETE
-    } else if ident == "choices" {
-        BuilderMethods::CHOICES
-    } else if ident == "page_size" || ident == "should_loop" {
-        BuilderMethods::LOOP_PAGE_SIZE
-    } else if ident == "mask" {
-        BuilderMethods::MASK
-    } else if ident == "editor" || ident == "extension" {
-        BuilderMethods::EDITOR
-    } else if ident == "on_esc" {
-        BuilderMethods::ON_ESC
-    } else if ident == "prompt" {
-        BuilderMethods::PROMPT
-    } else {
-        return Err(syn::Error::new(
-            ident.span(),
-            format!("unknown question option `{}`", ident),
-        ));
+    let builder_methods = kind.get_builder_methods();
+
+    let builder_method = match OPTIONS.iter().find(|(option, _)| ident == option) {
+        Some(&(_, builder_method)) => builder_method,
+        None => {
+            // Only suggest options which can actually be used with this kind
+            let allowed = OPTIONS
+                .iter()
+                .filter(|(_, builder_method)| builder_methods.contains(*builder_method))
+                .map(|&(option, _)| option);
+
+            return Err(syn::Error::new(
+                ident.span(),
+                with_suggestion(
+                    format!("unknown question option `{}`", ident),
+                    ident,
+                    COMMON_OPTIONS.iter().copied().chain(allowed),
+                ),
+            ));
+        }
     };
 
-    if kind.get_builder_methods().contains(builder_method) {
+    if builder_methods.contains(builder_method) {
         Ok(())
     } else {
         Err(syn::Error::new(
@@ -233,6 +258,84 @@ fn check_allowed(ident: &syn::Ident, kind: QuestionKind) -> syn::Result<()> {
     }
 }
 
+/// The type of literal an option expects.
+///
+/// Only literals are checked, since the type of any other expression is not known to the macro.
+/// This gives a clearer error than the type error from the generated builder method.
+#[derive(Clone, Copy)]
+enum LitType {
+    Bool,
+    Int,
+    Float,
+    Char,
+    Str,
+}
+
+impl LitType {
+    fn for_option(option: &str, kind: QuestionKind) -> Option<Self> {
+        match option {
+            "default" => match kind {
+                QuestionKind::Input | QuestionKind::Editor | QuestionKind::Expand => {
+                    Some(LitType::Str)
+                }
+                QuestionKind::Int | QuestionKind::Select | QuestionKind::RawSelect => {
+                    Some(LitType::Int)
+                }
+                QuestionKind::Float => Some(LitType::Float),
+                QuestionKind::Confirm => Some(LitType::Bool),
+                _ => None,
+            },
+            "when" | "ask_if_answered" | "should_loop" => Some(LitType::Bool),
+            "page_size" => Some(LitType::Int),
+            "mask" => Some(LitType::Char),
+            "message" | "extension" => Some(LitType::Str),
+            _ => None,
+        }
+    }
+
+    fn matches(self, lit: &syn::Lit) -> bool {
+        matches!(
+            (self, lit),
+            (LitType::Bool, syn::Lit::Bool(_))
+                | (LitType::Int, syn::Lit::Int(_))
+                | (LitType::Float, syn::Lit::Float(_))
+                | (LitType::Char, syn::Lit::Char(_))
+                // `String` also implements `From<char>`
+                | (LitType::Str, syn::Lit::Str(_) | syn::Lit::Char(_))
+        )
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            LitType::Bool => "a bool",
+            LitType::Int => "an integer",
+            LitType::Float => "a float",
+            LitType::Char => "a char",
+            LitType::Str => "a string",
+        }
+    }
+}
+
+fn check_lit_type(option: &str, value: &syn::Expr, kind: QuestionKind) -> syn::Result<()> {
+    let lit = match value {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => lit,
+        _ => return Ok(()),
+    };
+
+    match LitType::for_option(option, kind) {
+        Some(lit_type) if !lit_type.matches(lit) => Err(syn::Error::new(
+            lit.span(),
+            format!(
+                "option `{}` of kind `{}` expects {}",
+                option,
+                kind,
+                lit_type.as_str()
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
 pub(crate) struct Question {
     pub(crate) kind: QuestionKind,
     pub(crate) name: syn::Expr,
@@ -261,6 +364,12 @@ impl Parse for Question {
                 insert_non_dup(ident, &mut opts.when, &content)?;
             } else if ident == "ask_if_answered" {
                 insert_non_dup(ident, &mut opts.ask_if_answered, &content)?;
+            } else if ident == "theme" {
+                insert_non_dup(ident, &mut opts.theme, &content)?;
+            } else if ident == "before_ask" {
+                insert_non_dup(ident, &mut opts.before_ask, &content)?;
+            } else if ident == "after_ask" {
+                insert_non_dup(ident, &mut opts.after_ask, &content)?;
             } else if ident == "default" {
                 insert_non_dup(ident, &mut opts.default, &content)?;
             } else if ident == "validate" {
@@ -293,7 +402,9 @@ impl Parse for Question {
                 insert_non_dup(ident, &mut opts.extension, &content)?;
             } else if ident == "on_esc" {
                 insert_non_dup(ident, &mut opts.on_esc, &content)?;
-            } else if ident == "prompt" {
+            } else if ident == "prompt" || ident == "plugin" {
+                // `plugin` is only allowed for `Plugin`, and `prompt` only for `Custom`, so they
+                // can never both be given
                 insert_non_dup(ident, &mut opts.prompt, &content)?;
             } else {
                 unreachable!("check_allowed should have taken care of this case.");
@@ -304,11 +415,32 @@ impl Parse for Question {
             }
         }
 
-        if let QuestionKind::Custom = kind {
+        for (option, value) in [
+            ("message", &opts.message),
+            ("when", &opts.when),
+            ("ask_if_answered", &opts.ask_if_answered),
+            ("default", &opts.default),
+            ("page_size", &opts.page_size),
+            ("should_loop", &opts.should_loop),
+            ("mask", &opts.mask),
+            ("extension", &opts.extension),
+        ] {
+            if let Some(value) = value {
+                check_lit_type(option, value, kind)?;
+            }
+        }
+
+        let required_prompt = match kind {
+            QuestionKind::Custom => Some("prompt"),
+            QuestionKind::Plugin => Some("plugin"),
+            _ => None,
+        };
+
+        if let Some(option) = required_prompt {
             if opts.prompt.is_none() {
                 return Err(syn::Error::new(
                     brace.span,
-                    "missing required option `prompt`",
+                    format!("missing required option `{}`", option),
                 ));
             }
         }
@@ -335,6 +467,15 @@ impl Question {
                 ask_if_answered.span() => .ask_if_answered(#ask_if_answered)
             });
         }
+        if let Some(ref theme) = self.opts.theme {
+            tokens.extend(quote_spanned! { theme.span() => .theme(#theme) });
+        }
+        if let Some(ref before_ask) = self.opts.before_ask {
+            tokens.extend(quote_spanned! { before_ask.span() => .before_ask(#before_ask) });
+        }
+        if let Some(ref after_ask) = self.opts.after_ask {
+            tokens.extend(quote_spanned! { after_ask.span() => .after_ask(#after_ask) });
+        }
     }
 }
 
@@ -342,12 +483,12 @@ impl quote::ToTokens for Question {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = &self.name;
 
-        if let QuestionKind::Custom = self.kind {
+        if let QuestionKind::Custom | QuestionKind::Plugin = self.kind {
             let prompt = self
                 .opts
                 .prompt
                 .as_ref()
-                .expect("Parsing would error if no prompt was there");
+                .expect("Parsing would error if no prompt or plugin was there");
             // If just the name was passed into Question::custom, type errors associated
             // with its conversion to a string would take the span _including_ that of
             // prompt. Explicitly performing `String::from`, makes the error span due to
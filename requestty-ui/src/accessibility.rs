@@ -0,0 +1,53 @@
+//! An accessible mode for terminal screen readers.
+//!
+//! In accessible mode, [`Input`] never moves the cursor or redraws the prompt in place. Instead, it
+//! prints a new line with a plain description of the prompt whenever it changes, for example
+//! `Selected: Option B (3 of 7)`, which screen readers can read out as it is printed. Only prompts
+//! which [describe](crate::Prompt::describe) themselves can be shown this way, others are still
+//! rendered normally.
+//!
+//! [`Input`]: crate::Input
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether accessible mode is enabled.
+///
+/// If not set, it defaults to `false`.
+///
+/// Also see [`accessibility::set`](set).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::accessibility;
+/// # use requestty_ui::accessibility;
+///
+/// if accessibility::enabled() {
+///     println!("Using accessible mode");
+/// }
+/// ```
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enable or disable accessible mode for all prompts run after this.
+///
+/// Also see [`accessibility::enabled`](enabled).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::accessibility;
+/// # use requestty_ui::accessibility;
+///
+/// accessibility::set(true);
+/// assert!(accessibility::enabled());
+/// # accessibility::set(false);
+/// ```
+pub fn set(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
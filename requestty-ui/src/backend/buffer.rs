@@ -0,0 +1,604 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use super::{Backend, ClearType, MoveDirection, Size};
+use crate::style::{Attributes, Color, ColorSupport};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cell {
+    /// The text of the cell, which is empty if nothing was written to it. Zero width characters
+    /// are part of the cell of the character before them.
+    symbol: String,
+    /// The number of columns the symbol takes. The cell after a wide symbol is covered by it, and
+    /// has a width of 0.
+    width: u16,
+    fg: Color,
+    bg: Color,
+    attributes: Attributes,
+    /// The url the cell links to, if it is part of a hyperlink
+    link: Option<String>,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: String::new(),
+            width: 1,
+            fg: Color::Reset,
+            bg: Color::Reset,
+            attributes: Attributes::empty(),
+            link: None,
+        }
+    }
+}
+
+impl Cell {
+    fn is_empty(&self) -> bool {
+        self.symbol.is_empty() && self.width == 1
+    }
+}
+
+/// The style last set on the backend while drawing a [`Buffer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Style {
+    fg: Color,
+    bg: Color,
+    attributes: Attributes,
+}
+
+impl Style {
+    const RESET: Style = Style {
+        fg: Color::Reset,
+        bg: Color::Reset,
+        attributes: Attributes::empty(),
+    };
+
+    fn of(cell: &Cell) -> Self {
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            attributes: cell.attributes,
+        }
+    }
+
+    /// Sets the style on the backend if it is different from the current one, which is not known
+    /// if it is `None`.
+    fn apply<B: Backend + ?Sized>(self, current: &mut Option<Style>, b: &mut B) -> io::Result<()> {
+        let old = *current;
+
+        if old.map(|old| old.attributes) != Some(self.attributes) {
+            b.set_attributes(self.attributes)?;
+        }
+        if old.map(|old| old.fg) != Some(self.fg) {
+            b.set_fg(self.fg)?;
+        }
+        if old.map(|old| old.bg) != Some(self.bg) {
+            b.set_bg(self.bg)?;
+        }
+
+        *current = Some(self);
+        Ok(())
+    }
+}
+
+/// A grid of styled cells that a frame is rendered into before it is drawn on the terminal.
+///
+/// Drawing a frame with the one drawn before it only draws the cells which changed, so that the
+/// terminal does not flicker from clearing and writing the whole prompt on every key press.
+///
+/// The cursor is moved like it would be on a terminal, except that nothing scrolls. Characters
+/// written past the end of the last row overwrite it instead.
+#[derive(Debug, Clone)]
+pub(crate) struct Buffer {
+    cells: Vec<Cell>,
+    size: Size,
+    x: u16,
+    y: u16,
+    fg: Color,
+    bg: Color,
+    attributes: Attributes,
+    /// The url of the hyperlink being written, if any
+    link: Option<String>,
+    /// The start of a character which was split between two writes
+    partial: Vec<u8>,
+    /// The capabilities of the terminal the buffer is drawn to
+    color_support: ColorSupport,
+    unicode_support: bool,
+}
+
+impl Buffer {
+    pub(crate) fn new(size: Size) -> Self {
+        Self {
+            cells: vec![Cell::default(); size.area() as usize],
+            size,
+            x: 0,
+            y: 0,
+            fg: Color::Reset,
+            bg: Color::Reset,
+            attributes: Attributes::empty(),
+            link: None,
+            partial: Vec::new(),
+            color_support: ColorSupport::TrueColor,
+            unicode_support: true,
+        }
+    }
+
+    /// Gives the capabilities of `backend` when they are queried, so that what is rendered to the
+    /// buffer can adapt to the terminal it is drawn to.
+    pub(crate) fn with_capabilities_of<B: Backend + ?Sized>(mut self, backend: &B) -> Self {
+        self.color_support = backend.supports_colors();
+        self.unicode_support = backend.supports_unicode();
+        self
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.size.width as usize + x as usize
+    }
+
+    fn row(&self, y: u16) -> &[Cell] {
+        let start = self.index(0, y);
+        &self.cells[start..start + self.size.width as usize]
+    }
+
+    fn move_x(&mut self, x: u16) {
+        self.x = x.min(self.size.width.saturating_sub(1));
+    }
+
+    fn move_y(&mut self, y: u16) {
+        self.y = y.min(self.size.height.saturating_sub(1));
+    }
+
+    /// Resets the cell, along with the other half of a wide symbol it is a part of.
+    fn clear_cell(&mut self, i: usize) {
+        match self.cells[i].width {
+            0 if i > 0 => self.cells[i - 1] = Cell::default(),
+            2 if i + 1 < self.cells.len() => self.cells[i + 1] = Cell::default(),
+            _ => {}
+        }
+
+        self.cells[i] = Cell::default();
+    }
+
+    fn put_char(&mut self, c: char) {
+        match c {
+            '\n' => self.move_y(self.y + 1),
+            '\r' => self.x = 0,
+            '\t' => {
+                let x = 8 + self.x - (self.x % 8);
+                if x >= self.size.width && self.y + 1 < self.size.height {
+                    self.x = 0;
+                    self.y += 1;
+                } else {
+                    self.move_x(x);
+                }
+            }
+            c => {
+                let width = crate::layout::char_width(c);
+
+                if width == 0 {
+                    let x = self.x.saturating_sub(1);
+                    let i = self.index(x, self.y);
+                    let i = if self.cells[i].width == 0 { i - 1 } else { i };
+                    self.cells[i].symbol.push(c);
+                    return;
+                }
+
+                if self.x + width > self.size.width {
+                    self.x = 0;
+                    self.move_y(self.y + 1);
+                }
+
+                let i = self.index(self.x, self.y);
+                for j in i..(i + width as usize).min(self.cells.len()) {
+                    self.clear_cell(j);
+                }
+
+                self.cells[i] = Cell {
+                    symbol: c.to_string(),
+                    width,
+                    fg: self.fg,
+                    bg: self.bg,
+                    attributes: self.attributes,
+                    link: self.link.clone(),
+                };
+                if width == 2 && self.x + 1 < self.size.width {
+                    self.cells[i + 1].width = 0;
+                }
+
+                let x = self.x + width;
+                if x >= self.size.width {
+                    self.x = 0;
+                    self.move_y(self.y + 1);
+                } else {
+                    self.x = x;
+                }
+            }
+        }
+    }
+
+    /// Copies `count` rows of `src` starting at `src_row`, over the rows of this buffer starting at
+    /// `dst_row`. Both buffers must be as wide.
+    pub(crate) fn copy_rows(&mut self, src: &Buffer, src_row: u16, dst_row: u16, count: u16) {
+        debug_assert_eq!(self.size.width, src.size.width);
+
+        let src_start = src.index(0, src_row);
+        let dst_start = self.index(0, dst_row);
+        let len = count as usize * self.size.width as usize;
+
+        self.cells[dst_start..dst_start + len]
+            .clone_from_slice(&src.cells[src_start..src_start + len]);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        for i in start..end {
+            self.clear_cell(i);
+        }
+    }
+
+    /// Draws the rows from `start_row` to the bottom of the screen on the backend.
+    ///
+    /// If `prev` is the buffer which was drawn last, only the cells which changed are drawn.
+    /// Otherwise, the rows are cleared and everything is drawn.
+    pub(crate) fn draw<B: Backend + ?Sized>(
+        &self,
+        prev: Option<&Buffer>,
+        start_row: u16,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let prev = prev.filter(|prev| prev.size == self.size);
+
+        if prev.is_none() {
+            b.move_cursor_to(0, start_row)?;
+            b.clear(ClearType::FromCursorDown)?;
+        }
+
+        let mut style = None;
+        // Where the cursor is after the last write, so that it is only moved when required
+        let mut pos = None;
+
+        for y in start_row..self.size.height {
+            let row = self.row(y);
+            let prev_row = prev.map(|prev| prev.row(y));
+            // Everything after the last written cell can be cleared at once
+            let end = row
+                .iter()
+                .rposition(|cell| !cell.is_empty())
+                .map_or(0, |i| i + 1);
+
+            for (x, cell) in row.iter().enumerate().take(end) {
+                let unchanged = match prev_row {
+                    Some(prev_row) => prev_row[x] == *cell,
+                    None => cell.is_empty(),
+                };
+
+                if unchanged || cell.width == 0 {
+                    continue;
+                }
+
+                let x = x as u16;
+                if pos != Some((x, y)) {
+                    b.move_cursor_to(x, y)?;
+                }
+
+                Style::of(cell).apply(&mut style, b)?;
+                let symbol = if cell.symbol.is_empty() {
+                    " "
+                } else {
+                    &cell.symbol
+                };
+                match cell.link {
+                    // Every cell is written as a separate link to the same url
+                    Some(ref url) => b.write_hyperlink(url, &symbol)?,
+                    None => b.write_all(symbol.as_bytes())?,
+                }
+
+                pos = Some((x + cell.width, y));
+            }
+
+            let cleared = match prev_row {
+                Some(prev_row) => prev_row[end..].iter().any(|cell| !cell.is_empty()),
+                None => false,
+            };
+
+            if cleared {
+                let end = end as u16;
+                if pos != Some((end, y)) {
+                    b.move_cursor_to(end, y)?;
+                }
+
+                Style::RESET.apply(&mut style, b)?;
+                b.clear(ClearType::UntilNewLine)?;
+                pos = Some((end, y));
+            }
+        }
+
+        if style.is_some() {
+            Style::RESET.apply(&mut style, b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.partial.extend_from_slice(buf);
+        let bytes = std::mem::take(&mut self.partial);
+        let mut rest = &bytes[..];
+
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(s) => {
+                    s.chars().for_each(|c| self.put_char(c));
+                    break;
+                }
+                Err(e) => {
+                    let (valid, invalid) = rest.split_at(e.valid_up_to());
+                    std::str::from_utf8(valid)
+                        .expect("the bytes were checked to be valid")
+                        .chars()
+                        .for_each(|c| self.put_char(c));
+
+                    match e.error_len() {
+                        Some(len) => {
+                            self.put_char(char::REPLACEMENT_CHARACTER);
+                            rest = &invalid[len..];
+                        }
+                        None => {
+                            // The rest of the character will come in the next write
+                            self.partial = invalid.to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for Buffer {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        Ok((self.x, self.y))
+    }
+
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.move_x(x);
+        self.move_y(y);
+        Ok(())
+    }
+
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        match direction {
+            MoveDirection::Up(n) => self.y = self.y.saturating_sub(n),
+            MoveDirection::Down(n) => self.move_y(self.y.saturating_add(n)),
+            MoveDirection::Left(n) => self.x = self.x.saturating_sub(n),
+            MoveDirection::Right(n) => self.move_x(self.x.saturating_add(n)),
+            MoveDirection::NextLine(n) => {
+                self.x = 0;
+                self.move_y(self.y.saturating_add(n));
+            }
+            MoveDirection::Column(n) => self.move_x(n),
+            MoveDirection::PrevLine(n) => {
+                self.x = 0;
+                self.y = self.y.saturating_sub(n);
+            }
+        }
+        Ok(())
+    }
+
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        let len = self.cells.len();
+        let shift = (dist.unsigned_abs() as usize * self.size.width as usize).min(len);
+
+        // Like the `TestBackend`, a positive distance moves the content down
+        if dist.is_positive() {
+            self.cells.rotate_right(shift);
+            self.cells[..shift].fill(Cell::default());
+        } else {
+            self.cells.rotate_left(shift);
+            self.cells[len - shift..].fill(Cell::default());
+        }
+
+        Ok(())
+    }
+
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        self.attributes = attributes;
+        Ok(())
+    }
+
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        self.fg = color;
+        Ok(())
+    }
+
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        self.bg = color;
+        Ok(())
+    }
+
+    fn write_hyperlink(&mut self, url: &str, text: &dyn fmt::Display) -> io::Result<()> {
+        self.link = Some(url.to_owned());
+        let res = write!(self, "{}", text);
+        self.link = None;
+        res
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let width = self.size.width as usize;
+        let i = self.index(self.x, self.y);
+        let row_start = self.y as usize * width;
+
+        match clear_type {
+            ClearType::All => self.clear_range(0, self.cells.len()),
+            ClearType::FromCursorDown => self.clear_range(i, self.cells.len()),
+            ClearType::FromCursorUp => self.clear_range(0, i + 1),
+            ClearType::CurrentLine => self.clear_range(row_start, row_start + width),
+            ClearType::UntilNewLine => self.clear_range(i, row_start + width),
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn supports_colors(&self) -> ColorSupport {
+        self.color_support
+    }
+
+    fn supports_unicode(&self) -> bool {
+        self.unicode_support
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::backend::TestBackend;
+
+    fn frame(size: Size, lines: &[&str]) -> Buffer {
+        let mut buffer = Buffer::new(size);
+        for (y, line) in lines.iter().enumerate() {
+            buffer.move_cursor_to(0, y as u16).unwrap();
+            buffer.write_all(line.as_bytes()).unwrap();
+        }
+        buffer
+    }
+
+    fn drawn(size: Size, frame: &Buffer) -> TestBackend {
+        let mut backend = TestBackend::new(size);
+        frame.draw(None, 0, &mut backend).unwrap();
+        backend.move_cursor_to(0, 0).unwrap();
+        backend
+    }
+
+    #[test]
+    fn test_draw_unchanged() {
+        let size = (10, 3).into();
+        let prev = frame(size, &["hello", "world"]);
+        let mut backend = drawn(size, &prev);
+
+        // Anything which is not redrawn is left as it is
+        backend.move_cursor_to(8, 0).unwrap();
+        backend.write_all(b"x").unwrap();
+        backend.move_cursor_to(0, 0).unwrap();
+        let expected = backend.clone();
+
+        frame(size, &["hello", "world"])
+            .draw(Some(&prev), 0, &mut backend)
+            .unwrap();
+        assert_eq!(backend, expected);
+    }
+
+    #[test]
+    fn test_draw_diff() {
+        let size = (10, 3).into();
+        let prev = frame(size, &["hello", "world", "foo"]);
+        let mut backend = drawn(size, &prev);
+
+        let mut next = frame(size, &["help", "中a", ""]);
+        next.move_cursor_to(0, 2).unwrap();
+        next.set_fg(Color::Red).unwrap();
+        write!(next, "bar").unwrap();
+
+        next.draw(Some(&prev), 0, &mut backend).unwrap();
+        backend.move_cursor_to(0, 0).unwrap();
+        assert_eq!(backend, drawn(size, &next));
+
+        // a wide character is replaced by narrow ones
+        let prev = next;
+        let next = frame(size, &["help", "ab", "bar"]);
+        next.draw(Some(&prev), 0, &mut backend).unwrap();
+        backend.move_cursor_to(0, 0).unwrap();
+        assert_eq!(backend, drawn(size, &next));
+    }
+
+    #[test]
+    fn test_write() {
+        let size = (4, 2).into();
+        let mut buffer = Buffer::new(size);
+        // a character split between writes, and wrapping at the end of the line
+        buffer.write_all(&"ab中".as_bytes()[..3]).unwrap();
+        buffer.write_all(&"ab中c".as_bytes()[3..]).unwrap();
+
+        assert_eq!(buffer.get_cursor_pos().unwrap(), (1, 1));
+        assert_eq!(buffer.row(0)[2].symbol, "中");
+        assert_eq!(buffer.row(0)[3].width, 0);
+        assert_eq!(buffer.row(1)[0].symbol, "c");
+
+        // overwriting half of a wide character clears the other half
+        buffer.move_cursor_to(3, 0).unwrap();
+        buffer.write_all(b"d").unwrap();
+        assert!(buffer.row(0)[2].is_empty());
+        assert_eq!(buffer.row(0)[3].symbol, "d");
+    }
+
+    #[test]
+    fn test_hyperlink() {
+        use crate::style::Stylize;
+
+        let size = (10, 1).into();
+        let url = "https://example.com";
+        let mut buffer = Buffer::new(size);
+        buffer.write_all(b"a ").unwrap();
+        buffer.write_styled(&"link".hyperlink(url).bold()).unwrap();
+        buffer.write_all(b" b").unwrap();
+
+        let links: Vec<_> = buffer
+            .row(0)
+            .iter()
+            .map(|cell| cell.link.as_deref())
+            .collect();
+        let expected = [
+            None,
+            None,
+            Some(url),
+            Some(url),
+            Some(url),
+            Some(url),
+            None,
+            None,
+        ];
+        assert_eq!(links[..8], expected);
+
+        // the links are kept when drawing
+        let mut drawn_buffer = Buffer::new(size);
+        buffer.draw(None, 0, &mut drawn_buffer).unwrap();
+        assert_eq!(drawn_buffer.cells, buffer.cells);
+
+        // the test backend only shows the text
+        let mut expected = TestBackend::new(size);
+        expected.write_all(b"a ").unwrap();
+        expected.write_styled(&"link".bold()).unwrap();
+        expected.write_all(b" b").unwrap();
+        expected.move_cursor_to(0, 0).unwrap();
+        assert_eq!(drawn(size, &buffer), expected);
+    }
+}
@@ -1,10 +1,15 @@
 use std::{
     cmp::Ordering,
     io::{self, Write},
+    sync::mpsc,
+    thread,
+    time::Duration,
 };
+#[cfg(unix)]
+use std::{fs::File, os::unix::io::AsRawFd, os::unix::io::RawFd};
 
 use crossterm::{
-    cursor, queue,
+    cursor, event, queue,
     style::{
         Attribute as CAttribute, Color as CColor, SetAttribute, SetBackgroundColor,
         SetForegroundColor,
@@ -13,6 +18,10 @@ use crossterm::{
 };
 
 use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use crate::style::{color_support, ColorSupport};
+
+/// How long to wait for the terminal to respond to a query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(100);
 
 /// A backend that uses the `crossterm` library.
 #[derive(Debug, Clone)]
@@ -20,6 +29,11 @@ use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
 pub struct CrosstermBackend<W> {
     buffer: W,
     attributes: Attributes,
+    /// The controlling terminal if the backend was created with
+    /// [`from_tty`](CrosstermBackend::from_tty), which the cursor position is queried on since
+    /// crossterm always uses stdout
+    #[cfg(unix)]
+    tty: Option<RawFd>,
 }
 
 impl<W> CrosstermBackend<W> {
@@ -28,10 +42,29 @@ impl<W> CrosstermBackend<W> {
         CrosstermBackend {
             buffer,
             attributes: Attributes::empty(),
+            #[cfg(unix)]
+            tty: None,
         }
     }
 }
 
+#[cfg(unix)]
+impl CrosstermBackend<File> {
+    /// Creates a new [`CrosstermBackend`] which uses the controlling terminal (`/dev/tty`) instead
+    /// of stdout, so that prompts can be shown even if stdout is redirected.
+    ///
+    /// crossterm already reads keys from the terminal if stdin is redirected. Keys typed while
+    /// the position of the cursor is being queried are lost.
+    pub fn from_tty() -> io::Result<Self> {
+        let tty = crate::tty::open()?;
+        let fd = tty.as_raw_fd();
+
+        let mut backend = Self::new(tty);
+        backend.tty = Some(fd);
+        Ok(backend)
+    }
+}
+
 impl<W: Write> Write for CrosstermBackend<W> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.buffer.write(buf)
@@ -59,8 +92,74 @@ impl<W: Write> Backend for CrosstermBackend<W> {
         queue!(self.buffer, cursor::Show)
     }
 
+    fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::EnableMouseCapture)
+    }
+
+    fn disable_mouse_capture(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::DisableMouseCapture)
+    }
+
+    // The windows console already reports the modifiers of every key, and does not support the
+    // escape codes.
+    #[cfg(unix)]
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        queue!(
+            self.buffer,
+            event::PushKeyboardEnhancementFlags(
+                event::KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+            )
+        )
+    }
+
+    #[cfg(unix)]
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::PopKeyboardEnhancementFlags)
+    }
+
+    // The legacy windows console does not support bracketed paste either
+    #[cfg(unix)]
+    fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::EnableBracketedPaste)
+    }
+
+    #[cfg(unix)]
+    fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::DisableBracketedPaste)
+    }
+
+    #[cfg(unix)]
+    fn suspend(&mut self) -> io::Result<()> {
+        self.buffer.flush()?;
+        super::suspend_process()
+    }
+
+    fn uses_controlling_terminal(&self) -> bool {
+        true
+    }
+
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
-        cursor::position()
+        #[cfg(unix)]
+        if let Some(fd) = self.tty {
+            write!(self.buffer, "\x1B[6n")?;
+            self.buffer.flush()?;
+            return crate::tty::read_cursor_pos(fd, &mut Vec::new(), QUERY_TIMEOUT);
+        }
+
+        // crossterm waits for up to 2 seconds for the terminal to reply, and keeps waiting if
+        // reading from stdin fails. The query is made on another thread so that it can be given up
+        // on sooner, in which case the reply is still read by that thread when it arrives.
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(cursor::position());
+        });
+
+        receiver.recv_timeout(QUERY_TIMEOUT).unwrap_or_else(|_| {
+            Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "Cursor position detection timed out.",
+            ))
+        })
     }
 
     fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
@@ -96,16 +195,30 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     }
 
     fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
         set_attributes(self.attributes, attributes, &mut self.buffer)?;
         self.attributes = attributes;
         Ok(())
     }
 
     fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let color = color.degrade(self.supports_colors());
         queue!(self.buffer, SetForegroundColor(color.into()))
     }
 
     fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let color = color.degrade(self.supports_colors());
         queue!(self.buffer, SetBackgroundColor(color.into()))
     }
 
@@ -116,6 +229,14 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     fn size(&self) -> io::Result<Size> {
         terminal::size().map(Into::into)
     }
+
+    fn supports_colors(&self) -> ColorSupport {
+        color_support()
+    }
+
+    fn supports_unicode(&self) -> bool {
+        super::unicode_support()
+    }
 }
 
 impl From<Color> for CColor {
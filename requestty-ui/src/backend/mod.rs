@@ -17,8 +17,35 @@ pub fn get_backend<W: io::Write>(buf: W) -> impl Backend {
     return TermionBackend::new(buf);
 }
 
+/// Gets the default [`Backend`] based on the features enabled, using the controlling terminal
+/// (`/dev/tty`) instead of stdout.
+///
+/// This can be used to show prompts even if stdout is redirected, for example to a file. See
+/// [`CrosstermBackend::from_tty`] and [`TermionBackend::from_tty`] for more details.
+#[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(unix, any(feature = "crossterm", feature = "termion"))))
+)]
+pub fn get_tty_backend() -> io::Result<impl Backend> {
+    #[cfg(feature = "crossterm")]
+    return CrosstermBackend::from_tty();
+
+    #[cfg(not(feature = "crossterm"))]
+    return TermionBackend::from_tty();
+}
+
+mod buffer;
+pub(crate) use buffer::Buffer;
+
 mod test_backend;
-pub use test_backend::TestBackend;
+pub use test_backend::{StyledChar, TestBackend};
+
+mod wrapped;
+pub(crate) use wrapped::Wrapped;
+
+mod terminal_pair;
+pub use terminal_pair::TerminalPair;
 
 #[cfg(feature = "termion")]
 mod termion;
@@ -32,7 +59,7 @@ mod crossterm;
 #[cfg(feature = "crossterm")]
 pub use self::crossterm::CrosstermBackend;
 
-use crate::style::{Attributes, Color, Styled};
+use crate::style::{Attributes, Color, ColorSupport, Styled};
 
 /// A 2D size.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
@@ -100,6 +127,73 @@ pub trait Backend: io::Write {
     /// Shows the cursor.
     fn show_cursor(&mut self) -> io::Result<()>;
 
+    /// Starts capturing mouse events, so that they are reported by the
+    /// [`EventIterator`](crate::events::EventIterator).
+    ///
+    /// By default, this does nothing.
+    fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Stops capturing mouse events.
+    ///
+    /// By default, this does nothing.
+    fn disable_mouse_capture(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Asks the terminal to report keys with modifiers unambiguously, so that keys like
+    /// `Shift+Enter` can be told apart from `Enter`.
+    ///
+    /// Only some terminals support this, and the rest will continue to report keys as before. See
+    /// [`KeyModifiers`](crate::events::KeyModifiers) for which modifiers can be reported.
+    ///
+    /// By default, this does nothing.
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Stops reporting keys with modifiers unambiguously.
+    ///
+    /// By default, this does nothing.
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Asks the terminal to mark where pasted text starts and ends, so that it is reported as a
+    /// single [`Event::Paste`](crate::events::Event::Paste) instead of a key for every character.
+    ///
+    /// This is used by [`Input`](crate::Input) with the `bracketed-paste` feature.
+    ///
+    /// By default, this does nothing.
+    fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Stops marking where pasted text starts and ends.
+    ///
+    /// By default, this does nothing.
+    fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Suspends the process, returning once it is resumed.
+    ///
+    /// Terminals do not send `SIGTSTP` for `Ctrl+Z` in raw mode, so [`Input`](crate::Input) calls
+    /// this after disabling raw mode instead.
+    ///
+    /// By default, this does nothing.
+    fn suspend(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Whether raw mode is enabled on the controlling terminal of the process, which has to be
+    /// restored if the process is terminated while a prompt is shown.
+    ///
+    /// With the `signals` feature, [`Input`](crate::Input) only restores the terminal on signals
+    /// for backends which return `true`. By default, this is `false`. The `crossterm` and
+    /// `termion` backends return `true`.
+    fn uses_controlling_terminal(&self) -> bool {
+        false
+    }
+
     /// Gets the cursor position as (col, row). The top-left cell is (0, 0).
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)>;
     /// Moves the cursor to given position. The top-left cell is (0, 0).
@@ -129,10 +223,130 @@ pub trait Backend: io::Write {
         styled.write(self)
     }
 
+    /// Writes `text` as a hyperlink to `url`, which can be clicked in terminals that support
+    /// [OSC 8] hyperlinks. Other terminals show the `text` as it is.
+    ///
+    /// By default, the OSC 8 escape sequences are only written with the `hyperlinks` feature, and
+    /// only the `text` is written otherwise.
+    ///
+    /// See also [`Stylize::hyperlink`].
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    /// [`Stylize::hyperlink`]: crate::style::Stylize::hyperlink
+    fn write_hyperlink(&mut self, url: &str, text: &dyn Display) -> io::Result<()> {
+        if cfg!(feature = "hyperlinks") {
+            write!(self, "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+        } else {
+            write!(self, "{}", text)
+        }
+    }
+
     /// Clears the cells given by clear_type
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()>;
     /// Gets the size of the terminal in rows and columns.
     fn size(&self) -> io::Result<Size>;
+    /// Tells the backend that the terminal has been resized to `size`, so that backends which
+    /// remember the size can update it. [`Input`](crate::Input) calls this on
+    /// [`Event::Resize`](crate::events::Event::Resize).
+    ///
+    /// By default, this does nothing.
+    fn resized(&mut self, _size: Size) {}
+
+    /// The colors the terminal can display, so that widgets can choose colors which can be told
+    /// apart on it. Colors which are not supported are still [degraded](Color::degrade) by the
+    /// built-in backends before they are written.
+    ///
+    /// By default, this is [`ColorSupport::TrueColor`]. The built-in terminal backends give the
+    /// current [`color_support`](crate::style::color_support) instead, which can be overridden.
+    fn supports_colors(&self) -> ColorSupport {
+        ColorSupport::TrueColor
+    }
+    /// Whether the terminal can display characters outside of ascii, so that widgets can fall back
+    /// to ascii glyphs on terminals which cannot.
+    ///
+    /// By default, this is `true`. The `crossterm` and `termion` backends detect it from the locale
+    /// instead. It is always `true` on Windows. Elsewhere, the first of `$LC_ALL`, `$LC_CTYPE` and
+    /// `$LANG` which is set must name a UTF-8 locale, like `en_US.UTF-8`.
+    fn supports_unicode(&self) -> bool {
+        true
+    }
+
+    /// Runs `f` on another thread, animating a [`Spinner`] with the `message` on the current line
+    /// until it returns.
+    ///
+    /// The terminal is in raw mode while the spinner is shown, and the line is cleared before
+    /// returning. If `f` panics, the panic is resumed on the calling thread.
+    ///
+    /// ```no_run
+    /// # fn check_availability() -> bool { true }
+    /// # #[cfg(feature = "crossterm")]
+    /// # fn main() -> std::io::Result<()> {
+    /// use requestty_ui::backend::{get_backend, Backend};
+    ///
+    /// let mut backend = get_backend(std::io::stdout());
+    /// let available = backend.with_spinner("Checking availability...", check_availability)?;
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "crossterm"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`Spinner`]: crate::widgets::Spinner
+    fn with_spinner<M, F, T>(&mut self, message: M, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        M: AsRef<str>,
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        crate::spinner::with_spinner(self, message, f)
+    }
+}
+
+/// Stops the process like the terminal would on `Ctrl+Z`, which returns once it is continued.
+#[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+fn suspend_process() -> io::Result<()> {
+    // SAFETY: `raise` has no preconditions, and the default action for `SIGTSTP` is to stop the
+    // process
+    #[allow(unsafe_code)]
+    let res = unsafe { libc::raise(libc::SIGTSTP) };
+
+    if res == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+static UNICODE_SUPPORT: once_cell::sync::Lazy<bool> = once_cell::sync::Lazy::new(|| {
+    let var = |name| std::env::var(name).ok();
+    unicode_from_vars(&[var("LC_ALL"), var("LC_CTYPE"), var("LANG")])
+});
+
+/// Whether the terminal can display unicode, as detected from the locale by
+/// [`Backend::supports_unicode`].
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+fn unicode_support() -> bool {
+    *UNICODE_SUPPORT
+}
+
+/// The locale variables are given in order of precedence, where the first which is set and not
+/// empty is the one in effect.
+#[cfg(any(test, feature = "crossterm", feature = "termion"))]
+fn unicode_from_vars(vars: &[Option<String>]) -> bool {
+    // Windows does not use the locale variables, and its console can display unicode
+    if cfg!(windows) {
+        return true;
+    }
+
+    match vars.iter().flatten().find(|var| !var.is_empty()) {
+        Some(locale) => {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => false,
+    }
 }
 
 fn default_move_cursor<B: Backend + ?Sized>(
@@ -160,7 +374,7 @@ fn default_move_cursor<B: Backend + ?Sized>(
     backend.move_cursor_to(x, y)
 }
 
-impl<'a, B: Backend> Backend for &'a mut B {
+impl<'a, B: Backend + ?Sized> Backend for &'a mut B {
     fn enable_raw_mode(&mut self) -> io::Result<()> {
         (**self).enable_raw_mode()
     }
@@ -173,6 +387,30 @@ impl<'a, B: Backend> Backend for &'a mut B {
     fn show_cursor(&mut self) -> io::Result<()> {
         (**self).show_cursor()
     }
+    fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        (**self).enable_mouse_capture()
+    }
+    fn disable_mouse_capture(&mut self) -> io::Result<()> {
+        (**self).disable_mouse_capture()
+    }
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        (**self).enable_keyboard_enhancement()
+    }
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        (**self).disable_keyboard_enhancement()
+    }
+    fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        (**self).enable_bracketed_paste()
+    }
+    fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        (**self).disable_bracketed_paste()
+    }
+    fn suspend(&mut self) -> io::Result<()> {
+        (**self).suspend()
+    }
+    fn uses_controlling_terminal(&self) -> bool {
+        (**self).uses_controlling_terminal()
+    }
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
         (**self).get_cursor_pos()
     }
@@ -197,10 +435,50 @@ impl<'a, B: Backend> Backend for &'a mut B {
     fn write_styled(&mut self, styled: &Styled<dyn Display + '_>) -> io::Result<()> {
         (**self).write_styled(styled)
     }
+    fn write_hyperlink(&mut self, url: &str, text: &dyn Display) -> io::Result<()> {
+        (**self).write_hyperlink(url, text)
+    }
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
         (**self).clear(clear_type)
     }
     fn size(&self) -> io::Result<Size> {
         (**self).size()
     }
+    fn resized(&mut self, size: Size) {
+        (**self).resized(size)
+    }
+    fn supports_colors(&self) -> ColorSupport {
+        (**self).supports_colors()
+    }
+    fn supports_unicode(&self) -> bool {
+        (**self).supports_unicode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_from_vars() {
+        let vars = |vars: [Option<&str>; 3]| vars.map(|var| var.map(String::from));
+
+        assert!(unicode_from_vars(&vars([None, None, Some("en_US.UTF-8")])));
+        assert!(unicode_from_vars(&vars([None, Some("C.utf8"), Some("C")])));
+        assert!(unicode_from_vars(&vars([
+            Some(""),
+            None,
+            Some("de_DE.utf-8")
+        ])));
+
+        if !cfg!(windows) {
+            assert!(!unicode_from_vars(&vars([
+                Some("C"),
+                None,
+                Some("en_US.UTF-8")
+            ])));
+            assert!(!unicode_from_vars(&vars([None, None, Some("POSIX")])));
+            assert!(!unicode_from_vars(&vars([None, None, None])));
+        }
+    }
 }
@@ -0,0 +1,374 @@
+use std::{
+    cmp::Ordering,
+    io::{self, Read, Write},
+    sync::{Arc, Mutex},
+};
+
+use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use crate::{
+    events::{PairInput, TerminalPairEvents},
+    style::{color_support, ColorSupport},
+};
+
+/// A backend for any vt100 compatible terminal connected through a reader and a writer, such as a
+/// socket or the master side of a PTY.
+///
+/// Output is written to the writer as escape sequences, and the position of the cursor is queried
+/// by reading the reply of the terminal from the reader. Keys are read from the same reader by the
+/// [`TerminalPairEvents`] returned by [`events`](TerminalPair::events).
+///
+/// Since the terminal is on the other end of the connection, raw mode cannot be enabled by this
+/// backend, and enabling it does nothing. It is up to the other end to send its input as it is
+/// typed, for example by requesting a PTY over SSH. The size of the terminal cannot be read
+/// directly either, so it has to be set with [`with_size`](TerminalPair::with_size) or
+/// [`set_size`](TerminalPair::set_size) (for example with the size sent over SSH), or detected
+/// with [`detect_size`](TerminalPair::detect_size). It defaults to 80x24.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use std::net::TcpListener;
+///
+/// use requestty_ui::backend::{Size, TerminalPair};
+///
+/// let (stream, _) = TcpListener::bind("127.0.0.1:4000")?.accept()?;
+///
+/// let mut backend = TerminalPair::new(stream.try_clone()?, stream);
+/// backend.detect_size()?;
+/// let mut events = backend.events();
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TerminalPair<R, W> {
+    input: Arc<Mutex<PairInput<R>>>,
+    writer: W,
+    attributes: Attributes,
+    size: Size,
+}
+
+impl<R: Read, W: Write> TerminalPair<R, W> {
+    /// Creates a new `TerminalPair` which reads input from `reader` and writes to `writer`.
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            input: PairInput::new(reader),
+            writer,
+            attributes: Attributes::empty(),
+            size: Size {
+                width: 80,
+                height: 24,
+            },
+        }
+    }
+
+    /// Sets the size of the terminal.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the size of the terminal, for example once the other end reports it has been resized.
+    pub fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    /// Detects the size of the terminal by moving the cursor to the bottom right corner and
+    /// querying its position, and returns it. The cursor is restored afterwards.
+    pub fn detect_size(&mut self) -> io::Result<Size> {
+        self.writer.write_all(b"\x1B7\x1B[999;999H")?;
+        let pos = self.get_cursor_pos();
+        self.writer.write_all(b"\x1B8")?;
+        self.writer.flush()?;
+
+        let (x, y) = pos?;
+        self.size = Size {
+            width: x + 1,
+            height: y + 1,
+        };
+
+        Ok(self.size)
+    }
+
+    /// Creates the [`TerminalPairEvents`] which read keys from the reader of this backend.
+    pub fn events(&self) -> TerminalPairEvents<R> {
+        TerminalPairEvents::new(Arc::clone(&self.input))
+    }
+
+    /// Gets a reference to the writer.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the writer.
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<R, W: Write> Write for TerminalPair<R, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<R: Read, W: Write> Backend for TerminalPair<R, W> {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1B[?25l")
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1B[?25h")
+    }
+
+    fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1B[?2004h")
+    }
+
+    fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1B[?2004l")
+    }
+
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        self.writer.write_all(b"\x1B[6n")?;
+        self.writer.flush()?;
+
+        self.input.lock().expect("input poisoned").read_cursor_pos()
+    }
+
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        write!(self.writer, "\x1B[{};{}H", y + 1, x + 1)
+    }
+
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        let (n, c) = match direction {
+            MoveDirection::Up(n) => (n, 'A'),
+            MoveDirection::Down(n) => (n, 'B'),
+            MoveDirection::Right(n) => (n, 'C'),
+            MoveDirection::Left(n) => (n, 'D'),
+            MoveDirection::NextLine(n) => (n, 'E'),
+            MoveDirection::PrevLine(n) => (n, 'F'),
+            MoveDirection::Column(x) => return write!(self.writer, "\x1B[{}G", x + 1),
+        };
+
+        // A distance of 0 moves the cursor by 1
+        match n {
+            0 if c == 'E' || c == 'F' => self.writer.write_all(b"\r"),
+            0 => Ok(()),
+            n => write!(self.writer, "\x1B[{}{}", n, c),
+        }
+    }
+
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        match dist.cmp(&0) {
+            Ordering::Greater => write!(self.writer, "\x1B[{}T", dist),
+            Ordering::Less => write!(self.writer, "\x1B[{}S", -(dist as i32)),
+            Ordering::Equal => Ok(()),
+        }
+    }
+
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let diff = self.attributes.diff(attributes);
+        let mut to_add = diff.to_add;
+        let mut codes = Vec::new();
+
+        // Bold and dim are removed together, and so are the blinks, so the ones that remain are
+        // added back
+        if diff
+            .to_remove
+            .intersects(Attributes::BOLD | Attributes::DIM)
+        {
+            codes.push(22);
+            to_add |= attributes & (Attributes::BOLD | Attributes::DIM);
+        }
+        if diff
+            .to_remove
+            .intersects(Attributes::SLOW_BLINK | Attributes::RAPID_BLINK)
+        {
+            codes.push(25);
+            to_add |= attributes & (Attributes::SLOW_BLINK | Attributes::RAPID_BLINK);
+        }
+
+        let sgr = [
+            (Attributes::BOLD, 1, 22),
+            (Attributes::DIM, 2, 22),
+            (Attributes::ITALIC, 3, 23),
+            (Attributes::UNDERLINED, 4, 24),
+            (Attributes::SLOW_BLINK, 5, 25),
+            (Attributes::RAPID_BLINK, 6, 25),
+            (Attributes::REVERSED, 7, 27),
+            (Attributes::HIDDEN, 8, 28),
+            (Attributes::CROSSED_OUT, 9, 29),
+        ];
+
+        for &(attribute, _, remove) in sgr.iter() {
+            if diff.to_remove.contains(attribute) && !codes.contains(&remove) {
+                codes.push(remove);
+            }
+        }
+        for &(attribute, add, _) in sgr.iter() {
+            if to_add.contains(attribute) {
+                codes.push(add);
+            }
+        }
+
+        self.attributes = attributes;
+        write_sgr(&mut self.writer, &codes)
+    }
+
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let color = color.degrade(self.supports_colors());
+        write_color(&mut self.writer, color, 30)
+    }
+
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let color = color.degrade(self.supports_colors());
+        write_color(&mut self.writer, color, 40)
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let seq: &[u8] = match clear_type {
+            ClearType::All => b"\x1B[2J",
+            ClearType::FromCursorDown => b"\x1B[J",
+            ClearType::FromCursorUp => b"\x1B[1J",
+            ClearType::CurrentLine => b"\x1B[2K",
+            ClearType::UntilNewLine => b"\x1B[K",
+        };
+
+        self.writer.write_all(seq)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    // The terminal may not be the one this process runs in, so unicode support cannot be detected
+    // from the environment, and is left to the default.
+    fn supports_colors(&self) -> ColorSupport {
+        color_support()
+    }
+}
+
+/// Writes a select graphic rendition sequence with the given codes.
+fn write_sgr<W: Write>(w: &mut W, codes: &[u8]) -> io::Result<()> {
+    if codes.is_empty() {
+        return Ok(());
+    }
+
+    w.write_all(b"\x1B[")?;
+    for (i, code) in codes.iter().enumerate() {
+        if i != 0 {
+            w.write_all(b";")?;
+        }
+        write!(w, "{}", code)?;
+    }
+    w.write_all(b"m")
+}
+
+/// Writes the sequence to set the foreground (`base = 30`) or background (`base = 40`) color.
+fn write_color<W: Write>(w: &mut W, color: Color, base: u8) -> io::Result<()> {
+    let code = match color {
+        Color::Reset => base + 9,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Grey => base + 7,
+        Color::DarkGrey => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Ansi(i) => return write!(w, "\x1B[{};5;{}m", base + 8, i),
+        Color::Rgb(r, g, b) => return write!(w, "\x1B[{};2;{};{};{}m", base + 8, r, g, b),
+    };
+
+    write_sgr(w, &[code])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::{EventIterator, KeyCode};
+
+    use super::*;
+
+    fn output(f: impl FnOnce(&mut TerminalPair<&[u8], Vec<u8>>) -> io::Result<()>) -> String {
+        let mut backend = TerminalPair::new(&b""[..], Vec::new());
+        f(&mut backend).unwrap();
+        String::from_utf8(backend.writer).unwrap()
+    }
+
+    #[test]
+    fn test_output() {
+        assert_eq!(output(|b| b.move_cursor_to(3, 0)), "\x1B[1;4H");
+        assert_eq!(output(|b| b.move_cursor(MoveDirection::Up(2))), "\x1B[2A");
+        assert_eq!(output(|b| b.move_cursor(MoveDirection::Left(0))), "");
+        assert_eq!(
+            output(|b| b.move_cursor(MoveDirection::Column(0))),
+            "\x1B[1G"
+        );
+        assert_eq!(output(|b| b.scroll(-3)), "\x1B[3S");
+        assert_eq!(output(|b| b.clear(ClearType::CurrentLine)), "\x1B[2K");
+
+        if cfg!(feature = "no-style") {
+            return;
+        }
+
+        assert_eq!(
+            output(|b| {
+                b.set_fg(Color::LightCyan)?;
+                b.set_bg(Color::Reset)
+            }),
+            "\x1B[96m\x1B[49m"
+        );
+        assert_eq!(
+            output(|b| {
+                b.set_attributes(Attributes::BOLD | Attributes::DIM | Attributes::ITALIC)?;
+                b.set_attributes(Attributes::DIM)
+            }),
+            "\x1B[1;2;3m\x1B[22;23;2m"
+        );
+    }
+
+    #[test]
+    fn test_cursor_pos() {
+        let mut backend = TerminalPair::new(&b"x\x1B[24;80Ry"[..], Vec::new());
+        let mut events = backend.events();
+
+        assert_eq!(backend.detect_size().unwrap(), Size::from((80, 24)));
+        assert_eq!(backend.writer(), b"\x1B7\x1B[999;999H\x1B[6n\x1B8");
+
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('x').into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('y').into());
+    }
+}
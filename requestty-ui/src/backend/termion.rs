@@ -1,8 +1,10 @@
 use std::{
     cmp::Ordering,
     fmt,
+    fs::File,
     io::{self, Write},
     ops::{Deref, DerefMut},
+    os::unix::io::AsRawFd,
 };
 
 use termion::{
@@ -12,6 +14,7 @@ use termion::{
 };
 
 use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use crate::style::{color_support, ColorSupport};
 
 enum Terminal<W: Write> {
     Raw(RawTerminal<W>),
@@ -46,7 +49,18 @@ impl<W: Write> DerefMut for Terminal<W> {
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
 pub struct TermionBackend<W: Write> {
     attributes: Attributes,
+    /// The raw mode of the controlling terminal if the backend was created with
+    /// [`from_tty`](TermionBackend::from_tty), since termion always uses stdout. It must be
+    /// dropped before the buffer, which owns the terminal.
+    tty: Option<crate::tty::RawMode>,
     buffer: Terminal<W>,
+    low_latency: bool,
+    /// The position of the cursor tracked in low latency mode
+    cursor_pos: Option<(u16, u16)>,
+    /// The size of the terminal used to track the cursor position, so that it is not queried on
+    /// every write. It is refreshed when the cursor position is queried and on resizes.
+    size: Option<Size>,
+    write_state: WriteState,
 }
 
 impl<W: Write> TermionBackend<W> {
@@ -54,14 +68,183 @@ impl<W: Write> TermionBackend<W> {
     pub fn new(buffer: W) -> TermionBackend<W> {
         TermionBackend {
             buffer: Terminal::Normal(buffer),
+            tty: None,
             attributes: Attributes::empty(),
+            low_latency: false,
+            cursor_pos: None,
+            size: None,
+            write_state: WriteState::default(),
         }
     }
+
+    /// Avoid querying the terminal for the cursor position after the first time.
+    ///
+    /// Querying the cursor position requires reading the reply of the terminal from stdin, which
+    /// can block if the terminal does not reply. In low latency mode, the cursor position is only
+    /// queried once, after which it is tracked as the cursor is moved and text is written. If the
+    /// first query times out, the cursor is moved to the start of a new line at the bottom of the
+    /// screen, and tracked from there.
+    ///
+    /// Note that the tracked position will be wrong if something writes to the terminal without
+    /// going through this backend.
+    pub fn low_latency(mut self, low_latency: bool) -> Self {
+        self.low_latency = low_latency;
+        self
+    }
+
+    /// Updates the tracked cursor position after writing `buf`.
+    fn track_write(&mut self, buf: &[u8]) {
+        if let Some(pos) = self.cursor_pos {
+            let size = match self.size {
+                Some(size) => size,
+                None => {
+                    let size = self.size().unwrap_or(Size {
+                        width: u16::MAX,
+                        height: u16::MAX,
+                    });
+                    self.size = Some(size);
+                    size
+                }
+            };
+
+            self.cursor_pos = Some(self.write_state.track(buf, pos, size));
+        }
+    }
+}
+
+/// The part of an escape sequence which has been written so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    None,
+    /// An `ESC` which has not been followed by anything yet.
+    Esc,
+    /// A control sequence (`ESC [`), which ends with a byte in `0x40..=0x7E`.
+    Csi,
+    /// An operating system command (`ESC ]`), which ends with `BEL` or `ESC \`.
+    Osc,
+    /// An `ESC` within an operating system command.
+    OscEsc,
+}
+
+/// The state of the bytes written in low latency mode, since escape sequences and characters can
+/// be split across writes.
+#[derive(Debug)]
+struct WriteState {
+    escape: Escape,
+    /// The leading bytes of a character which has not been completely written yet.
+    partial: Vec<u8>,
+}
+
+impl Default for WriteState {
+    fn default() -> Self {
+        Self {
+            escape: Escape::None,
+            partial: Vec::with_capacity(4),
+        }
+    }
+}
+
+impl WriteState {
+    /// Gives the position of the cursor after writing `buf` starting at `(x, y)`.
+    ///
+    /// Escape sequences are not printed and so are skipped, and characters are wrapped the same
+    /// way as the [`Buffer`](super::buffer::Buffer) wraps them.
+    fn track(&mut self, buf: &[u8], (mut x, mut y): (u16, u16), size: Size) -> (u16, u16) {
+        let last_row = size.height.saturating_sub(1);
+
+        for &b in buf {
+            self.escape = match (self.escape, b) {
+                (Escape::Esc, b'[') => Escape::Csi,
+                (Escape::Esc, b']') => Escape::Osc,
+                (Escape::Esc, _) => Escape::None,
+                (Escape::Csi, 0x40..=0x7e) => Escape::None,
+                (Escape::Osc, 0x07) | (Escape::OscEsc, b'\\') => Escape::None,
+                (Escape::Osc, 0x1b) => Escape::OscEsc,
+                (Escape::OscEsc, _) => Escape::Osc,
+                (Escape::None, 0x1b) => {
+                    self.partial.clear();
+                    Escape::Esc
+                }
+                (Escape::None, b) => {
+                    self.partial.push(b);
+
+                    match std::str::from_utf8(&self.partial) {
+                        Ok(c) => {
+                            let c = c.chars().next().expect("at least one byte was pushed");
+                            self.partial.clear();
+
+                            match c {
+                                '\n' => y = (y + 1).min(last_row),
+                                '\r' => x = 0,
+                                '\t' => {
+                                    x = 8 + x - (x % 8);
+                                    if x >= size.width && y < last_row {
+                                        x = 0;
+                                        y += 1;
+                                    } else {
+                                        x = x.min(size.width - 1);
+                                    }
+                                }
+                                '\x08' => x = x.saturating_sub(1),
+                                c => {
+                                    let width = crate::layout::char_width(c);
+
+                                    if width > 0 && x + width > size.width {
+                                        x = 0;
+                                        y = (y + 1).min(last_row);
+                                    }
+
+                                    x += width;
+                                    if x >= size.width {
+                                        x = 0;
+                                        y = (y + 1).min(last_row);
+                                    }
+                                }
+                            }
+                        }
+                        // The character is invalid, so it is skipped
+                        Err(e) if e.error_len().is_some() => self.partial.clear(),
+                        // The rest of the character is yet to be written
+                        Err(_) => {}
+                    }
+
+                    Escape::None
+                }
+                (escape, _) => escape,
+            };
+        }
+
+        (x, y)
+    }
+}
+
+impl TermionBackend<File> {
+    /// Creates a new [`TermionBackend`] which uses the controlling terminal (`/dev/tty`) instead
+    /// of stdout, so that prompts can be shown even if stdout is redirected.
+    ///
+    /// Raw mode is enabled on the terminal, and its size is read from it. Once the terminal has
+    /// been opened, [`TermionEvents`] read from it as well.
+    ///
+    /// [`TermionEvents`]: crate::events::TermionEvents
+    pub fn from_tty() -> io::Result<Self> {
+        let tty = crate::events::open_tty()?;
+        let raw_mode = crate::tty::RawMode::new(tty.as_raw_fd());
+
+        let mut backend = Self::new(tty);
+        backend.tty = Some(raw_mode);
+        Ok(backend)
+    }
 }
 
 impl<W: Write> Write for TermionBackend<W> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.buffer.write(buf)
+        let n = self.buffer.write(buf)?;
+
+        if self.low_latency {
+            self.track_write(&buf[..n]);
+        }
+
+        Ok(n)
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -71,6 +254,10 @@ impl<W: Write> Write for TermionBackend<W> {
 
 impl<W: Write> Backend for TermionBackend<W> {
     fn enable_raw_mode(&mut self) -> io::Result<()> {
+        if let Some(ref mut tty) = self.tty {
+            return tty.enable();
+        }
+
         match self.buffer {
             Terminal::Raw(ref mut buf) => buf.activate_raw_mode(),
             Terminal::Normal(_) => {
@@ -88,6 +275,10 @@ impl<W: Write> Backend for TermionBackend<W> {
     }
 
     fn disable_raw_mode(&mut self) -> io::Result<()> {
+        if let Some(ref mut tty) = self.tty {
+            return tty.disable();
+        }
+
         match self.buffer {
             Terminal::Raw(ref buf) => buf.suspend_raw_mode(),
             Terminal::Normal(_) => {
@@ -109,13 +300,69 @@ impl<W: Write> Backend for TermionBackend<W> {
         write!(self.buffer, "{}", cursor::Show)
     }
 
+    fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        // The same sequence as `termion::input::MouseTerminal`, which cannot be used directly since
+        // it wraps the writer
+        self.buffer
+            .write_all(b"\x1b[?1000h\x1b[?1002h\x1b[?1015h\x1b[?1006h")
+    }
+
+    fn disable_mouse_capture(&mut self) -> io::Result<()> {
+        self.buffer
+            .write_all(b"\x1b[?1006l\x1b[?1015l\x1b[?1002l\x1b[?1000l")
+    }
+
+    fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        self.buffer.write_all(b"\x1b[?2004h")
+    }
+
+    fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        self.buffer.write_all(b"\x1b[?2004l")
+    }
+
+    fn suspend(&mut self) -> io::Result<()> {
+        self.buffer.flush()?;
+        super::suspend_process()
+    }
+
+    fn uses_controlling_terminal(&self) -> bool {
+        true
+    }
+
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
-        cursor::DetectCursorPos::cursor_pos(&mut *self.buffer)
-            // 0 index the position
-            .map(|(x, y)| (x - 1, y - 1))
+        if let (true, Some(pos)) = (self.low_latency, self.cursor_pos) {
+            return Ok(pos);
+        }
+
+        let pos = crate::events::query_cursor_pos(&mut *self.buffer);
+        // The cursor is tracked from here, so the size is queried again in case it has changed
+        self.size = None;
+
+        if !self.low_latency {
+            return pos;
+        }
+
+        let pos = match pos {
+            Ok(pos) => pos,
+            Err(_) => {
+                // The query has timed out, so the cursor is moved to the start of a new line at
+                // the bottom of the screen, which is where it can be tracked from. Moving to the
+                // last line and then down scrolls whatever is on it up instead of overwriting it.
+                let height = self.size()?.height;
+                writeln!(self.buffer, "{}", cursor::Goto(1, height))?;
+                (0, height.saturating_sub(1))
+            }
+        };
+
+        self.cursor_pos = Some(pos);
+        Ok(pos)
     }
 
     fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        if self.low_latency {
+            self.cursor_pos = Some((x, y));
+        }
+
         write!(self.buffer, "{}", cursor::Goto(x + 1, y + 1))
     }
 
@@ -125,7 +372,19 @@ impl<W: Write> Backend for TermionBackend<W> {
             MoveDirection::Down(n) => write!(self.buffer, "{}", cursor::Down(n))?,
             MoveDirection::Left(n) => write!(self.buffer, "{}", cursor::Left(n))?,
             MoveDirection::Right(n) => write!(self.buffer, "{}", cursor::Right(n))?,
-            _ => super::default_move_cursor(self, direction)?,
+            // The default implementation uses the tracked position through `get_cursor_pos` and
+            // `move_cursor_to`, so it does not need to be updated here
+            _ => return super::default_move_cursor(self, direction),
+        }
+
+        if let (true, Some((x, y))) = (self.low_latency, self.cursor_pos) {
+            self.cursor_pos = Some(match direction {
+                MoveDirection::Up(n) => (x, y.saturating_sub(n)),
+                MoveDirection::Down(n) => (x, y.saturating_add(n)),
+                MoveDirection::Left(n) => (x.saturating_sub(n), y),
+                MoveDirection::Right(n) => (x.saturating_add(n), y),
+                _ => unreachable!(),
+            });
         }
 
         Ok(())
@@ -144,16 +403,30 @@ impl<W: Write> Backend for TermionBackend<W> {
     }
 
     fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
         set_attributes(self.attributes, attributes, &mut *self.buffer)?;
         self.attributes = attributes;
         Ok(())
     }
 
     fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let color = color.degrade(self.supports_colors());
         write!(self.buffer, "{}", Fg(color))
     }
 
     fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        if cfg!(feature = "no-style") {
+            return Ok(());
+        }
+
+        let color = color.degrade(self.supports_colors());
         write!(self.buffer, "{}", Bg(color))
     }
 
@@ -174,8 +447,24 @@ impl<W: Write> Backend for TermionBackend<W> {
     }
 
     fn size(&self) -> io::Result<Size> {
+        if let Some(ref tty) = self.tty {
+            return crate::tty::size(tty.fd());
+        }
+
         termion::terminal_size().map(Into::into)
     }
+
+    fn resized(&mut self, size: Size) {
+        self.size = Some(size);
+    }
+
+    fn supports_colors(&self) -> ColorSupport {
+        color_support()
+    }
+
+    fn supports_unicode(&self) -> bool {
+        super::unicode_support()
+    }
 }
 
 pub(super) struct Fg(pub(super) Color);
@@ -304,3 +593,70 @@ pub(super) fn set_attributes<W: Write>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Stylize;
+
+    #[test]
+    fn test_track_write() {
+        let size = (10, 5).into();
+        let mut state = WriteState::default();
+
+        assert_eq!(state.track(b"abc", (0, 0), size), (3, 0));
+        assert_eq!(state.track("字字".as_bytes(), (3, 0), size), (7, 0));
+        // Wide characters which do not fit are moved to the next line
+        assert_eq!(state.track("abcdefghi字".as_bytes(), (0, 1), size), (2, 2));
+        assert_eq!(state.track(b"ab\r\nc", (5, 3), size), (1, 4));
+        // The cursor does not move past the last line
+        assert_eq!(state.track(b"\n\nabcdefghijk", (0, 3), size), (1, 4));
+
+        // Escape sequences are not printed
+        assert_eq!(state.track(b"\x1b[1;31ma\x1b[0m", (0, 0), size), (1, 0));
+        assert_eq!(
+            state.track(
+                b"\x1b]8;;https://example.com\x1b\\a\x1b]8;;\x07b",
+                (0, 0),
+                size
+            ),
+            (2, 0)
+        );
+
+        // Characters and escape sequences can be split across writes
+        let bytes = "字".as_bytes();
+        assert_eq!(state.track(&bytes[..1], (0, 0), size), (0, 0));
+        assert_eq!(state.track(&bytes[1..], (0, 0), size), (2, 0));
+        assert_eq!(state.track(b"\x1b[3", (2, 0), size), (2, 0));
+        assert_eq!(state.track(b"1ma", (2, 0), size), (3, 0));
+    }
+
+    #[test]
+    fn test_low_latency_write() {
+        let mut backend = TermionBackend::new(Vec::new()).low_latency(true);
+        backend.cursor_pos = Some((2, 1));
+
+        backend.write_styled(&"字a".red().bold().on_blue()).unwrap();
+        backend
+            .write_hyperlink("https://example.com", &"字")
+            .unwrap();
+        write!(backend, "\x1b[4mb").unwrap();
+
+        assert_eq!(backend.get_cursor_pos().unwrap(), (8, 1));
+    }
+
+    #[test]
+    fn test_low_latency_resized() {
+        let mut backend = TermionBackend::new(Vec::new()).low_latency(true);
+        backend.cursor_pos = Some((0, 0));
+        backend.resized((4, 3).into());
+
+        write!(backend, "abcdef").unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (2, 1));
+
+        backend.resized((10, 3).into());
+
+        write!(backend, "abcdef").unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (8, 1));
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 use super::{Backend, ClearType, MoveDirection, Size};
 use crate::{
     layout::Layout,
-    style::{Attributes, Color},
+    style::{Attributes, Color, ColorSupport},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -46,12 +46,43 @@ impl From<Cursor> for (u16, u16) {
     }
 }
 
+/// A single cell of a [`TestBackend`], see [`TestBackend::cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyledChar {
+    /// The character in the cell, which is a space if nothing was written to it.
+    pub value: char,
+    /// The foreground color of the cell.
+    pub fg: Color,
+    /// The background color of the cell.
+    pub bg: Color,
+    /// The attributes of the cell.
+    pub attributes: Attributes,
+}
+
+impl From<Cell> for StyledChar {
+    fn from(cell: Cell) -> Self {
+        Self {
+            value: cell.value.unwrap_or(' '),
+            fg: cell.fg,
+            bg: cell.bg,
+            attributes: cell.attributes,
+        }
+    }
+}
+
 /// A backend that can be used for tests.
 ///
+/// Apart from comparing whole backends, what was rendered can be checked with [`cell`],
+/// [`row_text`] and [`cursor`], or [`assert_text_style`] for the style of some text.
+///
 /// When asserting equality, it is recommended to use [`TestBackend::assert_eq`] or
 /// [`assert_backend_snapshot`] instead of [`assert_eq`].
 ///
 /// [`assert_backend_snapshot`]: crate::assert_backend_snapshot
+/// [`cell`]: TestBackend::cell
+/// [`row_text`]: TestBackend::row_text
+/// [`cursor`]: TestBackend::cursor
+/// [`assert_text_style`]: TestBackend::assert_text_style
 #[derive(Debug, Clone)]
 pub struct TestBackend {
     cells: Vec<Cell>,
@@ -63,6 +94,8 @@ pub struct TestBackend {
     current_bg: Color,
     current_attributes: Attributes,
     viewport_start: usize,
+    color_support: ColorSupport,
+    unicode_support: bool,
 }
 
 impl PartialEq for TestBackend {
@@ -81,6 +114,27 @@ impl PartialEq for TestBackend {
 impl Eq for TestBackend {}
 
 impl TestBackend {
+    /// A narrow, a short, a regular and a huge terminal size, for use with
+    /// [`render_at_sizes`](TestBackend::render_at_sizes).
+    pub const SIZES: [Size; 4] = [
+        Size {
+            width: 20,
+            height: 20,
+        },
+        Size {
+            width: 80,
+            height: 3,
+        },
+        Size {
+            width: 80,
+            height: 20,
+        },
+        Size {
+            width: 200,
+            height: 50,
+        },
+    ];
+
     /// Creates a new `TestBackend`
     pub fn new(size: Size) -> Self {
         Self::new_with_layout(size, Layout::new(0, size))
@@ -98,6 +152,8 @@ impl TestBackend {
             current_bg: Color::Reset,
             current_attributes: Attributes::empty(),
             viewport_start: 0,
+            color_support: ColorSupport::TrueColor,
+            unicode_support: true,
         };
 
         this.move_x(layout.line_offset + layout.offset_x);
@@ -136,6 +192,29 @@ impl TestBackend {
         backend
     }
 
+    /// Sets the colors given by [`supports_colors`], to test how prompts look on terminals with
+    /// fewer colors. Colors set afterwards are [degraded](Color::degrade) to the supported ones,
+    /// like they are by the terminal backends.
+    ///
+    /// By default, it is [`ColorSupport::TrueColor`].
+    ///
+    /// [`supports_colors`]: Backend::supports_colors
+    pub fn with_color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
+    /// Sets whether [`supports_unicode`] is `true`, to test how prompts look on terminals which can
+    /// only display ascii.
+    ///
+    /// By default, it is `true`.
+    ///
+    /// [`supports_unicode`]: Backend::supports_unicode
+    pub fn with_unicode_support(mut self, unicode_support: bool) -> Self {
+        self.unicode_support = unicode_support;
+        self
+    }
+
     /// Clears all the cells and moves the cursor to the offsets given by the layout.
     pub fn reset_with_layout(&mut self, layout: Layout) {
         self.clear_range(..);
@@ -143,6 +222,114 @@ impl TestBackend {
         self.move_y(layout.offset_y);
     }
 
+    /// The cell at the given column and row of the visible screen. The top-left cell is (0, 0).
+    ///
+    /// # Panics
+    ///
+    /// It panics if the position is outside the screen.
+    pub fn cell(&self, x: u16, y: u16) -> StyledChar {
+        assert!(
+            x < self.size.width && y < self.size.height,
+            "({}, {}) is outside the {}x{} screen",
+            x,
+            y,
+            self.size.width,
+            self.size.height
+        );
+
+        self.viewport()[Cursor { x, y }.to_linear(self.size.width)].into()
+    }
+
+    /// The text of the given row of the visible screen, without any trailing whitespace.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the row is outside the screen.
+    pub fn row_text(&self, y: u16) -> String {
+        let mut text: String = (0..self.size.width)
+            .map(|x| self.cell(x, y).value)
+            .collect();
+        text.truncate(text.trim_end().len());
+        text
+    }
+
+    /// The position of the cursor as (col, row). The top-left cell is (0, 0).
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor.into()
+    }
+
+    /// Whether the cursor is hidden.
+    pub fn is_cursor_hidden(&self) -> bool {
+        self.hidden_cursor
+    }
+
+    /// The position of the first occurrence of `text` on the visible screen, searching row by row.
+    /// The text cannot span multiple rows.
+    pub fn find_text(&self, text: &str) -> Option<(u16, u16)> {
+        let text: Vec<char> = text.chars().collect();
+        if text.is_empty() {
+            return Some((0, 0));
+        }
+
+        (0..self.size.height).find_map(|y| {
+            let row: Vec<char> = (0..self.size.width)
+                .map(|x| self.cell(x, y).value)
+                .collect();
+            row.windows(text.len())
+                .position(|window| window == &text[..])
+                .map(|x| (x as u16, y))
+        })
+    }
+
+    /// Asserts that every character of the first occurrence of `text` on the visible screen has
+    /// the given style, otherwise it panics with the first character that differs.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the text is not on the screen, or if any of its characters has a different
+    /// style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use requestty_ui::{
+    ///     backend::{Backend, TestBackend},
+    ///     style::{Attributes, Color, Stylize},
+    /// };
+    ///
+    /// let mut backend = TestBackend::new((20, 5).into());
+    /// backend.write_styled(&"Hello".cyan().bold()).unwrap();
+    /// backend.write_all(b" world").unwrap();
+    ///
+    /// assert_eq!(backend.row_text(0), "Hello world");
+    /// backend.assert_text_style("Hello", Color::Cyan, Color::Reset, Attributes::BOLD);
+    /// backend.assert_text_style("world", Color::Reset, Color::Reset, Attributes::empty());
+    /// ```
+    pub fn assert_text_style(&self, text: &str, fg: Color, bg: Color, attributes: Attributes) {
+        let (x, y) = self
+            .find_text(text)
+            .unwrap_or_else(|| panic!("{:?} is not on the screen", text));
+
+        for (i, value) in text.chars().enumerate() {
+            let x = x + i as u16;
+            let expected = StyledChar {
+                value,
+                fg,
+                bg,
+                attributes,
+            };
+            let cell = self.cell(x, y);
+
+            assert_eq!(
+                cell, expected,
+                "the style of {:?} at ({}, {}) in {:?} is different",
+                value, x, y, text
+            );
+        }
+    }
+
     fn viewport(&self) -> &[Cell] {
         &self.cells[self.viewport_start..(self.viewport_start + self.size.area() as usize)]
     }
@@ -180,7 +367,7 @@ impl TestBackend {
         self.viewport_start + self.cursor.to_linear(self.size.width)
     }
 
-    fn cell(&mut self) -> &mut Cell {
+    fn cell_mut(&mut self) -> &mut Cell {
         let i = self.cell_i();
         &mut self.cells[i]
     }
@@ -214,7 +401,7 @@ impl TestBackend {
             '\r' => self.cursor.x = 0,
             '\t' => {
                 let x = 8 + self.cursor.x - (self.cursor.x % 8);
-                if x >= self.size.width && self.cursor.y < self.size.width - 1 {
+                if x >= self.size.width && self.cursor.y + 1 < self.size.height {
                     self.cursor.x = 0;
                     self.cursor.y += 1;
                 } else {
@@ -222,10 +409,13 @@ impl TestBackend {
                 }
             }
             c => {
-                self.cell().value = Some(c);
-                self.cell().attributes = self.current_attributes;
-                self.cell().fg = self.current_fg;
-                self.cell().bg = self.current_bg;
+                let (attributes, fg, bg) =
+                    (self.current_attributes, self.current_fg, self.current_bg);
+                let cell = self.cell_mut();
+                cell.value = Some(c);
+                cell.attributes = attributes;
+                cell.fg = fg;
+                cell.bg = bg;
                 self.add_x(1);
             }
         }
@@ -355,27 +545,32 @@ impl super::Backend for TestBackend {
     }
 
     fn set_fg(&mut self, color: Color) -> io::Result<()> {
-        self.current_fg = color;
+        self.current_fg = color.degrade(self.color_support);
         Ok(())
     }
 
     fn set_bg(&mut self, color: Color) -> io::Result<()> {
-        self.current_bg = color;
+        self.current_bg = color.degrade(self.color_support);
         Ok(())
     }
 
+    /// Links are not shown in snapshots, so only the `text` is written.
+    fn write_hyperlink(&mut self, _: &str, text: &dyn std::fmt::Display) -> io::Result<()> {
+        write!(self, "{}", text)
+    }
+
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
         match clear_type {
             ClearType::All => self.clear_range(..),
             ClearType::FromCursorDown => self.clear_range(self.cell_i()..),
             ClearType::FromCursorUp => self.clear_range(..=self.cell_i()),
             ClearType::CurrentLine => {
-                let s = (self.cursor.y * self.size.width) as usize;
-                let e = ((self.cursor.y + 1) * self.size.width) as usize;
+                let s = self.viewport_start + (self.cursor.y * self.size.width) as usize;
+                let e = s + self.size.width as usize;
                 self.clear_range(s..e)
             }
             ClearType::UntilNewLine => {
-                let e = ((self.cursor.y + 1) * self.size.width) as usize;
+                let e = self.viewport_start + ((self.cursor.y + 1) * self.size.width) as usize;
                 self.clear_range(self.cell_i()..e)
             }
         }
@@ -385,6 +580,14 @@ impl super::Backend for TestBackend {
     fn size(&self) -> io::Result<Size> {
         Ok(self.size)
     }
+
+    fn supports_colors(&self) -> ColorSupport {
+        self.color_support
+    }
+
+    fn supports_unicode(&self) -> bool {
+        self.unicode_support
+    }
 }
 
 #[cfg(any(feature = "crossterm", feature = "termion"))]
@@ -513,4 +716,124 @@ impl TestBackend {
     pub fn write_to_buf<W: Write>(&self, buf: W) -> io::Result<()> {
         self.write_to_backend(super::get_backend(buf))
     }
+
+    /// Runs `f` with a new `TestBackend` of each of the given sizes, and returns what all of the
+    /// backends look like afterwards, one below the other with their size written above them.
+    ///
+    /// This can be used with [`assert_backend_snapshot`] to check that something also renders
+    /// correctly at uncommon sizes, such as the ones in [`TestBackend::SIZES`]. Since `f` is run
+    /// once for every size, anything it consumes (like the events of a prompt) should be created
+    /// inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use requestty_ui::{backend::{Backend, TestBackend}, layout::Layout, widgets::Text, Widget};
+    ///
+    /// let rendered = TestBackend::render_at_sizes(&TestBackend::SIZES, |backend| {
+    ///     let mut layout = Layout::new(0, backend.size().unwrap());
+    ///     Text::new("Hello world!").render(&mut layout, backend).unwrap();
+    /// });
+    ///
+    /// requestty_ui::assert_backend_snapshot!(rendered);
+    /// ```
+    ///
+    /// [`assert_backend_snapshot`]: crate::assert_backend_snapshot
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+    pub fn render_at_sizes<F>(sizes: &[Size], mut f: F) -> String
+    where
+        F: FnMut(&mut TestBackend),
+    {
+        let mut rendered = String::new();
+
+        for &size in sizes {
+            let mut backend = TestBackend::new(size);
+            f(&mut backend);
+
+            if !rendered.is_empty() {
+                rendered.push_str("\n\n");
+            }
+
+            rendered.push_str(&format!("{}x{}:\n{}", size.width, size.height, backend));
+        }
+
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled_backend() -> TestBackend {
+        let mut backend = TestBackend::new((20, 5).into());
+        backend.move_cursor_to(2, 1).unwrap();
+        backend.set_fg(Color::Cyan).unwrap();
+        backend.set_attributes(Attributes::BOLD).unwrap();
+        backend.write_all(b"Hello").unwrap();
+        backend.set_fg(Color::Reset).unwrap();
+        backend.set_attributes(Attributes::empty()).unwrap();
+        backend.write_all(b" world").unwrap();
+        backend
+    }
+
+    #[test]
+    fn test_query() {
+        let backend = styled_backend();
+
+        assert_eq!(
+            backend.cell(2, 1),
+            StyledChar {
+                value: 'H',
+                fg: Color::Cyan,
+                bg: Color::Reset,
+                attributes: Attributes::BOLD,
+            }
+        );
+        assert_eq!(backend.cell(0, 0).value, ' ');
+        assert_eq!(backend.row_text(0), "");
+        assert_eq!(backend.row_text(1), "  Hello world");
+        assert_eq!(backend.cursor(), (13, 1));
+        assert!(!backend.is_cursor_hidden());
+
+        assert_eq!(backend.find_text("world"), Some((8, 1)));
+        assert_eq!(backend.find_text("worlds"), None);
+
+        backend.assert_text_style("Hello", Color::Cyan, Color::Reset, Attributes::BOLD);
+        backend.assert_text_style(" world", Color::Reset, Color::Reset, Attributes::empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the style of ' ' at (7, 1) in \"lo w\" is different")]
+    fn test_assert_text_style_mismatch() {
+        styled_backend().assert_text_style("lo w", Color::Cyan, Color::Reset, Attributes::BOLD);
+    }
+
+    #[test]
+    #[should_panic(expected = "(20, 0) is outside the 20x5 screen")]
+    fn test_cell_outside() {
+        styled_backend().cell(20, 0);
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let mut backend = TestBackend::new((20, 5).into());
+        assert_eq!(backend.supports_colors(), ColorSupport::TrueColor);
+        assert!(backend.supports_unicode());
+
+        backend.set_fg(Color::Rgb(255, 135, 0)).unwrap();
+        backend.write_all(b"a").unwrap();
+        assert_eq!(backend.cell(0, 0).fg, Color::Rgb(255, 135, 0));
+
+        let mut backend = TestBackend::new((20, 5).into())
+            .with_color_support(ColorSupport::Ansi16)
+            .with_unicode_support(false);
+        assert_eq!(backend.supports_colors(), ColorSupport::Ansi16);
+        assert!(!backend.supports_unicode());
+
+        backend.set_fg(Color::Rgb(255, 135, 0)).unwrap();
+        backend.write_all(b"a").unwrap();
+        assert_eq!(backend.cell(0, 0).fg, Color::Yellow);
+    }
 }
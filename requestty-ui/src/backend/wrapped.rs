@@ -0,0 +1,156 @@
+use std::{fmt::Display, io};
+
+use super::{Backend, ClearType, MoveDirection, Size};
+use crate::{
+    layout::Layout,
+    style::{Attributes, Color, ColorSupport, Styled},
+};
+
+/// A backend which wraps text at the right edge of a [`Layout`] and continues it from the
+/// `offset_x` of the next line, instead of leaving it to the terminal which wraps it at its own
+/// edge.
+pub(crate) struct Wrapped<'b, B: ?Sized> {
+    backend: &'b mut B,
+    x: u16,
+    y: u16,
+    start: u16,
+    end: u16,
+}
+
+impl<'b, B: Backend + ?Sized> Wrapped<'b, B> {
+    /// Moves the cursor to the `line_offset` of the `layout`, which is where the text will start.
+    pub(crate) fn new(backend: &'b mut B, layout: Layout) -> io::Result<Self> {
+        let mut wrapped = Self {
+            backend,
+            x: 0,
+            y: 0,
+            start: layout.offset_x,
+            end: layout.width,
+        };
+
+        wrapped.move_cursor_to(layout.offset_x + layout.line_offset, layout.offset_y)?;
+        Ok(wrapped)
+    }
+}
+
+impl<B: Backend + ?Sized> Wrapped<'_, B> {
+    /// Writes the characters of `text` one by one, as hyperlinks to the `url` if there is one.
+    fn write_wrapped(&mut self, text: &str, url: Option<&str>) -> io::Result<()> {
+        let mut bytes = [0; 4];
+
+        for c in text.chars() {
+            let c = c.encode_utf8(&mut bytes);
+            let width = crate::layout::display_width(c);
+
+            if width > 0 && self.x + width > self.end {
+                self.move_cursor_to(self.start, self.y + 1)?;
+            }
+
+            match url {
+                Some(url) => self.backend.write_hyperlink(url, &&*c)?,
+                None => self.backend.write_all(c.as_bytes())?,
+            }
+            self.x += width;
+        }
+
+        Ok(())
+    }
+}
+
+impl<B: Backend + ?Sized> io::Write for Wrapped<'_, B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_wrapped(&String::from_utf8_lossy(buf), None)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()
+    }
+}
+
+impl<B: Backend + ?Sized> Backend for Wrapped<'_, B> {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.enable_raw_mode()
+    }
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.disable_raw_mode()
+    }
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.backend.hide_cursor()
+    }
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.backend.show_cursor()
+    }
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        Ok((self.x, self.y))
+    }
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.x = x;
+        self.y = y;
+        self.backend.move_cursor_to(x, y)
+    }
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        super::default_move_cursor(self, direction)
+    }
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        self.backend.scroll(dist)
+    }
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        self.backend.set_attributes(attributes)
+    }
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        self.backend.set_fg(color)
+    }
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        self.backend.set_bg(color)
+    }
+    fn write_styled(&mut self, styled: &Styled<dyn Display + '_>) -> io::Result<()> {
+        // Not forwarded, so that the text goes through `write`
+        styled.write(self)
+    }
+    fn write_hyperlink(&mut self, url: &str, text: &dyn Display) -> io::Result<()> {
+        self.write_wrapped(&text.to_string(), Some(url))
+    }
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.backend.clear(clear_type)
+    }
+    fn size(&self) -> io::Result<Size> {
+        self.backend.size()
+    }
+    fn resized(&mut self, size: Size) {
+        self.backend.resized(size)
+    }
+    fn supports_colors(&self) -> ColorSupport {
+        self.backend.supports_colors()
+    }
+    fn supports_unicode(&self) -> bool {
+        self.backend.supports_unicode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn test_wrap() {
+        let size = (20, 5).into();
+        let mut backend = TestBackend::new(size);
+        let layout = Layout::new(3, size)
+            .with_offset(5, 1)
+            .with_size((15, 5).into());
+
+        let mut wrapped = Wrapped::new(&mut backend, layout).unwrap();
+        write!(wrapped, "abcdefghijklmnop").unwrap();
+        assert_eq!(wrapped.get_cursor_pos().unwrap(), (14, 2));
+
+        // Wide characters are not split across lines
+        write!(wrapped, "字").unwrap();
+        assert_eq!(wrapped.get_cursor_pos().unwrap(), (7, 3));
+
+        crate::assert_backend_snapshot!(backend);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     backend::Backend,
     events::{KeyCode, KeyEvent},
-    layout::Layout,
+    layout::{char_width, Layout},
 };
 
 /// A widget that inputs a single character.
@@ -108,11 +108,6 @@ impl Default for CharInput {
     }
 }
 
-fn char_width(c: char) -> u16 {
-    let mut buf = [0u8; 4];
-    textwrap::core::display_width(c.encode_utf8(&mut buf)) as u16
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,15 +12,34 @@ pub enum ErrorKind {
     Interrupted,
     /// This occurs when `Null` is received in [`Input`](crate::Input).
     Eof,
-    /// The user aborted the question with `Esc`
+    /// The user aborted the question with `Esc`, or it was cancelled with a
+    /// [`CancellationToken`](crate::CancellationToken).
     Aborted,
+    /// The user pressed the [back key](crate::Input::back_key) to go back to the previous
+    /// question.
+    Back,
+    /// This occurs when an [`Input`](crate::Input) is run on a thread which is already running
+    /// one, unless it is allowed with [`Input::nested`](crate::Input::nested).
+    PromptInProgress,
+    /// The position of the cursor could not be detected when an [`Input`](crate::Input) started,
+    /// and the fallback was disabled with
+    /// [`Input::cursor_pos_fallback`](crate::Input::cursor_pos_fallback).
+    CursorPosition(io::Error),
+    /// A question with the given name has no answer, and could not be asked since prompting is
+    /// not interactive.
+    MissingAnswer(String),
 }
 
 impl std::error::Error for ErrorKind {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            ErrorKind::IoError(e) => Some(e),
-            ErrorKind::Interrupted | ErrorKind::Eof | ErrorKind::Aborted => None,
+            ErrorKind::IoError(e) | ErrorKind::CursorPosition(e) => Some(e),
+            ErrorKind::Interrupted
+            | ErrorKind::Eof
+            | ErrorKind::Aborted
+            | ErrorKind::Back
+            | ErrorKind::PromptInProgress
+            | ErrorKind::MissingAnswer(_) => None,
         }
     }
 }
@@ -31,7 +50,19 @@ impl fmt::Display for ErrorKind {
             ErrorKind::IoError(e) => write!(fmt, "IoError: {}", e),
             ErrorKind::Interrupted => write!(fmt, "CTRL+C"),
             ErrorKind::Aborted => write!(fmt, "ESC"),
+            ErrorKind::Back => write!(fmt, "Back"),
             ErrorKind::Eof => write!(fmt, "EOF"),
+            ErrorKind::PromptInProgress => write!(fmt, "A prompt is already running"),
+            ErrorKind::CursorPosition(e) => {
+                write!(fmt, "Could not detect the cursor position: {}", e)
+            }
+            ErrorKind::MissingAnswer(name) => {
+                write!(
+                    fmt,
+                    "No answer was given for '{}' in non-interactive mode",
+                    name
+                )
+            }
         }
     }
 }
@@ -0,0 +1,105 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::KeyEvent,
+    layout::Layout,
+    style::{Color, Stylize},
+    Severity, Widget,
+};
+
+/// A widget which renders an error returned by [`Prompt::validate`](crate::Prompt::validate),
+/// with a marker in front of it for its [`Severity`].
+///
+/// The error is indented past the marker, including the lines it wraps onto, and is given the
+/// [`max_height`](Layout::max_height) of the layout. Errors which are taller than that, such as a
+/// long [`Text`](crate::widgets::Text), are cut off instead of scrolling the terminal.
+#[derive(Debug, Clone)]
+pub struct ErrorView<E> {
+    error: E,
+    severity: Severity,
+}
+
+impl<E: Widget> ErrorView<E> {
+    /// Creates a new `ErrorView`
+    pub fn new(error: E, severity: Severity) -> Self {
+        Self { error, severity }
+    }
+
+    /// Get the error
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Get the severity
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Consume self returning the owned error
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// The layout the error is rendered in, which starts after the marker.
+    fn error_layout(layout: Layout) -> Layout {
+        layout
+            .with_offset(layout.offset_x + 2, layout.offset_y)
+            .with_line_offset(0)
+    }
+
+    fn marker_color(&self) -> Color {
+        let theme = crate::style::theme();
+
+        match self.severity {
+            Severity::Error => theme.error,
+            Severity::Warning => theme.warning,
+            Severity::Info => theme.highlight,
+        }
+    }
+}
+
+impl<E: Widget> Widget for ErrorView<E> {
+    /// Renders the error starting at the beginning of the row at `layout.offset_y`, ignoring the
+    /// `line_offset`.
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        let marker = match self.severity {
+            Severity::Error => crate::symbols::current().cross,
+            Severity::Warning => '!',
+            Severity::Info => 'i',
+        };
+
+        backend.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        backend.write_styled(&marker.with(self.marker_color()))?;
+        backend.write_all(b" ")?;
+
+        let mut error_layout = Self::error_layout(*layout);
+        self.error.render(&mut error_layout, backend)?;
+
+        layout.offset_y = error_layout.offset_y;
+        layout.line_offset = error_layout.line_offset;
+        Ok(())
+    }
+
+    /// The height of the error, which is at least 1 for the marker.
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let mut error_layout = Self::error_layout(*layout);
+        let height = self
+            .error
+            .height(&mut error_layout)
+            .clamp(1, layout.max_height.max(1));
+
+        layout.offset_y += height;
+        layout.line_offset = 0;
+        height
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        self.error.cursor_pos(Self::error_layout(layout))
+    }
+
+    /// This widget does not handle any events
+    fn handle_key(&mut self, _: KeyEvent) -> bool {
+        false
+    }
+}
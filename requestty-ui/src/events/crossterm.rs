@@ -1,4 +1,10 @@
 use std::convert::{TryFrom, TryInto};
+#[cfg(feature = "event-stream")]
+use std::{
+    fmt, io,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 use crossterm::event;
 
@@ -27,46 +33,326 @@ impl EventIterator for CrosstermEvents {
             }
         }
     }
+
+    fn next_any_event(&mut self) -> std::io::Result<super::Event> {
+        loop {
+            if let Ok(e) = event::read()?.try_into() {
+                return Ok(e);
+            }
+        }
+    }
+
+    fn discard_pending(&mut self) -> std::io::Result<()> {
+        while event::poll(std::time::Duration::from_secs(0))? {
+            event::read()?;
+        }
+
+        Ok(())
+    }
+
+    fn wait_for_event(&mut self, timeout: std::time::Duration) -> std::io::Result<bool> {
+        event::poll(timeout)
+    }
 }
 
-impl TryFrom<event::KeyEvent> for super::KeyEvent {
+/// An asynchronous stream of the input keys using the `crossterm` crate
+#[derive(Default)]
+#[cfg(feature = "event-stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "event-stream")))]
+pub struct CrosstermEventStream {
+    stream: event::EventStream,
+}
+
+#[cfg(feature = "event-stream")]
+impl CrosstermEventStream {
+    /// Creates a new `CrosstermEventStream`
+    pub fn new() -> Self {
+        Self {
+            stream: event::EventStream::new(),
+        }
+    }
+}
+
+#[cfg(feature = "event-stream")]
+impl fmt::Debug for CrosstermEventStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CrosstermEventStream")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "event-stream")]
+impl CrosstermEventStream {
+    fn poll_event(
+        &mut self,
+        cx: &mut Context<'_>,
+        any_event: bool,
+    ) -> Poll<io::Result<super::Event>> {
+        use futures_core::Stream;
+
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(Ok(event::Event::Key(k)))) => {
+                    if let Ok(k) = k.try_into() {
+                        return Poll::Ready(Ok(super::Event::Key(k)));
+                    }
+                }
+                Poll::Ready(Some(Ok(e))) if any_event => {
+                    if let Ok(e) = e.try_into() {
+                        return Poll::Ready(Ok(e));
+                    }
+                }
+                Poll::Ready(Some(Ok(_))) => {}
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e)),
+                // The stream has ended, so there will be no more input
+                Poll::Ready(None) => {
+                    return Poll::Ready(Ok(super::KeyEvent::from(super::KeyCode::Null).into()))
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "event-stream")]
+impl super::AsyncEventIterator for CrosstermEventStream {
+    fn poll_next_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<super::KeyEvent>> {
+        self.poll_event(cx, false).map(|res| {
+            res.map(|e| match e {
+                super::Event::Key(k) => k,
+                _ => unreachable!("only key events are polled"),
+            })
+        })
+    }
+
+    fn poll_next_any_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<super::Event>> {
+        self.poll_event(cx, true)
+    }
+}
+
+impl TryFrom<event::Event> for super::Event {
     type Error = ();
 
-    fn try_from(event: event::KeyEvent) -> Result<Self, ()> {
-        let code = match event.code {
-            event::KeyCode::Backspace => super::KeyCode::Backspace,
-            event::KeyCode::Enter => super::KeyCode::Enter,
-            event::KeyCode::Left => super::KeyCode::Left,
-            event::KeyCode::Right => super::KeyCode::Right,
-            event::KeyCode::Up => super::KeyCode::Up,
-            event::KeyCode::Down => super::KeyCode::Down,
-            event::KeyCode::Home => super::KeyCode::Home,
-            event::KeyCode::End => super::KeyCode::End,
-            event::KeyCode::PageUp => super::KeyCode::PageUp,
-            event::KeyCode::PageDown => super::KeyCode::PageDown,
-            event::KeyCode::Tab => super::KeyCode::Tab,
-            event::KeyCode::BackTab => super::KeyCode::BackTab,
-            event::KeyCode::Delete => super::KeyCode::Delete,
-            event::KeyCode::Insert => super::KeyCode::Insert,
-            event::KeyCode::F(f) => super::KeyCode::F(f),
-            event::KeyCode::Char(c) => super::KeyCode::Char(c),
-            event::KeyCode::Null => super::KeyCode::Null,
-            event::KeyCode::Esc => super::KeyCode::Esc,
+    fn try_from(event: event::Event) -> Result<Self, ()> {
+        match event {
+            event::Event::Key(k) => k.try_into().map(super::Event::Key),
+            event::Event::Mouse(m) => m.try_into().map(super::Event::Mouse),
+            event::Event::Resize(width, height) => Ok(super::Event::Resize((width, height).into())),
+            event::Event::Paste(text) => Ok(super::Event::Paste(text)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<event::MouseEvent> for super::MouseEvent {
+    type Error = ();
+
+    fn try_from(event: event::MouseEvent) -> Result<Self, ()> {
+        let kind = match event.kind {
+            event::MouseEventKind::Down(button) => super::MouseEventKind::Down(button.into()),
+            event::MouseEventKind::Up(_) => super::MouseEventKind::Up,
+            event::MouseEventKind::Drag(_) => super::MouseEventKind::Drag,
+            event::MouseEventKind::ScrollUp => super::MouseEventKind::ScrollUp,
+            event::MouseEventKind::ScrollDown => super::MouseEventKind::ScrollDown,
             _ => return Err(()),
         };
 
-        let mut modifiers = super::KeyModifiers::empty();
+        Ok(super::MouseEvent {
+            kind,
+            column: event.column,
+            row: event.row,
+            modifiers: event.modifiers.into(),
+        })
+    }
+}
 
-        if event.modifiers.contains(event::KeyModifiers::SHIFT) {
-            modifiers |= super::KeyModifiers::SHIFT;
+impl From<event::MouseButton> for super::MouseButton {
+    fn from(button: event::MouseButton) -> Self {
+        match button {
+            event::MouseButton::Left => super::MouseButton::Left,
+            event::MouseButton::Right => super::MouseButton::Right,
+            event::MouseButton::Middle => super::MouseButton::Middle,
         }
-        if event.modifiers.contains(event::KeyModifiers::CONTROL) {
-            modifiers |= super::KeyModifiers::CONTROL;
+    }
+}
+
+impl From<event::KeyModifiers> for super::KeyModifiers {
+    fn from(modifiers: event::KeyModifiers) -> Self {
+        let mut res = super::KeyModifiers::empty();
+
+        if modifiers.contains(event::KeyModifiers::SHIFT) {
+            res |= super::KeyModifiers::SHIFT;
+        }
+        if modifiers.contains(event::KeyModifiers::CONTROL) {
+            res |= super::KeyModifiers::CONTROL;
+        }
+        if modifiers.contains(event::KeyModifiers::ALT) {
+            res |= super::KeyModifiers::ALT;
         }
-        if event.modifiers.contains(event::KeyModifiers::ALT) {
-            modifiers |= super::KeyModifiers::ALT;
+
+        res
+    }
+}
+
+impl TryFrom<event::KeyEvent> for super::KeyEvent {
+    type Error = ();
+
+    fn try_from(event: event::KeyEvent) -> Result<Self, ()> {
+        // Releases are only reported with some keyboard enhancements, but a key should only be
+        // handled once
+        if event.kind == event::KeyEventKind::Release {
+            return Err(());
         }
 
-        Ok(super::KeyEvent { code, modifiers })
+        from_key_event(event, cfg!(windows))
+    }
+}
+
+/// Converts a crossterm key event, applying the translations for Windows consoles if `windows` is
+/// set.
+///
+/// On Windows, `Ctrl+Z` is how the end of input is sent, so it is translated to
+/// [`KeyCode::Null`](super::KeyCode::Null), which [`Input`](crate::Input) treats as EOF, instead of
+/// suspending the process. Characters composed with `AltGr` are also reported with both `CONTROL`
+/// and `ALT` set, which would stop them from being typed, so those modifiers are dropped for
+/// characters which are not ascii letters or digits.
+fn from_key_event(event: event::KeyEvent, windows: bool) -> Result<super::KeyEvent, ()> {
+    let ctrl_alt = event::KeyModifiers::CONTROL | event::KeyModifiers::ALT;
+
+    let mut modifiers = event.modifiers;
+
+    if windows {
+        match event.code {
+            event::KeyCode::Char('z') | event::KeyCode::Char('\x1a')
+                if modifiers.contains(event::KeyModifiers::CONTROL)
+                    && !modifiers.contains(event::KeyModifiers::ALT) =>
+            {
+                return Ok(super::KeyCode::Null.into());
+            }
+            event::KeyCode::Char(c)
+                if modifiers.contains(ctrl_alt) && !c.is_ascii_alphanumeric() =>
+            {
+                modifiers.remove(ctrl_alt);
+            }
+            _ => {}
+        }
+    }
+
+    let code = match event.code {
+        event::KeyCode::Backspace => super::KeyCode::Backspace,
+        event::KeyCode::Enter => super::KeyCode::Enter,
+        event::KeyCode::Left => super::KeyCode::Left,
+        event::KeyCode::Right => super::KeyCode::Right,
+        event::KeyCode::Up => super::KeyCode::Up,
+        event::KeyCode::Down => super::KeyCode::Down,
+        event::KeyCode::Home => super::KeyCode::Home,
+        event::KeyCode::End => super::KeyCode::End,
+        event::KeyCode::PageUp => super::KeyCode::PageUp,
+        event::KeyCode::PageDown => super::KeyCode::PageDown,
+        event::KeyCode::Tab => super::KeyCode::Tab,
+        event::KeyCode::BackTab => super::KeyCode::BackTab,
+        event::KeyCode::Delete => super::KeyCode::Delete,
+        event::KeyCode::Insert => super::KeyCode::Insert,
+        event::KeyCode::F(f) => super::KeyCode::F(f),
+        event::KeyCode::Char(c) => super::KeyCode::Char(c),
+        event::KeyCode::Null => super::KeyCode::Null,
+        event::KeyCode::Esc => super::KeyCode::Esc,
+        _ => return Err(()),
+    };
+
+    Ok(super::KeyEvent {
+        code,
+        modifiers: modifiers.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enter_modifiers() {
+        let convert = |modifiers| {
+            super::super::KeyEvent::try_from(event::KeyEvent::new(event::KeyCode::Enter, modifiers))
+        };
+
+        assert_eq!(
+            convert(event::KeyModifiers::NONE),
+            Ok(super::super::KeyCode::Enter.into())
+        );
+        assert_eq!(
+            convert(event::KeyModifiers::ALT),
+            Ok(super::super::KeyEvent::new(
+                super::super::KeyCode::Enter,
+                super::super::KeyModifiers::ALT
+            ))
+        );
+        assert_eq!(
+            convert(event::KeyModifiers::SHIFT),
+            Ok(super::super::KeyEvent::new(
+                super::super::KeyCode::Enter,
+                super::super::KeyModifiers::SHIFT
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ignore_release() {
+        let key = |kind| {
+            super::super::KeyEvent::try_from(event::KeyEvent::new_with_kind(
+                event::KeyCode::Enter,
+                event::KeyModifiers::SHIFT,
+                kind,
+            ))
+        };
+
+        assert!(key(event::KeyEventKind::Press).is_ok());
+        assert!(key(event::KeyEventKind::Repeat).is_ok());
+        assert_eq!(key(event::KeyEventKind::Release), Err(()));
+    }
+
+    #[test]
+    fn test_windows_keys() {
+        use super::super::{KeyCode, KeyEvent, KeyModifiers};
+
+        let convert = |code, modifiers, windows| {
+            from_key_event(event::KeyEvent::new(code, modifiers), windows)
+        };
+        let ctrl = event::KeyModifiers::CONTROL;
+        let ctrl_alt = event::KeyModifiers::CONTROL | event::KeyModifiers::ALT;
+
+        assert_eq!(
+            convert(event::KeyCode::Char('z'), ctrl, true),
+            Ok(KeyCode::Null.into())
+        );
+        assert_eq!(
+            convert(event::KeyCode::Char('z'), ctrl, false),
+            Ok(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL))
+        );
+
+        // AltGr
+        assert_eq!(
+            convert(event::KeyCode::Char('@'), ctrl_alt, true),
+            Ok(KeyCode::Char('@').into())
+        );
+        assert_eq!(
+            convert(event::KeyCode::Char('€'), ctrl_alt, true),
+            Ok(KeyCode::Char('€').into())
+        );
+        assert_eq!(
+            convert(event::KeyCode::Char('@'), ctrl_alt, false),
+            Ok(KeyEvent::new(
+                KeyCode::Char('@'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            ))
+        );
+        // A plain Ctrl+Alt shortcut
+        assert_eq!(
+            convert(event::KeyCode::Char('a'), ctrl_alt, true),
+            Ok(KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            ))
+        );
     }
 }
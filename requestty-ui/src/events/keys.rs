@@ -1,5 +1,19 @@
 bitflags::bitflags! {
     /// Represents key modifiers (shift, control, alt).
+    ///
+    /// Terminals cannot report every combination of a key and modifiers. In particular, for
+    /// [`KeyCode::Enter`]:
+    /// - `Alt+Enter` is reported with [`ALT`](KeyModifiers::ALT) by most terminals.
+    /// - `Shift+Enter` and `Ctrl+Enter` are only reported with [`SHIFT`](KeyModifiers::SHIFT) and
+    ///   [`CONTROL`](KeyModifiers::CONTROL) by the crossterm backend, on Windows or in terminals
+    ///   supporting the [kitty keyboard protocol] once
+    ///   [`Input::keyboard_enhancement`](crate::Input::keyboard_enhancement) is enabled. Otherwise,
+    ///   they are reported as a plain `Enter`.
+    ///
+    /// So a prompt should not rely on `Shift+Enter` alone, and should provide another key for the
+    /// same action.
+    ///
+    /// [kitty keyboard protocol]: https://sw.kovidgoyal.net/kitty/keyboard-protocol/
     pub struct KeyModifiers: u8 {
         #[allow(missing_docs)]
         const SHIFT = 0b0000_0001;
@@ -41,6 +55,8 @@ pub enum KeyCode {
     /// Backspace key.
     Backspace,
     /// Enter key.
+    ///
+    /// See [`KeyModifiers`] for the modifiers that can be reported with it.
     Enter,
     /// Left arrow key.
     Left,
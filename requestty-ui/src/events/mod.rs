@@ -1,6 +1,8 @@
-//! A module for handling key events
+//! A module for handling key, mouse, resize and paste events
 
-use std::io;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+use std::{io, time::Duration};
 
 #[cfg(feature = "crossterm")]
 mod crossterm;
@@ -10,14 +12,56 @@ mod termion;
 #[cfg(feature = "crossterm")]
 pub use self::crossterm::CrosstermEvents;
 
+#[cfg(feature = "event-stream")]
+pub use self::crossterm::CrosstermEventStream;
+
 #[cfg(feature = "termion")]
 pub use self::termion::TermionEvents;
+#[cfg(feature = "termion")]
+pub(crate) use self::termion::{open_tty, query_cursor_pos};
 
 mod keys;
+mod mouse;
 mod movement;
+mod terminal_pair;
 
 pub use keys::{KeyCode, KeyEvent, KeyModifiers};
+pub use mouse::{MouseButton, MouseEvent, MouseEventKind};
 pub use movement::Movement;
+pub(crate) use terminal_pair::PairInput;
+pub use terminal_pair::TerminalPairEvents;
+
+/// An input event from the keyboard or the mouse, or a change in the size of the terminal.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Event {
+    /// A key event.
+    Key(KeyEvent),
+    /// A mouse event.
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given size.
+    ///
+    /// Not all backends report resize events. Without them, the new size is only picked up the
+    /// next time the prompt is rendered.
+    Resize(crate::backend::Size),
+    /// Text was pasted into the terminal.
+    ///
+    /// Pasted text is only reported with the `bracketed-paste` feature, which asks the terminal to
+    /// mark where pasted text starts and ends. Without it, or in terminals which do not support it,
+    /// the text is received as a [`Key`](Event::Key) for every character.
+    Paste(String),
+}
+
+impl From<KeyEvent> for Event {
+    fn from(key: KeyEvent) -> Self {
+        Event::Key(key)
+    }
+}
+
+impl From<MouseEvent> for Event {
+    fn from(mouse: MouseEvent) -> Self {
+        Event::Mouse(mouse)
+    }
+}
 
 /// Gets the default [`EventIterator`] based on the features enabled.
 #[cfg(any(feature = "crossterm", feature = "termion"))]
@@ -34,10 +78,106 @@ pub fn get_events() -> impl EventIterator {
     return TermionEvents::new();
 }
 
-/// A trait to represent a source of [`KeyEvent`]s.
+/// Gets the default [`EventIterator`] based on the features enabled, which reads from the
+/// controlling terminal (`/dev/tty`) even if stdin is redirected.
+///
+/// crossterm always reads from the terminal if stdin is redirected, see
+/// [`TermionEvents::from_tty`] for termion.
+#[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(unix, any(feature = "crossterm", feature = "termion"))))
+)]
+pub fn get_tty_events() -> io::Result<impl EventIterator> {
+    #[cfg(feature = "crossterm")]
+    return Ok(CrosstermEvents::new());
+
+    #[cfg(not(feature = "crossterm"))]
+    return TermionEvents::from_tty();
+}
+
+/// Gets the default [`AsyncEventIterator`] based on the features enabled.
+#[cfg(feature = "event-stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "event-stream")))]
+pub fn get_async_events() -> impl AsyncEventIterator {
+    CrosstermEventStream::new()
+}
+
+/// A trait to represent a source of [`KeyEvent`]s, and optionally [`MouseEvent`]s and resize
+/// events.
 pub trait EventIterator {
     /// Get the next event
     fn next_event(&mut self) -> io::Result<KeyEvent>;
+
+    /// Get the next event, which may also be a mouse or resize event.
+    ///
+    /// Mouse events are only received if the mouse is being captured. By default, this only
+    /// returns the key events from [`next_event`](EventIterator::next_event).
+    fn next_any_event(&mut self) -> io::Result<Event> {
+        self.next_event().map(Event::Key)
+    }
+
+    /// Discard the events which have already been received, but have not been read yet.
+    ///
+    /// This is used to drop keys that were typed ahead of a prompt. By default, this does nothing.
+    fn discard_pending(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Wait until there is an event to read, or the `timeout` has passed. Returns whether there
+    /// is an event to read.
+    ///
+    /// This is used by [`Input::run_with_cancellation`](crate::Input::run_with_cancellation) to
+    /// check for cancellation while waiting for events. By default, this returns `true`
+    /// immediately, in which case the next event is waited on without checking for cancellation.
+    fn wait_for_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        let _ = timeout;
+        Ok(true)
+    }
+}
+
+impl<E: EventIterator + ?Sized> EventIterator for &mut E {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        (**self).next_event()
+    }
+    fn next_any_event(&mut self) -> io::Result<Event> {
+        (**self).next_any_event()
+    }
+    fn discard_pending(&mut self) -> io::Result<()> {
+        (**self).discard_pending()
+    }
+    fn wait_for_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        (**self).wait_for_event(timeout)
+    }
+}
+
+/// A trait to represent an asynchronous source of [`KeyEvent`]s.
+///
+/// This is the asynchronous counterpart of [`EventIterator`], and is used by
+/// [`Input::run_async`](crate::Input::run_async).
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait AsyncEventIterator {
+    /// Attempt to get the next event.
+    ///
+    /// If no event is available yet, `Poll::Pending` should be returned and the current task should
+    /// be woken up once there is one, similar to [`Future::poll`](std::future::Future::poll).
+    fn poll_next_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<KeyEvent>>;
+
+    /// Attempt to get the next event, which may also be a mouse or resize event.
+    ///
+    /// Mouse events are only received if the mouse is being captured. By default, this only
+    /// returns the key events from [`poll_next_event`](AsyncEventIterator::poll_next_event).
+    fn poll_next_any_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Event>> {
+        self.poll_next_event(cx).map(|res| res.map(Event::Key))
+    }
+
+    /// Discard the events which have already been received, but have not been read yet.
+    ///
+    /// This is used to drop keys that were typed ahead of a prompt. By default, this does nothing.
+    fn discard_pending(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 /// A simple wrapper around a [`KeyEvent`] iterator that can be used in tests.
@@ -85,6 +225,13 @@ impl<E: Iterator<Item = KeyEvent>> EventIterator for TestEvents<E> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<E: Iterator<Item = KeyEvent>> AsyncEventIterator for TestEvents<E> {
+    fn poll_next_event(&mut self, _: &mut Context<'_>) -> Poll<io::Result<KeyEvent>> {
+        Poll::Ready(self.next_event())
+    }
+}
+
 impl<E: Iterator<Item = KeyEvent>> Drop for TestEvents<E> {
     fn drop(&mut self) {
         let mut count = 0;
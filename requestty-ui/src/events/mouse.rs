@@ -0,0 +1,57 @@
+use super::KeyModifiers;
+
+/// Represents a mouse event.
+///
+/// Mouse events are only received if the mouse is being captured. See
+/// [`Backend::enable_mouse_capture`](crate::backend::Backend::enable_mouse_capture).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct MouseEvent {
+    /// The kind of mouse event.
+    pub kind: MouseEventKind,
+    /// The column of the event. The left-most column is 0.
+    pub column: u16,
+    /// The row of the event. The top-most row is 0.
+    pub row: u16,
+    /// Key modifiers held during the event. Not all backends report modifiers for mouse events.
+    pub modifiers: KeyModifiers,
+}
+
+impl MouseEvent {
+    /// Creates a new `MouseEvent` without any modifiers
+    pub fn new(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+}
+
+/// The different kinds of mouse events.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MouseEventKind {
+    /// A mouse button was pressed.
+    Down(MouseButton),
+    /// A mouse button was released.
+    ///
+    /// Not all backends report which button was released, so it is not given.
+    Up,
+    /// The mouse was moved while a button is held down.
+    Drag,
+    /// The mouse wheel was scrolled up.
+    ScrollUp,
+    /// The mouse wheel was scrolled down.
+    ScrollDown,
+}
+
+/// Represents a mouse button.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MouseButton {
+    /// Left mouse button.
+    Left,
+    /// Right mouse button.
+    Right,
+    /// Middle mouse button.
+    Middle,
+}
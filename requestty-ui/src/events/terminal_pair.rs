@@ -0,0 +1,372 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    sync::{Arc, Mutex},
+};
+
+use super::{Event, EventIterator, KeyCode, KeyEvent, KeyModifiers};
+
+const ESC: u8 = 0x1B;
+
+/// The sequences around pasted text when bracketed paste is enabled.
+const PASTE_START: &[u8] = b"\x1B[200~";
+const PASTE_END: &[u8] = b"\x1B[201~";
+
+/// The result of parsing the input at the start of a buffer.
+#[derive(Debug, PartialEq, Eq)]
+enum Parsed {
+    /// The buffer ends in the middle of a sequence, so more input has to be read.
+    Incomplete,
+    /// An event, which used the given number of bytes.
+    Event(Event, usize),
+    /// The response to a cursor position query, which used the given number of bytes. The
+    /// position is 0-indexed.
+    CursorPos((u16, u16), usize),
+    /// A sequence which is not understood, and should be skipped.
+    Skip(usize),
+}
+
+/// Parses the input sent by a vt100 compatible terminal.
+///
+/// `buf` must not be empty.
+fn parse(buf: &[u8]) -> Parsed {
+    let key = |code: KeyCode, modifiers: KeyModifiers, len: usize| {
+        Parsed::Event(Event::Key(KeyEvent::new(code, modifiers)), len)
+    };
+    let none = KeyModifiers::empty();
+
+    match buf[0] {
+        ESC => match buf.get(1) {
+            None => Parsed::Incomplete,
+            Some(&b'[') => parse_csi(buf),
+            Some(&b'O') => match buf.get(2) {
+                None => Parsed::Incomplete,
+                Some(&b) => match ss3_key(b) {
+                    Some(code) => key(code, none, 3),
+                    None => Parsed::Skip(3),
+                },
+            },
+            Some(&ESC) => key(KeyCode::Esc, none, 1),
+            Some(_) => match parse(&buf[1..]) {
+                Parsed::Event(Event::Key(k), len) => {
+                    key(k.code, k.modifiers | KeyModifiers::ALT, len + 1)
+                }
+                Parsed::Incomplete => Parsed::Incomplete,
+                _ => Parsed::Skip(1),
+            },
+        },
+        b'\r' | b'\n' => key(KeyCode::Enter, none, 1),
+        b'\t' => key(KeyCode::Tab, none, 1),
+        0x7F | 0x08 => key(KeyCode::Backspace, none, 1),
+        0 => key(KeyCode::Null, none, 1),
+        b @ 0x01..=0x1A => key(
+            KeyCode::Char((b - 1 + b'a') as char),
+            KeyModifiers::CONTROL,
+            1,
+        ),
+        b @ 0x1C..=0x1F => key(KeyCode::Char((b + 0x40) as char), KeyModifiers::CONTROL, 1),
+        b => {
+            let len = match b {
+                0xC0..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                0xF0..=0xF7 => 4,
+                0x80..=0xFF => return Parsed::Skip(1),
+                _ => 1,
+            };
+
+            if buf.len() < len {
+                return Parsed::Incomplete;
+            }
+
+            match std::str::from_utf8(&buf[..len])
+                .ok()
+                .and_then(|s| s.chars().next())
+            {
+                Some(c) => key(KeyCode::Char(c), none, len),
+                None => Parsed::Skip(1),
+            }
+        }
+    }
+}
+
+/// The keys sent as `ESC O <byte>`.
+fn ss3_key(b: u8) -> Option<KeyCode> {
+    let code = match b {
+        b'A' => KeyCode::Up,
+        b'B' => KeyCode::Down,
+        b'C' => KeyCode::Right,
+        b'D' => KeyCode::Left,
+        b'H' => KeyCode::Home,
+        b'F' => KeyCode::End,
+        b'P'..=b'S' => KeyCode::F(b - b'P' + 1),
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+/// Parses a control sequence, which looks like `ESC [ <parameters> <final byte>`.
+fn parse_csi(buf: &[u8]) -> Parsed {
+    if buf.starts_with(PASTE_START) {
+        let text = &buf[PASTE_START.len()..];
+
+        return match text.windows(PASTE_END.len()).position(|w| w == PASTE_END) {
+            Some(end) => Parsed::Event(
+                Event::Paste(String::from_utf8_lossy(&text[..end]).into_owned()),
+                PASTE_START.len() + end + PASTE_END.len(),
+            ),
+            None => Parsed::Incomplete,
+        };
+    }
+
+    let end = match buf[2..].iter().position(|b| (0x40..=0x7E).contains(b)) {
+        Some(end) => end + 2,
+        None => return Parsed::Incomplete,
+    };
+    let len = end + 1;
+
+    let params: Vec<u16> = match std::str::from_utf8(&buf[2..end]) {
+        Ok("") => Vec::new(),
+        Ok(params) => match params.split(';').map(str::parse).collect() {
+            Ok(params) => params,
+            Err(_) => return Parsed::Skip(len),
+        },
+        Err(_) => return Parsed::Skip(len),
+    };
+
+    // The second parameter of a key is 1 + a bitmask of its modifiers
+    let modifiers = match params.get(1) {
+        Some(&m) => {
+            let m = m.saturating_sub(1);
+            let mut modifiers = KeyModifiers::empty();
+            modifiers.set(KeyModifiers::SHIFT, m & 1 != 0);
+            modifiers.set(KeyModifiers::ALT, m & 2 != 0);
+            modifiers.set(KeyModifiers::CONTROL, m & 4 != 0);
+            modifiers
+        }
+        None => KeyModifiers::empty(),
+    };
+
+    let code = match (buf[end], params.first()) {
+        (b'R', Some(&row)) if params.len() == 2 => {
+            return Parsed::CursorPos((params[1].saturating_sub(1), row.saturating_sub(1)), len);
+        }
+        (b'Z', _) => KeyCode::BackTab,
+        (b'~', Some(&n)) => match n {
+            1 | 7 => KeyCode::Home,
+            2 => KeyCode::Insert,
+            3 => KeyCode::Delete,
+            4 | 8 => KeyCode::End,
+            5 => KeyCode::PageUp,
+            6 => KeyCode::PageDown,
+            11..=15 => KeyCode::F((n - 10) as u8),
+            17..=21 => KeyCode::F((n - 11) as u8),
+            23..=24 => KeyCode::F((n - 12) as u8),
+            _ => return Parsed::Skip(len),
+        },
+        (b, _) => match ss3_key(b) {
+            Some(code) => code,
+            None => return Parsed::Skip(len),
+        },
+    };
+
+    Parsed::Event(Event::Key(KeyEvent::new(code, modifiers)), len)
+}
+
+/// The read half of a [`TerminalPair`](crate::backend::TerminalPair), which is shared between
+/// the backend and its [`TerminalPairEvents`].
+pub(crate) struct PairInput<R> {
+    reader: R,
+    /// The bytes which have been read but not parsed yet.
+    pending: Vec<u8>,
+}
+
+impl<R: Read> PairInput<R> {
+    pub(crate) fn new(reader: R) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            reader,
+            pending: Vec::new(),
+        }))
+    }
+
+    fn read_more(&mut self) -> io::Result<()> {
+        let mut buf = [0; 1024];
+        let n = self.reader.read(&mut buf)?;
+
+        if n == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        self.pending.extend_from_slice(&buf[..n]);
+        Ok(())
+    }
+
+    /// Reads the next event, skipping the responses to cursor position queries.
+    fn next_event(&mut self) -> io::Result<Event> {
+        loop {
+            let parsed = match self.pending.first() {
+                // A lone escape at the end of the input cannot be the start of a sequence, since
+                // terminals send sequences all at once
+                Some(&ESC) if self.pending.len() == 1 => {
+                    Parsed::Event(Event::Key(KeyCode::Esc.into()), 1)
+                }
+                Some(_) => parse(&self.pending),
+                None => Parsed::Incomplete,
+            };
+
+            match parsed {
+                Parsed::Incomplete => self.read_more()?,
+                Parsed::Event(event, len) => {
+                    self.pending.drain(..len);
+                    return Ok(event);
+                }
+                Parsed::CursorPos(_, len) | Parsed::Skip(len) => {
+                    self.pending.drain(..len);
+                }
+            }
+        }
+    }
+
+    /// Reads till the response to a cursor position query. Everything read before it is kept to
+    /// be read as events later.
+    pub(crate) fn read_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        let mut at = 0;
+
+        loop {
+            let parsed = match self.pending.get(at..) {
+                Some(rest) if !rest.is_empty() => parse(rest),
+                _ => Parsed::Incomplete,
+            };
+
+            match parsed {
+                Parsed::CursorPos(pos, len) => {
+                    self.pending.drain(at..at + len);
+                    return Ok(pos);
+                }
+                Parsed::Event(_, len) | Parsed::Skip(len) => at += len,
+                Parsed::Incomplete => self.read_more()?,
+            }
+        }
+    }
+}
+
+/// An iterator over the keys read from the read half of a
+/// [`TerminalPair`](crate::backend::TerminalPair).
+///
+/// It is created with [`TerminalPair::events`](crate::backend::TerminalPair::events). The input
+/// is parsed as it would be sent by a vt100 compatible terminal in raw mode. Besides keys, pasted
+/// text is reported as [`Event::Paste`] once bracketed paste is enabled.
+///
+/// An escape is only read as [`KeyCode::Esc`] if nothing is sent along with it, so it can be told
+/// apart from the start of a sequence. If the reader returns an error, for example because a read
+/// timeout has been set on a socket, it is returned as it is.
+pub struct TerminalPairEvents<R> {
+    input: Arc<Mutex<PairInput<R>>>,
+}
+
+impl<R> TerminalPairEvents<R> {
+    pub(crate) fn new(input: Arc<Mutex<PairInput<R>>>) -> Self {
+        Self { input }
+    }
+}
+
+impl<R> fmt::Debug for TerminalPairEvents<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TerminalPairEvents").finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> EventIterator for TerminalPairEvents<R> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        loop {
+            if let Event::Key(key) = self.next_any_event()? {
+                return Ok(key);
+            }
+        }
+    }
+
+    fn next_any_event(&mut self) -> io::Result<Event> {
+        self.input.lock().expect("input poisoned").next_event()
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.input.lock().expect("input poisoned").pending.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers, len: usize) -> Parsed {
+        Parsed::Event(Event::Key(KeyEvent::new(code, modifiers)), len)
+    }
+
+    #[test]
+    fn test_parse() {
+        let none = KeyModifiers::empty();
+
+        assert_eq!(parse(b"ab"), key(KeyCode::Char('a'), none, 1));
+        assert_eq!(parse("é".as_bytes()), key(KeyCode::Char('é'), none, 2));
+        assert_eq!(parse(&"é".as_bytes()[..1]), Parsed::Incomplete);
+        assert_eq!(parse(b"\r"), key(KeyCode::Enter, none, 1));
+        assert_eq!(parse(b"\x7F"), key(KeyCode::Backspace, none, 1));
+        assert_eq!(
+            parse(b"\x03"),
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL, 1)
+        );
+        assert_eq!(
+            parse(b"\x1Bx"),
+            key(KeyCode::Char('x'), KeyModifiers::ALT, 2)
+        );
+        assert_eq!(parse(b"\x1B\x1B[A"), key(KeyCode::Esc, none, 1));
+
+        assert_eq!(parse(b"\x1B[A"), key(KeyCode::Up, none, 3));
+        assert_eq!(parse(b"\x1BOD"), key(KeyCode::Left, none, 3));
+        assert_eq!(parse(b"\x1BOQ"), key(KeyCode::F(2), none, 3));
+        assert_eq!(parse(b"\x1B[Z"), key(KeyCode::BackTab, none, 3));
+        assert_eq!(parse(b"\x1B[3~"), key(KeyCode::Delete, none, 4));
+        assert_eq!(parse(b"\x1B[24~"), key(KeyCode::F(12), none, 5));
+        assert_eq!(
+            parse(b"\x1B[1;5C"),
+            key(KeyCode::Right, KeyModifiers::CONTROL, 6)
+        );
+        assert_eq!(parse(b"\x1B[1;5"), Parsed::Incomplete);
+        assert_eq!(parse(b"\x1B[99~"), Parsed::Skip(5));
+
+        assert_eq!(parse(b"\x1B[12;40R"), Parsed::CursorPos((39, 11), 8));
+
+        assert_eq!(parse(b"\x1B[200~hi\x1B[201"), Parsed::Incomplete);
+        assert_eq!(
+            parse(b"\x1B[200~hi\x1B[201~"),
+            Parsed::Event(Event::Paste("hi".into()), 14)
+        );
+    }
+
+    #[test]
+    fn test_events() {
+        let input = PairInput::new(&b"a\x1B[12;40R\x1B[B\x1B"[..]);
+        let mut events = TerminalPairEvents::new(input);
+
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('a').into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Down.into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Esc.into());
+        assert_eq!(
+            events.next_event().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_read_cursor_pos() {
+        let input = PairInput::new(&b"a\x1B[A\x1B[5;10Rb"[..]);
+        let mut events = TerminalPairEvents::new(Arc::clone(&input));
+
+        assert_eq!(input.lock().unwrap().read_cursor_pos().unwrap(), (9, 4));
+
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('a').into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Up.into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('b').into());
+    }
+}
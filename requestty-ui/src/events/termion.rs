@@ -1,9 +1,15 @@
 use std::{
+    collections::VecDeque,
     convert::{TryFrom, TryInto},
     fmt,
-    io::{self, stdin, Stdin},
+    fs::File,
+    io::{self, Read, Write},
+    os::unix::io::{AsRawFd, RawFd},
+    sync::Mutex,
+    time::Duration,
 };
 
+use once_cell::sync::{Lazy, OnceCell};
 use termion::{
     event,
     input::{self, TermRead},
@@ -11,10 +17,84 @@ use termion::{
 
 use super::EventIterator;
 
+/// The bytes read from stdin while waiting for the terminal to respond to a query, which are given
+/// to [`TermionEvents`] before anything else is read from stdin.
+static TYPE_AHEAD: Lazy<Mutex<VecDeque<u8>>> = Lazy::new(|| Mutex::new(VecDeque::new()));
+
+/// The controlling terminal, once it has been opened by [`TermionEvents::from_tty`] or
+/// `TermionBackend::from_tty`. Input is read from it instead of stdin afterwards.
+static TTY: OnceCell<File> = OnceCell::new();
+
+/// How long to wait for the terminal to respond to a query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The sequences around pasted text when bracketed paste is enabled. termion does not know about
+/// them, so they are read as unsupported events.
+const PASTE_START: &[u8] = b"\x1B[200~";
+const PASTE_END: &[u8] = b"\x1B[201~";
+
+/// Queries the position of the cursor using `ESC [ 6 n`. The returned position is 0-indexed.
+///
+/// Unlike [`termion::cursor::DetectCursorPos`], keys typed before the terminal responds are not
+/// lost, and are instead read by [`TermionEvents`] afterwards.
+pub(crate) fn query_cursor_pos<W: Write>(w: &mut W) -> io::Result<(u16, u16)> {
+    write!(w, "\x1B[6n")?;
+    w.flush()?;
+
+    let mut read = Vec::new();
+    let res = crate::tty::read_cursor_pos(input_fd(), &mut read, QUERY_TIMEOUT);
+
+    TYPE_AHEAD.lock().expect("type ahead poisoned").extend(read);
+
+    res
+}
+
+/// Opens the controlling terminal, so that input is read from it instead of stdin, and returns a
+/// handle to it.
+pub(crate) fn open_tty() -> io::Result<File> {
+    TTY.get_or_try_init(crate::tty::open)?.try_clone()
+}
+
+/// The file descriptor input is read from.
+fn input_fd() -> RawFd {
+    match TTY.get() {
+        Some(tty) => tty.as_raw_fd(),
+        None => libc::STDIN_FILENO,
+    }
+}
+
+/// Stdin (or the controlling terminal if it has been opened), but the bytes in [`TYPE_AHEAD`] are
+/// read first.
+///
+/// Stdin is not buffered, since [`query_cursor_pos`] reads from it directly and anything buffered
+/// would be read out of order.
+struct TypeAheadStdin;
+
+impl Read for TypeAheadStdin {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        {
+            let mut type_ahead = TYPE_AHEAD.lock().expect("type ahead poisoned");
+
+            if !type_ahead.is_empty() {
+                let len = buf.len().min(type_ahead.len());
+                for (b, c) in buf.iter_mut().zip(type_ahead.drain(..len)) {
+                    *b = c;
+                }
+
+                return Ok(len);
+            }
+        }
+
+        crate::tty::read(input_fd(), buf)
+    }
+}
+
 /// An iterator over the input keys using the `termion` crate
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
 pub struct TermionEvents {
-    events: input::Keys<Stdin>,
+    /// `input::Keys` drops every event which is not a key, so all the events are read to be able
+    /// to return mouse events and pasted text from `next_any_event`
+    events: input::Events<TypeAheadStdin>,
 }
 
 struct Dash;
@@ -37,9 +117,21 @@ impl TermionEvents {
     /// Creates a new `TermionEvents` using stdin
     pub fn new() -> Self {
         Self {
-            events: stdin().keys(),
+            events: TypeAheadStdin.events(),
         }
     }
+
+    /// Creates a new `TermionEvents` using the controlling terminal (`/dev/tty`), so that keys can
+    /// be read even if stdin is redirected.
+    ///
+    /// Once the terminal has been opened, by this or [`TermionBackend::from_tty`], every
+    /// `TermionEvents` reads from it instead of stdin.
+    ///
+    /// [`TermionBackend::from_tty`]: crate::backend::TermionBackend::from_tty
+    pub fn from_tty() -> io::Result<Self> {
+        open_tty()?;
+        Ok(Self::new())
+    }
 }
 
 impl Default for TermionEvents {
@@ -48,13 +140,111 @@ impl Default for TermionEvents {
     }
 }
 
+impl TermionEvents {
+    fn next_termion_event(&mut self) -> io::Result<event::Event> {
+        self.events
+            .next()
+            .expect("TermionEvents ran out of user input!?")
+    }
+}
+
 impl EventIterator for TermionEvents {
     fn next_event(&mut self) -> io::Result<super::KeyEvent> {
-        let e = self
-            .events
-            .next()
-            .expect("TermionEvents ran out of user input!?")?;
-        e.try_into()
+        loop {
+            if let event::Event::Key(k) = self.next_termion_event()? {
+                return k.try_into();
+            }
+        }
+    }
+
+    fn next_any_event(&mut self) -> io::Result<super::Event> {
+        loop {
+            match self.next_termion_event()? {
+                event::Event::Key(k) => return k.try_into().map(super::Event::Key),
+                event::Event::Mouse(m) => return Ok(super::Event::Mouse(m.into())),
+                event::Event::Unsupported(ref seq) if seq == PASTE_START => {
+                    return read_paste(&mut self.events).map(super::Event::Paste);
+                }
+                event::Event::Unsupported(_) => {}
+            }
+        }
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        TYPE_AHEAD.lock().expect("type ahead poisoned").clear();
+
+        // Only a terminal has input that can be flushed, anything else (like a pipe) is kept as it
+        // is
+        // SAFETY: `isatty` and `tcflush` have no preconditions
+        #[allow(unsafe_code)]
+        let res = unsafe {
+            let fd = input_fd();
+            if libc::isatty(fd) == 0 {
+                return Ok(());
+            }
+
+            libc::tcflush(fd, libc::TCIFLUSH)
+        };
+
+        if res == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    fn wait_for_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        if !TYPE_AHEAD.lock().expect("type ahead poisoned").is_empty() {
+            return Ok(true);
+        }
+
+        crate::tty::poll(input_fd(), timeout)
+    }
+}
+
+/// Reads the text pasted after a [`PASTE_START`] up to the [`PASTE_END`].
+///
+/// termion reads the text as keys, and keys which are not characters are dropped.
+fn read_paste<I: Iterator<Item = io::Result<event::Event>>>(events: &mut I) -> io::Result<String> {
+    let mut text = String::new();
+
+    for event in events {
+        match event? {
+            event::Event::Unsupported(ref seq) if seq == PASTE_END => return Ok(text),
+            event::Event::Key(event::Key::Char(c)) => text.push(c),
+            _ => {}
+        }
+    }
+
+    Err(io::ErrorKind::UnexpectedEof.into())
+}
+
+impl From<event::MouseEvent> for super::MouseEvent {
+    fn from(event: event::MouseEvent) -> Self {
+        let (kind, column, row) = match event {
+            event::MouseEvent::Press(button, column, row) => {
+                let kind = match button {
+                    event::MouseButton::Left => {
+                        super::MouseEventKind::Down(super::MouseButton::Left)
+                    }
+                    event::MouseButton::Right => {
+                        super::MouseEventKind::Down(super::MouseButton::Right)
+                    }
+                    event::MouseButton::Middle => {
+                        super::MouseEventKind::Down(super::MouseButton::Middle)
+                    }
+                    event::MouseButton::WheelUp => super::MouseEventKind::ScrollUp,
+                    event::MouseButton::WheelDown => super::MouseEventKind::ScrollDown,
+                };
+
+                (kind, column, row)
+            }
+            event::MouseEvent::Release(column, row) => (super::MouseEventKind::Up, column, row),
+            event::MouseEvent::Hold(column, row) => (super::MouseEventKind::Drag, column, row),
+        };
+
+        // termion positions are 1-based
+        super::MouseEvent::new(kind, column.saturating_sub(1), row.saturating_sub(1))
     }
 }
 
@@ -146,3 +336,24 @@ fn parse_char(mut c: char, mut modifiers: super::KeyModifiers) -> io::Result<sup
 
     Ok(super::KeyEvent::new(code, modifiers))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_paste() {
+        let mut events = (&b"ab\nc\x1B[Dd\x1B[201~e"[..]).events();
+        assert_eq!(read_paste(&mut events).unwrap(), "ab\ncd");
+        assert_eq!(
+            events.next().unwrap().unwrap(),
+            event::Event::Key(event::Key::Char('e'))
+        );
+
+        let mut events = (&b"ab"[..]).events();
+        assert_eq!(
+            read_paste(&mut events).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
@@ -1,15 +1,23 @@
 use std::{
     io,
     ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
 
 use super::Widget;
+#[cfg(feature = "async")]
+use crate::events::AsyncEventIterator;
 use crate::{
-    backend::{Backend, ClearType, MoveDirection, Size},
+    backend::{Backend, Buffer, ClearType, MoveDirection, Size},
     error,
-    events::{EventIterator, KeyCode, KeyModifiers},
-    layout::Layout,
-    style::Stylize,
+    error_view::ErrorView,
+    events::{Event, EventIterator, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind},
+    layout::{Alignment, Layout},
+    prompt_lock::PromptLock,
 };
 
 /// The state of a prompt on validation.
@@ -39,6 +47,64 @@ pub enum OnEsc {
     Ignore,
 }
 
+/// How serious an error returned by [`Prompt::validate`] is.
+///
+/// See [`Prompt::severity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The prompt cannot be submitted until the error is fixed.
+    Error,
+    /// The prompt may be wrong, but it can still be submitted. The warning is shown, and the
+    /// prompt is submitted if it is submitted again without any changes.
+    Warning,
+    /// Information about the prompt, which is shown the same way as a [`Warning`](Self::Warning)
+    /// but in a different color.
+    Info,
+}
+
+/// A flag to cancel a running [`Input`], which can be shared with other threads.
+///
+/// Clones of a token share the same flag, so cancelling any of them cancels all of them. See
+/// [`Input::run_with_cancellation`].
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new `CancellationToken` which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the inputs that are run with this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// The error returned by [`Input::run_with_recovery`], along with the prompt as it was when the
+/// error occurred.
+#[derive(Debug)]
+pub struct Recovered<P> {
+    /// The error which stopped the prompt.
+    pub error: error::ErrorKind,
+    /// The prompt, with whatever the user had entered before the error.
+    pub prompt: P,
+}
+
+impl<P> From<Recovered<P>> for error::ErrorKind {
+    fn from(recovered: Recovered<P>) -> Self {
+        recovered.error
+    }
+}
+
+/// How often the [`CancellationToken`] is checked while waiting for events.
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// This trait should be implemented by all 'root' widgets.
 ///
 /// It provides the functionality required only by the main controlling widget. For the trait
@@ -54,12 +120,84 @@ pub trait Prompt: Widget {
     type Output;
 
     /// Determine whether the prompt state is ready to be submitted. It is called whenever the user
-    /// presses the enter key.
+    /// presses a key for which [`is_submit_key`](Prompt::is_submit_key) returns true.
     ///
     /// See [`Validation`]
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
         Ok(Validation::Finish)
     }
+
+    /// How serious an error returned by [`validate`](Prompt::validate) is.
+    ///
+    /// Errors which are not [`Severity::Error`] don't prevent the prompt from being submitted. They
+    /// are shown to the user, and if the prompt is submitted again without any changes, it is
+    /// finished even if `validate` returns an error again.
+    ///
+    /// By default, all errors are [`Severity::Error`].
+    fn severity(&self, err: &Self::ValidateErr) -> Severity {
+        let _ = err;
+        Severity::Error
+    }
+
+    /// A plain description of the current state of the prompt, for example
+    /// `Selected: Option B (3 of 7)`.
+    ///
+    /// In [accessible mode](crate::accessibility), the description is printed on a new line when
+    /// the prompt starts and whenever it changes, instead of rendering the prompt. If it is `None`,
+    /// the prompt is rendered normally even in accessible mode.
+    ///
+    /// By default, prompts are not described.
+    fn describe(&mut self) -> Option<String> {
+        None
+    }
+
+    /// A plain description of an error returned by [`validate`](Prompt::validate), which is
+    /// printed instead of rendering the error in [accessible mode](crate::accessibility).
+    ///
+    /// By default, it is `Invalid answer`.
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        let _ = err;
+        "Invalid answer".to_owned()
+    }
+
+    /// Whether the key should submit the prompt, in which case [`validate`](Prompt::validate) is
+    /// called instead of passing the key to [`Widget::handle_key`].
+    ///
+    /// By default, the enter key submits the prompt.
+    fn is_submit_key(&self, key: KeyEvent) -> bool {
+        key.code == KeyCode::Enter
+    }
+
+    /// Whether the mouse event should submit the prompt, in which case
+    /// [`validate`](Prompt::validate) is called instead of passing the event to
+    /// [`Widget::handle_mouse`].
+    ///
+    /// By default, mouse events never submit the prompt.
+    fn is_submit_click(&self, mouse: MouseEvent) -> bool {
+        let _ = mouse;
+        false
+    }
+
+    /// How long the prompt can wait for an event before [`tick`](Prompt::tick) should be called,
+    /// for example to update the prompt once the user stops typing. It is checked before waiting
+    /// for every event.
+    ///
+    /// Prompts are only ticked while waiting with [`EventIterator::wait_for_event`], so they are
+    /// never ticked with iterators which don't support it or in [`Input::run_async`].
+    ///
+    /// By default, it is `None` and the prompt is never ticked.
+    fn next_tick(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called when no event was received in the time returned by
+    /// [`next_tick`](Prompt::next_tick). Returns whether the prompt should be rendered again.
+    ///
+    /// By default, this does nothing.
+    fn tick(&mut self) -> bool {
+        false
+    }
+
     /// The value to return from [`Input::run`]. This will only be called once validation returns
     /// [`Validation::Finish`]
     fn finish(self) -> Self::Output;
@@ -76,10 +214,28 @@ pub trait Prompt: Widget {
 pub struct Input<P, B: Backend> {
     prompt: P,
     on_esc: OnEsc,
+    back_key: Option<KeyEvent>,
     backend: TerminalState<B>,
     base_row: u16,
     size: Size,
     render_overflow: bool,
+    /// The first row of the prompt which is shown when it is taller than the terminal
+    scroll: u16,
+    /// The number of rows of the prompt which are shown when it is taller than the terminal
+    shown_rows: u16,
+    /// Whether to scroll to the [focused rows](Widget::focused_rows) of the prompt when it is
+    /// rendered next
+    follow_focus: bool,
+    warning_shown: bool,
+    accessible: bool,
+    /// The description last printed in accessible mode
+    description: Option<String>,
+    nested: bool,
+    cursor_pos_fallback: bool,
+    /// The frame last drawn by [`render`](Input::render), if nothing else has been drawn since
+    frame: Option<Buffer>,
+    max_width: Option<u16>,
+    alignment: Alignment,
 }
 
 impl<P, B: Backend> Input<P, B> {
@@ -92,10 +248,22 @@ impl<P, B: Backend> Input<P, B> {
         Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            back_key: None,
             backend: TerminalState::new(backend, false),
             base_row: 0,
             size: Size::default(),
             render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: crate::accessibility::enabled(),
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
         }
     }
 
@@ -105,6 +273,81 @@ impl<P, B: Backend> Input<P, B> {
         self
     }
 
+    /// Captures mouse events while running the input, so that they are passed to
+    /// [`Widget::handle_mouse`].
+    ///
+    /// While the mouse is captured, the terminal cannot be used to select text or scroll.
+    pub fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.backend.mouse_capture = mouse_capture;
+        self
+    }
+
+    /// Asks the terminal to report keys with modifiers unambiguously while running the input. This
+    /// is required for the prompt to tell `Shift+Enter` apart from `Enter`.
+    ///
+    /// Terminals which don't support this continue to report keys as they would otherwise. See
+    /// [`Backend::enable_keyboard_enhancement`] and [`KeyModifiers`] for more information.
+    pub fn keyboard_enhancement(mut self, keyboard_enhancement: bool) -> Self {
+        self.backend.keyboard_enhancement = keyboard_enhancement;
+        self
+    }
+
+    /// Prints a plain line whenever the prompt changes instead of redrawing it, if the prompt can
+    /// [describe](Prompt::describe) itself. This won't do anything until it is [run](Input::run).
+    ///
+    /// If this is not set, it defaults to [`accessibility::enabled`](crate::accessibility::enabled).
+    pub fn accessible(mut self, accessible: bool) -> Self {
+        self.accessible = accessible;
+        self
+    }
+
+    /// Allows the input to be run while another prompt is running on the same thread, for example
+    /// from [`Prompt::validate`]. This won't do anything until it is [run](Input::run).
+    ///
+    /// Only one prompt can use the terminal at a time, so prompts run on other threads wait for
+    /// the running one to finish. A prompt run on the same thread returns
+    /// [`Error::PromptInProgress`](error::ErrorKind::PromptInProgress) instead of waiting forever,
+    /// unless this is set. The nested prompt is rendered below the running one, which is not
+    /// rendered again until the nested prompt finishes.
+    ///
+    /// Prompts run [asynchronously](Input::run_async) wait for the running one without blocking
+    /// the thread, and are only considered to be running on a thread while they handle an event.
+    pub fn nested(mut self, nested: bool) -> Self {
+        self.nested = nested;
+        self
+    }
+
+    /// Whether to fall back to a new line at the bottom of the terminal when the position of the
+    /// cursor cannot be detected. This won't do anything until it is [run](Input::run).
+    ///
+    /// Some terminals don't reply to the query for the cursor position, or reply with a position
+    /// outside the terminal. If this is set, which is the default, a new line is started and the
+    /// prompt is rendered on the last row of the terminal. Otherwise, running the input returns
+    /// [`Error::CursorPosition`](error::ErrorKind::CursorPosition).
+    pub fn cursor_pos_fallback(mut self, cursor_pos_fallback: bool) -> Self {
+        self.cursor_pos_fallback = cursor_pos_fallback;
+        self
+    }
+
+    /// The maximum width of the prompt, which is placed in the terminal as per the
+    /// [`alignment`](Input::alignment) if the terminal is wider. This won't do anything until it
+    /// is [run](Input::run).
+    ///
+    /// See [`Layout::max_width`] for the widgets which take this into account.
+    pub fn max_width(mut self, max_width: u16) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Where the prompt is placed if the terminal is wider than the [`max_width`](Input::max_width).
+    /// This won't do anything until it is [run](Input::run).
+    ///
+    /// If this is not set, it defaults to [`Alignment::Left`].
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
     /// What to do after receiving a `Esc`.
     ///
     /// For [`OnEsc::Terminate`] - an [`Error::Aborted`](error::ErrorKind::Aborted) will be returned.
@@ -116,11 +359,29 @@ impl<P, B: Backend> Input<P, B> {
         self.on_esc = on_esc;
         self
     }
+
+    /// The key which goes back to the previous question.
+    ///
+    /// When it is pressed, the currently shown prompt will be cleared, and an
+    /// [`Error::Back`](error::ErrorKind::Back) will be returned. The key matches events with the
+    /// same code and at least the same modifiers, so that for example `BackTab` also matches it
+    /// when the terminal reports `Shift` along with it. It takes priority over `Esc` and the keys
+    /// of the prompt.
+    ///
+    /// If this is not set, it defaults to `None`.
+    pub fn back_key(mut self, back_key: Option<KeyEvent>) -> Self {
+        self.back_key = back_key;
+        self
+    }
 }
 
 impl<P: Prompt, B: Backend> Input<P, B> {
     fn layout(&self) -> Layout {
-        Layout::new(0, self.size).with_offset(0, self.base_row)
+        let mut layout = Layout::new(0, self.size)
+            .with_offset(0, self.base_row)
+            .with_alignment(self.alignment);
+        layout.max_width = self.max_width;
+        layout
     }
 
     fn update_size(&mut self) -> io::Result<()> {
@@ -138,10 +399,71 @@ impl<P: Prompt, B: Backend> Input<P, B> {
         }
     }
 
-    fn init(&mut self) -> io::Result<()> {
+    fn init(&mut self) -> error::Result<()> {
         self.backend.init()?;
-        self.base_row = self.backend.get_cursor_pos()?.1;
-        self.render()
+
+        if self.accessible && self.prompt.describe().is_some() {
+            // The description is printed again, for example after the process is resumed
+            self.description = None;
+            self.render()?;
+            return Ok(());
+        }
+
+        self.accessible = false;
+        self.base_row = self.detect_base_row()?;
+        self.render()?;
+        Ok(())
+    }
+
+    /// Gets the row of the cursor, falling back to a new line at the bottom of the terminal if it
+    /// cannot be detected.
+    fn detect_base_row(&mut self) -> error::Result<u16> {
+        self.update_size()?;
+
+        let err = match self.backend.get_cursor_pos() {
+            Ok((x, y)) if x < self.size.width && y < self.size.height => return Ok(y),
+            Ok(pos) => io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Invalid cursor position {:?} for terminal {:?}",
+                    pos, self.size
+                ),
+            ),
+            Err(e) => e,
+        };
+
+        if !self.cursor_pos_fallback {
+            return Err(error::ErrorKind::CursorPosition(err));
+        }
+
+        // The cursor could be anywhere, so a new line is started to avoid writing over the current
+        // one. Everything below the cursor is empty, so the last row is always safe to render on.
+        self.backend.write_all(b"\r\n")?;
+        Ok(self.size.height - 1)
+    }
+
+    /// Prints `text` below everything printed so far, without moving the cursor anywhere else.
+    fn print_lines(&mut self, text: &str) -> io::Result<()> {
+        for line in text.lines() {
+            self.backend.write_all(line.as_bytes())?;
+            // The terminal is in raw mode, so the cursor must be moved to the start of the line
+            // as well
+            self.backend.write_all(b"\r\n")?;
+        }
+
+        self.backend.flush()
+    }
+
+    /// Prints the description of the prompt in accessible mode if it has changed.
+    fn describe(&mut self) -> io::Result<()> {
+        let description = self.prompt.describe();
+
+        if description.is_some() && description != self.description {
+            self.print_lines(description.as_deref().unwrap())?;
+            self.description = description;
+        }
+
+        Ok(())
     }
 
     fn adjust_scrollback(&mut self, height: u16) -> io::Result<u16> {
@@ -162,207 +484,688 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     fn flush(&mut self) -> io::Result<()> {
         if !self.backend.hide_cursor {
             let (x, y) = self.prompt.cursor_pos(self.layout());
+            let y = if self.render_overflow {
+                y.checked_sub(self.scroll)
+            } else {
+                Some(y)
+            };
 
-            if self.render_overflow && y >= self.size.height - 1 {
-                // If the height of the prompt exceeds the height of the terminal a cut-off message
-                // is displayed at the bottom. If the cursor is positioned on this cut-off, then we
-                // hide it.
-                if !self.backend.cursor_hidden {
-                    self.backend.cursor_hidden = true;
-                    self.backend.hide_cursor()?;
+            let y = match y {
+                // If the height of the prompt exceeds the height of the terminal, only some of its
+                // rows are shown above a scroll indicator. If the cursor is not on one of the shown
+                // rows, then we hide it.
+                Some(y) if !self.render_overflow || y < self.shown_rows => {
+                    if self.backend.cursor_hidden {
+                        // Otherwise, the cursor should be visible, and currently is not. So, we
+                        // show it.
+                        self.backend.cursor_hidden = false;
+                        self.backend.show_cursor()?;
+                    }
+                    y
                 }
-            } else if self.backend.cursor_hidden {
-                // Otherwise, the cursor should be visible, and currently is not. So, we show it.
-                self.backend.cursor_hidden = false;
-                self.backend.show_cursor()?;
-            }
+                _ => {
+                    if !self.backend.cursor_hidden {
+                        self.backend.cursor_hidden = true;
+                        self.backend.hide_cursor()?;
+                    }
+                    self.shown_rows
+                }
+            };
 
             self.backend.move_cursor_to(x, y)?;
         }
         self.backend.flush()
     }
 
-    fn render_cutoff_msg(&mut self) -> io::Result<()> {
-        let cross = crate::symbols::current().cross;
-        self.backend.set_fg(crate::style::Color::DarkGrey)?;
-        write!(
-            self.backend,
-            "{0} the window height is too small, the prompt has been cut-off {0}",
-            cross
-        )?;
-        self.backend.set_fg(crate::style::Color::Reset)
+    /// Renders the prompt into the first `rows` rows of `frame`.
+    ///
+    /// If the prompt is taller than `rows`, only the rows starting at [`scroll`](Input::scroll) are
+    /// shown, above a scroll indicator on the last of the `rows`. The prompt is scrolled to show its
+    /// [focused rows](Widget::focused_rows) if they should be followed.
+    fn render_prompt(&mut self, height: u16, rows: u16, frame: &mut Buffer) -> io::Result<()> {
+        self.render_overflow = height > rows;
+
+        if !self.render_overflow {
+            self.scroll = 0;
+            frame.move_cursor_to(0, self.base_row)?;
+            return self.prompt.render(&mut self.layout(), frame);
+        }
+
+        // The prompt is always scrolled to the top of the terminal when it does not fit, so the
+        // rows of the prompt are the rows of the full frame
+        let mut full = Buffer::new(Size {
+            width: self.size.width,
+            height,
+        })
+        .with_capabilities_of(frame);
+        self.prompt.render(&mut self.layout(), &mut full)?;
+
+        self.shown_rows = rows.saturating_sub(1);
+
+        if self.follow_focus {
+            let focused = self.prompt.focused_rows(self.layout());
+
+            if focused.end > self.scroll + self.shown_rows {
+                self.scroll = focused.end.saturating_sub(self.shown_rows);
+            }
+            if focused.start < self.scroll {
+                self.scroll = focused.start;
+            }
+        }
+        self.scroll = self.scroll.min(height - self.shown_rows);
+
+        frame.copy_rows(&full, self.scroll, 0, self.shown_rows);
+        frame.move_cursor_to(0, self.shown_rows)?;
+        self.render_scroll_indicator(height, frame)
+    }
+
+    fn render_scroll_indicator<W: Backend + ?Sized>(
+        &self,
+        height: u16,
+        frame: &mut W,
+    ) -> io::Result<()> {
+        let arrow = crate::symbols::current().arrow;
+        frame.set_fg(crate::style::theme().hint)?;
+
+        if self.shown_rows == 0 {
+            write!(frame, "{} {} lines are hidden", arrow, height)?;
+        } else {
+            write!(
+                frame,
+                "{} lines {}-{} of {}, scroll with PageUp and PageDown",
+                arrow,
+                self.scroll + 1,
+                self.scroll + self.shown_rows,
+                height
+            )?;
+        }
+
+        frame.set_fg(crate::style::Color::Reset)
+    }
+
+    /// Scrolls the prompt with the key if it is taller than the terminal, returning whether it was
+    /// scrolled.
+    fn scroll_with_key(&mut self, key: KeyEvent) -> bool {
+        if !self.render_overflow {
+            return false;
+        }
+
+        let scroll = match key.code {
+            KeyCode::Up => self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll.saturating_sub(self.shown_rows.max(1)),
+            KeyCode::PageDown => self.scroll.saturating_add(self.shown_rows.max(1)),
+            _ => return false,
+        };
+
+        // The scroll is clamped to the height of the prompt when it is rendered
+        let height = self.prompt.height(&mut self.layout());
+        let scroll = scroll.min(height.saturating_sub(self.shown_rows));
+
+        if scroll == self.scroll {
+            return false;
+        }
+
+        self.scroll = scroll;
+        self.follow_focus = false;
+        true
     }
 
+    /// Renders the prompt into a new frame, and draws the cells which are different from the
+    /// previous frame.
     fn render(&mut self) -> io::Result<()> {
+        // Rendering the prompt clears any warning, so it must be shown again before the prompt can
+        // be submitted with it
+        self.warning_shown = false;
+
+        if self.accessible {
+            return self.describe();
+        }
+
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
-        self.base_row = self.adjust_scrollback(height)?;
-        self.clear()?;
+        let base_row = self.adjust_scrollback(height)?;
+        if base_row != self.base_row {
+            // The terminal was scrolled, so the previous frame is not where it was drawn anymore
+            self.frame = None;
+        }
+        self.base_row = base_row;
 
-        self.prompt.render(&mut self.layout(), &mut *self.backend)?;
-        self.render_overflow = height > self.size.height;
+        let mut frame = Buffer::new(self.size).with_capabilities_of(&*self.backend);
+        self.render_prompt(height, self.size.height, &mut frame)?;
 
-        if self.render_overflow {
-            self.backend.move_cursor_to(0, self.size.height - 1)?;
-            self.render_cutoff_msg()?;
-        }
+        frame.draw(self.frame.as_ref(), self.base_row, &mut *self.backend)?;
+        self.frame = Some(frame);
 
         self.flush()
     }
 
     fn clear(&mut self) -> io::Result<()> {
+        if self.accessible {
+            // Everything printed in accessible mode is left as it is
+            return Ok(());
+        }
+
+        self.frame = None;
         self.backend.move_cursor_to(0, self.base_row)?;
         self.backend.clear(ClearType::FromCursorDown)
     }
 
     fn goto_last_line(&mut self, height: u16) -> io::Result<()> {
         self.base_row = self.adjust_scrollback(height + 1)?;
-        self.backend.move_cursor_to(0, self.base_row + height)
+        self.backend
+            .move_cursor_to(0, self.base_row.saturating_add(height))
     }
 
-    fn print_error(&mut self, mut e: P::ValidateErr) -> io::Result<()> {
+    fn print_error(&mut self, e: P::ValidateErr, severity: Severity) -> io::Result<()> {
+        if self.accessible {
+            let kind = match severity {
+                Severity::Error => "Error",
+                Severity::Warning => "Warning",
+                Severity::Info => "Info",
+            };
+            let line = format!("{}: {}", kind, self.prompt.describe_error(&e));
+            return self.print_lines(&line);
+        }
+
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
-        self.base_row = self.adjust_scrollback(height + 1)?;
-        self.clear()?;
-        self.prompt.render(&mut self.layout(), &mut *self.backend)?;
-
-        self.goto_last_line(height)?;
 
-        let mut layout = Layout::new(2, self.size).with_offset(0, self.base_row + height);
-        let err_height = e.height(&mut layout.clone());
-        self.base_row = self.adjust_scrollback(height + err_height)?;
+        // The error lines up with the message of the prompt. It can take all but one row, which is
+        // left for the scroll indicator if the prompt does not fit above it.
+        let prompt_layout = self.layout().aligned().with_line_offset(0);
+        let mut error = ErrorView::new(e, severity);
+        let err_height = error
+            .height(&mut prompt_layout.with_max_height(self.size.height.saturating_sub(1).max(1)));
 
-        if self.render_overflow {
-            self.backend
-                .move_cursor_to(0, self.size.height - err_height - 1)?;
-            self.backend.clear(ClearType::FromCursorDown)?;
-            self.render_cutoff_msg()?;
-            self.backend
-                .move_cursor_to(0, self.size.height - err_height)?;
+        let base_row = self.adjust_scrollback(height.saturating_add(err_height))?;
+        if base_row != self.base_row {
+            self.frame = None;
         }
+        self.base_row = base_row;
+
+        // Like the prompt, the error is rendered into a frame, so that it is cleared like any other
+        // change when the prompt is rendered again.
+        // If they do not both fit, the prompt is scrolled in the rows above the error
+        let err_row = self
+            .base_row
+            .saturating_add(height)
+            .min(self.size.height.saturating_sub(err_height));
+
+        let mut frame = Buffer::new(self.size).with_capabilities_of(&*self.backend);
+        self.render_prompt(height, err_row, &mut frame)?;
+        frame.move_cursor_to(0, err_row)?;
+        frame.clear(ClearType::FromCursorDown)?;
+
+        error.render(
+            &mut prompt_layout
+                .with_offset(prompt_layout.offset_x, err_row)
+                .with_max_height(err_height),
+            &mut frame,
+        )?;
 
-        self.backend
-            .write_styled(&crate::symbols::current().cross.red())?;
-        self.backend.write_all(b" ")?;
-
-        e.render(&mut layout, &mut *self.backend)?;
+        frame.draw(self.frame.as_ref(), self.base_row, &mut *self.backend)?;
+        self.frame = Some(frame);
 
         self.flush()
     }
 
+    /// Clears the prompt and restores the terminal while the process is suspended, rendering it
+    /// again once the process is resumed.
+    fn suspend(&mut self) -> error::Result<()> {
+        self.clear()?;
+        self.backend.reset()?;
+        self.backend.suspend()?;
+        // Other programs may have used the terminal while the process was suspended, so the
+        // prompt is rendered wherever the cursor is now.
+        self.init()
+    }
+
     fn exit(&mut self) -> io::Result<()> {
+        if self.accessible {
+            return self.backend.reset();
+        }
+
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
         self.goto_last_line(height)?;
         self.backend.reset()
     }
 
-    /// Display the prompt and process events until the user presses `Enter`.
-    ///
-    /// After the user presses `Enter`, [`validate`](Prompt::validate) will be called.
-    pub fn run<E>(mut self, events: &mut E) -> error::Result<Option<P::Output>>
-    where
-        E: EventIterator,
-    {
-        self.init()?;
-
-        loop {
-            let e = events.next_event()?;
+    fn is_back_key(&self, e: KeyEvent) -> bool {
+        match self.back_key {
+            Some(key) => e.code == key.code && e.modifiers.contains(key.modifiers),
+            None => false,
+        }
+    }
 
-            let key_handled = match e.code {
-                KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.exit()?;
-                    return Err(error::ErrorKind::Interrupted);
-                }
-                KeyCode::Null => {
-                    self.exit()?;
-                    return Err(error::ErrorKind::Eof);
-                }
-                KeyCode::Esc if self.on_esc == OnEsc::Terminate => {
-                    self.exit()?;
-                    return Err(error::ErrorKind::Aborted);
+    /// Handles a single event, returning what the event loop should do next.
+    fn handle_event(&mut self, e: Event) -> error::Result<Step> {
+        let e = match e {
+            Event::Key(e) => e,
+            Event::Mouse(mut e) => {
+                // The prompt is given positions as if it was not scrolled
+                if self.render_overflow {
+                    e.row = e.row.saturating_add(self.scroll);
                 }
-                KeyCode::Esc if self.on_esc == OnEsc::SkipQuestion => {
-                    self.clear()?;
-                    self.backend.reset()?;
 
-                    return Ok(None);
+                if self.prompt.is_submit_click(e) {
+                    return self.submit();
                 }
-                KeyCode::Enter => match self.prompt.validate() {
-                    Ok(Validation::Finish) => {
-                        self.clear()?;
-                        self.backend.reset()?;
 
-                        return Ok(Some(self.prompt.finish()));
+                let handled = if self.prompt.handle_mouse(e) {
+                    self.follow_focus = true;
+                    true
+                } else {
+                    match e.kind {
+                        MouseEventKind::ScrollUp => self.scroll_with_key(KeyCode::Up.into()),
+                        MouseEventKind::ScrollDown => self.scroll_with_key(KeyCode::Down.into()),
+                        _ => false,
                     }
-                    Ok(Validation::Continue) => true,
-                    Err(e) => {
-                        self.print_error(e)?;
+                };
 
-                        continue;
-                    }
-                },
-                _ => self.prompt.handle_key(e),
-            };
+                if handled {
+                    self.render()?;
+                }
+
+                return Ok(Step::Continue);
+            }
+            Event::Paste(text) => {
+                // The whole text is rendered at once, instead of after every character
+                if self.prompt.handle_paste(&text) {
+                    self.follow_focus = true;
+                    self.render()?;
+                }
 
-            if key_handled {
+                return Ok(Step::Continue);
+            }
+            Event::Resize(size) => {
+                self.backend.resized(size);
+                // Terminals keep the cursor on screen when they shrink, so the prompt cannot start
+                // below the last row anymore.
+                self.base_row = self.base_row.min(size.height.saturating_sub(1));
+                // Terminals may reflow what was drawn when they are resized
+                self.frame = None;
                 self.render()?;
+
+                return Ok(Step::Continue);
             }
-        }
-    }
-}
+        };
 
-#[derive(Debug)]
-struct TerminalState<B: Backend> {
-    backend: B,
-    hide_cursor: bool,
-    cursor_hidden: bool,
-    enabled: bool,
-}
+        #[cfg(feature = "tracing")]
+        tracing::trace!(key = ?e, "handling key");
 
-impl<B: Backend> TerminalState<B> {
-    fn new(backend: B, hide_cursor: bool) -> Self {
-        Self {
-            backend,
-            enabled: false,
-            hide_cursor,
-            cursor_hidden: false,
+        let key_handled = match e.code {
+            _ if self.is_back_key(e) => {
+                self.clear()?;
+                self.backend.reset()?;
+
+                return Err(error::ErrorKind::Back);
+            }
+            KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.exit()?;
+                return Err(error::ErrorKind::Interrupted);
+            }
+            KeyCode::Char('z') if e.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.suspend()?;
+
+                return Ok(Step::Continue);
+            }
+            KeyCode::Null => {
+                self.exit()?;
+                return Err(error::ErrorKind::Eof);
+            }
+            KeyCode::Esc if self.on_esc == OnEsc::Terminate => {
+                self.exit()?;
+                return Err(error::ErrorKind::Aborted);
+            }
+            KeyCode::Esc if self.on_esc == OnEsc::SkipQuestion => {
+                self.clear()?;
+                self.backend.reset()?;
+
+                return Ok(Step::Skip);
+            }
+            _ if self.prompt.is_submit_key(e) => return self.submit(),
+            _ if self.prompt.handle_key(e) => {
+                self.follow_focus = true;
+                true
+            }
+            _ => self.scroll_with_key(e),
+        };
+
+        if key_handled {
+            self.render()?;
         }
+
+        Ok(Step::Continue)
     }
 
-    fn init(&mut self) -> io::Result<()> {
-        self.enabled = true;
-        if self.hide_cursor && !self.cursor_hidden {
-            self.backend.hide_cursor()?;
-            self.cursor_hidden = true;
+    /// Validates the prompt after it was submitted, returning what the event loop should do next.
+    fn submit(&mut self) -> error::Result<Step> {
+        // Validation may use the terminal, for example to open an editor
+        self.frame = None;
+
+        match self.prompt.validate() {
+            Ok(Validation::Finish) => {}
+            Ok(Validation::Continue) => {
+                self.render()?;
+                return Ok(Step::Continue);
+            }
+            Err(e) => match self.prompt.severity(&e) {
+                // The user has already seen the warning, and chose to submit anyways
+                Severity::Warning | Severity::Info if self.warning_shown => {}
+                severity => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        ?severity,
+                        error = %self.prompt.describe_error(&e),
+                        "validation failed"
+                    );
+
+                    self.print_error(e, severity)?;
+                    self.warning_shown = severity != Severity::Error;
+
+                    return Ok(Step::Continue);
+                }
+            },
         }
-        self.backend.enable_raw_mode()
-    }
 
-    fn reset(&mut self) -> io::Result<()> {
-        self.enabled = false;
-        if self.cursor_hidden {
-            self.backend.show_cursor()?;
-            self.cursor_hidden = false;
+        self.clear()?;
+        self.backend.reset()?;
+
+        Ok(Step::Finish)
+    }
+    /// Display the prompt and process events until the user presses `Enter`.
+    ///
+    /// After the user presses `Enter`, [`validate`](Prompt::validate) will be called. Prompts can
+    /// use other keys to submit with [`Prompt::is_submit_key`].
+    ///
+    /// Pressing `Ctrl+Z` clears the prompt and [suspends](Backend::suspend) the process, rendering
+    /// the prompt again once it is resumed.
+    ///
+    /// If a prompt is running on another thread, this waits for it to finish first. See
+    /// [`nested`](Input::nested) for running a prompt while another is running on the same thread.
+    pub fn run<E>(mut self, events: &mut E) -> error::Result<Option<P::Output>>
+    where
+        E: EventIterator,
+    {
+        match self.run_until_done(events)? {
+            Step::Finish => Ok(Some(self.prompt.finish())),
+            _ => Ok(None),
         }
-        self.backend.disable_raw_mode()
     }
-}
 
-impl<B: Backend> Drop for TerminalState<B> {
-    fn drop(&mut self) {
-        if self.enabled {
-            let _ = self.reset();
+    /// Display the prompt and process events like [`run`](Input::run), returning the prompt along
+    /// with the error if it fails.
+    ///
+    /// This can be used to get what the user had entered before they interrupted or aborted the
+    /// prompt, for example to let them resume from it later.
+    pub fn run_with_recovery<E>(mut self, events: &mut E) -> Result<Option<P::Output>, Recovered<P>>
+    where
+        E: EventIterator,
+    {
+        match self.run_until_done(events) {
+            Ok(Step::Finish) => Ok(Some(self.prompt.finish())),
+            Ok(_) => Ok(None),
+            Err(error) => Err(Recovered {
+                error,
+                prompt: self.prompt,
+            }),
         }
     }
-}
 
-impl<B: Backend> Deref for TerminalState<B> {
-    type Target = B;
+    /// Processes events until the prompt is either finished or skipped.
+    fn run_until_done<E>(&mut self, events: &mut E) -> error::Result<Step>
+    where
+        E: EventIterator,
+    {
+        let _lock = PromptLock::acquire(self.nested)?;
+        self.init()?;
 
-    fn deref(&self) -> &Self::Target {
-        &self.backend
+        loop {
+            if !self.wait_or_tick(events, None)? {
+                continue;
+            }
+
+            let e = events.next_any_event()?;
+
+            match self.handle_event(e)? {
+                Step::Continue => {}
+                step => return Ok(step),
+            }
+        }
+    }
+
+    /// Waits for an event for at most `timeout`, or until the prompt should be
+    /// [ticked](Prompt::tick), and ticks it. Returns whether there is an event to read.
+    fn wait_or_tick<E>(&mut self, events: &mut E, timeout: Option<Duration>) -> error::Result<bool>
+    where
+        E: EventIterator,
+    {
+        let tick = self.prompt.next_tick();
+        let wait = match (tick, timeout) {
+            (Some(tick), Some(timeout)) => tick.min(timeout),
+            (Some(wait), None) | (None, Some(wait)) => wait,
+            (None, None) => return Ok(true),
+        };
+
+        if events.wait_for_event(wait)? {
+            return Ok(true);
+        }
+
+        if matches!(tick, Some(tick) if tick <= wait) && self.prompt.tick() {
+            self.render()?;
+        }
+
+        Ok(false)
+    }
+
+    /// Display the prompt and process events until the user presses `Enter`, or the `token` is
+    /// cancelled.
+    ///
+    /// This is the same as [`run`](Input::run), except that the `token` is checked periodically
+    /// while waiting for events with [`EventIterator::wait_for_event`]. Once it is cancelled, the
+    /// terminal is restored and an [`Error::Aborted`](error::ErrorKind::Aborted) is returned.
+    pub fn run_with_cancellation<E>(
+        mut self,
+        events: &mut E,
+        token: &CancellationToken,
+    ) -> error::Result<Option<P::Output>>
+    where
+        E: EventIterator,
+    {
+        let _lock = PromptLock::acquire(self.nested)?;
+        self.init()?;
+
+        loop {
+            if token.is_cancelled() {
+                self.exit()?;
+                return Err(error::ErrorKind::Aborted);
+            }
+
+            if !self.wait_or_tick(events, Some(CANCELLATION_POLL_INTERVAL))? {
+                continue;
+            }
+
+            let e = events.next_any_event()?;
+
+            match self.handle_event(e)? {
+                Step::Continue => {}
+                Step::Finish => return Ok(Some(self.prompt.finish())),
+                Step::Skip => return Ok(None),
+            }
+        }
+    }
+
+    /// Display the prompt and process events until the user presses `Enter`, without blocking
+    /// while waiting for events.
+    ///
+    /// This is the asynchronous version of [`run`](Input::run). Rendering is still done
+    /// synchronously, only the waiting on events is asynchronous.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn run_async<E>(self, events: &mut E) -> error::Result<Option<P::Output>>
+    where
+        E: AsyncEventIterator,
+    {
+        self.run_async_with_recovery(events)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Display the prompt and process events like [`run_async`](Input::run_async), returning the
+    /// prompt along with the error if it fails.
+    ///
+    /// This is the asynchronous version of [`run_with_recovery`](Input::run_with_recovery).
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn run_async_with_recovery<E>(
+        mut self,
+        events: &mut E,
+    ) -> Result<Option<P::Output>, Recovered<P>>
+    where
+        E: AsyncEventIterator,
+    {
+        match self.run_async_until_done(events).await {
+            Ok(Step::Finish) => Ok(Some(self.prompt.finish())),
+            Ok(_) => Ok(None),
+            Err(error) => Err(Recovered {
+                error,
+                prompt: self.prompt,
+            }),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    async fn run_async_until_done<E>(&mut self, events: &mut E) -> error::Result<Step>
+    where
+        E: AsyncEventIterator,
+    {
+        // The task may be moved to another thread while it waits, so the lock is only tied to
+        // the thread while the prompt is used
+        let lock = PromptLock::acquire_async(self.nested).await?;
+        lock.enter(|| self.init())?;
+
+        loop {
+            let e = NextEvent(events).await?;
+
+            match lock.enter(|| self.handle_event(e))? {
+                Step::Continue => {}
+                step => return Ok(step),
+            }
+        }
+    }
+}
+
+/// What the event loop should do after an event is handled.
+enum Step {
+    Continue,
+    Finish,
+    Skip,
+}
+
+/// A future resolving to the next event of an [`AsyncEventIterator`].
+#[cfg(feature = "async")]
+struct NextEvent<'a, E>(&'a mut E);
+
+#[cfg(feature = "async")]
+impl<E: AsyncEventIterator> std::future::Future for NextEvent<'_, E> {
+    type Output = io::Result<Event>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.0.poll_next_any_event(cx)
+    }
+}
+
+#[derive(Debug)]
+struct TerminalState<B: Backend> {
+    backend: B,
+    hide_cursor: bool,
+    cursor_hidden: bool,
+    mouse_capture: bool,
+    keyboard_enhancement: bool,
+    enabled: bool,
+    #[cfg(all(unix, feature = "signals"))]
+    signal_guard: Option<crate::signals::SignalGuard>,
+}
+
+impl<B: Backend> TerminalState<B> {
+    fn new(backend: B, hide_cursor: bool) -> Self {
+        Self {
+            backend,
+            enabled: false,
+            hide_cursor,
+            cursor_hidden: false,
+            mouse_capture: false,
+            keyboard_enhancement: false,
+            #[cfg(all(unix, feature = "signals"))]
+            signal_guard: None,
+        }
+    }
+
+    fn init(&mut self) -> io::Result<()> {
+        self.enabled = true;
+        if self.hide_cursor && !self.cursor_hidden {
+            self.backend.hide_cursor()?;
+            self.cursor_hidden = true;
+        }
+        if self.mouse_capture {
+            self.backend.enable_mouse_capture()?;
+        }
+        if self.keyboard_enhancement {
+            self.backend.enable_keyboard_enhancement()?;
+        }
+        if cfg!(feature = "bracketed-paste") {
+            self.backend.enable_bracketed_paste()?;
+        }
+        // Without a controlling terminal, there is nothing to restore
+        #[cfg(all(unix, feature = "signals"))]
+        if self.signal_guard.is_none() && self.backend.uses_controlling_terminal() {
+            self.signal_guard = crate::signals::SignalGuard::new().ok();
+        }
+        self.backend.enable_raw_mode()
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.enabled = false;
+        if self.cursor_hidden {
+            self.backend.show_cursor()?;
+            self.cursor_hidden = false;
+        }
+        if self.mouse_capture {
+            self.backend.disable_mouse_capture()?;
+        }
+        if self.keyboard_enhancement {
+            self.backend.disable_keyboard_enhancement()?;
+        }
+        if cfg!(feature = "bracketed-paste") {
+            self.backend.disable_bracketed_paste()?;
+        }
+        self.backend.disable_raw_mode()?;
+        #[cfg(all(unix, feature = "signals"))]
+        {
+            self.signal_guard = None;
+        }
+        Ok(())
+    }
+}
+
+impl<B: Backend> Drop for TerminalState<B> {
+    fn drop(&mut self) {
+        if self.enabled {
+            let _ = self.reset();
+        }
+    }
+}
+
+impl<B: Backend> Deref for TerminalState<B> {
+    type Target = B;
+
+    fn deref(&self) -> &Self::Target {
+        &self.backend
     }
 }
 
@@ -407,7 +1210,7 @@ mod tests {
     }
 
     impl Prompt for TestPrompt {
-        type ValidateErr = &'static str;
+        type ValidateErr = crate::widgets::Text<&'static str>;
 
         type Output = ();
 
@@ -438,10 +1241,22 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                back_key: None,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                scroll: 0,
+                shown_rows: 0,
+                follow_focus: true,
+                warning_shown: false,
+                accessible: false,
+                description: None,
+                nested: false,
+                cursor_pos_fallback: true,
+                frame: None,
+                max_width: None,
+                alignment: Alignment::Left,
             }
             .adjust_scrollback(3)
             .unwrap(),
@@ -454,10 +1269,22 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                back_key: None,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                scroll: 0,
+                shown_rows: 0,
+                follow_focus: true,
+                warning_shown: false,
+                accessible: false,
+                description: None,
+                nested: false,
+                cursor_pos_fallback: true,
+                frame: None,
+                max_width: None,
+                alignment: Alignment::Left,
             }
             .adjust_scrollback(6)
             .unwrap(),
@@ -469,10 +1296,22 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                back_key: None,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                scroll: 0,
+                shown_rows: 0,
+                follow_focus: true,
+                warning_shown: false,
+                accessible: false,
+                description: None,
+                nested: false,
+                cursor_pos_fallback: true,
+                frame: None,
+                max_width: None,
+                alignment: Alignment::Left,
             }
             .adjust_scrollback(10)
             .unwrap(),
@@ -491,10 +1330,22 @@ mod tests {
         assert!(Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            back_key: None,
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 5,
             render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
         }
         .render()
         .is_ok());
@@ -511,10 +1362,22 @@ mod tests {
         let mut input = Input {
             prompt: TestPrompt::default(),
             on_esc: OnEsc::Ignore,
+            back_key: None,
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 15,
             render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
         };
 
         assert!(input.goto_last_line(9).is_ok());
@@ -524,6 +1387,570 @@ mod tests {
         crate::assert_backend_snapshot!(backend);
     }
 
+    #[test]
+    fn test_resize() {
+        let prompt = TestPrompt { height: 5 };
+        let mut backend = TestBackend::new((100, 10).into());
+        backend.move_cursor_to(0, 9).unwrap();
+
+        let mut input = Input {
+            prompt,
+            on_esc: OnEsc::Ignore,
+            back_key: None,
+            backend: TerminalState::new(&mut backend, false),
+            size: (100, 20).into(),
+            base_row: 15,
+            render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
+        };
+
+        assert!(matches!(
+            input.handle_event(Event::Resize((100, 10).into())),
+            Ok(Step::Continue)
+        ));
+        assert_eq!(input.size, (100, 10).into());
+        assert_eq!(input.base_row, 5);
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_suspend() {
+        let prompt = TestPrompt { height: 5 };
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+        backend.move_cursor_to(0, 9).unwrap();
+
+        let mut input = Input {
+            prompt,
+            on_esc: OnEsc::Ignore,
+            back_key: None,
+            backend: TerminalState::new(&mut backend, false),
+            size,
+            base_row: 3,
+            render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
+        };
+
+        assert!(matches!(
+            input.handle_event(Event::Key(KeyEvent::new(
+                KeyCode::Char('z'),
+                KeyModifiers::CONTROL
+            ))),
+            Ok(Step::Continue)
+        ));
+        assert!(input.backend.enabled);
+        assert_eq!(input.base_row, 3);
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_warning() {
+        /// Always fails validation with the given severity, counting the number of validations
+        struct WarnPrompt {
+            severity: Severity,
+            validations: usize,
+        }
+
+        impl Widget for WarnPrompt {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+                TestPrompt { height: 1 }.render(layout, b)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                TestPrompt { height: 1 }.height(layout)
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                TestPrompt { height: 1 }.cursor_pos(layout)
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                key.code == KeyCode::Char('a')
+            }
+        }
+
+        impl Prompt for WarnPrompt {
+            type ValidateErr = &'static str;
+            type Output = usize;
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                self.validations += 1;
+                Err("warning")
+            }
+
+            fn severity(&self, _: &Self::ValidateErr) -> Severity {
+                self.severity
+            }
+
+            fn finish(self) -> Self::Output {
+                self.validations
+            }
+        }
+
+        let run = |severity, events: Vec<KeyEvent>| {
+            let mut backend = TestBackend::new((100, 20).into());
+            let prompt = WarnPrompt {
+                severity,
+                validations: 0,
+            };
+
+            Input::new(prompt, &mut backend).run(&mut TestEvents::new(events))
+        };
+        let enter = || KeyEvent::from(KeyCode::Enter);
+
+        assert!(matches!(
+            run(Severity::Warning, vec![enter(), enter()]),
+            Ok(Some(2))
+        ));
+        assert!(matches!(
+            run(Severity::Info, vec![enter(), enter()]),
+            Ok(Some(2))
+        ));
+        // Changing the prompt hides the warning, so it must be shown again
+        assert!(matches!(
+            run(
+                Severity::Warning,
+                vec![enter(), KeyCode::Char('a').into(), enter(), enter()]
+            ),
+            Ok(Some(3))
+        ));
+        // Keys which are not handled leave the warning
+        assert!(matches!(
+            run(
+                Severity::Warning,
+                vec![enter(), KeyCode::Char('b').into(), enter()]
+            ),
+            Ok(Some(2))
+        ));
+        // Errors never allow the prompt to be submitted
+        assert!(matches!(
+            run(
+                Severity::Error,
+                vec![
+                    enter(),
+                    enter(),
+                    KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+                ]
+            ),
+            Err(error::ErrorKind::Interrupted)
+        ));
+    }
+
+    #[test]
+    fn test_accessible() {
+        /// Counts the number of times `a` is pressed, and needs at least 2 to be submitted
+        struct CountPrompt(usize);
+
+        impl Widget for CountPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                unreachable!("the prompt is described instead")
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                unreachable!("the prompt is described instead")
+            }
+
+            fn cursor_pos(&mut self, _: Layout) -> (u16, u16) {
+                unreachable!("the prompt is described instead")
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                match key.code {
+                    KeyCode::Char('a') => self.0 += 1,
+                    // Handled, but doesn't change the description
+                    KeyCode::Char('b') => {}
+                    _ => return false,
+                }
+
+                true
+            }
+        }
+
+        impl Prompt for CountPrompt {
+            type ValidateErr = &'static str;
+            type Output = usize;
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                if self.0 < 2 {
+                    Err("too few")
+                } else {
+                    Ok(Validation::Finish)
+                }
+            }
+
+            fn describe(&mut self) -> Option<String> {
+                Some(format!("Count: {}", self.0))
+            }
+
+            fn describe_error(&self, err: &Self::ValidateErr) -> String {
+                format!("Count is {}", err)
+            }
+
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        backend.move_cursor_to(0, 3).unwrap();
+
+        let events = vec![
+            KeyCode::Char('a').into(),
+            KeyCode::Char('b').into(),
+            KeyCode::Enter.into(),
+            KeyCode::Char('a').into(),
+            KeyCode::Enter.into(),
+        ];
+
+        assert!(matches!(
+            Input::new(CountPrompt(0), &mut backend)
+                .accessible(true)
+                .run(&mut TestEvents::new(events)),
+            Ok(Some(2))
+        ));
+
+        crate::assert_backend_snapshot!(backend);
+
+        // Prompts which cannot be described are rendered normally
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut input = Input::new(TestPrompt { height: 2 }, &mut backend).accessible(true);
+        assert!(input.init().is_ok());
+        assert!(!input.accessible);
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_nested() {
+        /// Runs another prompt when validated, finishing with its result
+        struct NestingPrompt {
+            nested: bool,
+            result: Option<error::Result<Option<()>>>,
+        }
+
+        impl Widget for NestingPrompt {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+                TestPrompt { height: 1 }.render(layout, b)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                TestPrompt { height: 1 }.height(layout)
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                TestPrompt { height: 1 }.cursor_pos(layout)
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for NestingPrompt {
+            type ValidateErr = &'static str;
+            type Output = error::Result<Option<()>>;
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                let mut backend = TestBackend::new((100, 20).into());
+                // The prompt doesn't run if it is refused
+                let mut events = TestEvents::new(if self.nested {
+                    Some(KeyCode::Enter.into())
+                } else {
+                    None
+                });
+
+                self.result = Some(
+                    Input::new(TestPrompt::default(), &mut backend)
+                        .nested(self.nested)
+                        .run(&mut events),
+                );
+
+                Ok(Validation::Finish)
+            }
+
+            fn finish(self) -> Self::Output {
+                self.result.unwrap()
+            }
+        }
+
+        let run = |nested| {
+            let mut backend = TestBackend::new((100, 20).into());
+            let prompt = NestingPrompt {
+                nested,
+                result: None,
+            };
+
+            Input::new(prompt, &mut backend)
+                .run(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+                .unwrap()
+                .unwrap()
+        };
+
+        assert!(matches!(
+            run(false),
+            Err(error::ErrorKind::PromptInProgress)
+        ));
+        assert!(matches!(run(true), Ok(Some(()))));
+    }
+
+    #[test]
+    fn test_run_with_cancellation() {
+        /// Never has any events, and cancels the token after waiting for them twice
+        struct CancelAfterWaits<'a> {
+            token: &'a CancellationToken,
+            waits: usize,
+        }
+
+        impl EventIterator for CancelAfterWaits<'_> {
+            fn next_event(&mut self) -> io::Result<KeyEvent> {
+                unreachable!("there are no events")
+            }
+
+            fn wait_for_event(&mut self, _: Duration) -> io::Result<bool> {
+                self.waits += 1;
+                if self.waits == 2 {
+                    self.token.cancel();
+                }
+
+                Ok(false)
+            }
+        }
+
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+        let token = CancellationToken::new();
+
+        let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .run_with_cancellation(&mut TestEvents::new(Some(KeyCode::Enter.into())), &token);
+        assert!(matches!(res, Ok(Some(()))));
+
+        let mut events = CancelAfterWaits {
+            token: &token.clone(),
+            waits: 0,
+        };
+        let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .run_with_cancellation(&mut events, &token);
+        assert!(matches!(res, Err(error::ErrorKind::Aborted)));
+        assert_eq!(events.waits, 2);
+        assert!(token.is_cancelled());
+
+        // The token stays cancelled, so no events are read
+        let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .run_with_cancellation(&mut TestEvents::new(std::iter::empty()), &token);
+        assert!(matches!(res, Err(error::ErrorKind::Aborted)));
+    }
+
+    #[test]
+    fn test_back_key() {
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+        let back_tab = KeyEvent::from(KeyCode::BackTab);
+
+        // Some terminals report `Shift` along with `BackTab`
+        let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .back_key(Some(back_tab))
+            .run(&mut TestEvents::new(Some(KeyEvent::new(
+                KeyCode::BackTab,
+                KeyModifiers::SHIFT,
+            ))));
+        assert!(matches!(res, Err(error::ErrorKind::Back)));
+
+        // It takes priority over `Esc`
+        let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .on_esc(OnEsc::Terminate)
+            .back_key(Some(KeyCode::Esc.into()))
+            .run(&mut TestEvents::new(Some(KeyCode::Esc.into())));
+        assert!(matches!(res, Err(error::ErrorKind::Back)));
+
+        // The modifiers of the back key are required
+        let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .back_key(Some(KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT)))
+            .run(&mut TestEvents::new(Some(KeyCode::Enter.into())));
+        assert!(matches!(res, Ok(Some(()))));
+    }
+
+    #[test]
+    fn test_run_with_recovery() {
+        /// Counts the number of keys pressed
+        struct KeyCount(usize);
+
+        impl Widget for KeyCount {
+            fn render<B: Backend>(&mut self, _: &mut Layout, b: &mut B) -> io::Result<()> {
+                write!(b, "{}", self.0)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 1;
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                self.0 += 1;
+                true
+            }
+        }
+
+        impl Prompt for KeyCount {
+            type ValidateErr = &'static str;
+            type Output = usize;
+
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        let res =
+            Input::new(KeyCount(0), &mut backend).run_with_recovery(&mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Enter.into(),
+            ]));
+        assert!(matches!(res, Ok(Some(1))));
+
+        let res =
+            Input::new(KeyCount(0), &mut backend).run_with_recovery(&mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Char('b').into(),
+                ctrl_c,
+            ]));
+        match res {
+            Err(Recovered {
+                error: error::ErrorKind::Interrupted,
+                prompt: KeyCount(2),
+            }) => {}
+            res => panic!(
+                "expected the prompt to be recovered, got {:?}",
+                res.map_err(|r| r.error)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_tick() {
+        /// Asks to be ticked twice, and finishes with the number of ticks
+        struct TickPrompt(usize);
+
+        impl Widget for TickPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, b: &mut B) -> io::Result<()> {
+                write!(b, "{}", self.0)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 1;
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((1, 0))
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for TickPrompt {
+            type ValidateErr = &'static str;
+            type Output = usize;
+
+            fn next_tick(&self) -> Option<Duration> {
+                if self.0 < 2 {
+                    Some(Duration::from_millis(10))
+                } else {
+                    None
+                }
+            }
+
+            fn tick(&mut self) -> bool {
+                self.0 += 1;
+                true
+            }
+
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        /// Has an event only after it has been waited on `waits` times
+        struct SlowEvents {
+            waits: usize,
+            timeouts: Vec<Duration>,
+        }
+
+        impl EventIterator for SlowEvents {
+            fn next_event(&mut self) -> io::Result<KeyEvent> {
+                Ok(KeyCode::Enter.into())
+            }
+
+            fn wait_for_event(&mut self, timeout: Duration) -> io::Result<bool> {
+                self.timeouts.push(timeout);
+                Ok(self.timeouts.len() > self.waits)
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut events = SlowEvents {
+            waits: 5,
+            timeouts: Vec::new(),
+        };
+
+        let res = Input::new(TickPrompt(0), &mut backend).run(&mut events);
+        assert!(matches!(res, Ok(Some(2))));
+        // Once the prompt doesn't need to be ticked, events are read without waiting
+        assert_eq!(events.timeouts, [Duration::from_millis(10); 2]);
+
+        // With cancellation, the prompt is ticked when it needs to be before the token is checked
+        let mut events = SlowEvents {
+            waits: 5,
+            timeouts: Vec::new(),
+        };
+        let res = Input::new(TickPrompt(0), &mut backend)
+            .run_with_cancellation(&mut events, &CancellationToken::new());
+        assert!(matches!(res, Ok(Some(2))));
+        assert_eq!(
+            events.timeouts,
+            [
+                Duration::from_millis(10),
+                Duration::from_millis(10),
+                CANCELLATION_POLL_INTERVAL,
+                CANCELLATION_POLL_INTERVAL,
+                CANCELLATION_POLL_INTERVAL,
+                CANCELLATION_POLL_INTERVAL,
+            ]
+        );
+    }
+
     #[test]
     fn test_print_error() {
         let error = "error text";
@@ -533,15 +1960,150 @@ mod tests {
         assert!(Input {
             prompt: TestPrompt { height: 5 },
             on_esc: OnEsc::Ignore,
+            back_key: None,
             backend: TerminalState::new(&mut backend, true),
             base_row: 0,
             size,
             render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
         }
-        .print_error(error)
+        .print_error(error.into(), Severity::Error)
         .is_ok());
 
         crate::assert_backend_snapshot!(backend);
+
+        let size = (40, 20).into();
+        let mut backend = TestBackend::new(size);
+
+        assert!(Input {
+            prompt: TestPrompt { height: 5 },
+            on_esc: OnEsc::Ignore,
+            back_key: None,
+            backend: TerminalState::new(&mut backend, true),
+            base_row: 0,
+            size,
+            render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
+        }
+        .print_error(crate::test_consts::LOREM.into(), Severity::Error)
+        .is_ok());
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_print_error_overflow() {
+        let size = (40, 10).into();
+        let mut backend = TestBackend::new(size);
+        let mut input = Input {
+            prompt: TestPrompt { height: 3 },
+            on_esc: OnEsc::Ignore,
+            back_key: None,
+            backend: TerminalState::new(&mut backend, true),
+            base_row: 4,
+            size,
+            render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
+        };
+
+        // The error is taller than the terminal, so it hides all of the prompt
+        input
+            .print_error(crate::test_consts::LOREM.into(), Severity::Warning)
+            .unwrap();
+        assert_eq!(input.base_row, 0);
+        assert!(input.render_overflow);
+        crate::assert_backend_snapshot!(*input.backend);
+
+        // Rendering the prompt again clears all of the error
+        input.render().unwrap();
+        assert!(!input.render_overflow);
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_scroll_overflow() {
+        let size = (60, 10).into();
+        let mut backend = TestBackend::new(size);
+        let mut input = Input {
+            prompt: TestPrompt { height: 20 },
+            on_esc: OnEsc::Ignore,
+            back_key: None,
+            backend: TerminalState::new(&mut backend, false),
+            base_row: 0,
+            size,
+            render_overflow: false,
+            scroll: 0,
+            shown_rows: 0,
+            follow_focus: true,
+            warning_shown: false,
+            accessible: false,
+            description: None,
+            nested: false,
+            cursor_pos_fallback: true,
+            frame: None,
+            max_width: None,
+            alignment: Alignment::Left,
+        };
+
+        // The cursor is below the last line, so the prompt starts scrolled to the bottom
+        input.render().unwrap();
+        assert!(input.render_overflow);
+        assert_eq!(input.shown_rows, 9);
+        assert_eq!(input.scroll, 11);
+
+        assert!(input.scroll_with_key(KeyCode::PageUp.into()));
+        assert_eq!(input.scroll, 2);
+        assert!(input.scroll_with_key(KeyCode::Up.into()));
+        assert!(input.scroll_with_key(KeyCode::Up.into()));
+        assert!(!input.scroll_with_key(KeyCode::Up.into()));
+        assert_eq!(input.scroll, 0);
+        assert!(!input.follow_focus);
+
+        input.render().unwrap();
+        assert_eq!(input.scroll, 0);
+        crate::assert_backend_snapshot!(*input.backend);
+
+        assert!(input.scroll_with_key(KeyCode::Down.into()));
+        assert!(input.scroll_with_key(KeyCode::PageDown.into()));
+        assert!(input.scroll_with_key(KeyCode::PageDown.into()));
+        assert!(!input.scroll_with_key(KeyCode::PageDown.into()));
+        assert_eq!(input.scroll, 11);
+        input.render().unwrap();
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend);
     }
 
     #[test]
@@ -578,4 +2140,107 @@ mod tests {
             "Invalid terminal Size { width: 0, height: 20 }. Both width and height must be larger than 0"
         );
     }
+
+    #[test]
+    fn test_cursor_pos_fallback() {
+        use std::io::Write;
+
+        /// A backend for a terminal which doesn't reply to cursor position queries.
+        struct NoCursorBackend(TestBackend);
+
+        impl Write for NoCursorBackend {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.flush()
+            }
+        }
+
+        impl Backend for NoCursorBackend {
+            fn enable_raw_mode(&mut self) -> io::Result<()> {
+                self.0.enable_raw_mode()
+            }
+            fn disable_raw_mode(&mut self) -> io::Result<()> {
+                self.0.disable_raw_mode()
+            }
+            fn hide_cursor(&mut self) -> io::Result<()> {
+                self.0.hide_cursor()
+            }
+            fn show_cursor(&mut self) -> io::Result<()> {
+                self.0.show_cursor()
+            }
+            fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+                Err(io::Error::new(io::ErrorKind::TimedOut, "timed out"))
+            }
+            fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+                self.0.move_cursor_to(x, y)
+            }
+            fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+                self.0.move_cursor(direction)
+            }
+            fn scroll(&mut self, dist: i16) -> io::Result<()> {
+                self.0.scroll(dist)
+            }
+            fn set_attributes(&mut self, attributes: crate::style::Attributes) -> io::Result<()> {
+                self.0.set_attributes(attributes)
+            }
+            fn set_fg(&mut self, color: crate::style::Color) -> io::Result<()> {
+                self.0.set_fg(color)
+            }
+            fn set_bg(&mut self, color: crate::style::Color) -> io::Result<()> {
+                self.0.set_bg(color)
+            }
+            fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+                self.0.clear(clear_type)
+            }
+            fn size(&self) -> io::Result<Size> {
+                self.0.size()
+            }
+        }
+
+        let size = (20, 10).into();
+        let mut backend = NoCursorBackend(TestBackend::new(size));
+        backend.write_all(b"partial line").unwrap();
+
+        let mut input = Input::new(TestPrompt { height: 1 }, &mut backend);
+        input.init().unwrap();
+        assert_eq!(input.base_row, 9);
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend.0);
+
+        let mut backend = NoCursorBackend(TestBackend::new(size));
+        let err = Input::new(TestPrompt::default(), &mut backend)
+            .cursor_pos_fallback(false)
+            .run(&mut TestEvents::new([]))
+            .expect_err("cursor position should not be detected");
+
+        match err {
+            crate::ErrorKind::CursorPosition(err) => {
+                assert_eq!(err.kind(), io::ErrorKind::TimedOut)
+            }
+            err => panic!("expected cursor position error, got {:?}", err),
+        }
+        assert_eq!(backend.0, TestBackend::new(size));
+    }
+
+    #[cfg(all(unix, feature = "signals"))]
+    #[test]
+    fn test_signal_guard() {
+        // Only the backends which enable raw mode on the controlling terminal need it restored
+        let mut backend = TestBackend::new((20, 10).into());
+        let mut state = TerminalState::new(&mut backend, true);
+        state.init().unwrap();
+        assert!(state.signal_guard.is_none());
+        state.reset().unwrap();
+
+        let mut state = TerminalState::new(
+            crate::backend::TerminalPair::new(io::empty(), Vec::new()),
+            true,
+        );
+        state.init().unwrap();
+        assert!(state.signal_guard.is_none());
+        state.reset().unwrap();
+    }
 }
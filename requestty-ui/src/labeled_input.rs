@@ -0,0 +1,144 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::KeyEvent,
+    layout::Layout,
+    widgets::{FilterMapChar, Prompt, StringInput},
+    Widget,
+};
+
+/// A [`StringInput`] on the same line as a [`Prompt`] which labels it.
+///
+/// This is how the built-in `input` prompt is laid out: the label is shown in bold, followed by an
+/// optional hint, and the text is typed after it. If the text is too long, it wraps onto the next
+/// lines.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::LabeledInput;
+///
+/// let input = LabeledInput::new("Name")
+///     .hint("optional")
+///     .initial_value("Ferris".into());
+///
+/// assert_eq!(input.value(), "Ferris");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LabeledInput<F = FilterMapChar> {
+    prompt: Prompt<String, String>,
+    input: StringInput<F>,
+}
+
+impl LabeledInput {
+    /// Creates a new `LabeledInput` with the given label, which accepts all characters.
+    pub fn new<L: Into<String>>(label: L) -> Self {
+        Self::with_input(label, StringInput::new())
+    }
+}
+
+impl<F> LabeledInput<F> {
+    /// Creates a new `LabeledInput` with the given label and input, which can be used to filter
+    /// or mask the characters typed.
+    pub fn with_input<L: Into<String>>(label: L, input: StringInput<F>) -> Self {
+        Self {
+            prompt: Prompt::new(label.into()),
+            input,
+        }
+    }
+
+    /// Sets the hint shown after the label.
+    pub fn hint<H: Into<String>>(mut self, hint: H) -> Self {
+        self.prompt.set_hint(Some(hint.into()));
+        self
+    }
+
+    /// Sets the text that is already typed in, with the cursor at its end.
+    pub fn initial_value(mut self, value: String) -> Self {
+        self.set_value(value);
+        self.input.set_at(usize::MAX);
+        self
+    }
+
+    /// The text that has been typed.
+    pub fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    /// Sets the text that has been typed.
+    pub fn set_value(&mut self, value: String) {
+        self.input.set_value(value)
+    }
+
+    /// The [`Prompt`] showing the label.
+    pub fn prompt_mut(&mut self) -> &mut Prompt<String, String> {
+        &mut self.prompt
+    }
+
+    /// The [`StringInput`] the text is typed into.
+    pub fn input_mut(&mut self) -> &mut StringInput<F> {
+        &mut self.input
+    }
+
+    /// Consumes the `LabeledInput`, returning the text that has been typed.
+    pub fn finish(self) -> String {
+        self.input.finish()
+    }
+}
+
+impl<F: Fn(char) -> Option<char>> Widget for LabeledInput<F> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        // The input starts on the last line of the prompt
+        self.prompt.height(layout) - 1 + self.input.height(layout)
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+        self.input.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input.handle_key(key)
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        self.input.handle_paste(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, events::KeyCode};
+
+    #[test]
+    fn test_labeled_input() {
+        let size = (20, 10).into();
+        let base_layout = Layout::new(0, size);
+        let mut backend = TestBackend::new(size);
+
+        let mut input = LabeledInput::new("Name").hint("nick");
+        assert!(input.handle_key(KeyCode::Char('a').into()));
+        assert!(input.handle_key(KeyCode::Char('b').into()));
+        assert_eq!(input.value(), "ab");
+
+        // `? Name (nick) ` takes 14 columns
+        let mut layout = base_layout;
+        assert_eq!(input.height(&mut layout), 1);
+        assert_eq!(input.cursor_pos(base_layout), (16, 0));
+
+        input.set_value("x".repeat(10));
+        let mut layout = base_layout;
+        assert_eq!(input.height(&mut layout), 2);
+
+        layout = base_layout;
+        input.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+    }
+}
@@ -1,5 +1,7 @@
 //! A module to describe regions of the screen that can be rendered to.
 
+use std::convert::TryFrom;
+
 /// The part of the text to render if the full text cannot be rendered
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
@@ -15,6 +17,26 @@ impl Default for RenderRegion {
     }
 }
 
+/// Where widgets are placed horizontally if the [`max_width`] of the [`Layout`] is less than the
+/// available width.
+///
+/// [`max_width`]: Layout::max_width
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+// `#[default]` on enum variants is not supported by the MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for Alignment {
+    fn default() -> Self {
+        Alignment::Left
+    }
+}
+
 /// `Layout` represents a portion of the screen that is available to be rendered to.
 ///
 /// Assume the highlighted part of the block below is the place available for rendering
@@ -27,6 +49,18 @@ impl Default for RenderRegion {
 /// |  ██████████|
 /// '------------'
 /// ```
+///
+/// A widget is given a layout which starts where the terminal cursor is, and moves it past the
+/// space it used, so that the next widget starts where it ended. See the [render cycle] of
+/// [`Widget`] for what is expected of widgets. Rather than changing the fields directly, the
+/// helpers such as [`split_vertical`], [`indent`] and [`constrain_width`] can be used to give
+/// parts of the layout to child widgets.
+///
+/// [render cycle]: crate::Widget#render-cycle
+/// [`Widget`]: crate::Widget
+/// [`split_vertical`]: Layout::split_vertical
+/// [`indent`]: Layout::indent
+/// [`constrain_width`]: Layout::constrain_width
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
 pub struct Layout {
     /// ```text
@@ -86,6 +120,27 @@ pub struct Layout {
     pub max_height: u16,
     /// The region to render if full text cannot be rendered
     pub render_region: RenderRegion,
+    /// The maximum width that widgets can take, or `None` if they can take the full width.
+    ///
+    /// ```text
+    ///  ____________
+    /// |  max_width |
+    /// |  vvvvvv    |
+    /// |     ███    |
+    /// |  ██████    |
+    /// '------------'
+    /// ```
+    ///
+    /// Only [`Prompt`], [`StringInput`] and [`Select`] take the `max_width` and [`alignment`] into
+    /// account.
+    ///
+    /// [`Prompt`]: crate::widgets::Prompt
+    /// [`StringInput`]: crate::widgets::StringInput
+    /// [`Select`]: crate::widgets::Select
+    /// [`alignment`]: Layout::alignment
+    pub max_width: Option<u16>,
+    /// Where the `max_width` wide region is placed in the available width.
+    pub alignment: Alignment,
 }
 
 impl Layout {
@@ -99,6 +154,8 @@ impl Layout {
             height: size.height,
             max_height: size.height,
             render_region: RenderRegion::Top,
+            max_width: None,
+            alignment: Alignment::Left,
         }
     }
 
@@ -133,14 +190,158 @@ impl Layout {
         self
     }
 
-    /// Creates a new `Layout` that represents a region past the `cursor_pos`. `cursor_pos` is
-    /// relative to `offset_x` and `offset_y`.
-    pub fn with_cursor_pos(mut self, cursor_pos: (u16, u16)) -> Self {
-        self.line_offset = cursor_pos.0;
-        self.offset_y = cursor_pos.1;
+    /// Creates a new `Layout` with new `max_width`.
+    pub fn with_max_width(mut self, max_width: u16) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Creates a new `Layout` with new `alignment`.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Splits the layout into the region of the first `rows` rows, and the region below it.
+    ///
+    /// ```text
+    ///  ____________      ____________
+    /// |            |    |            |
+    /// |     ███████| => |     ███████|
+    /// |  ██████████|    |  ██████████|
+    /// |  ██████████|    |  ▒▒▒▒▒▒▒▒▒▒|
+    /// '------------'    '------------'
+    /// ```
+    ///
+    /// The `max_height` of the first region is at most `rows`, and it keeps the `line_offset`. The
+    /// second region starts at the beginning of the row after the first region, and is given the
+    /// rest of the `max_height`, if any.
+    pub fn split_vertical(self, rows: u16) -> (Self, Self) {
+        let rows = rows.min(self.max_height);
+        let top = self.with_max_height(rows);
+        let bottom = Self {
+            line_offset: 0,
+            offset_y: self.offset_y.saturating_add(rows),
+            max_height: self.max_height - rows,
+            ..self
+        };
+
+        (top, bottom)
+    }
+
+    /// Creates a new `Layout` which is moved `columns` to the right, such as for the elements of a
+    /// list which are rendered after a pointer.
+    ///
+    /// ```text
+    ///  ____________      ____________
+    /// |            |    |            |
+    /// |     ███████| => |       █████|
+    /// |  ██████████|    |    ████████|
+    /// '------------'    '------------'
+    /// ```
+    ///
+    /// The `line_offset` stays relative to the new `offset_x`. The `offset_x` does not go past the
+    /// `width`.
+    pub fn indent(mut self, columns: u16) -> Self {
+        self.offset_x = self.offset_x.saturating_add(columns).min(self.width);
+        self
+    }
+
+    /// The number of rows that can be rendered to from the `offset_y`, which is the smaller of the
+    /// `max_height` and the rows left until the bottom of the terminal.
+    pub fn remaining_height(&self) -> u16 {
+        self.height
+            .saturating_sub(self.offset_y)
+            .min(self.max_height)
+    }
+
+    /// Creates a new `Layout` whose [`available_width`] is at most `width`, by cutting off the
+    /// columns to the right of it.
+    ///
+    /// ```text
+    ///  ____________      ____________
+    /// |            |    |            |
+    /// |     ███████| => |     ████   |
+    /// |  ██████████|    |  ███████   |
+    /// '------------'    '------------'
+    /// ```
+    ///
+    /// Unlike the [`max_width`], this is honoured by every widget, since the region to the right is
+    /// no longer part of the layout.
+    ///
+    /// [`available_width`]: Layout::available_width
+    /// [`max_width`]: Layout::max_width
+    pub fn constrain_width(mut self, width: u16) -> Self {
+        self.width = self.width.min(self.offset_x.saturating_add(width));
         self
     }
 
+    /// Creates a new `Layout` which only covers the region that is at most `max_width` wide,
+    /// placed as per the `alignment`. Widgets which honour the `max_width` render to this region.
+    ///
+    /// ```text
+    ///  ____________      ____________
+    /// |            |    |            |
+    /// |  ██████████| => |     ████   |
+    /// |  ██████████|    |     ████   |
+    /// '------------'    '------------'
+    /// ```
+    ///
+    /// The `line_offset` stays relative to the new `offset_x`. Aligning a layout which is already
+    /// aligned does nothing.
+    pub fn aligned(mut self) -> Self {
+        let available_width = self.available_width();
+
+        if let Some(max_width) = self.max_width.filter(|&w| w < available_width) {
+            self.offset_x += match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (available_width - max_width) / 2,
+                Alignment::Right => available_width - max_width,
+            };
+            self.width = self.offset_x + max_width;
+        }
+
+        self
+    }
+
+    /// The width of the lines that text wraps to after the first line.
+    ///
+    /// Text is normally wrapped by the terminal from its left edge, but if there is a `max_width`,
+    /// it is wrapped by the widgets from the `offset_x`.
+    pub(crate) fn wrap_width(&self) -> u16 {
+        if self.max_width.is_some() {
+            self.available_width()
+        } else {
+            self.width
+        }
+    }
+
+    /// The layout without the row it starts at and the `line_offset`, which widgets can compare
+    /// to find if what they measured with a layout, such as the heights of lines that start at
+    /// `offset_x`, is still valid. It is invalid if the layout is resized, but not if it is only
+    /// moved down.
+    pub(crate) fn without_position(self) -> Self {
+        Self {
+            line_offset: 0,
+            offset_y: 0,
+            ..self
+        }
+    }
+
+    /// Creates a new `Layout` that represents a region past the `cursor_pos`. `cursor_pos` is
+    /// relative to (0, 0), like the positions returned by [`Widget::cursor_pos`].
+    ///
+    /// The new `Layout` is [aligned](Layout::aligned), since the `cursor_pos` of widgets which
+    /// honour the `max_width` is in the aligned region.
+    ///
+    /// [`Widget::cursor_pos`]: crate::Widget::cursor_pos
+    pub fn with_cursor_pos(self, cursor_pos: (u16, u16)) -> Self {
+        let mut layout = self.aligned();
+        layout.line_offset = cursor_pos.0.saturating_sub(layout.offset_x);
+        layout.offset_y = cursor_pos.1;
+        layout
+    }
+
     /// Sets the `width` and `height` of the layout.
     pub fn set_size(&mut self, terminal_size: crate::backend::Size) {
         self.width = terminal_size.width;
@@ -149,7 +350,10 @@ impl Layout {
 
     /// Converts a `cursor_pos` relative to (`offset_x`, `offset_y`) to be relative to (0, 0)
     pub fn offset_cursor(&self, cursor_pos: (u16, u16)) -> (u16, u16) {
-        (self.offset_x + cursor_pos.0, self.offset_y + cursor_pos.1)
+        (
+            self.offset_x.saturating_add(cursor_pos.0),
+            self.offset_y.saturating_add(cursor_pos.1),
+        )
     }
 
     /// Gets the width of renderable space on the first line.
@@ -162,8 +366,10 @@ impl Layout {
     /// |  ██████████|
     /// '------------'
     /// ```
+    ///
+    /// It is 0 if the `line_offset` is past the `available_width`.
     pub fn line_width(&self) -> u16 {
-        self.available_width() - self.line_offset
+        self.available_width().saturating_sub(self.line_offset)
     }
 
     /// Gets the width of renderable space on subsequent lines.
@@ -176,8 +382,10 @@ impl Layout {
     /// |  ██████████|
     /// '------------'
     /// ```
+    ///
+    /// It is 0 if the `offset_x` is past the `width`.
     pub fn available_width(&self) -> u16 {
-        self.width - self.offset_x
+        self.width.saturating_sub(self.offset_x)
     }
 
     /// Gets the starting line number for the given `height` taking into account the `max_height`
@@ -186,7 +394,7 @@ impl Layout {
     /// If the height of the widget to render is 5 and the max_height is 2, then the start would be:
     /// - `RenderRegion::Top`: 0
     /// - `RenderRegion::Middle`: 1
-    /// - `RenderRegion::Top`: 3
+    /// - `RenderRegion::Bottom`: 3
     pub fn get_start(&self, height: u16) -> u16 {
         if height > self.max_height {
             match self.render_region {
@@ -200,6 +408,48 @@ impl Layout {
     }
 }
 
+/// The number of columns `text` takes when rendered, which is what the widths and offsets of a
+/// [`Layout`] are measured in. Double width characters, like most CJK characters and emoji, take
+/// two columns, and combining characters take none.
+///
+/// It saturates at `u16::MAX`.
+pub fn display_width(text: &str) -> u16 {
+    u16::try_from(textwrap::core::display_width(text)).unwrap_or(u16::MAX)
+}
+
+/// The number of columns `c` takes when rendered, like [`display_width`] for a single character.
+pub fn char_width(c: char) -> u16 {
+    display_width(c.encode_utf8(&mut [0; 4]))
+}
+
+#[test]
+fn test_aligned() {
+    let layout = Layout::new(0, (100, 5).into()).with_offset(10, 2);
+    assert_eq!(layout.aligned(), layout);
+
+    let layout = layout.with_max_width(30);
+    assert_eq!(
+        layout.with_alignment(Alignment::Left).aligned(),
+        layout.with_size((40, 5).into())
+    );
+    let center = layout.with_alignment(Alignment::Center);
+    assert_eq!(
+        center.aligned(),
+        center.with_offset(40, 2).with_size((70, 5).into())
+    );
+    let right = layout.with_alignment(Alignment::Right).aligned();
+    assert_eq!(
+        right,
+        layout.with_offset(70, 2).with_alignment(Alignment::Right)
+    );
+    assert_eq!(right.aligned(), right);
+
+    assert_eq!(
+        layout.with_max_width(100).aligned(),
+        layout.with_max_width(100)
+    );
+}
+
 #[test]
 fn test_layout() {
     let layout = Layout::new(0, (100, 5).into());
@@ -220,3 +470,74 @@ fn test_layout() {
         5
     );
 }
+
+#[test]
+fn test_split_vertical() {
+    let layout = Layout::new(4, (100, 20).into()).with_offset(2, 5);
+
+    let (top, bottom) = layout.split_vertical(3);
+    assert_eq!(top, layout.with_max_height(3));
+    assert_eq!(
+        bottom,
+        layout
+            .with_line_offset(0)
+            .with_offset(2, 8)
+            .with_max_height(17)
+    );
+
+    // The split can not go past the max height
+    let layout = layout.with_max_height(2);
+    let (top, bottom) = layout.split_vertical(5);
+    assert_eq!(top, layout);
+    assert_eq!(bottom.offset_y, 7);
+    assert_eq!(bottom.max_height, 0);
+}
+
+#[test]
+fn test_indent() {
+    let layout = Layout::new(4, (20, 5).into()).with_offset(2, 1);
+    assert_eq!(layout.indent(3), layout.with_offset(5, 1));
+    assert_eq!(layout.indent(3).line_width(), 11);
+    assert_eq!(layout.indent(30), layout.with_offset(20, 1));
+    assert_eq!(layout.indent(30).available_width(), 0);
+}
+
+#[test]
+fn test_remaining_height() {
+    let layout = Layout::new(0, (100, 20).into());
+    assert_eq!(layout.remaining_height(), 20);
+    assert_eq!(layout.with_offset(0, 15).remaining_height(), 5);
+    assert_eq!(layout.with_offset(0, 25).remaining_height(), 0);
+    assert_eq!(
+        layout
+            .with_offset(0, 15)
+            .with_max_height(3)
+            .remaining_height(),
+        3
+    );
+}
+
+#[test]
+fn test_constrain_width() {
+    let layout = Layout::new(0, (100, 5).into()).with_offset(10, 0);
+    assert_eq!(layout.constrain_width(30).available_width(), 30);
+    assert_eq!(layout.constrain_width(30).width, 40);
+    assert_eq!(layout.constrain_width(200), layout);
+    assert_eq!(layout.indent(5).constrain_width(30).width, 45);
+}
+
+#[test]
+fn test_display_width() {
+    assert_eq!(display_width(""), 0);
+    assert_eq!(display_width("abc"), 3);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("e\u{301}"), 1);
+    assert_eq!(display_width("🦀 crab"), 7);
+}
+
+#[test]
+fn test_char_width() {
+    assert_eq!(char_width('a'), 1);
+    assert_eq!(char_width('日'), 2);
+    assert_eq!(char_width('\u{301}'), 0);
+}
@@ -16,6 +16,32 @@
 //! - [`termion`](https://crates.io/crates/termion)
 //!
 //! The different backends can be enabled using the features of the same name.
+//!
+//! With the `no-style` feature, the backends ignore colors and attributes, and the
+//! [`ASCII`](symbols::ASCII) symbol set is used by default.
+//!
+//! With the `hyperlinks` feature, text styled with [`Stylize::hyperlink`](style::Stylize::hyperlink)
+//! is written as OSC 8 hyperlinks.
+//!
+//! With the `bracketed-paste` feature, [`Input`] asks the terminal to mark pasted text, which is
+//! then received as a single [`Event::Paste`](events::Event::Paste).
+//!
+//! With the `signals` feature, [`Input`] restores the terminal if the process receives `SIGTERM`,
+//! `SIGHUP` or `SIGQUIT` while it is running, before the signal terminates the process. This is
+//! only done for backends which enable raw mode on the controlling terminal (see
+//! [`Backend::uses_controlling_terminal`](backend::Backend::uses_controlling_terminal)), and has no
+//! effect on platforms other than unix.
+//!
+//! With the `tracing` feature, [`Input`] emits [`tracing`](https://crates.io/crates/tracing) events
+//! for every key it handles, at the `TRACE` level, and whenever validation fails, at the `DEBUG`
+//! level. The keys are part of the events, so the `TRACE` level should not be recorded for prompts
+//! which ask for secrets.
+//!
+//! # Async
+//!
+//! With the `async` feature, [`Input::run_async`] can be used to wait for events without blocking,
+//! using an [`AsyncEventIterator`](events::AsyncEventIterator). The `event-stream` feature
+//! provides an implementation using `crossterm`'s event stream.
 #![deny(
     missing_docs,
     missing_debug_implementations,
@@ -26,23 +52,41 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub use error::{ErrorKind, Result};
-pub use input::{Input, OnEsc, Prompt, Validation};
+pub use input::{CancellationToken, Input, OnEsc, Prompt, Recovered, Severity, Validation};
 pub use widgets::Widget;
 
+pub mod accessibility;
 pub mod backend;
 mod char_input;
 mod error;
+mod error_view;
 pub mod events;
 mod input;
+mod labeled_input;
 pub mod layout;
+mod list_box;
+mod multiline_text_input;
 mod prompt;
+mod prompt_lock;
 mod select;
+#[cfg(all(unix, feature = "signals"))]
+mod signals;
+mod slider;
+mod spinner;
 mod string_input;
 pub mod style;
 pub mod symbols;
 mod text;
+#[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+mod tty;
 pub mod widgets;
 
+// Re-exported so that `requestty` can emit events without depending on `tracing` itself, which
+// would give it a `tracing` feature that cannot also enable this crate's `tracing` feature.
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
 #[doc(hidden)]
 pub mod features {
     #[cfg(feature = "crossterm")]
@@ -54,6 +98,10 @@ pub mod features {
     // (documentation) with both features enabled will not error
     #[cfg(not(feature = "crossterm"))]
     pub const SNAPSHOT_PATH: &str = "termion-snapshots";
+
+    // Checked here instead of in the macros, where `cfg!` would check the features of the crate
+    // using them
+    pub const NO_STYLE: bool = cfg!(feature = "no-style");
 }
 
 /// A testing utility to assert visual equality with [`TestBackend`](backend::TestBackend).
@@ -61,6 +109,9 @@ pub mod features {
 /// It is a simple wrapper around [`insta::assert_display_snapshot`] which puts the snapshots in
 /// `$CARGO_MANIFEST_DIR/{crossterm/termion}-snapshots`.
 ///
+/// The snapshots are taken with styling and the default symbols, so they are not checked with the
+/// `no-style` feature.
+///
 /// [`insta::assert_display_snapshot`]: https://docs.rs/insta/1.11.0/insta/macro.assert_display_snapshot.html
 #[cfg(any(feature = "crossterm", feature = "termion"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
@@ -82,11 +133,13 @@ macro_rules! assert_backend_snapshot {
 #[macro_export]
 macro_rules! assert_backend_snapshot_impl {
     ($($tt:tt)*) => {{
-        ::insta::with_settings!({
-            snapshot_path => ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($crate::features::SNAPSHOT_PATH)
-        }, {
-            $($tt)*
-        })
+        if !$crate::features::NO_STYLE {
+            ::insta::with_settings!({
+                snapshot_path => ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($crate::features::SNAPSHOT_PATH)
+            }, {
+                $($tt)*
+            })
+        }
     }}
 }
 
@@ -0,0 +1,418 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
+    layout::Layout,
+    style::Color,
+    widgets::{List, Select},
+    Widget,
+};
+
+/// The items of a [`ListBox`] or [`CheckList`], with whether each one is checked for the latter.
+#[derive(Debug, Clone)]
+struct Items<T> {
+    items: Vec<T>,
+    checked: Option<Vec<bool>>,
+    page_size: usize,
+    should_loop: bool,
+}
+
+impl<T> Items<T> {
+    /// The width taken by the pointer and the check mark before every item.
+    fn indent(&self) -> u16 {
+        if self.checked.is_some() {
+            4
+        } else {
+            2
+        }
+    }
+}
+
+impl<T: Widget> List for Items<T> {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        mut layout: Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let theme = crate::style::theme();
+
+        if hovered {
+            b.set_fg(theme.highlight)?;
+            write!(b, "{} ", crate::symbols::current().pointer)?;
+        } else {
+            b.write_all(b"  ")?;
+        }
+
+        if let Some(ref checked) = self.checked {
+            b.set_fg(if checked[index] {
+                theme.selected
+            } else {
+                theme.hint
+            })?;
+            write!(b, "{} ", crate::symbols::current().completed)?;
+            b.set_fg(if hovered {
+                theme.highlight
+            } else {
+                Color::Reset
+            })?;
+        }
+
+        layout.offset_x += self.indent();
+        self.items[index].render(&mut layout, b)?;
+
+        b.set_fg(Color::Reset)
+    }
+
+    fn is_selectable(&self, _: usize) -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn should_loop(&self) -> bool {
+        self.should_loop
+    }
+
+    fn height_at(&mut self, index: usize, mut layout: Layout) -> u16 {
+        layout.offset_x += self.indent();
+        self.items[index].height(&mut layout)
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+macro_rules! impl_list_options {
+    () => {
+        /// The maximum height that can be taken by the list, after which it is paginated.
+        ///
+        /// Defaults to 15.
+        ///
+        /// # Panics
+        ///
+        /// It panics if `page_size` is less than 5.
+        pub fn page_size(mut self, page_size: usize) -> Self {
+            assert!(page_size >= 5, "page size can be a minimum of 5");
+            self.select.list.page_size = page_size;
+            self
+        }
+
+        /// Whether to wrap around when the user goes past the last item, or before the first.
+        ///
+        /// Defaults to `true`.
+        pub fn should_loop(mut self, should_loop: bool) -> Self {
+            self.select.list.should_loop = should_loop;
+            self
+        }
+
+        /// The index of the item that is hovered.
+        pub fn get_at(&self) -> usize {
+            self.select.get_at()
+        }
+
+        /// Hover the item at `at`.
+        pub fn set_at(&mut self, at: usize) {
+            self.select.set_at(at)
+        }
+
+        /// The items of the list.
+        pub fn items(&self) -> &[T] {
+            &self.select.list.items
+        }
+    };
+}
+
+macro_rules! impl_widget {
+    ($name:ident) => {
+        impl<T: Widget> Widget for $name<T> {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+                self.select.render(layout, b)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                self.select.height(layout)
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                self.select.cursor_pos(layout)
+            }
+
+            fn focused_rows(&mut self, layout: Layout) -> std::ops::Range<u16> {
+                self.select.focused_rows(layout)
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                self.handle_key_impl(key)
+            }
+
+            fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+                self.handle_mouse_impl(mouse)
+            }
+        }
+    };
+}
+
+/// A list of items which can be moved through with the arrow keys, to select one of them.
+///
+/// This is the list used by the built-in `select` prompt: the hovered item is marked by a pointer,
+/// and the list is paginated if it is taller than the page size. Any [`Widget`] can be an item,
+/// such as a [`Text`](crate::widgets::Text) for items which span multiple lines.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::ListBox;
+///
+/// let list = ListBox::new(vec!["red", "green", "blue"])
+///     .page_size(5)
+///     .should_loop(false);
+///
+/// assert_eq!(list.selected(), &"red");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ListBox<T> {
+    select: Select<Items<T>>,
+}
+
+impl<T: Widget> ListBox<T> {
+    /// Creates a new `ListBox` with the given items.
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            select: Select::new(Items {
+                items,
+                checked: None,
+                page_size: 15,
+                should_loop: true,
+            }),
+        }
+    }
+
+    impl_list_options!();
+
+    /// The hovered item.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the list is empty.
+    pub fn selected(&self) -> &T {
+        &self.select.list.items[self.select.get_at()]
+    }
+
+    /// Consumes the `ListBox`, returning its items.
+    pub fn into_items(self) -> Vec<T> {
+        self.select.into_inner().items
+    }
+
+    fn handle_key_impl(&mut self, key: KeyEvent) -> bool {
+        self.select.handle_key(key)
+    }
+
+    fn handle_mouse_impl(&mut self, mouse: MouseEvent) -> bool {
+        self.select.handle_mouse(mouse)
+    }
+}
+
+impl_widget!(ListBox);
+
+/// A list of items which can each be checked, to select any number of them.
+///
+/// This is the list used by the built-in `multi_select` prompt. It moves through the items like a
+/// [`ListBox`], and the hovered item is toggled with `Space`. `a` toggles all the items, and `i`
+/// inverts every item. Clicking on the hovered item also toggles it.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::CheckList;
+///
+/// let list = CheckList::new(vec!["cheese", "olives", "peppers"]).with_checked(1, true);
+///
+/// assert_eq!(list.checked(), &[false, true, false]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CheckList<T> {
+    select: Select<Items<T>>,
+}
+
+impl<T: Widget> CheckList<T> {
+    /// Creates a new `CheckList` with the given items, none of which are checked.
+    pub fn new(items: Vec<T>) -> Self {
+        let checked = vec![false; items.len()];
+
+        Self {
+            select: Select::new(Items {
+                items,
+                checked: Some(checked),
+                page_size: 15,
+                should_loop: true,
+            }),
+        }
+    }
+
+    /// Sets whether the item at `index` is checked.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `index` is out of bounds.
+    pub fn with_checked(mut self, index: usize, checked: bool) -> Self {
+        self.set_checked(index, checked);
+        self
+    }
+
+    impl_list_options!();
+
+    /// Whether each item is checked.
+    pub fn checked(&self) -> &[bool] {
+        self.checked_ref()
+    }
+
+    /// Sets whether the item at `index` is checked.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `index` is out of bounds.
+    pub fn set_checked(&mut self, index: usize, checked: bool) {
+        self.checked_mut()[index] = checked;
+    }
+
+    /// The checked items, along with their indices.
+    pub fn checked_items(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.select
+            .list
+            .items
+            .iter()
+            .enumerate()
+            .zip(self.checked_ref())
+            .filter_map(|(item, &checked)| if checked { Some(item) } else { None })
+    }
+
+    /// Consumes the `CheckList`, returning its items along with whether each one is checked.
+    pub fn into_items(self) -> Vec<(T, bool)> {
+        let items = self.select.into_inner();
+        let checked = items
+            .checked
+            .expect("a check list always has checked items");
+        items.items.into_iter().zip(checked).collect()
+    }
+
+    fn checked_ref(&self) -> &[bool] {
+        self.select
+            .list
+            .checked
+            .as_deref()
+            .expect("a check list always has checked items")
+    }
+
+    fn checked_mut(&mut self) -> &mut [bool] {
+        self.select
+            .list
+            .checked
+            .as_deref_mut()
+            .expect("a check list always has checked items")
+    }
+
+    fn handle_key_impl(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(' ') if !self.select.list.items.is_empty() => {
+                let at = self.select.get_at();
+                let checked = self.checked_mut();
+                checked[at] = !checked[at];
+            }
+            KeyCode::Char('i') => self.checked_mut().iter_mut().for_each(|c| *c = !*c),
+            KeyCode::Char('a') => {
+                let checked = self.checked_mut();
+                let state = checked.iter().any(|c| !c);
+                checked.iter_mut().for_each(|c| *c = state);
+            }
+            _ => return self.select.handle_key(key),
+        }
+
+        true
+    }
+
+    fn handle_mouse_impl(&mut self, mouse: MouseEvent) -> bool {
+        // The clicked index must be found before handling the event, since the page may move
+        let clicked = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.select.index_at_row(mouse.row),
+            _ => None,
+        };
+
+        let handled = self.select.handle_mouse(mouse);
+
+        match clicked {
+            Some(index) if index == self.select.get_at() => {
+                let checked = self.checked_mut();
+                checked[index] = !checked[index];
+                true
+            }
+            _ => handled,
+        }
+    }
+}
+
+impl_widget!(CheckList);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, events::KeyModifiers};
+
+    #[test]
+    fn test_list_box() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(0, size);
+        let mut backend = TestBackend::new(size);
+
+        let mut list = ListBox::new(vec!["first", "second", "third"]).should_loop(false);
+
+        assert!(list.handle_key(KeyCode::Down.into()));
+        assert!(list.handle_key(KeyCode::Down.into()));
+        assert!(!list.handle_key(KeyCode::Down.into()));
+        assert_eq!(list.selected(), &"third");
+
+        let mut layout = base_layout;
+        assert_eq!(list.height(&mut layout), 3);
+        layout = base_layout;
+        list.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_check_list() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(0, size);
+        let mut backend = TestBackend::new(size);
+
+        let mut list = CheckList::new(vec!["first", "second", "third"]).with_checked(2, true);
+
+        assert!(list.handle_key(KeyCode::Char(' ').into()));
+        assert_eq!(list.checked(), &[true, false, true]);
+        assert!(list.handle_key(KeyCode::Char('i').into()));
+        assert_eq!(list.checked(), &[false, true, false]);
+        assert!(list.handle_key(KeyCode::Char('a').into()));
+        assert_eq!(list.checked(), &[true, true, true]);
+        assert!(list.handle_key(KeyCode::Char('a').into()));
+        assert_eq!(list.checked(), &[false, false, false]);
+
+        assert!(list.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::empty())));
+        assert!(list.handle_key(KeyCode::Char(' ').into()));
+        assert_eq!(list.checked_items().collect::<Vec<_>>(), [(1, &"second")]);
+
+        let mut layout = base_layout;
+        assert_eq!(list.height(&mut layout), 3);
+        layout = base_layout;
+        list.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+
+        assert_eq!(
+            list.into_items(),
+            [("first", false), ("second", true), ("third", false)]
+        );
+    }
+}
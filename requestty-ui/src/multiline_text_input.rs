@@ -0,0 +1,458 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent, KeyModifiers, Movement},
+    layout::Layout,
+};
+
+/// A widget that inputs text over multiple lines.
+///
+/// Unlike [`StringInput`], `Enter` inserts a new line, so a [`Prompt`] using this widget should
+/// submit on [`MultilineTextInput::is_submit_key`] instead, by overriding [`Prompt::is_submit_key`].
+///
+/// The text is always rendered starting on a new line, and long lines wrap around. If the text
+/// takes more rows than the max height, it scrolls to keep the cursor in view.
+///
+/// [`StringInput`]: crate::widgets::StringInput
+/// [`Prompt`]: crate::Prompt
+/// [`Prompt::is_submit_key`]: crate::Prompt::is_submit_key
+#[derive(Debug, Clone)]
+pub struct MultilineTextInput {
+    /// The lines of the text. There is always at least one line.
+    lines: Vec<String>,
+    /// The line that the 'cursor' is on
+    row: usize,
+    /// The position of the 'cursor' in characters within its line
+    col: usize,
+    max_height: u16,
+    /// The first row that is rendered, in case the text takes more rows than `max_height`
+    scroll: usize,
+}
+
+impl MultilineTextInput {
+    /// Creates a new, empty [`MultilineTextInput`].
+    pub fn new() -> Self {
+        Self {
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+            max_height: 10,
+            scroll: 0,
+        }
+    }
+
+    /// The maximum number of rows that are rendered, after which the text will scroll.
+    ///
+    /// The `max_height` must be a minimum of 1. If it is not set, it will default to 10.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `max_height` is 0.
+    pub fn max_height(mut self, max_height: u16) -> Self {
+        assert!(max_height >= 1, "max height can be a minimum of 1");
+
+        self.max_height = max_height;
+        self
+    }
+
+    /// Whether the key should submit the text. This is `Alt+Enter` or `Ctrl+D`.
+    ///
+    /// `Ctrl+D` is accepted as well since not all terminals report `Alt+Enter`.
+    pub fn is_submit_key(key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Enter => key.modifiers.contains(KeyModifiers::ALT),
+            KeyCode::Char('d') => key.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        }
+    }
+
+    /// The value of the `MultilineTextInput`, with the lines separated by `'\n'`
+    pub fn value(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Sets the value, and moves the 'cursor' to the end of it
+    pub fn set_value(&mut self, value: String) {
+        self.lines = value.split('\n').map(String::from).collect();
+        self.row = self.lines.len() - 1;
+        self.col = self.line_len(self.row);
+    }
+
+    /// Returns the inputted text
+    pub fn finish(self) -> String {
+        self.value()
+    }
+
+    /// Gets the line and the position in characters within the line of the 'cursor'.
+    pub fn get_at(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Sets the line and the position in characters within the line of the 'cursor'.
+    pub fn set_at(&mut self, (row, col): (usize, usize)) {
+        self.row = row.min(self.lines.len() - 1);
+        self.col = col.min(self.line_len(self.row));
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    /// Gets the byte index of the 'cursor' in its line
+    fn byte_i(&self) -> usize {
+        let line = &self.lines[self.row];
+
+        line.char_indices()
+            .nth(self.col)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| line.len())
+    }
+
+    fn move_to_row(&mut self, row: usize) {
+        self.row = row;
+        self.col = self.col.min(self.line_len(row));
+    }
+
+    /// Splits the lines into the rows they are rendered in for the given width.
+    ///
+    /// It also returns the row of the 'cursor', and its position in that row.
+    fn rows(&self, width: u16) -> (Vec<&str>, (usize, u16)) {
+        let width = width.max(1);
+        let mut rows = Vec::with_capacity(self.lines.len());
+        let mut cursor = (0, 0);
+
+        for (row, line) in self.lines.iter().enumerate() {
+            let mut start = 0;
+            let mut row_width = 0;
+
+            for (col, (i, c)) in line.char_indices().enumerate() {
+                let c_width = crate::layout::char_width(c);
+
+                if row_width + c_width > width {
+                    rows.push(&line[start..i]);
+                    start = i;
+                    row_width = 0;
+                }
+
+                if row == self.row && col == self.col {
+                    cursor = (rows.len(), row_width);
+                }
+
+                row_width += c_width;
+            }
+
+            if row == self.row && self.col == self.line_len(row) {
+                // The cursor is placed after the last character, which may need an extra row
+                if row_width == width {
+                    rows.push(&line[start..]);
+                    start = line.len();
+                    row_width = 0;
+                }
+
+                cursor = (rows.len(), row_width);
+            }
+
+            rows.push(&line[start..]);
+        }
+
+        (rows, cursor)
+    }
+
+    /// Gets the first row that should be rendered so that the 'cursor' is visible.
+    fn get_scroll(&self, nrows: usize, cursor_row: usize) -> usize {
+        let max_height = self.max_height as usize;
+
+        if cursor_row < self.scroll {
+            cursor_row
+        } else if cursor_row >= self.scroll + max_height {
+            cursor_row + 1 - max_height
+        } else {
+            // If the text has become shorter, there shouldn't be unused rows at the bottom
+            self.scroll.min(nrows.saturating_sub(max_height))
+        }
+    }
+}
+
+impl super::Widget for MultilineTextInput {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            // FIXME: all chars with ctrl and alt are ignored, even though only some
+            // need to be ignored
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                let byte_i = self.byte_i();
+                self.lines[self.row].insert(byte_i, c);
+                self.col += 1;
+                return true;
+            }
+            KeyCode::Enter => {
+                let byte_i = self.byte_i();
+                let rest = self.lines[self.row].split_off(byte_i);
+                self.row += 1;
+                self.col = 0;
+                self.lines.insert(self.row, rest);
+                return true;
+            }
+
+            KeyCode::Backspace if self.col != 0 => {
+                self.col -= 1;
+                let byte_i = self.byte_i();
+                self.lines[self.row].remove(byte_i);
+                return true;
+            }
+            KeyCode::Backspace if self.row != 0 => {
+                let line = self.lines.remove(self.row);
+                self.row -= 1;
+                self.col = self.line_len(self.row);
+                self.lines[self.row].push_str(&line);
+                return true;
+            }
+            KeyCode::Delete if self.col != self.line_len(self.row) => {
+                let byte_i = self.byte_i();
+                self.lines[self.row].remove(byte_i);
+                return true;
+            }
+            KeyCode::Delete if self.row + 1 != self.lines.len() => {
+                let line = self.lines.remove(self.row + 1);
+                self.lines[self.row].push_str(&line);
+                return true;
+            }
+            KeyCode::Backspace | KeyCode::Delete => return false,
+
+            _ => {}
+        }
+
+        let last_row = self.lines.len() - 1;
+
+        match Movement::try_from_key(key) {
+            Some(Movement::Left) if self.col != 0 => {
+                self.col -= 1;
+            }
+            Some(Movement::Left) if self.row != 0 => {
+                self.row -= 1;
+                self.col = self.line_len(self.row);
+            }
+            Some(Movement::Right) if self.col != self.line_len(self.row) => {
+                self.col += 1;
+            }
+            Some(Movement::Right) if self.row != last_row => {
+                self.row += 1;
+                self.col = 0;
+            }
+
+            Some(Movement::Up) if self.row != 0 => self.move_to_row(self.row - 1),
+            Some(Movement::Down) if self.row != last_row => self.move_to_row(self.row + 1),
+            Some(Movement::PageUp) if self.row != 0 => {
+                self.move_to_row(self.row.saturating_sub(self.max_height as usize))
+            }
+            Some(Movement::PageDown) if self.row != last_row => {
+                self.move_to_row((self.row + self.max_height as usize).min(last_row))
+            }
+
+            Some(Movement::Home) if self.col != 0 => {
+                self.col = 0;
+            }
+            Some(Movement::End) if self.col != self.line_len(self.row) => {
+                self.col = self.line_len(self.row);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// The text is rendered at [`layout.offset_x`], starting on the next line if
+    /// [`layout.line_offset`] is not 0.
+    ///
+    /// [`layout.offset_x`]: Layout.offset_x
+    /// [`layout.line_offset`]: Layout.line_offset
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            backend.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        let (rows, (cursor_row, _)) = self.rows(layout.available_width());
+        let scroll = self.get_scroll(rows.len(), cursor_row);
+        let nrows = rows.len().min(self.max_height as usize);
+
+        for row in &rows[scroll..(scroll + nrows)] {
+            backend.write_all(row.as_bytes())?;
+            layout.offset_y += 1;
+            backend.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        self.scroll = scroll;
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let skipped = (layout.line_offset != 0) as u16;
+
+        let (rows, (cursor_row, _)) = self.rows(layout.available_width());
+        let nrows = rows.len();
+        self.scroll = self.get_scroll(nrows, cursor_row);
+
+        let height = skipped + nrows.min(self.max_height as usize) as u16;
+
+        layout.line_offset = 0;
+        layout.offset_y += height;
+
+        height
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let skipped = (layout.line_offset != 0) as u16;
+        let (_, (cursor_row, x)) = self.rows(layout.available_width());
+
+        layout.offset_cursor((x, skipped + cursor_row.saturating_sub(self.scroll) as u16))
+    }
+}
+
+impl Default for MultilineTextInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, Widget};
+
+    fn with_value(value: &str) -> MultilineTextInput {
+        let mut input = MultilineTextInput::new();
+        input.set_value(value.into());
+        input
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut input = MultilineTextInput::new();
+
+        for c in "ab".chars() {
+            assert!(input.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(input.handle_key(KeyCode::Enter.into()));
+        assert!(input.handle_key(KeyCode::Char('c').into()));
+        assert_eq!(input.value(), "ab\nc");
+        assert_eq!(input.get_at(), (1, 1));
+
+        assert!(input.handle_key(KeyCode::Up.into()));
+        assert_eq!(input.get_at(), (0, 1));
+        assert!(input.handle_key(KeyCode::Enter.into()));
+        assert_eq!(input.value(), "a\nb\nc");
+        assert_eq!(input.get_at(), (1, 0));
+
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.get_at(), (0, 1));
+        assert!(input.handle_key(KeyCode::Right.into()));
+        assert_eq!(input.get_at(), (1, 0));
+        assert!(!input.handle_key(KeyCode::Home.into()));
+
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "ab\nc");
+        assert_eq!(input.get_at(), (0, 1));
+        assert!(input.handle_key(KeyCode::End.into()));
+        assert!(input.handle_key(KeyCode::Delete.into()));
+        assert_eq!(input.value(), "abc");
+        assert_eq!(input.get_at(), (0, 2));
+
+        assert!(!input.handle_key(KeyCode::Down.into()));
+        assert!(!input.handle_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)));
+
+        let mut input = with_value("a\nbcd\nef");
+        input.set_at((1, 3));
+        assert!(input.handle_key(KeyCode::Down.into()));
+        assert_eq!(input.get_at(), (2, 2));
+        assert!(input.handle_key(KeyCode::PageUp.into()));
+        assert_eq!(input.get_at(), (0, 1));
+        assert!(input.handle_key(KeyCode::PageDown.into()));
+        assert_eq!(input.get_at(), (2, 1));
+    }
+
+    #[test]
+    fn test_is_submit_key() {
+        assert!(MultilineTextInput::is_submit_key(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::ALT
+        )));
+        assert!(MultilineTextInput::is_submit_key(KeyEvent::new(
+            KeyCode::Char('d'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!MultilineTextInput::is_submit_key(KeyCode::Enter.into()));
+        assert!(!MultilineTextInput::is_submit_key(
+            KeyCode::Char('d').into()
+        ));
+    }
+
+    #[test]
+    fn test_height() {
+        fn test(input: &mut MultilineTextInput, line_offset: u16, height: u16) {
+            let mut layout = Layout::new(line_offset, (10, 20).into());
+            assert_eq!(input.height(&mut layout), height);
+            assert_eq!(
+                layout,
+                Layout::new(0, (10, 20).into()).with_offset(0, height)
+            );
+        }
+
+        test(&mut with_value(""), 0, 1);
+        test(&mut with_value(""), 5, 2);
+        test(&mut with_value("a\nb\nc"), 0, 3);
+        // the line wraps, and the cursor after it needs another row
+        test(&mut with_value("0123456789"), 0, 2);
+        test(&mut with_value("0123456789abc"), 3, 3);
+
+        let mut scrolled = with_value("a\nb\nc\nd\ne").max_height(3);
+        test(&mut scrolled, 0, 3);
+        assert_eq!(scrolled.scroll, 2);
+        scrolled.set_at((0, 0));
+        test(&mut scrolled, 0, 3);
+        assert_eq!(scrolled.scroll, 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (20, 10).into();
+        let mut backend = TestBackend::new_with_layout(size, Layout::new(5, size));
+        let mut layout = Layout::new(5, size);
+
+        let mut input = with_value("Hello,\nWorld! This line is long\n\nLast").max_height(4);
+        input.set_at((1, 2));
+
+        input.render(&mut layout, &mut backend).unwrap();
+        assert_eq!(layout, Layout::new(0, size).with_offset(0, 5));
+        crate::assert_backend_snapshot!(backend);
+
+        // scrolls down to the cursor
+        input.set_at((3, 4));
+        backend.reset_with_layout(Layout::new(0, size));
+        layout = Layout::new(0, size);
+        input.render(&mut layout, &mut backend).unwrap();
+        assert_eq!(layout, Layout::new(0, size).with_offset(0, 4));
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_cursor_pos() {
+        let layout = Layout::new(0, (10, 20).into());
+        let mut input = with_value("a\n0123456789ab");
+
+        assert_eq!(input.cursor_pos(layout), (2, 2));
+        input.set_at((1, 10));
+        assert_eq!(input.cursor_pos(layout), (0, 2));
+        input.set_at((1, 9));
+        assert_eq!(input.cursor_pos(layout), (9, 1));
+        input.set_at((0, 1));
+        assert_eq!(input.cursor_pos(layout.with_line_offset(4)), (1, 1));
+        assert_eq!(input.cursor_pos(layout.with_offset(2, 3)), (3, 3));
+    }
+}
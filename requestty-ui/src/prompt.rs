@@ -1,9 +1,11 @@
-use std::{convert::TryFrom, io};
+use std::{borrow::Cow, convert::TryFrom, io};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    backend::Backend,
+    backend::{Backend, Wrapped},
     events,
-    layout::Layout,
+    layout::{char_width, display_width, Layout},
     style::{Color, Stylize},
     Widget,
 };
@@ -39,11 +41,23 @@ impl From<Delimiter> for Option<(char, char)> {
 }
 
 /// A generic prompt that renders a message and an optional hint.
+///
+/// The message and hint can be any type that implements `AsRef<str>`. For hints which are only
+/// sometimes computed, [`Cow<'_, str>`](std::borrow::Cow) can be used to avoid allocating when the
+/// hint is static.
+///
+/// The prompt is rendered as `<prefix> <message> <hint> ` or `<prefix> <message> <arrow> ` if there
+/// is no hint. The prefix and arrow default to the ones in the current
+/// [`SymbolSet`](crate::symbols::SymbolSet), and the prefix is colored with the
+/// [`prefix`](crate::style::Theme::prefix) color of the current theme.
 #[derive(Debug, Clone)]
 pub struct Prompt<M, H = &'static str> {
     message: M,
     hint: Option<H>,
     delim: Delimiter,
+    prefix: Option<Cow<'static, str>>,
+    prefix_color: Option<Color>,
+    arrow: Option<char>,
     message_len: u16,
     hint_len: u16,
 }
@@ -57,6 +71,9 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
             message,
             hint: None,
             delim: Delimiter::Parentheses,
+            prefix: None,
+            prefix_color: None,
+            arrow: None,
             hint_len: 0,
         }
     }
@@ -77,12 +94,48 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         }
     }
 
+    /// Sets the hint of an existing prompt.
+    ///
+    /// This allows a prompt to be reused when the hint changes, instead of creating a new one.
+    pub fn set_hint(&mut self, hint: Option<H>) {
+        self.hint_len = match hint {
+            Some(ref hint) => u16::try_from(textwrap::core::display_width(hint.as_ref()))
+                .expect("hint must fit within a u16"),
+            None => 0,
+        };
+        self.hint = hint;
+    }
+
     /// Sets the hint delimiter
     pub fn with_delim(mut self, delim: Delimiter) -> Self {
         self.delim = delim;
         self
     }
 
+    /// Sets the text shown before the message, instead of the
+    /// [`prefix`](crate::symbols::SymbolSet::prefix) of the current symbol set.
+    ///
+    /// An empty prefix is not followed by a space, so the message starts at the beginning of the
+    /// line.
+    pub fn with_prefix<P: Into<Cow<'static, str>>>(mut self, prefix: P) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the color of the prefix, instead of the [`prefix`](crate::style::Theme::prefix)
+    /// color of the current theme.
+    pub fn with_prefix_color(mut self, color: Color) -> Self {
+        self.prefix_color = Some(color);
+        self
+    }
+
+    /// Sets the symbol shown after the message when there is no hint, instead of the
+    /// [`arrow`](crate::symbols::SymbolSet::arrow) of the current symbol set.
+    pub fn with_arrow(mut self, arrow: char) -> Self {
+        self.arrow = Some(arrow);
+        self
+    }
+
     /// Get the message
     pub fn message(&self) -> &M {
         &self.message
@@ -130,23 +183,42 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         }
     }
 
+    /// The character length of the prefix including the space after it. It is 0 if the prefix is
+    /// empty
+    pub fn prefix_len(&self) -> u16 {
+        let len = match self.prefix {
+            Some(ref prefix) => display_width(prefix),
+            None => char_width(crate::symbols::current().prefix),
+        };
+
+        if len == 0 {
+            0
+        } else {
+            len + 1
+        }
+    }
+
     /// The character length of the fully rendered prompt
     pub fn width(&self) -> u16 {
         if self.hint.is_some() {
             // `? <message> <hint> `
-            2 + self.message_len + 1 + self.hint_len() + 1
+            self.prefix_len() + self.message_len + 1 + self.hint_len() + 1
         } else {
             // `? <message> › `
-            2 + self.message_len + 3
+            let arrow = self
+                .arrow
+                .unwrap_or_else(|| crate::symbols::current().arrow);
+            self.prefix_len() + self.message_len + 1 + char_width(arrow) + 1
         }
     }
 
     fn cursor_pos_impl(&self, layout: Layout) -> (u16, u16) {
+        let layout = layout.aligned();
         let mut width = self.width();
         let relative_pos = if width > layout.line_width() {
             width -= layout.line_width();
 
-            (width % layout.width, 1 + width / layout.width)
+            (width % layout.wrap_width(), 1 + width / layout.wrap_width())
         } else {
             (layout.line_offset + width, 0)
         };
@@ -163,37 +235,105 @@ impl<M: AsRef<str>> Prompt<M, &'static str> {
         backend: &mut B,
     ) -> io::Result<()> {
         let symbol_set = crate::symbols::current();
+        let theme = crate::style::theme();
         if skipped {
             backend.write_styled(&symbol_set.cross.yellow())?;
         } else {
-            backend.write_styled(&symbol_set.completed.light_green())?;
+            backend.write_styled(&symbol_set.completed.with(theme.prefix))?;
         }
         backend.write_all(b" ")?;
         backend.write_styled(&message.as_ref().bold())?;
         backend.write_all(b" ")?;
-        backend.write_styled(&symbol_set.middle_dot.dark_grey())?;
+        backend.write_styled(&symbol_set.middle_dot.with(theme.hint))?;
         backend.write_all(b" ")
     }
+
+    /// Truncates `answer` with `...` so that it fits in the rest of the line after the end prompt
+    /// written by [`write_finished_message`](Self::write_finished_message), in a terminal of the
+    /// given width.
+    ///
+    /// The last column is left empty, since terminals wrap onto the next line after writing to it.
+    pub fn truncate_finished_answer<'a>(message: &M, answer: &'a str, width: u16) -> Cow<'a, str> {
+        if width == 0 {
+            return answer.into();
+        }
+
+        // `✔ <message> · ` has the same width as `? <message> › `
+        let used = Prompt::<_, &str>::new(message.as_ref()).width() % width;
+        let available = (width - used - 1) as usize;
+
+        if display_width(answer) as usize <= available {
+            return answer.into();
+        }
+
+        let available = available.saturating_sub(3);
+        let mut len = 0;
+        let mut truncated = String::new();
+
+        for grapheme in answer.graphemes(true) {
+            len += display_width(grapheme) as usize;
+            if len > available {
+                break;
+            }
+            truncated.push_str(grapheme);
+        }
+
+        truncated.push_str("...");
+        truncated.into()
+    }
 }
 
-impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
-    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
-        b.write_styled(&"? ".light_green())?;
+impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
+    fn write_prompt<B: Backend + ?Sized>(&self, b: &mut B) -> io::Result<()> {
+        let theme = crate::style::theme();
+        let prefix_color = self.prefix_color.unwrap_or(theme.prefix);
+
+        // The hint is written directly instead of through `write!` to avoid going through the
+        // formatting machinery on every render.
+        let mut buf = [0; 4];
+
+        let prefix = match self.prefix {
+            Some(ref prefix) => &**prefix,
+            None => &*crate::symbols::current().prefix.encode_utf8(&mut buf),
+        };
+        if !prefix.is_empty() {
+            b.write_styled(&format_args!("{} ", prefix).with(prefix_color))?;
+        }
+
         b.write_styled(&self.message.as_ref().bold())?;
         b.write_all(b" ")?;
 
-        b.set_fg(Color::DarkGrey)?;
+        b.set_fg(theme.hint)?;
 
         match (&self.hint, self.delim.into()) {
-            (Some(hint), Some((start, end))) => write!(b, "{}{}{}", start, hint.as_ref(), end)?,
-            (Some(hint), None) => write!(b, "{}", hint.as_ref())?,
+            (Some(hint), Some((start, end))) => {
+                b.write_all(start.encode_utf8(&mut buf).as_bytes())?;
+                b.write_all(hint.as_ref().as_bytes())?;
+                b.write_all(end.encode_utf8(&mut buf).as_bytes())?;
+            }
+            (Some(hint), None) => b.write_all(hint.as_ref().as_bytes())?,
             (None, _) => {
-                write!(b, "{}", crate::symbols::current().arrow)?;
+                let arrow = self
+                    .arrow
+                    .unwrap_or_else(|| crate::symbols::current().arrow);
+                b.write_all(arrow.encode_utf8(&mut buf).as_bytes())?;
             }
         }
 
         b.set_fg(Color::Reset)?;
-        b.write_all(b" ")?;
+        b.write_all(b" ")
+    }
+}
+
+impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        *layout = layout.aligned();
+
+        if layout.max_width.is_some() {
+            self.write_prompt(&mut Wrapped::new(b, *layout)?)?;
+        } else {
+            self.write_prompt(b)?;
+        }
 
         *layout = layout.with_cursor_pos(self.cursor_pos_impl(*layout));
 
@@ -201,13 +341,15 @@ impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
     }
 
     fn height(&mut self, layout: &mut Layout) -> u16 {
+        *layout = layout.aligned();
+
         // preserve the old offset since `cursor_pos` is absolute.
         let offset_y = layout.offset_y;
 
         let cursor_pos = self.cursor_pos_impl(*layout);
         *layout = layout.with_cursor_pos(cursor_pos);
 
-        cursor_pos.1 + 1 - offset_y
+        cursor_pos.1.saturating_sub(offset_y) + 1
     }
 
     fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
@@ -221,7 +363,7 @@ impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{backend::TestBackend, test_consts::*};
+    use crate::{backend::TestBackend, layout::Alignment, test_consts::*};
 
     use super::*;
 
@@ -241,6 +383,32 @@ mod tests {
         assert_eq!(Prompt::new(LOREM).with_hint(UNICODE).width(), 946);
     }
 
+    #[test]
+    fn test_truncate_finished_answer() {
+        assert_eq!(
+            Prompt::truncate_finished_answer(&"Hello", "world", 20),
+            "world"
+        );
+        assert_eq!(
+            Prompt::truncate_finished_answer(&"Hello", "012345678", 20),
+            "012345678"
+        );
+        // the last column is left empty
+        assert_eq!(
+            Prompt::truncate_finished_answer(&"Hello", "0123456789", 20),
+            "012345..."
+        );
+        assert_eq!(
+            Prompt::truncate_finished_answer(&"Hello", "ｈｅｌｌｏ ｗｏｒｌｄ", 20),
+            "ｈｅｌ..."
+        );
+        // the message wraps onto a second line with 5 columns left
+        assert_eq!(
+            Prompt::truncate_finished_answer(&"Hello", "0123456789", 15),
+            "0..."
+        );
+    }
+
     #[test]
     fn test_render() {
         fn test(
@@ -315,6 +483,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_aligned() {
+        let size = (100, 20).into();
+        let base_layout = Layout::new(0, size)
+            .with_max_width(20)
+            .with_alignment(Alignment::Right);
+        let mut layout = base_layout;
+        let mut prompt = Prompt::new("What is your favourite colour?");
+        let mut backend = TestBackend::new(size);
+
+        // `? <message> › ` is 35 wide, so it wraps once at 20
+        assert_eq!(prompt.height(&mut layout.clone()), 2);
+        assert_eq!(prompt.cursor_pos(layout), (95, 1));
+
+        prompt.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(layout, base_layout.with_offset(80, 1).with_line_offset(15));
+    }
+
+    #[test]
+    fn test_render_custom_prefix() {
+        let size = (100, 20).into();
+        let base_layout = Layout::new(5, size);
+        let mut layout = base_layout;
+        let mut prompt = Prompt::new("Hello")
+            .with_prefix(">>")
+            .with_prefix_color(Color::Magenta)
+            .with_arrow(':');
+        let mut backend = TestBackend::new_with_layout(size, layout);
+
+        // `>> Hello : `
+        assert_eq!(prompt.width(), 11);
+        prompt.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(layout, base_layout.with_line_offset(16));
+
+        // without a prefix, the message starts at the beginning of the line
+        let prompt = Prompt::new("Hello").with_prefix("").with_hint("world");
+        assert_eq!(prompt.prefix_len(), 0);
+        assert_eq!(prompt.width(), 14);
+    }
+
+    #[test]
+    fn test_set_hint() {
+        use std::borrow::Cow;
+
+        let mut prompt = super::Prompt::new("Hello").with_hint(Cow::Borrowed("world"));
+        assert_eq!(prompt.width(), 16);
+
+        prompt.set_hint(Some(Cow::Owned("world!!".to_owned())));
+        assert_eq!(prompt.hint().map(|hint| &**hint), Some("world!!"));
+        assert_eq!(prompt.width(), 18);
+
+        prompt.set_hint(None);
+        assert_eq!(prompt.hint_len(), 0);
+        assert_eq!(prompt.width(), 10);
+    }
+
     #[test]
     fn test_height() {
         let mut layout = Layout::new(5, (100, 20).into());
@@ -0,0 +1,253 @@
+//! A process-wide lock so that only one [`Input`](crate::Input) uses the terminal at a time.
+
+use std::{
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    thread::{self, ThreadId},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::error;
+
+struct Owner {
+    /// The thread running the prompt. Prompts run asynchronously are only tied to a thread while
+    /// they are handling an event, since their task may move between threads while it waits.
+    thread: Option<ThreadId>,
+    /// The number of nested prompts running on the thread
+    depth: usize,
+}
+
+#[derive(Default)]
+struct State {
+    owner: Option<Owner>,
+    /// The tasks waiting for the lock to be released
+    #[cfg(feature = "async")]
+    waiting: Vec<std::task::Waker>,
+}
+
+impl State {
+    /// Takes the lock if it is free, or joins it if it is held by a prompt running on `thread`
+    /// and `nested` is true.
+    fn try_take(&mut self, thread: Option<ThreadId>, nested: bool) -> Option<error::Result<()>> {
+        let current = thread::current().id();
+
+        match self.owner {
+            None => {
+                self.owner = Some(Owner { thread, depth: 1 });
+                Some(Ok(()))
+            }
+            Some(ref mut owner) if owner.thread == Some(current) => {
+                if !nested {
+                    return Some(Err(error::ErrorKind::PromptInProgress));
+                }
+
+                owner.depth += 1;
+                Some(Ok(()))
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+static STATE: Lazy<Mutex<State>> = Lazy::new(|| Mutex::new(State::default()));
+static RELEASED: Lazy<Condvar> = Lazy::new(Condvar::new);
+
+fn state() -> MutexGuard<'static, State> {
+    // The lock is never held while running user code, so the state is valid even if poisoned
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Held while a prompt is running, and released when it is dropped.
+#[derive(Debug)]
+pub(crate) struct PromptLock(());
+
+impl PromptLock {
+    /// Waits for prompts running on other threads to finish before taking the lock.
+    ///
+    /// If a prompt is already running on this thread, the lock is only taken if `nested` is true,
+    /// as waiting would never finish.
+    pub(crate) fn acquire(nested: bool) -> error::Result<Self> {
+        let current = thread::current().id();
+        let mut state = state();
+
+        loop {
+            match state.try_take(Some(current), nested) {
+                Some(res) => return res.map(PromptLock),
+                None => state = RELEASED.wait(state).unwrap_or_else(PoisonError::into_inner),
+            }
+        }
+    }
+
+    /// Waits for other prompts to finish before taking the lock, without blocking the thread.
+    ///
+    /// The lock is not tied to the current thread, so it can be released from another one.
+    /// Nested prompts can only be run while an event is handled, see [`enter`](Self::enter).
+    #[cfg(feature = "async")]
+    pub(crate) fn acquire_async(nested: bool) -> AcquireAsync {
+        AcquireAsync { nested }
+    }
+
+    /// Ties a lock taken with [`acquire_async`](Self::acquire_async) to the current thread while
+    /// `f` runs, so that nested prompts can be run from it.
+    #[cfg(feature = "async")]
+    pub(crate) fn enter<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// Unties the lock from the thread even if `f` panics.
+        struct Entered;
+
+        impl Drop for Entered {
+            fn drop(&mut self) {
+                if let Some(ref mut owner) = state().owner {
+                    owner.thread = None;
+                }
+            }
+        }
+
+        if let Some(ref mut owner) = state().owner {
+            owner.thread = Some(thread::current().id());
+        }
+
+        let _entered = Entered;
+        f()
+    }
+}
+
+impl Drop for PromptLock {
+    fn drop(&mut self) {
+        let mut state = state();
+
+        if let Some(ref mut owner) = state.owner {
+            owner.depth -= 1;
+
+            if owner.depth == 0 {
+                state.owner = None;
+                RELEASED.notify_all();
+
+                #[cfg(feature = "async")]
+                for waker in state.waiting.drain(..) {
+                    waker.wake();
+                }
+            }
+        }
+    }
+}
+
+/// The future returned by [`PromptLock::acquire_async`].
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub(crate) struct AcquireAsync {
+    nested: bool,
+}
+
+#[cfg(feature = "async")]
+impl std::future::Future for AcquireAsync {
+    type Output = error::Result<PromptLock>;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let mut state = state();
+
+        match state.try_take(None, self.nested) {
+            Some(res) => std::task::Poll::Ready(res.map(PromptLock)),
+            None => {
+                if !state.waiting.iter().any(|w| w.will_wake(cx.waker())) {
+                    state.waiting.push(cx.waker().clone());
+                }
+
+                std::task::Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn test_prompt_lock() {
+        let outer = PromptLock::acquire(false).unwrap();
+
+        assert!(matches!(
+            PromptLock::acquire(false),
+            Err(error::ErrorKind::PromptInProgress)
+        ));
+        let inner = PromptLock::acquire(true).unwrap();
+        drop(inner);
+        assert!(matches!(
+            PromptLock::acquire(false),
+            Err(error::ErrorKind::PromptInProgress)
+        ));
+
+        let (tx, rx) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let _lock = PromptLock::acquire(false).unwrap();
+            tx.send(()).unwrap();
+        });
+
+        // The other thread waits until the prompt on this thread finishes
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+        drop(outer);
+        assert!(rx.recv().is_ok());
+
+        handle.join().unwrap();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_prompt_lock_async() {
+        use std::{
+            future::Future,
+            sync::{
+                atomic::{AtomicBool, Ordering},
+                Arc,
+            },
+            task::{Context, Poll, Wake},
+        };
+
+        struct Unpark {
+            thread: thread::Thread,
+            woken: AtomicBool,
+        }
+
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.woken.store(true, Ordering::SeqCst);
+                self.thread.unpark();
+            }
+        }
+
+        let waker = Arc::new(Unpark {
+            thread: thread::current(),
+            woken: AtomicBool::new(false),
+        });
+        let std_waker = waker.clone().into();
+        let mut cx = Context::from_waker(&std_waker);
+
+        // Other tests may be running prompts
+        fn block_on(mut future: AcquireAsync, cx: &mut Context<'_>) -> PromptLock {
+            loop {
+                if let Poll::Ready(lock) = std::pin::Pin::new(&mut future).poll(cx) {
+                    return lock.unwrap();
+                }
+                thread::park();
+            }
+        }
+
+        let lock = block_on(PromptLock::acquire_async(false), &mut cx);
+
+        // Another prompt on the same thread waits for the lock instead of failing
+        let mut second = PromptLock::acquire_async(false);
+        assert!(std::pin::Pin::new(&mut second).poll(&mut cx).is_pending());
+        waker.woken.store(false, Ordering::SeqCst);
+
+        // The lock is not tied to the thread it was taken on
+        thread::spawn(move || drop(lock)).join().unwrap();
+        assert!(waker.woken.load(Ordering::SeqCst));
+
+        drop(block_on(second, &mut cx));
+    }
+}
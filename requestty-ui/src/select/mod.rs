@@ -1,13 +1,15 @@
 use std::{
     io,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
+    time::{Duration, Instant},
 };
 
 use crate::{
     backend::Backend,
-    events::{KeyEvent, Movement},
+    events::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind, Movement},
     layout::{Layout, RenderRegion},
     style::Stylize,
+    widgets::Text,
 };
 
 #[cfg(test)]
@@ -52,7 +54,148 @@ pub trait List {
     /// This only applies when the list is scrollable, i.e. page size > total height.
     fn should_loop(&self) -> bool;
 
+    /// Whether `PageUp` and `PageDown` should jump to the previous and next section instead of by
+    /// a page.
+    ///
+    /// A section is a run of selectable elements, and sections are separated by elements which are
+    /// not selectable (such as separators). When this is enabled, `[` and `]` can also be used to
+    /// jump to the previous and next section respectively.
+    ///
+    /// Defaults to `false`.
+    fn jump_by_section(&self) -> bool {
+        false
+    }
+
+    /// Whether the list can be filtered by typing.
+    ///
+    /// When this is enabled, typed characters are added to the [filter](Select::filter) instead of
+    /// being used for navigation, and only the selectable elements whose
+    /// [`filter_text`](List::filter_text) fuzzy matches the filter are shown.
+    ///
+    /// Defaults to `false`.
+    fn filterable(&self) -> bool {
+        false
+    }
+
+    /// Whether typing should hover the first selectable element whose
+    /// [`filter_text`](List::filter_text) starts with what was typed, like the list boxes of GUIs.
+    ///
+    /// The typed characters are collected until nothing is typed for a second, so that several
+    /// characters can be used to find an element. Typing the same character again moves to the next
+    /// element starting with it. Matching ignores case.
+    ///
+    /// When this is enabled, characters are used for this instead of for navigation, so `j`, `k`,
+    /// `[` and `]` no longer move the hover. It has no effect if the list is
+    /// [filterable](List::filterable).
+    ///
+    /// Defaults to `false`.
+    fn jump_to_prefix(&self) -> bool {
+        false
+    }
+
+    /// The text that the filter is matched against for the element at an index.
+    ///
+    /// Elements without any text are hidden while the filter is not empty.
+    ///
+    /// Defaults to `None`.
+    fn filter_text(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+
+    /// Render the placeholder shown when there are no selectable elements in the list.
+    ///
+    /// The placeholder is given a single line. It is not used when the list is non-empty, but no
+    /// elements match the [filter](Select::filter).
+    ///
+    /// Defaults to writing `No choices available` in the [hint](crate::style::Theme::hint) color.
+    fn render_empty<B: Backend>(&mut self, layout: Layout, backend: &mut B) -> io::Result<()> {
+        let _ = layout;
+        backend.write_styled(&"No choices available".with(crate::style::theme().hint))
+    }
+
+    /// Render a scrollbar next to the elements of the current page, when the list is
+    /// [paginating](Select::is_paginating).
+    ///
+    /// `rows` are the rows of the list shown in the page out of its `total` rows, as returned by
+    /// [`Select::page_range`]. The `layout` starts at the first row of the page, and its
+    /// `max_height` is the number of rows in the page. This is called after the elements have been
+    /// rendered, and they are given the full width of the layout, so lists which render a
+    /// scrollbar should keep the elements clear of it in [`render_item`](List::render_item) and
+    /// [`height_at`](List::height_at).
+    ///
+    /// Defaults to rendering nothing.
+    fn render_scrollbar<B: Backend>(
+        &mut self,
+        rows: Range<u16>,
+        total: u16,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let _ = (rows, total, layout, backend);
+        Ok(())
+    }
+
+    /// The number of columns to lay the elements out in.
+    ///
+    /// When there is more than one column, the elements are laid out in a grid similar to shell
+    /// completions, filling each row from left to right. Every element is given a single line and
+    /// an equal share of the width, and `Left` and `Right` move between the columns. If this is 0,
+    /// the most columns in which every element fits on a single line are used.
+    ///
+    /// Fewer columns may be used if there is not enough width for all of them.
+    ///
+    /// Defaults to 1.
+    fn columns(&self) -> usize {
+        1
+    }
+
+    /// The full text of the element at an index, shown below the list while it is hovered.
+    ///
+    /// This is only shown when the elements are laid out in a [grid](List::columns), where every
+    /// element is cut off after a single line. The text is wrapped to the width of the list and
+    /// rendered in the [hint](crate::style::Theme::hint) color.
+    ///
+    /// Defaults to `None`, in which case nothing is shown.
+    fn hover_detail(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+
+    /// A description of the element at an index, shown below the list while it is hovered.
+    ///
+    /// Unlike the [`hover_detail`](List::hover_detail), it is shown whether or not the elements
+    /// are laid out in a grid, and it is shown instead of the `hover_detail` if there is one. It is
+    /// wrapped to the width of the list and rendered in the [hint](crate::style::Theme::hint)
+    /// color.
+    ///
+    /// Defaults to `None`, in which case nothing is shown.
+    fn description(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+
+    /// Called with the indices of the elements in the page that is about to be rendered, before
+    /// any of them are rendered.
+    ///
+    /// This can be used to load the elements lazily, for example from a database page by page,
+    /// instead of all of them up front. If the list loops and the page wraps around to the start of
+    /// the list, this is called once for each part of the page. While the list is
+    /// [filtered](Select::filter), the range also includes the elements hidden between the shown
+    /// ones.
+    ///
+    /// Since the [heights](List::height_at) of all the elements are measured before this is
+    /// called, lists which load their elements lazily should know their heights up front.
+    ///
+    /// Defaults to doing nothing.
+    fn load_range(&mut self, indices: Range<usize>) {
+        let _ = indices;
+    }
+
     /// The height of the element at an index will take to render
+    ///
+    /// The heights are only measured again when the list is [refreshed](Select::refresh) or the
+    /// size of the layout changes, so they should not depend on the row the layout starts at.
     fn height_at(&mut self, index: usize, layout: Layout) -> u16;
 
     /// The length of the list
@@ -67,14 +210,45 @@ pub trait List {
 #[derive(Debug, Clone)]
 struct Heights {
     heights: Vec<u16>,
+    /// The layout the heights were computed with, [without its
+    /// position](Layout::without_position)
+    prev_layout: Layout,
+}
+
+/// The columns of a [`Select`] laid out in a grid.
+#[derive(Debug, Clone, Copy)]
+struct Grid {
+    columns: usize,
+    column_width: u16,
+    /// The layout the grid was computed with, [without its position](Layout::without_position)
     prev_layout: Layout,
 }
 
+/// The space left between the columns of a grid.
+const COLUMN_GAP: u16 = 2;
+
+/// The position of a [`Select`] in its list.
+///
+/// This can be used to restore the position of a [`Select`] created later for the same list, for
+/// example to remember where the user was across prompts. See [`Select::state`] and
+/// [`Select::set_state`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SelectState {
+    /// The index of the element that is hovered.
+    pub at: usize,
+    /// The index of the first element shown when the list is paginating.
+    pub page_start: usize,
+}
+
 /// A widget to select a single item from a list.
 ///
-/// The list must implement the [`List`] trait.
+/// The list must implement the [`List`] trait. If the list is [filterable](List::filterable), the
+/// user can type to only show the elements that fuzzy match what they typed. Indices given to and
+/// returned from a `Select` always refer to the elements of the underlying list, regardless of
+/// which elements are shown.
 #[derive(Debug, Clone)]
 pub struct Select<L> {
+    has_selectable: bool,
     first_selectable: usize,
     last_selectable: usize,
     at: usize,
@@ -84,34 +258,57 @@ pub struct Select<L> {
     page_end_height: u16,
     height: u16,
     heights: Option<Heights>,
+    /// The columns the list was last laid out in, if it has been laid out
+    grid: Option<Grid>,
+    /// The row the first item of the page was last rendered at, used to find the clicked item
+    render_row: Option<u16>,
+    filter: String,
+    /// The indices of the elements that match the filter, if it is not empty
+    ///
+    /// `at`, `page_start`, `page_end` and `heights` refer to the position of an element in this
+    /// instead of its index when the list is filtered.
+    visible: Option<Vec<usize>>,
+    /// The characters typed to [jump to an element](List::jump_to_prefix), in lowercase
+    prefix: String,
+    /// When the last character of the `prefix` was typed
+    prefix_typed: Option<Instant>,
     /// The underlying list
     pub list: L,
 }
 
+/// How long after the last typed character the characters typed to [jump to an
+/// element](List::jump_to_prefix) are forgotten.
+const PREFIX_TIMEOUT: Duration = Duration::from_secs(1);
+
 impl<L: List> Select<L> {
     /// Creates a new [`Select`].
     ///
+    /// If there are no selectable items, the placeholder from [`List::render_empty`] is rendered
+    /// instead.
+    ///
     /// # Panics
     ///
-    /// Panics if there are no selectable items, or if `list.page_size()` is less than 5.
+    /// Panics if `list.page_size()` is less than 5.
     pub fn new(list: L) -> Self {
-        let first_selectable = (0..list.len())
-            .position(|i| list.is_selectable(i))
-            .expect("there must be at least one selectable item");
-
-        let last_selectable = (0..list.len())
-            .rposition(|i| list.is_selectable(i))
-            .unwrap();
+        let bounds = selectable_bounds(list.len(), |i| list.is_selectable(i));
+        let (first_selectable, last_selectable) = bounds.unwrap_or((0, 0));
 
         assert!(list.page_size() >= 5, "page size can be a minimum of 5");
 
         Self {
+            has_selectable: bounds.is_some(),
             first_selectable,
             last_selectable,
             height: u16::MAX,
             page_start_height: u16::MAX,
             page_end_height: u16::MAX,
             heights: None,
+            grid: None,
+            render_row: None,
+            filter: String::new(),
+            visible: None,
+            prefix: String::new(),
+            prefix_typed: None,
             at: first_selectable,
             page_start: 0,
             page_end: usize::MAX,
@@ -121,15 +318,17 @@ impl<L: List> Select<L> {
 
     /// The index of the element that is currently being hovered.
     pub fn get_at(&self) -> usize {
-        self.at
+        self.list_index(self.at)
     }
 
     /// Set the index of the element that is currently being hovered.
     ///
     /// `at` can be any number (even beyond `list.len()`), but the caller is responsible for making
-    /// sure that it is a selectable element.
+    /// sure that it is a selectable element. If the element is hidden by the filter, no element
+    /// will be hovered.
     pub fn set_at(&mut self, at: usize) {
-        let dir = if self.at >= self.list.len() || self.at < at {
+        let at = self.position_of(at);
+        let dir = if self.at >= self.len() || self.at < at {
             Movement::Down
         } else {
             Movement::Up
@@ -137,20 +336,349 @@ impl<L: List> Select<L> {
 
         self.at = at;
 
-        if self.is_paginating() {
-            if at >= self.list.len() {
+        if let Some(columns) = self.grid_columns().filter(|_| self.has_selectable) {
+            self.fill_grid_page(columns);
+        } else if self.is_paginating() && self.heights.is_some() {
+            // The page is computed on the next render if the heights are not known yet
+            if at >= self.len() {
                 self.init_page();
-            } else if self.heights.is_some() {
+            } else {
                 self.maybe_adjust_page(dir);
             }
         }
     }
 
+    /// The current position of the [`Select`], which can be restored with
+    /// [`set_state`](Self::set_state).
+    pub fn state(&self) -> SelectState {
+        SelectState {
+            at: self.list_index(self.at),
+            page_start: self.list_index(self.page_start),
+        }
+    }
+
+    /// Restores a position previously returned by [`state`](Self::state).
+    ///
+    /// The page is recomputed on the next render, keeping `page_start` as the first element shown
+    /// if possible. As with [`set_at`](Self::set_at), the caller is responsible for making sure
+    /// that `state.at` is a selectable element.
+    pub fn set_state(&mut self, state: SelectState) {
+        self.at = self.position_of(state.at);
+        self.page_start = self
+            .position_of(state.page_start)
+            .min(self.len().saturating_sub(1));
+        // The page will be recomputed from page_start on the next render
+        self.page_end = usize::MAX;
+    }
+
+    /// The indices of the first and last elements shown in the current page, if it has been
+    /// computed.
+    ///
+    /// The page is computed on the first render. If the list loops, the last element may have a
+    /// smaller index than the first.
+    pub fn page(&self) -> Option<(usize, usize)> {
+        if self.page_end == usize::MAX {
+            None
+        } else {
+            Some((
+                self.list_index(self.page_start),
+                self.list_index(self.page_end),
+            ))
+        }
+    }
+
+    /// The rows of the list shown in the current page, if it has been computed.
+    ///
+    /// The rows count from the top of the first element shown by the list, out of the total height
+    /// of the list, which is the sum of the [`visible_heights`](Self::visible_heights), or the
+    /// number of rows if the elements are laid out in a grid. If the list loops and the page wraps
+    /// around to its start, the range extends past the total height.
+    ///
+    /// This can be used to render a scrollbar, see also [`List::render_scrollbar`].
+    pub fn page_range(&self) -> Option<Range<u16>> {
+        if self.page_end == usize::MAX || !self.has_selectable {
+            return None;
+        }
+
+        if let Some(columns) = self.grid_columns() {
+            let start = (self.page_start / columns) as u16;
+            return Some(start..(self.page_end / columns) as u16 + 1);
+        }
+
+        let heights = self.visible_heights()?;
+        let start = heights[..self.page_start].iter().sum::<u16>() + heights[self.page_start]
+            - self.page_start_height;
+        let height = self
+            .page_indices()
+            .map(|i| self.height_in_page(i, heights))
+            .sum::<u16>();
+
+        Some(start..start + height)
+    }
+
+    /// The heights of the elements shown by the list, in the order they are shown, as they were
+    /// last measured.
+    ///
+    /// Elements hidden by the [filter](Self::filter) are left out. This is `None` if the list has
+    /// not been measured yet, or if the elements are laid out in a grid, where each of them takes
+    /// a single line.
+    pub fn visible_heights(&self) -> Option<&[u16]> {
+        match self.heights {
+            Some(ref heights) if self.grid_columns().is_none() => Some(&heights.heights),
+            _ => None,
+        }
+    }
+
+    /// Whether the list is taller than its page size, so that only a page of it is shown at a
+    /// time.
+    ///
+    /// This is only known once the list has been measured by [`Widget::height`] or
+    /// [`Widget::render`], before which it is `true`.
+    ///
+    /// [`Widget::height`]: crate::Widget::height
+    /// [`Widget::render`]: crate::Widget::render
+    pub fn is_paginating(&self) -> bool {
+        self.height > self.page_size()
+    }
+
+    /// The position of the hovered element among the selectable elements shown, counting from 1,
+    /// and the number of selectable elements shown.
+    ///
+    /// This is `None` if no selectable element is hovered.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        if !self.has_selectable || self.at >= self.len() || !self.is_selectable(self.at) {
+            return None;
+        }
+
+        let position = (0..=self.at).filter(|&i| self.is_selectable(i)).count();
+        let total = position
+            + (self.at + 1..self.len())
+                .filter(|&i| self.is_selectable(i))
+                .count();
+
+        Some((position, total))
+    }
+
+    /// Scrolls the page so that the element at `index` is shown, without changing the hovered
+    /// element.
+    ///
+    /// If the page has not been computed yet, it will start at `index` on the next render if the
+    /// hovered element is also visible from there. Nothing happens if the element is hidden by the
+    /// filter.
+    pub fn ensure_visible(&mut self, index: usize) {
+        let position = self.position_of(index);
+        if position >= self.len() {
+            return;
+        }
+
+        if let Some(columns) = self.grid_columns().filter(|_| self.has_selectable) {
+            let at = std::mem::replace(&mut self.at, position);
+            self.fill_grid_page(columns);
+            self.at = at;
+        } else if self.page_end == usize::MAX {
+            // The page will be computed from page_start on the next render
+            self.page_start = position;
+        } else if self.is_paginating() {
+            let dir = if self.page_start <= self.page_end && position < self.page_start {
+                Movement::Up
+            } else {
+                Movement::Down
+            };
+
+            let at = std::mem::replace(&mut self.at, position);
+            self.maybe_adjust_page(dir);
+            self.at = at;
+        }
+    }
+
+    /// Scrolls the page by the given number of elements, or rows if the elements are laid out in
+    /// a grid. A negative number scrolls upwards.
+    ///
+    /// If the hovered element is scrolled out of the page, the closest selectable element in the
+    /// page is hovered instead. Nothing happens if the page has not been computed yet. Returns
+    /// whether the page moved.
+    pub fn scroll_by(&mut self, lines: isize) -> bool {
+        if lines == 0 || !self.has_selectable || self.page_end == usize::MAX {
+            return false;
+        }
+
+        let prev_page_start = self.page_start;
+        // at is hidden so that the page is not moved back to it
+        let at = std::mem::replace(&mut self.at, usize::MAX);
+
+        if let Some(columns) = self.grid_columns() {
+            let last_row = self.grid_rows(columns) - self.grid_page_rows(columns);
+            let row = (self.page_start / columns) as isize + lines;
+            self.page_start = (row.max(0) as usize).min(last_row) * columns;
+            self.fill_grid_page(columns);
+        } else if self.is_paginating() {
+            let len = self.len() as isize;
+            let start = self.page_start as isize + lines;
+            self.page_start = if self.list.should_loop() {
+                start.rem_euclid(len) as usize
+            } else {
+                start.max(0).min(len - 1) as usize
+            };
+            self.fill_page();
+        }
+
+        self.at = at;
+
+        if at < self.len() && !self.is_in_page(at) {
+            let mut selectable = self.page_indices().filter(|&i| self.is_selectable(i));
+            let closest = if lines > 0 {
+                selectable.next()
+            } else {
+                selectable.next_back()
+            };
+
+            if let Some(closest) = closest {
+                self.at = closest;
+            }
+        }
+
+        self.page_start != prev_page_start
+    }
+
+    /// Gets the index of the element that was rendered at the given row in the last render, if
+    /// any.
+    ///
+    /// This can be used to find which element a [`MouseEvent`] is over. If the elements are laid out
+    /// in a grid, the first element of the row is returned.
+    pub fn index_at_row(&self, row: u16) -> Option<usize> {
+        self.position_at_row(row).map(|at| self.list_index(at))
+    }
+
+    /// Whether the mouse event is a left click on the hovered element.
+    ///
+    /// Clicking on an element only hovers it, so this can be used in
+    /// [`Prompt::is_submit_click`](crate::Prompt::is_submit_click) to submit the list when the
+    /// hovered element is clicked again.
+    pub fn is_hovered_click(&self, mouse: MouseEvent) -> bool {
+        mouse.kind == MouseEventKind::Down(MouseButton::Left)
+            && self.has_selectable
+            && self.position_at(mouse.column, mouse.row) == Some(self.at)
+    }
+
+    /// The text typed by the user to filter the list.
+    ///
+    /// This is always empty if the list is not [filterable](List::filterable).
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Whether the element at `index` is shown, which is only false if it is hidden by the
+    /// [filter](Self::filter).
+    pub fn is_shown(&self, index: usize) -> bool {
+        match self.visible {
+            Some(ref visible) => visible.binary_search(&index).is_ok(),
+            None => index < self.list.len(),
+        }
+    }
+
+    /// Sets the text used to filter the list, and refreshes the shown elements.
+    ///
+    /// The first matching element is hovered. If `filter` is empty, all the elements are shown.
+    pub fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+        self.refresh();
+    }
+
+    /// Whether there is at least one selectable element in the list.
+    ///
+    /// If there are none, [`get_at`](Self::get_at) does not refer to a valid element.
+    pub fn has_selectable(&self) -> bool {
+        self.has_selectable
+    }
+
+    /// Recomputes the selectable and shown elements, and resets the page and the hovered element.
+    ///
+    /// This must be called after changing `list` in a way which changes its length, which of its
+    /// elements are selectable or their filter text. The list is allowed to have no selectable elements, in which case
+    /// the placeholder from [`List::render_empty`] is rendered.
+    pub fn refresh(&mut self) {
+        self.visible = if self.filter.is_empty() {
+            None
+        } else {
+            let list = &self.list;
+            let filter = &self.filter;
+
+            Some(
+                (0..list.len())
+                    .filter(|&i| {
+                        if !list.is_selectable(i) {
+                            return false;
+                        }
+
+                        match list.filter_text(i) {
+                            Some(text) => fuzzy_matches(filter, text),
+                            None => false,
+                        }
+                    })
+                    .collect(),
+            )
+        };
+
+        let bounds = selectable_bounds(self.len(), |i| self.is_selectable(i));
+
+        self.has_selectable = bounds.is_some();
+        let (first_selectable, last_selectable) = bounds.unwrap_or((0, 0));
+        self.first_selectable = first_selectable;
+        self.last_selectable = last_selectable;
+
+        self.at = first_selectable;
+        self.height = u16::MAX;
+        self.heights = None;
+        self.grid = None;
+        self.page_start = 0;
+        self.page_end = usize::MAX;
+    }
+
     /// Consumes the [`Select`] returning the original list.
     pub fn into_inner(self) -> L {
         self.list
     }
 
+    /// The number of elements shown.
+    fn len(&self) -> usize {
+        match self.visible {
+            Some(ref visible) => visible.len(),
+            None => self.list.len(),
+        }
+    }
+
+    /// Whether the element shown at a position is selectable.
+    fn is_selectable(&self, at: usize) -> bool {
+        self.list.is_selectable(self.list_index(at))
+    }
+
+    /// Converts the position of an element shown to its index in the list.
+    ///
+    /// Positions beyond the shown elements are mapped beyond the end of the list.
+    fn list_index(&self, at: usize) -> usize {
+        match self.visible {
+            Some(ref visible) => match visible.get(at) {
+                Some(&index) => index,
+                None => self.list.len() + (at - visible.len()),
+            },
+            None => at,
+        }
+    }
+
+    /// Converts the index of an element in the list to the position it is shown at.
+    ///
+    /// Indices beyond the end of the list, and elements which are not shown, are mapped beyond the
+    /// shown elements.
+    fn position_of(&self, index: usize) -> usize {
+        match self.visible {
+            Some(ref visible) => match visible.binary_search(&index) {
+                Ok(at) => at,
+                Err(_) => visible.len() + index.saturating_sub(self.list.len()),
+            },
+            None => index,
+        }
+    }
+
     fn next_selectable(&self) -> usize {
         if self.at >= self.last_selectable {
             return if self.list.should_loop() {
@@ -161,10 +689,10 @@ impl<L: List> Select<L> {
         }
 
         // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
-        let mut at = self.at.min(self.list.len());
+        let mut at = self.at.min(self.len());
         loop {
-            at = (at + 1) % self.list.len();
-            if self.list.is_selectable(at) {
+            at = (at + 1) % self.len();
+            if self.is_selectable(at) {
                 break;
             }
         }
@@ -181,17 +709,108 @@ impl<L: List> Select<L> {
         }
 
         // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
-        let mut at = self.at.min(self.list.len());
+        let mut at = self.at.min(self.len());
         loop {
-            at = (self.list.len() + at - 1) % self.list.len();
-            if self.list.is_selectable(at) {
+            at = (self.len() + at - 1) % self.len();
+            if self.is_selectable(at) {
                 break;
             }
         }
         at
     }
 
-    fn maybe_update_heights(&mut self, mut layout: Layout) {
+    /// Adds `c` to the typed prefix and hovers the first shown element which starts with it,
+    /// returning whether one was found.
+    fn jump_to_prefix(&mut self, c: char) -> bool {
+        let now = Instant::now();
+        if matches!(self.prefix_typed, Some(typed) if now.duration_since(typed) > PREFIX_TIMEOUT) {
+            self.prefix.clear();
+        }
+        self.prefix_typed = Some(now);
+        self.prefix.extend(c.to_lowercase());
+
+        // Typing the same character again cycles through the elements starting with it, instead
+        // of looking for elements starting with it twice
+        let mut chars = self.prefix.chars();
+        let repeated = chars.next().filter(|&first| chars.all(|c| c == first));
+        let (prefix, start) = match repeated {
+            Some(first) => (first.to_string(), 1),
+            None => (self.prefix.clone(), 0),
+        };
+
+        let len = self.len();
+        let at = self.at.min(len);
+        let found = (start..len + start).map(|i| (at + i) % len).find(|&at| {
+            self.is_selectable(at)
+                && matches!(
+                    self.list.filter_text(self.list_index(at)),
+                    Some(text) if starts_with_ignore_case(text, &prefix)
+                )
+        });
+
+        match found {
+            Some(found) if found != self.at => {
+                self.set_at(self.list_index(found));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the first element of the section containing `at`.
+    ///
+    /// `at` is assumed to be a selectable element.
+    fn section_start(&self, at: usize) -> usize {
+        (self.first_selectable..at)
+            .rev()
+            .find(|&i| !self.is_selectable(i))
+            .map(|separator| separator + 1)
+            .unwrap_or(self.first_selectable)
+    }
+
+    /// Gets the element to move to when jumping to the previous section.
+    ///
+    /// If `self.at` is not the first element of its section, the first element of the current
+    /// section is returned instead.
+    fn prev_section(&self) -> usize {
+        // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
+        let at = self.at.min(self.last_selectable);
+        let start = self.section_start(at);
+
+        if start < at {
+            return start;
+        }
+
+        match (self.first_selectable..start)
+            .rev()
+            .find(|&i| self.is_selectable(i))
+        {
+            Some(prev) => self.section_start(prev),
+            None => start,
+        }
+    }
+
+    /// Gets the element to move to when jumping to the next section.
+    ///
+    /// If there is no next section, the last selectable element is returned.
+    fn next_section(&self) -> usize {
+        // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
+        let at = self.at.min(self.last_selectable);
+
+        (at..self.last_selectable)
+            .find(|&i| !self.is_selectable(i))
+            .and_then(|separator| {
+                (separator..=self.last_selectable).find(|&i| self.is_selectable(i))
+            })
+            .unwrap_or(self.last_selectable)
+    }
+
+    /// Computes the heights of the elements, unless they were already computed with the same
+    /// layout. Only the size of the layout is compared, so that rendering the same list on another
+    /// row, or after something else on the same line, does not measure every element again.
+    fn maybe_update_heights(&mut self, layout: Layout) {
+        let layout = layout.without_position();
+        let len = self.len();
         let heights = match self.heights {
             Some(ref mut heights) if heights.prev_layout != layout => {
                 heights.heights.clear();
@@ -200,7 +819,7 @@ impl<L: List> Select<L> {
             }
             None => {
                 self.heights = Some(Heights {
-                    heights: Vec::with_capacity(self.list.len()),
+                    heights: Vec::with_capacity(len),
                     prev_layout: layout,
                 });
 
@@ -209,22 +828,198 @@ impl<L: List> Select<L> {
             _ => return,
         };
 
-        layout.line_offset = 0;
-
         self.height = 0;
-        for i in 0..self.list.len() {
-            let height = self.list.height_at(i, layout);
+        for i in 0..len {
+            let index = match self.visible {
+                Some(ref visible) => visible[i],
+                None => i,
+            };
+            let height = self.list.height_at(index, layout);
             self.height += height;
             heights.push(height);
         }
     }
 
-    fn page_size(&self) -> u16 {
-        self.list.page_size() as u16
+    /// The [description](List::description) of the hovered element, or its
+    /// [detail](List::hover_detail) if it is shown below the grid.
+    fn hover_detail(&self) -> Option<Text<&str>> {
+        if !self.has_selectable || self.at >= self.len() {
+            return None;
+        }
+
+        let index = self.list_index(self.at);
+
+        if let Some(description) = self.list.description(index) {
+            return Some(Text::new(description));
+        }
+
+        self.grid_columns()?;
+        self.list.hover_detail(index).map(Text::new)
     }
 
-    fn is_paginating(&self) -> bool {
-        self.height > self.page_size()
+    /// The number of columns in the grid, if the elements are laid out in one.
+    fn grid_columns(&self) -> Option<usize> {
+        match self.grid {
+            Some(grid) if grid.columns > 1 => Some(grid.columns),
+            _ => None,
+        }
+    }
+
+    fn maybe_update_grid(&mut self, layout: Layout) {
+        let layout = layout.without_position();
+        if matches!(self.grid, Some(ref grid) if grid.prev_layout == layout) {
+            return;
+        }
+
+        let prev_layout = layout;
+        let was_grid = self.grid_columns().is_some();
+
+        let width = layout.available_width();
+        // Every column must have space for at least a single character
+        let max_columns = (width / (COLUMN_GAP + 1)).max(1) as usize;
+        let columns = match self.list.columns() {
+            0 => self.auto_columns(layout, max_columns),
+            columns => columns.min(max_columns),
+        };
+
+        self.grid = Some(Grid {
+            columns,
+            column_width: width / columns as u16,
+            prev_layout,
+        });
+
+        if columns > 1 {
+            // The heights are not used by the grid, and must be recomputed if the layout goes back
+            // to a single column
+            self.heights = None;
+            self.height = self.grid_rows(columns).min(u16::MAX as usize) as u16;
+        }
+
+        if columns > 1 || was_grid {
+            // The page is recomputed on the next render
+            self.page_end = usize::MAX;
+        }
+    }
+
+    /// Gets the most columns, up to `max_columns`, in which every element of the list fits on a
+    /// single line.
+    fn auto_columns(&mut self, layout: Layout, max_columns: usize) -> usize {
+        let width = layout.available_width();
+        let mut fewest = 1;
+        let mut most = max_columns;
+
+        // Fewer columns are always wider, so the most columns that fit can be binary searched
+        while fewest < most {
+            let columns = fewest + (most - fewest) / 2 + 1;
+            // The height of the elements must not be limited to find the ones which wrap
+            let cell = cell_layout(layout, width / columns as u16, 0).with_max_height(u16::MAX);
+
+            if (0..self.list.len()).all(|i| self.list.height_at(i, cell) <= 1) {
+                fewest = columns;
+            } else {
+                most = columns - 1;
+            }
+        }
+
+        fewest
+    }
+
+    fn grid_rows(&self, columns: usize) -> usize {
+        match self.len() {
+            0 => 0,
+            len => (len - 1) / columns + 1,
+        }
+    }
+
+    /// The number of rows of the grid shown in a page.
+    fn grid_page_rows(&self, columns: usize) -> usize {
+        if self.is_paginating() {
+            // -1 since the message at the end takes one line
+            self.page_size() as usize - 1
+        } else {
+            self.grid_rows(columns)
+        }
+    }
+
+    /// Computes the page of the grid starting at the row of `page_start`, and makes sure `at` is
+    /// visible in it.
+    fn fill_grid_page(&mut self, columns: usize) {
+        let rows = self.grid_rows(columns);
+        let page_rows = self.grid_page_rows(columns);
+        let mut start_row = self.page_start / columns;
+
+        if self.at < self.len() {
+            let at_row = self.at / columns;
+
+            if at_row < start_row {
+                start_row = at_row;
+            } else if at_row >= start_row + page_rows {
+                start_row = at_row + 1 - page_rows;
+            }
+        }
+
+        start_row = start_row.min(rows - page_rows);
+
+        self.page_start = start_row * columns;
+        self.page_end = ((start_row + page_rows) * columns).min(self.len()) - 1;
+    }
+
+    /// The closest selectable element at or before `at`, if any.
+    fn selectable_at_or_before(&self, at: usize) -> Option<usize> {
+        (self.first_selectable..=at)
+            .rev()
+            .find(|&i| self.is_selectable(i))
+    }
+
+    /// The closest selectable element at or after `at`, if any.
+    fn selectable_at_or_after(&self, at: usize) -> Option<usize> {
+        (at..=self.last_selectable).find(|&i| self.is_selectable(i))
+    }
+
+    /// Moves `at` in a grid with the given number of columns, and returns whether it moved.
+    fn move_in_grid(&mut self, movement: Movement, columns: usize) -> bool {
+        // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
+        let at = self.at.min(self.last_selectable);
+        let page = self.grid_page_rows(columns) * columns;
+
+        let moved_to = match movement {
+            Movement::Left => Some(self.prev_selectable()),
+            Movement::Right => Some(self.next_selectable()),
+            Movement::Up if at / columns > self.first_selectable / columns => Some(
+                self.selectable_at_or_before(at - columns)
+                    .unwrap_or(self.first_selectable),
+            ),
+            Movement::Down if at / columns < self.last_selectable / columns => Some(
+                self.selectable_at_or_after(at + columns)
+                    .unwrap_or(self.last_selectable),
+            ),
+            Movement::PageUp if self.list.jump_by_section() => Some(self.prev_section()),
+            Movement::PageDown if self.list.jump_by_section() => Some(self.next_section()),
+            Movement::PageUp => Some(
+                self.selectable_at_or_before(at.saturating_sub(page))
+                    .unwrap_or(self.first_selectable),
+            ),
+            Movement::PageDown => Some(
+                self.selectable_at_or_after(at + page)
+                    .unwrap_or(self.last_selectable),
+            ),
+            Movement::Home => Some(self.first_selectable),
+            Movement::End => Some(self.last_selectable),
+            _ => None,
+        };
+
+        match moved_to {
+            Some(moved_to) if moved_to != self.at => {
+                self.at = moved_to;
+                self.fill_grid_page(columns);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn page_size(&self) -> u16 {
+        self.list.page_size() as u16
     }
 
     /// Checks whether the page bounds need to be adjusted
@@ -249,17 +1044,17 @@ impl<L: List> Select<L> {
         if delta.is_positive() {
             let res = self.at + delta as usize;
 
-            if res < self.list.len() {
+            if res < self.len() {
                 Some(res)
             } else if self.list.should_loop() {
-                Some(res - self.list.len())
+                Some(res - self.len())
             } else {
                 None
             }
         } else {
             let delta = -delta as usize;
             if self.list.should_loop() {
-                Some((self.at + self.list.len() - delta) % self.list.len())
+                Some((self.at + self.len() - delta) % self.len())
             } else {
                 self.at.checked_sub(delta)
             }
@@ -383,34 +1178,215 @@ impl<L: List> Select<L> {
     }
 
     fn init_page(&mut self) {
+        self.page_start = 0;
+        self.fill_page();
+    }
+
+    /// Computes the page starting at `page_start`, and makes sure `at` is visible in it.
+    fn fill_page(&mut self) {
         let heights = &self
             .heights
             .as_ref()
-            .expect("`init_page` called before `height` or `render`")
+            .expect("`fill_page` called before `height` or `render`")
             .heights[..];
 
-        self.page_start = 0;
+        if !self.is_paginating() {
+            self.page_start = 0;
+            self.page_start_height = heights[0];
+            self.page_end = self.len() - 1;
+            self.page_end_height = heights[self.page_end];
+            return;
+        }
+
         self.page_start_height = heights[self.page_start];
+        self.page_end = self.page_start;
 
-        if self.is_paginating() {
-            let mut height = heights[0];
-            // -1 since the message at the end takes one line
-            let max_height = self.page_size() - 1;
+        let mut height = heights[self.page_start];
+        // -1 since the message at the end takes one line
+        let max_height = self.page_size() - 1;
 
-            #[allow(clippy::needless_range_loop)]
-            for i in 1..heights.len() {
-                if height >= max_height {
+        let mut i = self.page_start;
+        while height < max_height {
+            i += 1;
+            if i == heights.len() {
+                if !self.list.should_loop() {
                     break;
                 }
-                self.page_end = i;
-                self.page_end_height = (height + heights[i]).min(max_height) - height;
+                i = 0;
+            }
+
+            self.page_end = i;
+            self.page_end_height = (height + heights[i]).min(max_height) - height;
 
-                height += heights[i];
+            height += heights[i];
+        }
+
+        // There were not enough elements after page_start to fill the page, so the page is
+        // extended upwards instead
+        while height < max_height && self.page_start > 0 {
+            self.page_start -= 1;
+            self.page_start_height = (height + heights[self.page_start]).min(max_height) - height;
+
+            height += heights[self.page_start];
+        }
+
+        if !self.is_in_page(self.at) && self.at < self.len() {
+            if self.at < self.page_start {
+                self.adjust_page(Movement::Up);
+            } else {
+                self.adjust_page(Movement::Down);
             }
+        }
+    }
+
+    /// Whether the element at the given position is in the current page.
+    fn is_in_page(&self, at: usize) -> bool {
+        if self.page_start <= self.page_end {
+            self.page_start <= at && at <= self.page_end
         } else {
-            self.page_end = self.list.len() - 1;
-            self.page_end_height = heights[self.page_end];
+            self.page_start <= at || at <= self.page_end
+        }
+    }
+
+    /// The indices of the elements in the current page, in the order they are shown.
+    fn page_indices(&self) -> std::iter::Chain<std::ops::Range<usize>, std::ops::Range<usize>> {
+        if self.page_end < self.page_start {
+            (self.page_start..self.len()).chain(0..self.page_end + 1)
+        } else {
+            (self.page_start..self.page_end + 1).chain(0..0)
+        }
+    }
+
+    /// Gives the [list](List::load_range) the indices of the elements in the current page.
+    fn load_page(&mut self) {
+        if self.page_end < self.page_start {
+            self.load_positions(self.page_start, self.len() - 1);
+            self.load_positions(0, self.page_end);
+        } else {
+            self.load_positions(self.page_start, self.page_end);
+        }
+    }
+
+    /// Gives the list the indices of the elements shown from the position `first` to `last`,
+    /// inclusive.
+    fn load_positions(&mut self, first: usize, last: usize) {
+        let indices = self.list_index(first)..self.list_index(last) + 1;
+        self.list.load_range(indices);
+    }
+
+    /// The height that the element at the given position in the current page is rendered with,
+    /// since the first and last elements may be cut off.
+    fn height_in_page(&self, at: usize, heights: &[u16]) -> u16 {
+        if at == self.page_start {
+            self.page_start_height
+        } else if at == self.page_end {
+            self.page_end_height
+        } else {
+            heights[at]
+        }
+    }
+
+    /// Gets the position of the element that was rendered at the given row in the last render, if
+    /// any.
+    fn position_at_row(&self, row: u16) -> Option<usize> {
+        // The page has not been computed since it was last reset
+        if self.page_end == usize::MAX {
+            return None;
+        }
+
+        if let Some(columns) = self.grid_columns() {
+            let at = self.page_start + row.checked_sub(self.render_row?)? as usize * columns;
+            return if at <= self.page_end { Some(at) } else { None };
+        }
+
+        let heights = &self.heights.as_ref()?.heights[..];
+        let mut start = self.render_row?;
+
+        for i in self.page_indices() {
+            let height = self.height_in_page(i, heights);
+
+            if (start..start + height).contains(&row) {
+                return Some(i);
+            }
+
+            start += height;
         }
+
+        None
+    }
+
+    /// The rows the hovered element was rendered on in the last render, if it was rendered.
+    fn hovered_rows(&self) -> Option<Range<u16>> {
+        // The page has not been computed since it was last reset
+        if self.page_end == usize::MAX || !self.has_selectable {
+            return None;
+        }
+
+        let render_row = self.render_row?;
+
+        if let Some(columns) = self.grid_columns() {
+            let row = render_row + (self.at.checked_sub(self.page_start)? / columns) as u16;
+            return Some(row..row + 1);
+        }
+
+        let heights = &self.heights.as_ref()?.heights[..];
+        let mut start = render_row;
+
+        for i in self.page_indices() {
+            let height = self.height_in_page(i, heights);
+
+            if i == self.at {
+                return Some(start..start + height);
+            }
+
+            start += height;
+        }
+
+        None
+    }
+
+    /// Gets the position of the element that was rendered at the given cell in the last render, if
+    /// any.
+    fn position_at(&self, column: u16, row: u16) -> Option<usize> {
+        let at = self.position_at_row(row)?;
+
+        match self.grid {
+            Some(grid) if grid.columns > 1 => {
+                let column =
+                    (column.checked_sub(grid.prev_layout.offset_x)? / grid.column_width) as usize;
+                let at = at + column;
+
+                if column < grid.columns && at <= self.page_end {
+                    Some(at)
+                } else {
+                    None
+                }
+            }
+            _ => Some(at),
+        }
+    }
+
+    /// Renders the elements of the current page in a grid
+    fn render_grid<B: Backend>(
+        &mut self,
+        grid: Grid,
+        layout: &mut Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        for i in self.page_indices() {
+            let column = i % grid.columns;
+            let cell = cell_layout(*layout, grid.column_width, column);
+
+            b.move_cursor_to(cell.offset_x, cell.offset_y)?;
+            let index = self.list_index(i);
+            self.list.render_item(index, i == self.at, cell, b)?;
+
+            if column + 1 == grid.columns || i == self.page_end {
+                layout.offset_y += 1;
+            }
+        }
+
+        b.move_cursor_to(layout.offset_x, layout.offset_y)
     }
 
     /// Renders the lines in a given iterator
@@ -441,7 +1417,8 @@ impl<L: List> Select<L> {
                 layout.max_height = heights[i];
             }
 
-            self.list.render_item(i, i == self.at, layout, b)?;
+            let index = self.list_index(i);
+            self.list.render_item(index, i == self.at, layout, b)?;
             layout.offset_y += layout.max_height;
 
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
@@ -454,27 +1431,115 @@ impl<L: List> Select<L> {
     }
 }
 
+/// Gets the layout of a single line cell in the given column of a grid.
+fn cell_layout(layout: Layout, column_width: u16, column: usize) -> Layout {
+    let mut cell = layout.with_max_height(1);
+    cell.line_offset = 0;
+    cell.render_region = RenderRegion::Top;
+    cell.offset_x += column_width * column as u16;
+    cell.width = cell.offset_x + column_width.saturating_sub(COLUMN_GAP);
+    cell
+}
+
+/// Gets the indices of the first and last selectable elements, if any.
+fn selectable_bounds<F: Fn(usize) -> bool>(len: usize, is_selectable: F) -> Option<(usize, usize)> {
+    let first_selectable = (0..len).position(&is_selectable)?;
+    let last_selectable = (0..len).rposition(is_selectable)?;
+
+    Some((first_selectable, last_selectable))
+}
+
+/// Whether `text` starts with `prefix`, which is in lowercase, ignoring case.
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+
+    prefix.chars().all(|c| text.next() == Some(c))
+}
+
+/// Whether all the characters of `filter` appear in `text` in the same order, ignoring case.
+fn fuzzy_matches(filter: &str, text: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+
+    filter
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|c| text.any(|t| t == c))
+}
+
+impl<L> Select<L> {
+    /// The index in the list of the hovered element, which must be shown.
+    fn visible_index(&self) -> usize {
+        match self.visible {
+            Some(ref visible) => visible[self.at],
+            None => self.at,
+        }
+    }
+}
+
 impl<L: Index<usize>> Select<L> {
     /// Returns a reference to the currently hovered item.
     pub fn selected(&self) -> &L::Output {
-        &self.list[self.at]
+        &self.list[self.visible_index()]
     }
 }
 
 impl<L: IndexMut<usize>> Select<L> {
     /// Returns a mutable reference to the currently hovered item.
     pub fn selected_mut(&mut self) -> &mut L::Output {
-        &mut self.list[self.at]
+        let index = self.visible_index();
+        &mut self.list[index]
     }
 }
 
 impl<L: List> super::Widget for Select<L> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        let movement = match Movement::try_from_key(key) {
-            Some(movement) => movement,
-            None => return false,
+        if self.list.filterable() {
+            match key.code {
+                KeyCode::Char(c)
+                    if !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    self.filter.push(c);
+                    self.refresh();
+                    return true;
+                }
+                KeyCode::Backspace if !self.filter.is_empty() => {
+                    self.filter.pop();
+                    self.refresh();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        if !self.has_selectable {
+            return false;
+        }
+
+        if let KeyCode::Char(c) = key.code {
+            if self.list.jump_to_prefix()
+                && !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+            {
+                return self.jump_to_prefix(c);
+            }
+        }
+
+        let movement = match key.code {
+            KeyCode::Char('[') if self.list.jump_by_section() => Movement::PageUp,
+            KeyCode::Char(']') if self.list.jump_by_section() => Movement::PageDown,
+            _ => match Movement::try_from_key(key) {
+                Some(movement) => movement,
+                None => return false,
+            },
         };
 
+        if let Some(columns) = self.grid_columns() {
+            return self.move_in_grid(movement, columns);
+        }
+
         let moved = match movement {
             Movement::Up if self.list.should_loop() || self.at > self.first_selectable => {
                 self.at = self.prev_selectable();
@@ -485,6 +1550,23 @@ impl<L: List> super::Widget for Select<L> {
                 Movement::Down
             }
 
+            Movement::PageUp if self.list.jump_by_section() => {
+                let at = self.prev_section();
+                if at == self.at {
+                    return false;
+                }
+                self.at = at;
+                Movement::Up
+            }
+            Movement::PageDown if self.list.jump_by_section() => {
+                let at = self.next_section();
+                if at == self.at {
+                    return false;
+                }
+                self.at = at;
+                Movement::Down
+            }
+
             Movement::PageUp
                 if !self.is_paginating() // No pagination, PageUp is same as Home
                     // No looping and first item is shown in this page
@@ -530,7 +1612,7 @@ impl<L: List> super::Widget for Select<L> {
             Movement::PageDown
                 if !self.is_paginating() // No pagination, PageDown same as End
                     || (!self.list.should_loop() // No looping and last item is shown in this page
-                        && self.page_end + 1 == self.list.len()) =>
+                        && self.page_end + 1 == self.len()) =>
             {
                 if self.at >= self.last_selectable {
                     return false;
@@ -553,7 +1635,7 @@ impl<L: List> super::Widget for Select<L> {
                 // so we overshoot by 1...
                 self.at = self.page_end;
 
-                if self.page_end + 1 == self.list.len() && !self.list.should_loop() {
+                if self.page_end + 1 == self.len() && !self.list.should_loop() {
                     // ...but since we reached the end and there is no looping, self.page_start may
                     // not be right so we have to recompute it
                     self.adjust_page(Movement::Down);
@@ -588,12 +1670,63 @@ impl<L: List> super::Widget for Select<L> {
         true
     }
 
+    /// Scrolling moves up and down the list, and left clicking an item hovers it. Clicking the
+    /// hovered item is not handled, see [`is_hovered_click`](Select::is_hovered_click).
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if !self.has_selectable {
+            return false;
+        }
+
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.handle_key(KeyCode::Up.into()),
+            MouseEventKind::ScrollDown => self.handle_key(KeyCode::Down.into()),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let index = match self.position_at(mouse.column, mouse.row) {
+                    Some(index) if index != self.at && self.is_selectable(index) => index,
+                    _ => return false,
+                };
+
+                // The first and last elements of the page may only be partially visible
+                let moved = if index == self.page_start {
+                    Movement::Up
+                } else {
+                    Movement::Down
+                };
+
+                self.at = index;
+
+                if let Some(columns) = self.grid_columns() {
+                    self.fill_grid_page(columns);
+                } else if self.is_paginating() {
+                    self.maybe_adjust_page(moved);
+                }
+
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
-        self.maybe_update_heights(*layout);
+        let aligned = layout.aligned();
+        if aligned.offset_x != layout.offset_x && layout.line_offset == 0 {
+            b.move_cursor_to(aligned.offset_x, aligned.offset_y)?;
+        }
+        *layout = aligned;
 
-        // this is the first render, so we need to set page_end
-        if self.page_end == usize::MAX {
-            self.init_page();
+        self.maybe_update_grid(*layout);
+        let grid_columns = self.grid_columns();
+
+        if grid_columns.is_none() {
+            self.maybe_update_heights(*layout);
+        }
+
+        // this is the first render (or the state or layout was changed), so we need to set page_end
+        if self.page_end == usize::MAX && self.has_selectable {
+            match grid_columns {
+                Some(columns) => self.fill_grid_page(columns),
+                None => self.fill_page(),
+            }
         }
 
         if layout.line_offset != 0 {
@@ -602,37 +1735,104 @@ impl<L: List> super::Widget for Select<L> {
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
         }
 
-        if self.page_end < self.page_start {
-            self.render_in(
-                (self.page_start..self.list.len()).chain(0..=self.page_end),
-                layout,
-                b,
-            )?;
-        } else {
-            self.render_in(self.page_start..=self.page_end, layout, b)?;
+        self.render_row = Some(layout.offset_y);
+
+        if !self.has_selectable {
+            if self.filter.is_empty() {
+                self.list.render_empty(layout.with_max_height(1), b)?;
+            } else {
+                b.write_styled(
+                    &format!("No matches for '{}'", self.filter).with(crate::style::theme().hint),
+                )?;
+            }
+            layout.offset_y += 1;
+
+            return b.move_cursor_to(layout.offset_x, layout.offset_y);
+        }
+
+        let page_row = layout.offset_y;
+
+        self.load_page();
+
+        match self.grid {
+            Some(grid) if grid.columns > 1 => self.render_grid(grid, layout, b)?,
+            _ => self.render_in(self.page_indices(), layout, b)?,
         }
 
         if self.is_paginating() {
+            if let Some(rows) = self.page_range() {
+                let page_layout = layout
+                    .with_offset(layout.offset_x, page_row)
+                    .with_max_height(layout.offset_y - page_row);
+
+                self.list
+                    .render_scrollbar(rows, self.height, page_layout, b)?;
+                b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            }
+
             // This is the message at the end that other places refer to
-            b.write_styled(&"(Move up and down to reveal more choices)".dark_grey())?;
+            b.write_styled(
+                &"(Move up and down to reveal more choices)".with(crate::style::theme().hint),
+            )?;
             layout.offset_y += 1;
 
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
         }
 
+        if let Some(mut detail) = self.hover_detail() {
+            b.set_fg(crate::style::theme().hint)?;
+            detail.render(layout, b)?;
+            b.set_fg(crate::style::Color::Reset)?;
+        }
+
         Ok(())
     }
 
     /// Returns the starting location of the layout. It should not be relied upon for a sensible
     /// cursor position.
     fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let layout = layout.aligned();
         layout.offset_cursor((layout.line_offset, 0))
     }
 
+    /// The rows of the hovered element, so that it stays on screen when the list is scrolled.
+    fn focused_rows(&mut self, layout: Layout) -> Range<u16> {
+        self.hovered_rows().unwrap_or_else(|| {
+            let (_, y) = self.cursor_pos(layout);
+            y..y + 1
+        })
+    }
+
     fn height(&mut self, layout: &mut Layout) -> u16 {
-        self.maybe_update_heights(*layout);
+        *layout = layout.aligned();
+
+        self.maybe_update_grid(*layout);
+        let is_grid = self.grid_columns().is_some();
+
+        if !is_grid {
+            self.maybe_update_heights(*layout);
+        }
+
+        if !self.has_selectable {
+            // The placeholder takes a single line
+            let height = (layout.line_offset != 0) as u16 + 1;
+
+            layout.line_offset = 0;
+            layout.offset_y += height;
 
-        let height = (layout.line_offset != 0) as u16 // Add one if we go to the next line
+            return height;
+        }
+
+        let detail_height = match self.hover_detail() {
+            Some(mut detail) => detail.height(&mut layout.with_line_offset(0)),
+            None => 0,
+        };
+
+        let list_height = if is_grid {
+            // Every row of the grid takes a single line, including the message at the end
+            (layout.line_offset != 0) as u16 + self.height.min(self.page_size())
+        } else {
+            (layout.line_offset != 0) as u16 // Add one if we go to the next line
             // Try to show everything
             + self
                 .height
@@ -648,7 +1848,9 @@ impl<L: List> super::Widget for Select<L> {
                     .unwrap_or(&0)
                     // +1 if paginating since the message at the end takes one line
                     + self.is_paginating() as u16,
-                );
+                )
+        };
+        let height = list_height + detail_height;
 
         layout.line_offset = 0;
         layout.offset_y += height;
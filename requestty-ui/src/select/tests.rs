@@ -11,6 +11,14 @@ struct List<T> {
     selectable: Vec<bool>,
     page_size: usize,
     should_loop: bool,
+    jump_by_section: bool,
+    columns: usize,
+    filter_texts: Option<Vec<String>>,
+    jump_to_prefix: bool,
+    hover_details: Option<Vec<String>>,
+    descriptions: Vec<Option<&'static str>>,
+    scrollbar: bool,
+    loaded: Vec<Range<usize>>,
 }
 
 impl<T> List<T> {
@@ -20,6 +28,14 @@ impl<T> List<T> {
             selectable: Vec::new(),
             page_size: 15,
             should_loop: true,
+            jump_by_section: false,
+            columns: 1,
+            filter_texts: None,
+            jump_to_prefix: false,
+            hover_details: None,
+            descriptions: Vec::new(),
+            scrollbar: false,
+            loaded: Vec::new(),
         }
     }
 
@@ -33,6 +49,46 @@ impl<T> List<T> {
         self
     }
 
+    fn with_jump_by_section(mut self, jump_by_section: bool) -> Self {
+        self.jump_by_section = jump_by_section;
+        self
+    }
+
+    fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    fn with_filter_texts(mut self, filter_texts: Vec<String>) -> Self {
+        assert_eq!(filter_texts.len(), self.vec.len());
+        self.filter_texts = Some(filter_texts);
+        self
+    }
+
+    /// Uses the texts to jump to elements instead of filtering them
+    fn with_prefix_texts(mut self, prefix_texts: Vec<String>) -> Self {
+        self = self.with_filter_texts(prefix_texts);
+        self.jump_to_prefix = true;
+        self
+    }
+
+    fn with_hover_details(mut self, hover_details: Vec<String>) -> Self {
+        assert_eq!(hover_details.len(), self.vec.len());
+        self.hover_details = Some(hover_details);
+        self
+    }
+
+    fn with_descriptions(mut self, descriptions: Vec<Option<&'static str>>) -> Self {
+        assert_eq!(descriptions.len(), self.vec.len());
+        self.descriptions = descriptions;
+        self
+    }
+
+    fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
     fn with_selectable(mut self, selectable: Vec<bool>) -> Self {
         assert_eq!(selectable.len(), self.vec.len());
         self.selectable = selectable;
@@ -70,6 +126,63 @@ impl<T: Widget> super::List for List<T> {
         self.should_loop
     }
 
+    fn jump_by_section(&self) -> bool {
+        self.jump_by_section
+    }
+
+    fn columns(&self) -> usize {
+        self.columns
+    }
+
+    fn filterable(&self) -> bool {
+        self.filter_texts.is_some() && !self.jump_to_prefix
+    }
+
+    fn jump_to_prefix(&self) -> bool {
+        self.jump_to_prefix
+    }
+
+    fn filter_text(&self, index: usize) -> Option<&str> {
+        self.filter_texts.as_ref().map(|texts| &texts[index][..])
+    }
+
+    fn hover_detail(&self, index: usize) -> Option<&str> {
+        self.hover_details
+            .as_ref()
+            .map(|details| &details[index][..])
+    }
+
+    fn description(&self, index: usize) -> Option<&str> {
+        self.descriptions.get(index).copied().flatten()
+    }
+
+    fn render_scrollbar<B: Backend>(
+        &mut self,
+        rows: Range<u16>,
+        total: u16,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        if !self.scrollbar {
+            return Ok(());
+        }
+
+        let height = layout.max_height;
+        for row in 0..height {
+            backend.move_cursor_to(layout.width - 1, layout.offset_y + row)?;
+            // The rows of the list covered by this row of the scrollbar overlap the page
+            let thumb = row * total < rows.end * height && (row + 1) * total > rows.start * height;
+            let c = if thumb { "█" } else { "│" };
+            backend.write_all(c.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    fn load_range(&mut self, indices: Range<usize>) {
+        self.loaded.push(indices);
+    }
+
     fn height_at(&mut self, index: usize, mut layout: Layout) -> u16 {
         self.vec[index].height(&mut layout)
     }
@@ -163,6 +276,70 @@ fn test_selectable() {
     assert_eq!(select.next_selectable(), 9);
 }
 
+#[test]
+fn test_sections() {
+    let list = List::new(single_line_vec(11)).with_selectable(vec![
+        false, true, true, true, true, true, false, false, true, true, false,
+    ]);
+
+    let mut select = Select::new(list);
+    select.maybe_update_heights(Layout::new(0, (100, 20).into()));
+    select.init_page();
+
+    assert_eq!(select.section_start(1), 1);
+    assert_eq!(select.section_start(4), 1);
+    assert_eq!(select.section_start(9), 8);
+
+    assert_eq!(select.next_section(), 8);
+    assert_eq!(select.prev_section(), 1);
+    select.set_at(3);
+    assert_eq!(select.prev_section(), 1);
+    assert_eq!(select.next_section(), 8);
+    select.set_at(8);
+    assert_eq!(select.prev_section(), 1);
+    assert_eq!(select.next_section(), 9);
+    select.set_at(9);
+    assert_eq!(select.prev_section(), 8);
+    assert_eq!(select.next_section(), 9);
+    select.set_at(10);
+    assert_eq!(select.prev_section(), 8);
+}
+
+#[test]
+fn test_handle_key_sections() {
+    let list = List::new(single_line_vec(11))
+        .with_selectable(vec![
+            false, true, true, true, true, true, false, false, true, true, false,
+        ])
+        .with_jump_by_section(true);
+
+    let mut select = Select::new(list);
+    select.maybe_update_heights(Layout::new(0, (100, 20).into()));
+    select.init_page();
+
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 8);
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 9);
+    assert!(!select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 9);
+    assert!(select.handle_key(KeyCode::Char('[').into()));
+    assert_eq!(select.get_at(), 8);
+    assert!(select.handle_key(KeyCode::PageUp.into()));
+    assert_eq!(select.get_at(), 1);
+    assert!(!select.handle_key(KeyCode::PageUp.into()));
+    assert!(select.handle_key(KeyCode::Char(']').into()));
+    assert_eq!(select.get_at(), 8);
+
+    let list = select.into_inner().with_jump_by_section(false);
+    let mut select = Select::new(list);
+    select.maybe_update_heights(Layout::new(0, (100, 20).into()));
+    select.init_page();
+
+    assert!(!select.handle_key(KeyCode::Char(']').into()));
+    assert_eq!(select.get_at(), 1);
+}
+
 #[test]
 fn test_update_heights() {
     let layout = Layout::new(0, (100, 20).into());
@@ -182,6 +359,15 @@ fn test_update_heights() {
     assert_eq!(heights[0], 5);
     assert_eq!(heights[9], 5);
     assert!(heights[1..9].iter().all(|&h| h == 2));
+
+    // Moving the layout does not measure the elements again
+    select.heights.as_mut().unwrap().heights[0] = 0;
+    select.maybe_update_heights(layout.with_offset(0, 5).with_line_offset(10));
+    assert_eq!(select.heights.as_ref().unwrap().heights[0], 0);
+
+    // But resizing it does
+    select.maybe_update_heights(Layout::new(0, (90, 20).into()));
+    assert_ne!(select.heights.as_ref().unwrap().heights[0], 0);
 }
 
 #[test]
@@ -422,6 +608,404 @@ fn test_init_page() {
     assert_eq!(select.page_end_height, 1);
 }
 
+#[test]
+fn test_state() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(20)).with_should_loop(false));
+    assert_eq!(select.page(), None);
+    select.set_state(SelectState {
+        at: 10,
+        page_start: 8,
+    });
+    select.maybe_update_heights(layout);
+    select.fill_page();
+
+    // there are not enough elements after 8, so the page is extended upwards
+    assert_eq!(select.page(), Some((6, 19)));
+    assert_eq!(select.page_start_height, 1);
+    assert_eq!(select.page_end_height, 1);
+    assert_eq!(select.get_at(), 10);
+
+    let mut select = Select::new(List::new(single_line_vec(20)));
+    select.set_state(SelectState {
+        at: 10,
+        page_start: 8,
+    });
+    select.maybe_update_heights(layout);
+    select.fill_page();
+
+    assert_eq!(select.page(), Some((8, 1)));
+    assert_eq!(select.page_end_height, 1);
+
+    let mut select = Select::new(List::new(single_line_vec(20)).with_should_loop(false));
+    select.set_state(SelectState {
+        at: 2,
+        page_start: 10,
+    });
+    select.maybe_update_heights(layout);
+    select.fill_page();
+
+    // at is not visible from page_start, so the page is moved to it
+    assert!(!select.at_outside_page());
+
+    let mut backend = TestBackend::new((100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(40)));
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    let state = select.state();
+    let page = select.page();
+
+    let mut restored = Select::new(List::new(single_line_vec(40)));
+    restored.set_state(state);
+    restored
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+
+    assert_eq!(restored.state(), state);
+    assert_eq!(restored.page(), page);
+}
+
+#[test]
+fn test_ensure_visible() {
+    let layout = Layout::new(0, (100, 20).into());
+    let mut backend = TestBackend::new((100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(40)).with_should_loop(false));
+    select.ensure_visible(20);
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+
+    // at is not visible from 20, so the page is moved back to it
+    assert_eq!(select.page(), Some((0, 13)));
+
+    select.set_at(15);
+    select.ensure_visible(19);
+    assert_eq!(select.page(), Some((7, 20)));
+    assert_eq!(select.get_at(), 15);
+
+    // already visible
+    select.ensure_visible(10);
+    assert_eq!(select.page(), Some((7, 20)));
+
+    select.ensure_visible(2);
+    assert_eq!(select.page(), Some((1, 14)));
+    assert_eq!(select.get_at(), 15);
+
+    let mut select = Select::new(
+        List::new(single_line_vec(30))
+            .with_page_size(5)
+            .with_columns(3),
+    );
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    select.ensure_visible(20);
+    assert_eq!(select.page(), Some((9, 20)));
+    assert_eq!(select.get_at(), 0);
+}
+
+#[test]
+fn test_scroll_by() {
+    let layout = Layout::new(0, (100, 20).into());
+    let mut backend = TestBackend::new((100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(40)).with_should_loop(false));
+    // the page has not been computed yet
+    assert!(!select.scroll_by(1));
+
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    select.set_at(5);
+
+    assert!(select.scroll_by(3));
+    assert_eq!(select.page(), Some((3, 16)));
+    assert_eq!(select.get_at(), 5);
+
+    // at is scrolled out of the page, so the first element of the page is hovered
+    assert!(select.scroll_by(5));
+    assert_eq!(select.page(), Some((8, 21)));
+    assert_eq!(select.get_at(), 8);
+
+    assert!(select.scroll_by(100));
+    assert_eq!(select.page(), Some((26, 39)));
+    assert!(!select.scroll_by(1));
+
+    assert!(select.scroll_by(-100));
+    assert_eq!(select.page(), Some((0, 13)));
+    assert_eq!(select.get_at(), 13);
+
+    let mut select = Select::new(List::new(single_line_vec(40)));
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    assert!(select.scroll_by(-2));
+    assert_eq!(select.page(), Some((38, 11)));
+    assert_eq!(select.get_at(), 0);
+
+    let mut select = Select::new(
+        List::new(single_line_vec(30))
+            .with_page_size(5)
+            .with_columns(3)
+            .with_selectable((0..30).map(|i| i != 9).collect()),
+    );
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+
+    assert!(select.scroll_by(3));
+    assert_eq!(select.page(), Some((9, 20)));
+    // the first element of the page is not selectable
+    assert_eq!(select.get_at(), 10);
+    assert!(select.scroll_by(10));
+    assert_eq!(select.page(), Some((18, 29)));
+    assert!(!select.scroll_by(1));
+}
+
+#[test]
+fn test_page_range() {
+    let layout = Layout::new(0, (100, 20).into());
+    let mut backend = TestBackend::new((100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(5)));
+    assert!(select.is_paginating());
+    assert_eq!(select.page_range(), None);
+    assert_eq!(select.visible_heights(), None);
+
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    assert!(!select.is_paginating());
+    assert_eq!(select.page_range(), Some(0..5));
+    assert_eq!(select.visible_heights(), Some(&[1; 5][..]));
+
+    let mut select = Select::new(List::new(multi_line_list(7)).with_should_loop(false));
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    assert!(select.is_paginating());
+    assert_eq!(select.visible_heights(), Some(&[5, 2, 2, 2, 2, 2, 5][..]));
+    assert_eq!(select.page_range(), Some(0..14));
+
+    select.set_at(6);
+    // the first element of the page is cut off
+    assert_eq!(select.page(), Some((1, 6)));
+    assert_eq!(select.page_range(), Some(6..20));
+
+    // the page wraps around to the start of the list
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    select.set_at(19);
+    assert_eq!(select.page(), Some((12, 0)));
+    assert_eq!(select.page_range(), Some(12..21));
+
+    let mut select = Select::new(
+        List::new(single_line_vec(30))
+            .with_page_size(5)
+            .with_columns(3),
+    );
+    select
+        .render(&mut layout.with_line_offset(0), &mut backend)
+        .unwrap();
+    select.set_at(20);
+    assert_eq!(select.visible_heights(), None);
+    assert_eq!(select.page_range(), Some(3..7));
+}
+
+#[test]
+fn test_render_scrollbar() {
+    let size = (50, 20).into();
+    let mut layout = Layout::new(0, size);
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(
+        List::new(single_line_vec(20))
+            .with_page_size(10)
+            .with_should_loop(false)
+            .with_scrollbar(true),
+    );
+    select.maybe_update_heights(layout);
+    select.init_page();
+    select.set_at(12);
+    select.render(&mut layout, &mut backend).unwrap();
+
+    crate::assert_backend_snapshot!(backend);
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_load_range() {
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    assert_eq!(select.list.loaded, [0..9]);
+
+    select.list.loaded.clear();
+    select.set_at(15);
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    assert_eq!(select.list.loaded, [8..17]);
+
+    // The page wraps around to the start of the list
+    select.list.loaded.clear();
+    select.set_at(19);
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    assert_eq!(select.list.loaded, [12..20, 0..1]);
+
+    // Only the shown elements are counted, but the range includes the hidden ones between them
+    let mut select = Select::new(
+        List::new(single_line_vec(20))
+            .with_page_size(10)
+            .with_filter_texts(single_line_vec(20)),
+    );
+    select.set_filter("1".into());
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    assert_eq!(select.list.loaded, [1..18]);
+}
+
+#[test]
+fn test_position() {
+    let filter_texts = ["a", "a", "b", "a", "b", "a", "b", "b"];
+    let mut select = Select::new(
+        List::new(single_line_vec(8))
+            .with_filter_texts(filter_texts.iter().map(|&t| t.to_owned()).collect())
+            .with_selectable(vec![false, true, true, false, true, true, true, false]),
+    );
+
+    assert_eq!(select.position(), Some((1, 5)));
+    select.set_at(5);
+    assert_eq!(select.position(), Some((4, 5)));
+    // not selectable
+    select.set_at(3);
+    assert_eq!(select.position(), None);
+
+    // only 1 and 5 are shown
+    select.set_filter("a".into());
+    assert_eq!(select.position(), Some((1, 2)));
+    select.set_at(5);
+    assert_eq!(select.position(), Some((2, 2)));
+
+    select.set_filter("x".into());
+    assert_eq!(select.position(), None);
+}
+
+#[test]
+fn test_jump_to_prefix() {
+    let texts = ["Apple", "banana", "Blueberry", "-", "Cherry", "Blackberry"];
+    let mut select = Select::new(
+        List::new(single_line_vec(6))
+            .with_prefix_texts(texts.iter().map(|&t| t.to_owned()).collect())
+            .with_selectable(vec![true, true, true, false, true, true]),
+    );
+    let typed = |c| KeyEvent::from(KeyCode::Char(c));
+
+    assert!(select.handle_key(typed('c')));
+    assert_eq!(select.get_at(), 4);
+
+    // typing the same character again cycles through the matches, ignoring case
+    select.prefix_typed = Some(Instant::now() - PREFIX_TIMEOUT * 2);
+    assert!(select.handle_key(typed('B')));
+    assert_eq!(select.get_at(), 5);
+    assert!(select.handle_key(typed('b')));
+    assert_eq!(select.get_at(), 1);
+    assert!(select.handle_key(typed('b')));
+    assert_eq!(select.get_at(), 2);
+
+    // characters typed shortly after each other are looked up together
+    select.prefix_typed = Some(Instant::now() - PREFIX_TIMEOUT * 2);
+    assert!(select.handle_key(typed('b')));
+    assert_eq!(select.get_at(), 5);
+    // the hovered element still matches, so it stays hovered
+    assert!(!select.handle_key(typed('l')));
+    assert_eq!(select.get_at(), 5);
+    assert!(select.handle_key(typed('u')));
+    assert_eq!(select.get_at(), 2);
+    assert!(!select.handle_key(typed('x')));
+    assert_eq!(select.get_at(), 2);
+
+    // but are forgotten after a while, like before each of the earlier cases
+    select.prefix_typed = Some(Instant::now() - PREFIX_TIMEOUT * 2);
+    assert!(select.handle_key(typed('a')));
+    assert_eq!(select.get_at(), 0);
+
+    // characters are not used for navigation
+    assert!(!select.handle_key(typed('j')));
+    assert_eq!(select.get_at(), 0);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 1);
+}
+
+#[test]
+fn test_handle_mouse() {
+    fn click(row: u16) -> MouseEvent {
+        MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 0, row)
+    }
+
+    let layout = Layout::new(0, (100, 20).into()).with_offset(0, 3);
+    let mut backend = TestBackend::new((100, 20).into());
+
+    let mut select = Select::new(
+        List::new(single_line_vec(20))
+            .with_should_loop(false)
+            .with_selectable((0..20).map(|i| i != 4).collect()),
+    );
+
+    // Nothing has been rendered yet, so the clicked item cannot be known
+    assert!(!select.handle_mouse(click(5)));
+
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    let (page_start, page_end) = select.page().unwrap();
+    assert_eq!(page_start, 0);
+
+    assert!(!select.is_hovered_click(click(5)));
+    assert!(select.handle_mouse(click(5)));
+    assert_eq!(select.get_at(), 2);
+    // already hovered
+    assert!(select.is_hovered_click(click(5)));
+    assert!(!select.handle_mouse(click(5)));
+    assert!(!select.is_hovered_click(MouseEvent::new(
+        MouseEventKind::Down(MouseButton::Right),
+        0,
+        5
+    )));
+    // above the list
+    assert!(!select.handle_mouse(click(2)));
+    // not selectable
+    assert!(!select.handle_mouse(click(7)));
+    assert!(!select.handle_mouse(MouseEvent::new(
+        MouseEventKind::Down(MouseButton::Right),
+        0,
+        6
+    )));
+
+    assert!(select.handle_mouse(MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)));
+    assert_eq!(select.get_at(), 3);
+    assert!(select.handle_mouse(MouseEvent::new(MouseEventKind::ScrollUp, 0, 0)));
+    assert_eq!(select.get_at(), 2);
+
+    // The last item in the page is only partially visible, so the page is moved
+    assert!(select.handle_mouse(click(3 + (page_end - page_start) as u16)));
+    assert_eq!(select.get_at(), page_end);
+    assert!(!select.at_outside_page());
+}
+
 #[test]
 fn test_handle_key() {
     let layout = Layout::new(0, (100, 20).into());
@@ -592,3 +1176,298 @@ fn test_render() {
     crate::assert_backend_snapshot!(backend);
     assert_eq!(layout, base_layout.with_offset(20, 31));
 }
+
+#[test]
+fn test_render_aligned() {
+    let size = (100, 20).into();
+    let base_layout = Layout::new(10, size)
+        .with_max_width(40)
+        .with_alignment(crate::layout::Alignment::Center);
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(List::new(single_line_vec(5)));
+    assert_eq!(select.height(&mut layout.clone()), 6);
+    assert_eq!(select.cursor_pos(layout), (40, 0));
+
+    select.render(&mut layout, &mut backend).unwrap();
+
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(
+        layout,
+        base_layout
+            .with_line_offset(0)
+            .with_offset(30, 6)
+            .with_size((70, 20).into())
+    );
+}
+
+#[test]
+fn test_grid() {
+    fn click(column: u16, row: u16) -> MouseEvent {
+        MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, row)
+    }
+
+    let size = (100, 20).into();
+    let base_layout = Layout::new(0, size).with_offset(0, 3);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let mut select = Select::new(
+        List::new(single_line_vec(30))
+            .with_page_size(5)
+            .with_columns(3)
+            .with_selectable((0..30).map(|i| i != 4).collect()),
+    );
+
+    // 10 rows, of which 4 are shown along with the message at the end
+    assert_eq!(select.height(&mut base_layout.clone()), 5);
+
+    assert!(select.handle_key(KeyCode::Right.into()));
+    assert_eq!(select.get_at(), 1);
+    // the unselectable element is skipped
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 5);
+    assert!(select.handle_key(KeyCode::Left.into()));
+    assert_eq!(select.get_at(), 3);
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 0);
+    assert!(!select.handle_key(KeyCode::Up.into()));
+
+    assert!(select.handle_key(KeyCode::Left.into()));
+    assert_eq!(select.get_at(), 29);
+    assert_eq!(select.page(), Some((18, 29)));
+    assert!(!select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 26);
+
+    assert!(select.handle_key(KeyCode::Home.into()));
+    assert_eq!(select.get_at(), 0);
+    assert_eq!(select.page(), Some((0, 11)));
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 12);
+    assert_eq!(select.page(), Some((3, 14)));
+    assert!(select.handle_key(KeyCode::PageUp.into()));
+    assert_eq!(select.get_at(), 0);
+    assert_eq!(select.page(), Some((0, 11)));
+
+    let mut layout = base_layout;
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 8));
+
+    assert_eq!(select.index_at_row(4), Some(3));
+    // not selectable
+    assert!(!select.handle_mouse(click(40, 4)));
+    assert!(select.handle_mouse(click(70, 4)));
+    assert_eq!(select.get_at(), 5);
+    // past the last column
+    assert!(!select.handle_mouse(click(99, 4)));
+    // below the list
+    assert!(!select.handle_mouse(click(0, 7)));
+}
+
+#[test]
+fn test_hover_detail() {
+    let size = (40, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let details: Vec<_> = (0..6)
+        .map(|i| format!("{} list item with a long description that wraps", i))
+        .collect();
+
+    let mut select = Select::new(
+        List::new(details.clone())
+            .with_columns(3)
+            .with_hover_details(details),
+    );
+
+    // 2 rows and the detail of the hovered element, which wraps to 2 lines
+    assert_eq!(select.height(&mut base_layout.clone()), 4);
+
+    let mut layout = base_layout;
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 4));
+
+    assert!(select.handle_key(KeyCode::Right.into()));
+    backend.reset_with_layout(base_layout);
+    layout = base_layout;
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+
+    // the detail is not shown when there is a single column
+    let mut select =
+        Select::new(List::new(single_line_vec(6)).with_hover_details(single_line_vec(6)));
+    assert_eq!(select.height(&mut base_layout.clone()), 6);
+}
+
+#[test]
+fn test_description() {
+    let size = (40, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let mut select = Select::new(
+        List::new(single_line_vec(3))
+            .with_descriptions(vec![
+                Some("The first item, with a description that wraps"),
+                None,
+                Some("The last item"),
+            ])
+            .with_hover_details(single_line_vec(3)),
+    );
+
+    // 3 items and the description of the hovered element, which wraps to 2 lines
+    assert_eq!(select.height(&mut base_layout.clone()), 5);
+
+    let mut layout = base_layout;
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 5));
+
+    // the hover detail is not shown in place of a missing description when there is a single
+    // column
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.height(&mut base_layout.clone()), 3);
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.height(&mut base_layout.clone()), 4);
+    backend.reset_with_layout(base_layout);
+    layout = base_layout;
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+}
+
+#[test]
+fn test_auto_columns() {
+    let layout = Layout::new(0, (100, 20).into());
+    let list = || List::new((0..20).map(|i| Text::new(format!("item {}", i))).collect());
+
+    // The widest element takes 7 characters, and 2 are left between the columns
+    let mut select = Select::new(list().with_columns(0));
+    assert_eq!(select.height(&mut layout.clone()), 2);
+    assert_eq!(select.grid_columns(), Some(11));
+
+    // There is not enough space for more than one column
+    let mut select = Select::new(list().with_columns(0));
+    assert_eq!(select.height(&mut layout.with_size((10, 20).into())), 15);
+    assert_eq!(select.grid_columns(), None);
+
+    let mut select = Select::new(list().with_columns(4));
+    assert_eq!(select.height(&mut layout.clone()), 5);
+    assert_eq!(select.grid_columns(), Some(4));
+}
+
+#[test]
+fn test_empty() {
+    let size = (100, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(List::new(single_line_vec(0)));
+    assert!(!select.has_selectable());
+    assert!(!select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.height(&mut base_layout.with_offset(0, 0)), 1);
+
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 1));
+
+    let mut select =
+        Select::new(List::new(single_line_vec(3)).with_selectable(vec![false, false, false]));
+    assert!(!select.has_selectable());
+
+    select.list = List::new(single_line_vec(3)).with_selectable(vec![false, true, true]);
+    select.refresh();
+    assert!(select.has_selectable());
+    assert_eq!(select.get_at(), 1);
+
+    layout = base_layout;
+    backend.reset_with_layout(layout);
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 3));
+}
+
+#[test]
+fn test_filter() {
+    let size = (100, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(List::new(single_line_vec(20)));
+    assert!(!select.handle_key(KeyCode::Char('1').into()));
+    assert_eq!(select.filter(), "");
+
+    let mut select = Select::new(
+        List::new(single_line_vec(20))
+            .with_filter_texts(single_line_vec(20))
+            .with_selectable((0..20).map(|i| i != 12).collect()),
+    );
+    // not paginating
+    assert_eq!(select.height(&mut base_layout.clone()), 15);
+
+    assert!(select.handle_key(KeyCode::Char('1').into()));
+    assert_eq!(select.filter(), "1");
+    assert_eq!(select.get_at(), 1);
+    // 1, 10, 11, 13, 14, ..., 19
+    assert_eq!(select.height(&mut base_layout.clone()), 10);
+
+    // characters only need to appear in order
+    assert!(select.handle_key(KeyCode::Char('I').into()));
+    assert_eq!(select.get_at(), 1);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 10);
+    // the unselectable element is skipped
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 13);
+    assert_eq!(select.state().at, 13);
+
+    let mut layout = base_layout;
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 10));
+    assert_eq!(select.index_at_row(2), Some(11));
+    assert_eq!(select.page(), Some((1, 19)));
+
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert!(select.handle_key(KeyCode::Char('9').into()));
+    assert!(select.handle_key(KeyCode::Char('x').into()));
+    assert_eq!(select.filter(), "19x");
+    assert!(!select.has_selectable());
+    assert!(!select.handle_key(KeyCode::Down.into()));
+
+    layout = base_layout;
+    backend.reset_with_layout(layout);
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 1));
+
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(select.get_at(), 19);
+    select.set_at(4);
+    assert_eq!(select.get_at(), 20);
+
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert!(!select.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(select.get_at(), 0);
+    assert_eq!(select.height(&mut base_layout.clone()), 15);
+
+    select.set_filter("2".into());
+    assert_eq!(select.get_at(), 2);
+    select.set_state(SelectState {
+        at: 2,
+        page_start: 0,
+    });
+    assert_eq!(
+        select.state(),
+        SelectState {
+            at: 2,
+            page_start: 2
+        }
+    );
+}
@@ -0,0 +1,82 @@
+//! Restores the terminal if the process is terminated by a signal while a prompt is shown.
+
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    os::unix::io::{AsRawFd, RawFd},
+};
+
+use signal_hook::{
+    consts::signal::{SIGHUP, SIGQUIT, SIGTERM},
+    low_level, SigId,
+};
+
+/// The signals which terminate the process by default, and would leave the terminal in raw mode.
+const SIGNALS: [libc::c_int; 3] = [SIGTERM, SIGHUP, SIGQUIT];
+
+const SHOW_CURSOR: &[u8] = b"\x1B[?25h";
+
+/// Handlers for `SIGTERM`, `SIGHUP` and `SIGQUIT` which show the cursor and restore the mode the
+/// terminal was in when the guard was created, before re-raising the signal.
+///
+/// The handlers are removed when the guard is dropped.
+#[derive(Debug)]
+pub(crate) struct SignalGuard {
+    ids: Vec<SigId>,
+    /// The controlling terminal, which is kept open while the handlers can use it
+    _tty: File,
+}
+
+impl SignalGuard {
+    /// Installs the handlers. This must be done before raw mode is enabled, so that the mode to
+    /// restore is known.
+    pub(crate) fn new() -> io::Result<Self> {
+        let tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+        let fd = tty.as_raw_fd();
+
+        // SAFETY: `termios` is plain data, so it can be zeroed
+        #[allow(unsafe_code)]
+        let mut termios: libc::termios = unsafe { std::mem::zeroed() };
+
+        // SAFETY: `tcgetattr` writes a single `termios`
+        #[allow(unsafe_code)]
+        if unsafe { libc::tcgetattr(fd, &mut termios) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut guard = Self {
+            ids: Vec::with_capacity(SIGNALS.len()),
+            _tty: tty,
+        };
+
+        for &signal in &SIGNALS {
+            // SAFETY: the handler only calls async-signal-safe functions
+            #[allow(unsafe_code)]
+            let id = unsafe { low_level::register(signal, move || restore(fd, &termios, signal)) }?;
+            guard.ids.push(id);
+        }
+
+        Ok(guard)
+    }
+}
+
+impl Drop for SignalGuard {
+    fn drop(&mut self) {
+        for &id in &self.ids {
+            low_level::unregister(id);
+        }
+    }
+}
+
+/// Runs in the signal handler, so it must only do async-signal-safe operations.
+fn restore(fd: RawFd, termios: &libc::termios, signal: libc::c_int) {
+    // SAFETY: `write` and `tcsetattr` are async-signal-safe, and `termios` was returned by
+    // `tcgetattr`. Nothing can be done about errors while the process is being terminated.
+    #[allow(unsafe_code)]
+    unsafe {
+        libc::write(fd, SHOW_CURSOR.as_ptr().cast(), SHOW_CURSOR.len());
+        libc::tcsetattr(fd, libc::TCSANOW, termios);
+    }
+
+    let _ = low_level::emulate_default_handler(signal);
+}
@@ -0,0 +1,293 @@
+use std::{convert::TryFrom, io};
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent},
+    layout::Layout,
+    style::Color,
+};
+
+/// The maximum width of the gauge.
+const GAUGE_WIDTH: u16 = 30;
+/// The minimum width of the gauge before it is moved to the next line.
+const MIN_GAUGE_WIDTH: u16 = 5;
+
+/// A widget that picks a number in a range, shown as a horizontal gauge followed by the value.
+///
+/// `Left` and `Right` change the value by the step, `PageDown` and `PageUp` change it by a tenth
+/// of the range (rounded up to a multiple of the step), and `Home` and `End` go to the start and
+/// end of the range.
+///
+/// The gauge is rendered on the same line if there is enough space left on it, otherwise it is
+/// rendered on the next line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slider {
+    min: i64,
+    max: i64,
+    step: i64,
+    value: i64,
+}
+
+impl Slider {
+    /// Creates a new `Slider` for the range `min..=max`, with a step of 1 and the value starting at
+    /// `min`.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `min` is greater than `max`.
+    pub fn new(min: i64, max: i64) -> Self {
+        assert!(min <= max, "min cannot be greater than max");
+
+        Self {
+            min,
+            max,
+            step: 1,
+            value: min,
+        }
+    }
+
+    /// The amount the value changes by with `Left` and `Right`.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the `step` is not positive.
+    pub fn with_step(mut self, step: i64) -> Self {
+        assert!(step > 0, "step must be positive");
+
+        self.step = step;
+        self
+    }
+
+    /// The current value.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Sets the value, clamping it to the range of the slider.
+    pub fn set_value(&mut self, value: i64) {
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Adds `by` to the value, returning whether it changed.
+    fn change_value(&mut self, by: i64) -> bool {
+        let prev = self.value;
+        self.set_value(self.value.saturating_add(by));
+        self.value != prev
+    }
+
+    /// The amount the value changes by with `PageUp` and `PageDown`.
+    fn page_step(&self) -> i64 {
+        let tenth = (self.max as i128 - self.min as i128) / 10;
+        let steps = (tenth + self.step as i128 - 1) / self.step as i128;
+
+        i64::try_from(steps.max(1) * self.step as i128).unwrap_or(i64::MAX)
+    }
+
+    fn label(&self) -> String {
+        self.value.to_string()
+    }
+
+    /// Whether the gauge does not fit on the current line and should be moved to the next one.
+    fn on_next_line(&self, layout: Layout) -> bool {
+        layout.line_offset != 0
+            && layout.line_width() < self.label().len() as u16 + 1 + MIN_GAUGE_WIDTH
+    }
+
+    /// The width of the gauge, given the width of the line it is rendered on.
+    fn gauge_width(&self, line_width: u16) -> u16 {
+        GAUGE_WIDTH.min(line_width.saturating_sub(self.label().len() as u16 + 1))
+    }
+
+    /// The position of the knob within the gauge.
+    fn knob(&self, gauge_width: u16) -> u16 {
+        let last = gauge_width.saturating_sub(1);
+
+        if self.min == self.max {
+            return last;
+        }
+
+        let num = (self.value as i128 - self.min as i128) * last as i128;
+        let den = self.max as i128 - self.min as i128;
+
+        (num / den) as u16
+    }
+
+    /// The width taken by the gauge and the value when rendered on a line of the given width.
+    fn width(&self, line_width: u16) -> u16 {
+        let gauge_width = self.gauge_width(line_width);
+        let label_width = self.label().len() as u16;
+
+        if gauge_width == 0 {
+            label_width.min(line_width)
+        } else {
+            gauge_width + 1 + label_width
+        }
+    }
+}
+
+impl super::Widget for Slider {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        if self.on_next_line(*layout) {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            backend.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        let line_width = layout.line_width();
+        let gauge_width = self.gauge_width(line_width);
+        let mut label = self.label();
+
+        if gauge_width == 0 {
+            label.truncate(line_width as usize);
+        } else {
+            let symbol_set = crate::symbols::current();
+            let theme = crate::style::theme();
+            let knob = self.knob(gauge_width);
+
+            backend.set_fg(theme.highlight)?;
+            for _ in 0..knob {
+                write!(backend, "{}", symbol_set.box_horizontal)?;
+            }
+            write!(backend, "{}", symbol_set.box_vertical)?;
+            backend.set_fg(theme.hint)?;
+            for _ in (knob + 1)..gauge_width {
+                write!(backend, "{}", symbol_set.box_horizontal)?;
+            }
+            backend.set_fg(Color::Reset)?;
+            backend.write_all(b" ")?;
+        }
+
+        backend.write_all(label.as_bytes())?;
+        layout.line_offset += self.width(line_width);
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        if self.on_next_line(*layout) {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            layout.line_offset = self.width(layout.line_width());
+            2
+        } else {
+            layout.line_offset += self.width(layout.line_width());
+            1
+        }
+    }
+
+    /// Returns the position of the knob.
+    fn cursor_pos(&mut self, mut layout: Layout) -> (u16, u16) {
+        let mut y = 0;
+
+        if self.on_next_line(layout) {
+            layout.line_offset = 0;
+            y = 1;
+        }
+
+        let knob = self.knob(self.gauge_width(layout.line_width()));
+        layout.offset_cursor((layout.line_offset + knob, y))
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Left => self.change_value(-self.step),
+            KeyCode::Right => self.change_value(self.step),
+            KeyCode::PageDown => self.change_value(-self.page_step()),
+            KeyCode::PageUp => self.change_value(self.page_step()),
+            KeyCode::Home => self.change_value(i64::MIN),
+            KeyCode::End => self.change_value(i64::MAX),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, Widget};
+
+    #[test]
+    fn test_handle_key() {
+        let mut slider = Slider::new(0, 100).with_step(5);
+        assert_eq!(slider.value(), 0);
+
+        assert!(!slider.handle_key(KeyCode::Left.into()));
+        assert!(slider.handle_key(KeyCode::Right.into()));
+        assert_eq!(slider.value(), 5);
+        assert!(slider.handle_key(KeyCode::PageUp.into()));
+        assert_eq!(slider.value(), 15);
+        assert!(slider.handle_key(KeyCode::End.into()));
+        assert_eq!(slider.value(), 100);
+        assert!(!slider.handle_key(KeyCode::Right.into()));
+        assert!(slider.handle_key(KeyCode::PageDown.into()));
+        assert_eq!(slider.value(), 90);
+        assert!(slider.handle_key(KeyCode::Home.into()));
+        assert_eq!(slider.value(), 0);
+        assert!(!slider.handle_key(KeyCode::Char('a').into()));
+
+        let mut slider = Slider::new(i64::MIN, i64::MAX).with_step(i64::MAX);
+        assert!(slider.handle_key(KeyCode::PageUp.into()));
+        assert_eq!(slider.value(), -1);
+        assert!(slider.handle_key(KeyCode::Right.into()));
+        assert!(slider.handle_key(KeyCode::Right.into()));
+        assert_eq!(slider.value(), i64::MAX);
+    }
+
+    #[test]
+    fn test_set_value() {
+        let mut slider = Slider::new(-10, 10);
+        slider.set_value(5);
+        assert_eq!(slider.value(), 5);
+        slider.set_value(20);
+        assert_eq!(slider.value(), 10);
+        slider.set_value(-20);
+        assert_eq!(slider.value(), -10);
+    }
+
+    #[test]
+    fn test_height() {
+        let layout = Layout::new(0, (100, 20).into());
+        let mut slider = Slider::new(0, 100);
+
+        let mut l = layout;
+        assert_eq!(slider.height(&mut l), 1);
+        assert_eq!(l, layout.with_line_offset(32));
+
+        let mut l = layout.with_line_offset(95);
+        assert_eq!(slider.height(&mut l), 2);
+        assert_eq!(l, layout.with_line_offset(32).with_offset(0, 1));
+    }
+
+    #[test]
+    fn test_cursor_pos() {
+        let layout = Layout::new(0, (100, 20).into());
+        let mut slider = Slider::new(0, 29);
+
+        assert_eq!(slider.cursor_pos(layout), (0, 0));
+        assert_eq!(slider.cursor_pos(layout.with_line_offset(5)), (5, 0));
+
+        slider.set_value(29);
+        assert_eq!(slider.cursor_pos(layout), (29, 0));
+        assert_eq!(slider.cursor_pos(layout.with_line_offset(95)), (29, 1));
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 10).into();
+        let base_layout = Layout::new(0, size);
+        let mut slider = Slider::new(0, 100).with_step(10);
+        slider.set_value(40);
+
+        let mut layout = base_layout;
+        let mut backend = TestBackend::new(size);
+        slider.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(layout, base_layout.with_line_offset(33));
+
+        let mut layout = base_layout.with_line_offset(45);
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        slider.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(layout, base_layout.with_line_offset(33).with_offset(0, 1));
+    }
+}
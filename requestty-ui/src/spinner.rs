@@ -0,0 +1,206 @@
+use std::{
+    io, panic,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    backend::{Backend, ClearType, MoveDirection},
+    events::KeyEvent,
+    layout::Layout,
+    style::Color,
+    Widget,
+};
+
+const UNICODE_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const ASCII_FRAMES: &[char] = &['|', '/', '-', '\\'];
+
+/// The time between the frames of a spinner animated by [`Backend::with_spinner`].
+const FRAME_INTERVAL: Duration = Duration::from_millis(80);
+
+/// A widget that shows a spinner followed by a message, for work which takes a while.
+///
+/// The spinner moves to its next frame with [`tick`](Spinner::tick). It is rendered on a single
+/// line, and the message is cut short if it does not fit. [`Backend::with_spinner`] can be used to
+/// animate a spinner while a closure runs.
+///
+/// The frames are braille dots, or ascii characters if the [`pointer`] of the current symbol set
+/// is ascii.
+///
+/// [`pointer`]: crate::symbols::SymbolSet::pointer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spinner<M> {
+    message: M,
+    frames: &'static [char],
+    frame: usize,
+}
+
+impl<M: AsRef<str>> Spinner<M> {
+    /// Creates a new `Spinner` with the given message.
+    pub fn new(message: M) -> Self {
+        let frames = if crate::symbols::current().pointer.is_ascii() {
+            ASCII_FRAMES
+        } else {
+            UNICODE_FRAMES
+        };
+
+        Self {
+            message,
+            frames,
+            frame: 0,
+        }
+    }
+
+    /// Moves the spinner to its next frame.
+    pub fn tick(&mut self) {
+        self.frame = (self.frame + 1) % self.frames.len();
+    }
+
+    /// The message shown after the spinner.
+    pub fn message(&self) -> &M {
+        &self.message
+    }
+}
+
+impl<M: AsRef<str>> Widget for Spinner<M> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        backend.set_fg(crate::style::theme().highlight)?;
+        write!(backend, "{} ", self.frames[self.frame])?;
+        backend.set_fg(Color::Reset)?;
+
+        layout.line_offset += 2;
+        self.message.as_ref().render(layout, backend)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        layout.offset_y += 1;
+        layout.line_offset = 0;
+        1
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        layout.offset_cursor((layout.line_offset, 0))
+    }
+
+    /// This widget does not handle any events
+    fn handle_key(&mut self, _: KeyEvent) -> bool {
+        false
+    }
+}
+
+/// See [`Backend::with_spinner`].
+pub(crate) fn with_spinner<B, M, F, T>(backend: &mut B, message: M, f: F) -> io::Result<T>
+where
+    B: Backend,
+    M: AsRef<str>,
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // The receiver is only dropped if rendering failed, in which case the result is not needed
+        let _ = sender.send(f());
+    });
+
+    let mut spinner = Spinner::new(message);
+
+    let res = backend
+        .enable_raw_mode()
+        .and_then(|_| backend.hide_cursor())
+        .and_then(|_| loop {
+            draw(&mut spinner, backend)?;
+
+            match receiver.recv_timeout(FRAME_INTERVAL) {
+                Ok(value) => break Ok(Some(value)),
+                Err(RecvTimeoutError::Timeout) => spinner.tick(),
+                // The closure can only stop without sending anything if it panicked
+                Err(RecvTimeoutError::Disconnected) => break Ok(None),
+            }
+        });
+
+    let cleared = clear(backend);
+
+    match res? {
+        Some(value) => cleared.map(|_| value),
+        None => panic::resume_unwind(handle.join().expect_err("the closure must have panicked")),
+    }
+}
+
+fn draw<B, M>(spinner: &mut Spinner<M>, backend: &mut B) -> io::Result<()>
+where
+    B: Backend,
+    M: AsRef<str>,
+{
+    let (_, row) = backend.get_cursor_pos()?;
+    backend.move_cursor_to(0, row)?;
+    backend.clear(ClearType::CurrentLine)?;
+
+    let mut layout = Layout::new(0, backend.size()?).with_offset(0, row);
+    spinner.render(&mut layout, backend)?;
+    // the message moves the cursor to the next line
+    backend.move_cursor_to(0, row)?;
+    backend.flush()
+}
+
+fn clear<B: Backend>(backend: &mut B) -> io::Result<()> {
+    backend.move_cursor(MoveDirection::Column(0))?;
+    backend.clear(ClearType::CurrentLine)?;
+    backend.show_cursor()?;
+    backend.disable_raw_mode()?;
+    backend.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn test_render() {
+        let size = (20, 5).into();
+        let layout = Layout::new(0, size);
+        let mut backend = TestBackend::new(size);
+        let mut spinner = Spinner::new("Checking availability");
+
+        let mut render_layout = layout;
+        spinner.render(&mut render_layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(render_layout, layout.with_offset(0, 1));
+        assert_eq!(spinner.height(&mut layout.clone()), 1);
+
+        for _ in 0..spinner.frames.len() {
+            spinner.tick();
+        }
+        assert_eq!(spinner.frame, 0);
+        spinner.tick();
+
+        backend.reset_with_layout(layout);
+        spinner.render(&mut layout.clone(), &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_with_spinner() {
+        let size = (20, 5).into();
+        let mut backend = TestBackend::new(size);
+
+        let value = backend
+            .with_spinner("Waiting", || {
+                thread::sleep(FRAME_INTERVAL * 2);
+                5
+            })
+            .unwrap();
+
+        assert_eq!(value, 5);
+        // the spinner clears itself
+        assert_eq!(backend, TestBackend::new(size));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed")]
+    fn test_with_spinner_panic() {
+        let mut backend = TestBackend::new((20, 5).into());
+        let _ = backend.with_spinner("Waiting", || -> () { panic!("failed") });
+    }
+}
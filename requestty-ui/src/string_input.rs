@@ -6,7 +6,7 @@ use std::{
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    backend::Backend,
+    backend::{Backend, Wrapped},
     events::{KeyCode, KeyEvent, KeyModifiers, Movement},
     layout::Layout,
 };
@@ -24,6 +24,9 @@ pub struct StringInput<F = super::widgets::FilterMapChar> {
     value: String,
     mask: Option<char>,
     hide_output: bool,
+    reveal_last: bool,
+    /// The position in characters of the last typed character, if it should be revealed
+    revealed: Option<usize>,
     /// The character length of the string
     value_len: usize,
     /// The position of the 'cursor' in characters
@@ -48,6 +51,8 @@ impl<F> StringInput<F> {
             filter_map,
             mask: None,
             hide_output: false,
+            reveal_last: false,
+            revealed: None,
         }
     }
 
@@ -59,6 +64,14 @@ impl<F> StringInput<F> {
         self
     }
 
+    /// Show the most recently typed character until the next key press when a mask is set.
+    ///
+    /// This has no effect if there is no mask.
+    pub fn reveal_last(mut self, reveal_last: bool) -> Self {
+        self.reveal_last = reveal_last;
+        self
+    }
+
     /// Hide the value being entered, and render nothing.
     ///
     /// This is useful for passwords.
@@ -131,6 +144,77 @@ impl<F> StringInput<F> {
             .unwrap_or_else(|| self.value.char_indices().count())
     }
 
+    /// Returns the char index of the start of the grapheme cluster before the 'cursor', so that the
+    /// 'cursor' is never moved into the middle of a cluster, like between a letter and its accent.
+    fn prev_grapheme(&self) -> usize {
+        let byte_i = self.get_byte_i(self.at);
+
+        self.value[..byte_i]
+            .graphemes(true)
+            .next_back()
+            .map(|grapheme| self.at - grapheme.chars().count())
+            .unwrap_or(0)
+    }
+
+    /// Returns the char index of the end of the grapheme cluster after the 'cursor'.
+    fn next_grapheme(&self) -> usize {
+        let byte_i = self.get_byte_i(self.at);
+
+        self.value[byte_i..]
+            .graphemes(true)
+            .next()
+            .map(|grapheme| self.at + grapheme.chars().count())
+            .unwrap_or(self.value_len)
+    }
+
+    /// Removes the characters between the char indices `start` and `end`, moving the 'cursor' to
+    /// `start`.
+    fn remove_chars(&mut self, start: usize, end: usize) {
+        let byte_range = self.get_byte_i(start)..self.get_byte_i(end);
+        self.value.replace_range(byte_range, "");
+        self.value_len -= end - start;
+        self.at = start;
+    }
+
+    /// The characters as they are shown, which are the mask except for the revealed character if
+    /// there is a mask.
+    fn shown_chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.value
+            .chars()
+            .enumerate()
+            .map(move |(i, c)| match self.mask {
+                Some(mask) if self.revealed != Some(i) => mask,
+                _ => c,
+            })
+    }
+
+    /// Gets the position after the first `count` characters when they are shown from the
+    /// `line_offset` of the `layout`, as the column relative to the `offset_x` and the row relative
+    /// to the `offset_y`. It also returns the width of the row the position is in.
+    ///
+    /// Characters which do not fit in the rest of a row are moved to the next row, which is how
+    /// wide characters like CJK and emoji are wrapped by the terminal and [`Wrapped`]. The column
+    /// is the width of the row if it is completely filled.
+    fn shown_pos(&self, count: usize, layout: Layout) -> (u16, u16, u16) {
+        let mut x = layout.line_offset;
+        let mut y = 0;
+        let mut row_width = layout.available_width();
+
+        for c in self.shown_chars().take(count) {
+            let c_width = crate::layout::char_width(c);
+
+            if c_width > 0 && x + c_width > row_width {
+                x = 0;
+                y += 1;
+                row_width = layout.wrap_width();
+            }
+
+            x += c_width;
+        }
+
+        (x, y, row_width)
+    }
+
     /// Get the word bound iterator for a given range
     fn word_iter(&self, r: Range<usize>) -> impl DoubleEndedIterator<Item = (usize, &str)> {
         self.value[r]
@@ -180,6 +264,27 @@ impl<F> StringInput<F> {
             _ => None,
         }
     }
+
+    fn write_value<W: Write>(&self, backend: &mut W) -> io::Result<()> {
+        if let Some(mask) = self.mask {
+            match self.revealed {
+                Some(revealed) => {
+                    let start = self.get_byte_i(revealed);
+                    let end = self.get_byte_i(revealed + 1);
+
+                    print_mask(revealed, mask, backend)?;
+                    backend.write_all(&self.value.as_bytes()[start..end])?;
+                    print_mask(self.value_len - revealed - 1, mask, backend)?;
+                }
+                None => print_mask(self.value_len, mask, backend)?,
+            }
+
+            Ok(())
+        } else {
+            // The terminal or `Wrapped` takes care of wrapping in case of large strings
+            backend.write_all(self.value.as_bytes())
+        }
+    }
 }
 
 impl<F> super::Widget for StringInput<F>
@@ -187,6 +292,9 @@ where
     F: Fn(char) -> Option<char>,
 {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        // The last character is only revealed until the next key press
+        self.revealed = None;
+
         if let Some(movement) = self.get_delete_movement(key) {
             match movement {
                 Movement::Home => {
@@ -205,17 +313,8 @@ where
                     self.value.replace_range(prev_word..byte_i, "");
                     return true;
                 }
-                Movement::Left if self.at == self.value_len => {
-                    self.at -= 1;
-                    self.value_len -= 1;
-                    self.value.pop();
-                    return true;
-                }
                 Movement::Left => {
-                    self.at -= 1;
-                    let byte_i = self.get_byte_i(self.at);
-                    self.value_len -= 1;
-                    self.value.remove(byte_i);
+                    self.remove_chars(self.prev_grapheme(), self.at);
                     return true;
                 }
 
@@ -232,15 +331,8 @@ where
                     self.value.replace_range(byte_i..next_word, "");
                     return true;
                 }
-                Movement::Right if self.at == self.value_len - 1 => {
-                    self.value_len -= 1;
-                    self.value.pop();
-                    return true;
-                }
                 Movement::Right => {
-                    let byte_i = self.get_byte_i(self.at);
-                    self.value_len -= 1;
-                    self.value.remove(byte_i);
+                    self.remove_chars(self.at, self.next_grapheme());
                     return true;
                 }
 
@@ -264,6 +356,10 @@ where
                         self.value.insert(byte_i, c);
                     };
 
+                    if self.reveal_last {
+                        self.revealed = Some(self.at);
+                    }
+
                     self.at += 1;
                     self.value_len += 1;
                     return true;
@@ -278,14 +374,14 @@ where
                 self.at = self.get_char_i(self.find_word_left(self.get_byte_i(self.at)));
             }
             Some(Movement::Left) if self.at != 0 => {
-                self.at -= 1;
+                self.at = self.prev_grapheme();
             }
 
             Some(Movement::NextWord) if self.at != self.value_len => {
                 self.at = self.get_char_i(self.find_word_right(self.get_byte_i(self.at)));
             }
             Some(Movement::Right) if self.at != self.value_len => {
-                self.at += 1;
+                self.at = self.next_grapheme();
             }
 
             Some(Movement::Home) if self.at != 0 => {
@@ -300,19 +396,50 @@ where
         true
     }
 
-    /// This widget ignores [`layout.offset_x`] and wraps around in the terminal.
+    /// Inserts all of the pasted text at once. Line breaks and other control characters are dropped,
+    /// along with the characters that the `filter_map` does not allow.
+    fn handle_paste(&mut self, text: &str) -> bool {
+        self.revealed = None;
+
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .filter_map(&self.filter_map)
+            .collect();
+        let len = text.chars().count();
+
+        if len == 0 {
+            return false;
+        }
+
+        let byte_i = self.get_byte_i(self.at);
+        self.value.insert_str(byte_i, &text);
+
+        if self.reveal_last {
+            self.revealed = Some(self.at + len - 1);
+        }
+
+        self.at += len;
+        self.value_len += len;
+        true
+    }
+
+    /// Unless the layout has a [`max_width`], this widget ignores [`layout.offset_x`] and wraps
+    /// around in the terminal.
     ///
+    /// [`max_width`]: Layout::max_width
     /// [`layout.offset_x`]: Layout.offset_x
     fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
         if self.hide_output {
             return Ok(());
         }
 
-        if let Some(mask) = self.mask {
-            print_mask(self.value_len, mask, backend)?;
+        *layout = layout.aligned();
+
+        if layout.max_width.is_some() {
+            self.write_value(&mut Wrapped::new(backend, *layout)?)?;
         } else {
-            // Terminal takes care of wrapping in case of large strings
-            backend.write_all(self.value.as_bytes())?;
+            self.write_value(backend)?;
         }
 
         // Adjust layout
@@ -326,35 +453,34 @@ where
             return 1;
         }
 
-        let mut width = textwrap::core::display_width(&self.value) as u16;
+        *layout = layout.aligned();
+        let (mut x, mut y, row_width) = self.shown_pos(self.value_len, *layout);
 
-        if width > layout.line_width() {
-            width -= layout.line_width();
+        // Widgets after this one continue on the first row even if it is completely filled, but
+        // start on a new row after any other row which is completely filled
+        if y > 0 && x >= row_width {
+            x = 0;
+            y += 1;
+        }
 
-            layout.line_offset = width % layout.width;
-            layout.offset_y += 1 + width / layout.width;
+        layout.line_offset = x;
+        layout.offset_y += y;
 
-            2 + width / layout.width
-        } else {
-            layout.line_offset += width;
-            1
-        }
+        y + 1
     }
 
     fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
-        let display_at =
-            textwrap::core::display_width(&self.value[..self.get_byte_i(self.at)]) as u16;
+        let layout = layout.aligned();
 
         let relative_pos = if self.hide_output {
             // Nothing will be outputted so no need to move the cursor
             (layout.line_offset, 0)
-        } else if layout.line_width() > display_at {
-            // It is in the same line as the prompt
-            (layout.line_offset + display_at, 0)
         } else {
-            let at = display_at - layout.line_width();
-
-            (at % layout.width, 1 + at / layout.width)
+            match self.shown_pos(self.at, layout) {
+                // The cursor cannot be past the end of the row
+                (x, y, row_width) if x >= row_width => (0, y + 1),
+                (x, y, _) => (x, y),
+            }
         };
 
         layout.offset_cursor(relative_pos)
@@ -381,7 +507,9 @@ fn print_mask<W: Write>(len: usize, mask: char, w: &mut W) -> io::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{backend::TestBackend, events::KeyModifiers, test_consts::*, Widget};
+    use crate::{
+        backend::TestBackend, events::KeyModifiers, layout::Alignment, test_consts::*, Widget,
+    };
 
     #[test]
     fn test_print_mask() {
@@ -520,6 +648,56 @@ mod tests {
         test(UNICODE, 70, 4);
     }
 
+    #[test]
+    fn test_render_aligned() {
+        let size = (100, 20).into();
+        let base_layout = Layout::new(5, size)
+            .with_max_width(30)
+            .with_alignment(Alignment::Center);
+        let mut layout = base_layout;
+
+        let mut backend = TestBackend::new(size);
+        let mut input = StringInput::default();
+        input.set_value("Hello, World! ".repeat(4));
+        input.set_at(30);
+
+        let aligned = base_layout.with_offset(35, 0).with_size((65, 20).into());
+        assert_eq!(input.height(&mut layout.clone()), 3);
+        assert_eq!(input.cursor_pos(layout), (40, 1));
+
+        input.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(layout, aligned.with_line_offset(1).with_offset(35, 2));
+    }
+
+    #[test]
+    fn test_reveal_last() {
+        let size = (100, 20).into();
+        let mut input = StringInput::default().mask('*').reveal_last(true);
+
+        assert!(input.handle_key(KeyCode::Char('a').into()));
+        assert!(input.handle_key(KeyCode::Char('b').into()));
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.revealed, None);
+        assert!(input.handle_key(KeyCode::Char('c').into()));
+        assert_eq!(input.revealed, Some(1));
+
+        let mut backend = TestBackend::new(size);
+        input
+            .render(&mut Layout::new(0, size), &mut backend)
+            .unwrap();
+        crate::assert_backend_snapshot!(backend);
+
+        assert!(!input.handle_key(KeyCode::Tab.into()));
+        assert_eq!(input.revealed, None);
+
+        let mut backend = TestBackend::new(size);
+        input
+            .render(&mut Layout::new(0, size), &mut backend)
+            .unwrap();
+        crate::assert_backend_snapshot!(backend);
+    }
+
     #[test]
     fn test_handle_key() {
         let mut input = StringInput::with_filter_map(|c| if c == 'i' { None } else { Some(c) });
@@ -623,6 +801,25 @@ mod tests {
         assert_eq!(input.value().chars().count(), 386);
     }
 
+    #[test]
+    fn test_handle_paste() {
+        let mut input = StringInput::with_filter_map(|c| if c == 'x' { None } else { Some(c) });
+        input.set_value("Hello world".into());
+        input.set_at(5);
+
+        assert!(input.handle_paste(", brave\r\nñew"));
+        assert_eq!(input.value(), "Hello, braveñew world");
+        assert_eq!(input.get_at(), 15);
+
+        assert!(!input.handle_paste("x\n"));
+        assert_eq!(input.value(), "Hello, braveñew world");
+
+        input.set_at(21);
+        assert!(input.handle_paste("!"));
+        assert_eq!(input.value(), "Hello, braveñew world!");
+        assert_eq!(input.get_at(), 22);
+    }
+
     #[test]
     fn test_height() {
         fn test(text: &str, indent: usize, max_width: usize, height: u16) {
@@ -638,6 +835,18 @@ mod tests {
 
         test(LOREM, 0, 100, 5);
         test(LOREM, 40, 100, 6);
+
+        // Only 3 of the double width characters fit in a row of 7
+        test("你好世界你好世界你好", 0, 7, 4);
+        test("你好世界你好世界你好", 1, 7, 4);
+        test("你好世界你好世界你好", 2, 7, 4);
+        test("你好世界你好世界你好", 6, 7, 5);
+
+        let mut layout = Layout::new(0, (5, 40).into());
+        let mut input = StringInput::default().mask('*');
+        input.set_value("你好世界".into());
+        assert_eq!(input.height(&mut layout), 1);
+        assert_eq!(layout.line_offset, 4);
     }
 
     #[test]
@@ -686,5 +895,41 @@ mod tests {
 
         layout.offset_y = 3;
         assert_eq!(input.cursor_pos(layout), (35, 4));
+
+        let layout = Layout::new(0, (7, 20).into());
+        input.set_value("你好世界".into());
+        input.set_at(3);
+        assert_eq!(input.cursor_pos(layout), (6, 0));
+        // The fourth character does not fit in the rest of the row
+        input.set_at(4);
+        assert_eq!(input.cursor_pos(layout), (2, 1));
+    }
+
+    #[test]
+    fn test_graphemes() {
+        let mut input = StringInput::default();
+        input.set_value("e\u{301}a\u{1F468}\u{200D}\u{1F469}".into());
+        input.set_at(6);
+
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.get_at(), 3);
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.get_at(), 2);
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.get_at(), 0);
+        assert!(input.handle_key(KeyCode::Right.into()));
+        assert_eq!(input.get_at(), 2);
+
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "a\u{1F468}\u{200D}\u{1F469}");
+        assert_eq!(input.get_at(), 0);
+
+        input.set_at(1);
+        assert!(input.handle_key(KeyCode::Delete.into()));
+        assert_eq!(input.value(), "a");
+        assert_eq!(input.get_at(), 1);
+
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "");
     }
 }
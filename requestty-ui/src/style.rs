@@ -1,6 +1,8 @@
 //! A module to control the looks of text.
 
-use std::{fmt::Display, io};
+use std::{cell::Cell, fmt::Display, io, sync::Mutex};
+
+use once_cell::sync::Lazy;
 
 /// Some content with a particular style applied.
 ///
@@ -12,6 +14,7 @@ pub struct Styled<T: ?Sized> {
     fg: Option<Color>,
     bg: Option<Color>,
     attributes: Attributes,
+    link: Option<String>,
     content: T,
 }
 
@@ -22,6 +25,7 @@ impl<T: Display> Styled<T> {
             fg: None,
             bg: None,
             attributes: Attributes::empty(),
+            link: None,
             content,
         }
     }
@@ -42,7 +46,7 @@ impl<T: Display + ?Sized> Styled<T> {
             backend.set_attributes(self.attributes)?;
         }
 
-        write!(backend, "{}", &self.content)?;
+        self.write_content(backend)?;
 
         if self.fg.is_some() {
             backend.set_fg(Color::Reset)?;
@@ -55,6 +59,16 @@ impl<T: Display + ?Sized> Styled<T> {
         }
         Ok(())
     }
+
+    fn write_content<B: crate::backend::Backend + ?Sized>(
+        &self,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        match self.link {
+            Some(ref url) => backend.write_hyperlink(url, &format_args!("{}", &self.content)),
+            None => write!(backend, "{}", &self.content),
+        }
+    }
 }
 
 impl<T: Display> From<T> for Styled<T> {
@@ -89,6 +103,320 @@ pub enum Color {
     Ansi(u8),
 }
 
+/// The colors used by the built-in prompts and widgets.
+///
+/// The default theme is used unless it is changed with [`set_theme`], or temporarily overridden
+/// with [`with_theme`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::prompt::style::{Color, Theme};
+/// # use requestty_ui::style::{Color, Theme};
+///
+/// let theme = Theme {
+///     highlight: Color::Magenta,
+///     ..Theme::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// Used for the question mark before a question, and the symbol shown once it is completed.
+    ///
+    /// Defaults to [`Color::LightGreen`].
+    pub prefix: Color,
+    /// Used for the currently hovered item, and the answer of a completed question.
+    ///
+    /// Defaults to [`Color::Cyan`].
+    pub highlight: Color,
+    /// Used for hints, defaults and other secondary text.
+    ///
+    /// Defaults to [`Color::DarkGrey`].
+    pub hint: Color,
+    /// Used for invalid input and the marker shown if validation fails.
+    ///
+    /// Defaults to [`Color::Red`].
+    pub error: Color,
+    /// Used for the marker shown if validation returns a warning.
+    ///
+    /// Defaults to [`Color::Yellow`].
+    pub warning: Color,
+    /// Used for the marker of selected items in prompts where multiple items can be selected.
+    ///
+    /// Defaults to [`Color::LightGreen`].
+    pub selected: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            prefix: Color::LightGreen,
+            highlight: Color::Cyan,
+            hint: Color::DarkGrey,
+            error: Color::Red,
+            warning: Color::Yellow,
+            selected: Color::LightGreen,
+        }
+    }
+}
+
+static THEME: Lazy<Mutex<Theme>> = Lazy::new(|| Mutex::new(Theme::default()));
+
+thread_local! {
+    // `const` initializers for thread locals are not supported by the MSRV
+    #[allow(clippy::missing_const_for_thread_local)]
+    static THEME_OVERRIDE: Cell<Option<Theme>> = Cell::new(None);
+}
+
+/// Get the current [`Theme`]
+///
+/// This is the theme given to [`with_theme`] if called from within it, otherwise the theme set by
+/// [`set_theme`]. If neither is used, it is the [default](Theme::default) theme.
+pub fn theme() -> Theme {
+    THEME_OVERRIDE
+        .with(Cell::get)
+        .unwrap_or_else(|| *THEME.lock().expect("theme poisoned"))
+}
+
+/// Set the current [`Theme`]
+///
+/// Also see [`style::theme`](theme).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::prompt::style;
+/// # use requestty_ui::style;
+///
+/// let theme = style::Theme {
+///     highlight: style::Color::Magenta,
+///     ..style::Theme::default()
+/// };
+///
+/// style::set_theme(theme);
+/// assert_eq!(style::theme(), theme);
+/// ```
+pub fn set_theme(new: Theme) {
+    *THEME.lock().expect("theme poisoned") = new;
+}
+
+/// Run `f` with `theme` as the current [`Theme`] of this thread.
+///
+/// The previous theme is restored once `f` returns.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::prompt::style;
+/// # use requestty_ui::style;
+///
+/// let theme = style::Theme {
+///     hint: style::Color::Blue,
+///     ..style::Theme::default()
+/// };
+///
+/// style::with_theme(theme, || assert_eq!(style::theme(), theme));
+/// ```
+pub fn with_theme<T, F: FnOnce() -> T>(theme: Theme, f: F) -> T {
+    struct Restore(Option<Theme>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            THEME_OVERRIDE.with(|theme| theme.set(self.0));
+        }
+    }
+
+    let _restore = Restore(THEME_OVERRIDE.with(|old| old.replace(Some(theme))));
+    f()
+}
+
+/// The colors a terminal can display.
+///
+/// Colors which are not supported are replaced by the nearest supported color before they are
+/// written by the built-in backends. See [`Color::degrade`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorSupport {
+    /// Only the 16 named colors and [`Color::Ansi`] values below 16.
+    Ansi16,
+    /// The 256 [`Color::Ansi`] values.
+    Ansi256,
+    /// Any [`Color::Rgb`] value.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detects the colors supported by the terminal from the environment.
+    ///
+    /// True color is assumed if `$COLORTERM` is `truecolor` or `24bit`, and 256 colors if `$TERM`
+    /// ends with `256color` (the convention used by terminfo entry names). On Windows, a missing
+    /// `$TERM` is treated as true color since the console has supported it since Windows 10. Any
+    /// other terminal is assumed to only support 16 colors.
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").ok();
+        let term = std::env::var("TERM").ok();
+        Self::from_vars(colorterm.as_deref(), term.as_deref())
+    }
+
+    fn from_vars(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if let Some("truecolor") | Some("24bit") = colorterm {
+            return ColorSupport::TrueColor;
+        }
+
+        match term {
+            Some(term) if term.ends_with("256color") => ColorSupport::Ansi256,
+            None if cfg!(windows) => ColorSupport::TrueColor,
+            _ => ColorSupport::Ansi16,
+        }
+    }
+}
+
+static COLOR_SUPPORT: Lazy<Mutex<ColorSupport>> = Lazy::new(|| Mutex::new(ColorSupport::detect()));
+
+/// Get the current [`ColorSupport`]
+///
+/// This is the one set by [`set_color_support`], otherwise the one [detected](ColorSupport::detect)
+/// from the environment.
+pub fn color_support() -> ColorSupport {
+    *COLOR_SUPPORT.lock().expect("color support poisoned")
+}
+
+/// Set the current [`ColorSupport`], overriding the one detected from the environment.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::prompt::style;
+/// # use requestty_ui::style;
+///
+/// style::set_color_support(style::ColorSupport::Ansi16);
+/// assert_eq!(style::color_support(), style::ColorSupport::Ansi16);
+/// ```
+pub fn set_color_support(support: ColorSupport) {
+    *COLOR_SUPPORT.lock().expect("color support poisoned") = support;
+}
+
+/// The colors of the 16 named colors in xterm, in the order of their ansi values.
+const ANSI16_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The intensities used by each component of the 6x6x6 color cube in the 256 colors.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn ansi_to_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => ANSI16_RGB[i as usize],
+        16..=231 => {
+            let i = i - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (i - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_ansi256(rgb: (u8, u8, u8)) -> u8 {
+    let nearest_level = |c: u8| {
+        (0..6)
+            .min_by_key(|&i| (i32::from(CUBE_LEVELS[i]) - i32::from(c)).abs())
+            .unwrap_or(0) as u8
+    };
+
+    let cube = 16 + 36 * nearest_level(rgb.0) + 6 * nearest_level(rgb.1) + nearest_level(rgb.2);
+
+    let average = (u32::from(rgb.0) + u32::from(rgb.1) + u32::from(rgb.2)) / 3;
+    let grey = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if distance(ansi_to_rgb(grey), rgb) < distance(ansi_to_rgb(cube), rgb) {
+        grey
+    } else {
+        cube
+    }
+}
+
+fn nearest_ansi16(rgb: (u8, u8, u8)) -> Color {
+    let i = (0..16)
+        .min_by_key(|&i| distance(ANSI16_RGB[i], rgb))
+        .unwrap_or(0);
+
+    [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Grey,
+        Color::DarkGrey,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ][i]
+}
+
+impl Color {
+    /// Replaces the color with the nearest color that is supported by `support`.
+    ///
+    /// The named colors are supported by every terminal, so only [`Color::Rgb`] and
+    /// [`Color::Ansi`] are changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+    /// use requestty::prompt::style::{Color, ColorSupport};
+    /// # use requestty_ui::style::{Color, ColorSupport};
+    ///
+    /// let orange = Color::Rgb(255, 135, 0);
+    /// assert_eq!(orange.degrade(ColorSupport::TrueColor), orange);
+    /// assert_eq!(orange.degrade(ColorSupport::Ansi256), Color::Ansi(208));
+    /// assert_eq!(orange.degrade(ColorSupport::Ansi16), Color::Yellow);
+    /// ```
+    pub fn degrade(self, support: ColorSupport) -> Color {
+        match (self, support) {
+            (Color::Rgb(r, g, b), ColorSupport::Ansi256) => Color::Ansi(nearest_ansi256((r, g, b))),
+            (Color::Rgb(r, g, b), ColorSupport::Ansi16) => nearest_ansi16((r, g, b)),
+            (Color::Ansi(i), ColorSupport::Ansi16) => nearest_ansi16(ansi_to_rgb(i)),
+            (color, _) => color,
+        }
+    }
+}
+
 bitflags::bitflags! {
     /// Attributes change the way a piece of text is displayed.
     pub struct Attributes: u16 {
@@ -138,7 +466,17 @@ pub struct AttributeDiff {
 /// Every method with the `on_` prefix sets the background color. Other color methods set the
 /// foreground color.
 ///
-/// Method names correspond to the [`Color`] enum variants and [`Attributes`] names.
+/// Method names correspond to the [`Color`] enum variants and [`Attributes`] names. The [`with`]
+/// and [`on`] methods can be used to set an arbitrary [`Color`].
+///
+/// [`with`]: Stylize::with
+/// [`on`]: Stylize::on
+///
+/// The [`hyperlink`] method makes the text a link, which can be clicked in terminals that support
+/// it. See [`Backend::write_hyperlink`] for more information.
+///
+/// [`hyperlink`]: Stylize::hyperlink
+/// [`Backend::write_hyperlink`]: crate::backend::Backend::write_hyperlink
 ///
 /// See also [`Styled`] and [`write_styled`].
 ///
@@ -163,6 +501,7 @@ pub trait Stylize<T> {
     fn grey(self) -> Styled<T>;
     fn rgb(self, r: u8, g: u8, b: u8) -> Styled<T>;
     fn ansi(self, ansi: u8) -> Styled<T>;
+    fn with(self, color: Color) -> Styled<T>;
 
     fn on_black(self) -> Styled<T>;
     fn on_dark_grey(self) -> Styled<T>;
@@ -182,6 +521,7 @@ pub trait Stylize<T> {
     fn on_grey(self) -> Styled<T>;
     fn on_rgb(self, r: u8, g: u8, b: u8) -> Styled<T>;
     fn on_ansi(self, ansi: u8) -> Styled<T>;
+    fn on(self, color: Color) -> Styled<T>;
 
     fn bold(self) -> Styled<T>;
     fn underlined(self) -> Styled<T>;
@@ -192,6 +532,8 @@ pub trait Stylize<T> {
     fn rapid_blink(self) -> Styled<T>;
     fn hidden(self) -> Styled<T>;
     fn crossed_out(self) -> Styled<T>;
+
+    fn hyperlink<U: Into<String>>(self, url: U) -> Styled<T>;
 }
 
 impl<T, I: Into<Styled<T>>> Stylize<T> for I {
@@ -285,6 +627,11 @@ impl<T, I: Into<Styled<T>>> Stylize<T> for I {
         styled.fg = Some(Color::Ansi(ansi));
         styled
     }
+    fn with(self, color: Color) -> Styled<T> {
+        let mut styled = self.into();
+        styled.fg = Some(color);
+        styled
+    }
 
     fn on_black(self) -> Styled<T> {
         let mut styled = self.into();
@@ -376,6 +723,11 @@ impl<T, I: Into<Styled<T>>> Stylize<T> for I {
         styled.bg = Some(Color::Ansi(ansi));
         styled
     }
+    fn on(self, color: Color) -> Styled<T> {
+        let mut styled = self.into();
+        styled.bg = Some(color);
+        styled
+    }
 
     fn bold(self) -> Styled<T> {
         let mut styled = self.into();
@@ -422,4 +774,80 @@ impl<T, I: Into<Styled<T>>> Stylize<T> for I {
         styled.attributes |= Attributes::CROSSED_OUT;
         styled
     }
+
+    fn hyperlink<U: Into<String>>(self, url: U) -> Styled<T> {
+        let mut styled = self.into();
+        styled.link = Some(url.into());
+        styled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_support_from_vars() {
+        assert_eq!(
+            ColorSupport::from_vars(Some("truecolor"), Some("xterm")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_vars(Some("24bit"), None),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_vars(None, Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_vars(Some("yes"), Some("screen-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_vars(None, Some("linux")),
+            ColorSupport::Ansi16
+        );
+    }
+
+    #[test]
+    fn test_degrade() {
+        for &support in &[
+            ColorSupport::Ansi16,
+            ColorSupport::Ansi256,
+            ColorSupport::TrueColor,
+        ] {
+            assert_eq!(Color::LightCyan.degrade(support), Color::LightCyan);
+            assert_eq!(Color::Reset.degrade(support), Color::Reset);
+        }
+
+        assert_eq!(
+            Color::Rgb(0, 0, 0).degrade(ColorSupport::Ansi256),
+            Color::Ansi(16)
+        );
+        assert_eq!(
+            Color::Rgb(128, 128, 128).degrade(ColorSupport::Ansi256),
+            Color::Ansi(244)
+        );
+        assert_eq!(
+            Color::Rgb(95, 135, 255).degrade(ColorSupport::Ansi256),
+            Color::Ansi(69)
+        );
+        assert_eq!(
+            Color::Ansi(100).degrade(ColorSupport::Ansi256),
+            Color::Ansi(100)
+        );
+
+        assert_eq!(
+            Color::Rgb(250, 10, 10).degrade(ColorSupport::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            Color::Rgb(120, 120, 130).degrade(ColorSupport::Ansi16),
+            Color::DarkGrey
+        );
+        assert_eq!(Color::Ansi(4).degrade(ColorSupport::Ansi16), Color::Blue);
+        assert_eq!(Color::Ansi(15).degrade(ColorSupport::Ansi16), Color::White);
+        assert_eq!(Color::Ansi(231).degrade(ColorSupport::Ansi16), Color::White);
+    }
 }
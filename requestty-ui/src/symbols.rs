@@ -1,7 +1,8 @@
 //! Special characters used for prompts/widgets.
 //!
 //! There are 2 default [`SymbolSet`]s -- [`UNICODE`] and [`ASCII`]. If a particular [`SymbolSet`]
-//! is not set, [`UNICODE`] is used. The [`ASCII`] symbol set exists if you want to have larger
+//! is not set, [`UNICODE`] is used, unless the `no-style` feature is enabled in which case
+//! [`ASCII`] is used. The [`ASCII`] symbol set exists if you want to have larger
 //! compatibility with terminal emulators (such as Windows' `cmd.exe`) which do not support unicode
 //! characters.
 
@@ -9,11 +10,18 @@ use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
 
-static SET: Lazy<Mutex<SymbolSet>> = Lazy::new(|| Mutex::new(UNICODE));
+static SET: Lazy<Mutex<SymbolSet>> = Lazy::new(|| {
+    Mutex::new(if cfg!(feature = "no-style") {
+        ASCII
+    } else {
+        UNICODE
+    })
+});
 
 /// Get the current [`SymbolSet`]
 ///
-/// If not set, it defaults to the [`UNICODE`] symbol set.
+/// If not set, it defaults to the [`UNICODE`] symbol set, or the [`ASCII`] symbol set with the
+/// `no-style` feature.
 ///
 /// Also see [`symbols::set`](set).
 ///
@@ -52,6 +60,10 @@ pub fn set(new: SymbolSet) {
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// The various special symbols used by the prompts during rendering.
 pub struct SymbolSet {
+    /// Decoration to show before a question that has not been answered yet.
+    ///
+    /// For example, this is shown before the message of every prompt.
+    pub prefix: char,
     /// Used to point to a special item.
     ///
     /// For example, this is used in the various list prompts to show the currently hovered item.
@@ -63,7 +75,7 @@ pub struct SymbolSet {
     pub arrow: char,
     /// Decoration to show when a question is completed.
     ///
-    /// For example, this is replaces the question mark when the question is answered.
+    /// For example, this replaces the [`prefix`](Self::prefix) when the question is answered.
     pub completed: char,
     /// Decoration to add some spacing without leaving it empty.
     ///
@@ -91,6 +103,7 @@ pub struct SymbolSet {
 ///
 /// It is composed of unicode characters and so may not be supported by all terminal emulators.
 pub const UNICODE: SymbolSet = SymbolSet {
+    prefix: '?',
     /// `'❯' U+276F`
     pointer: '❯',
     /// `'›' U+203A`
@@ -120,6 +133,7 @@ pub const UNICODE: SymbolSet = SymbolSet {
 /// Since it contains only ASCII, it will be supported by all terminal emulators but may not look as
 /// good.
 pub const ASCII: SymbolSet = SymbolSet {
+    prefix: '?',
     pointer: '>',
     arrow: '>',
     completed: '?',
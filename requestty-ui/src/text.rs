@@ -173,10 +173,11 @@ mod tests {
             assert_eq!(nlines, filled.lines().count());
             let mut lines = filled.lines();
 
-            assert!(lines.next().unwrap().chars().count() <= max_width - indent);
+            let width = textwrap::core::display_width;
+            assert!(width(lines.next().unwrap()) <= max_width - indent);
 
             for line in lines {
-                assert!(line.chars().count() <= max_width);
+                assert!(width(line) <= max_width);
             }
         }
 
@@ -186,6 +187,10 @@ mod tests {
 
         test(LOREM, 40, 80, 7);
         test(UNICODE, 40, 80, 7);
+
+        // Only 3 of the double width characters fit in a line of 7
+        test("你好世界你好世界你好", 0, 7, 4);
+        test("你好世界 你好世界你好", 2, 7, 4);
     }
 
     #[test]
@@ -0,0 +1,209 @@
+//! Helpers to use the controlling terminal directly, for when stdin or stdout are redirected.
+
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    os::unix::io::RawFd,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "termion")]
+use crate::backend::Size;
+
+/// Opens the controlling terminal for reading and writing.
+pub(crate) fn open() -> io::Result<File> {
+    OpenOptions::new().read(true).write(true).open("/dev/tty")
+}
+
+/// Converts the return value of a libc function to a `Result`, where `-1` is an error.
+#[cfg(feature = "termion")]
+fn cvt(res: libc::c_int) -> io::Result<libc::c_int> {
+    if res == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(res)
+    }
+}
+
+/// Gets the size of the terminal at `fd`.
+#[cfg(feature = "termion")]
+pub(crate) fn size(fd: RawFd) -> io::Result<Size> {
+    // SAFETY: `winsize` is plain data, so it can be zeroed
+    #[allow(unsafe_code)]
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+
+    // SAFETY: `TIOCGWINSZ` writes a single `winsize`
+    #[allow(unsafe_code)]
+    cvt(unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) })?;
+
+    Ok(Size {
+        width: size.ws_col,
+        height: size.ws_row,
+    })
+}
+
+/// The raw mode of a terminal other than stdout, which is restored when it is dropped.
+#[cfg(feature = "termion")]
+pub(crate) struct RawMode {
+    fd: RawFd,
+    /// The mode of the terminal before raw mode was enabled, if it is enabled
+    original: Option<libc::termios>,
+}
+
+#[cfg(feature = "termion")]
+impl RawMode {
+    pub(crate) fn new(fd: RawFd) -> Self {
+        Self { fd, original: None }
+    }
+
+    pub(crate) fn fd(&self) -> RawFd {
+        self.fd
+    }
+
+    pub(crate) fn enable(&mut self) -> io::Result<()> {
+        if self.original.is_some() {
+            return Ok(());
+        }
+
+        // SAFETY: `termios` is plain data, so it can be zeroed
+        #[allow(unsafe_code)]
+        let mut termios: libc::termios = unsafe { std::mem::zeroed() };
+
+        // SAFETY: `tcgetattr` writes a single `termios`, and `cfmakeraw` only modifies it
+        #[allow(unsafe_code)]
+        unsafe {
+            cvt(libc::tcgetattr(self.fd, &mut termios))?;
+            let original = termios;
+            libc::cfmakeraw(&mut termios);
+            cvt(libc::tcsetattr(self.fd, libc::TCSANOW, &termios))?;
+            self.original = Some(original);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn disable(&mut self) -> io::Result<()> {
+        if let Some(original) = self.original.take() {
+            // SAFETY: `original` was returned by `tcgetattr`
+            #[allow(unsafe_code)]
+            cvt(unsafe { libc::tcsetattr(self.fd, libc::TCSANOW, &original) })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "termion")]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = self.disable();
+    }
+}
+
+/// Waits till `fd` can be read, returning false if the timeout was reached first.
+pub(crate) fn poll(fd: RawFd, timeout: Duration) -> io::Result<bool> {
+    let mut fd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+
+    // SAFETY: `fd` is a single valid `pollfd`
+    #[allow(unsafe_code)]
+    let res = unsafe { libc::poll(&mut fd, 1, timeout.as_millis() as libc::c_int) };
+
+    match res {
+        -1 => match io::Error::last_os_error() {
+            e if e.kind() == io::ErrorKind::Interrupted => Ok(false),
+            e => Err(e),
+        },
+        0 => Ok(false),
+        _ => Ok(true),
+    }
+}
+
+/// Reads directly from `fd`, without any buffering.
+pub(crate) fn read(fd: RawFd, buf: &mut [u8]) -> io::Result<usize> {
+    loop {
+        // SAFETY: `buf` is valid for writes of `buf.len()` bytes
+        #[allow(unsafe_code)]
+        let res = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+
+        if res >= 0 {
+            return Ok(res as usize);
+        }
+
+        let e = io::Error::last_os_error();
+        if e.kind() != io::ErrorKind::Interrupted {
+            return Err(e);
+        }
+    }
+}
+
+/// Reads from `fd` till the response to a cursor position query (`ESC [ 6 n`), for up to
+/// `timeout`. Everything read before the response is left in `read`. The returned position is
+/// 0-indexed.
+pub(crate) fn read_cursor_pos(
+    fd: RawFd,
+    read: &mut Vec<u8>,
+    timeout: Duration,
+) -> io::Result<(u16, u16)> {
+    let start = Instant::now();
+    let mut byte = [0];
+
+    loop {
+        let remaining = timeout.checked_sub(start.elapsed()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::TimedOut,
+                "Cursor position detection timed out.",
+            )
+        })?;
+
+        if !poll(fd, remaining)? {
+            continue;
+        }
+
+        if self::read(fd, &mut byte)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        read.push(byte[0]);
+
+        if byte[0] == b'R' {
+            if let Some((start, pos)) = parse_cursor_pos(read) {
+                read.truncate(start);
+                return Ok(pos);
+            }
+        }
+    }
+}
+
+/// Parses the response at the end of `read`, which looks like `ESC [ row ; column R`.
+///
+/// It returns the index the response starts at, along with the 0-indexed position.
+fn parse_cursor_pos(read: &[u8]) -> Option<(usize, (u16, u16))> {
+    let start = read.iter().rposition(|&b| b == b'\x1B')?;
+    let response = std::str::from_utf8(&read[start..]).ok()?;
+
+    let (row, column) = response
+        .strip_prefix("\x1B[")?
+        .strip_suffix('R')?
+        .split_once(';')?;
+    let row: u16 = row.parse().ok()?;
+    let column: u16 = column.parse().ok()?;
+
+    Some((start, (column.saturating_sub(1), row.saturating_sub(1))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cursor_pos() {
+        assert_eq!(parse_cursor_pos(b"\x1B[12;4R"), Some((0, (3, 11))));
+        assert_eq!(parse_cursor_pos(b"ab\x1B[A\x1B[1;1R"), Some((5, (0, 0))));
+        assert_eq!(parse_cursor_pos(b"\x1B[12R"), None);
+        assert_eq!(parse_cursor_pos(b"12;4R"), None);
+    }
+}
@@ -1,14 +1,24 @@
 //! A module containing the in-built widgets and types required by them
 
-use std::io;
+use std::{io, ops::Range};
 
 use textwrap::{core::Fragment, WordSeparator};
 
-use crate::{backend::Backend, events::KeyEvent, layout::Layout};
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent, MouseEvent},
+    layout::Layout,
+};
 
 pub use crate::char_input::CharInput;
+pub use crate::error_view::ErrorView;
+pub use crate::labeled_input::LabeledInput;
+pub use crate::list_box::{CheckList, ListBox};
+pub use crate::multiline_text_input::MultilineTextInput;
 pub use crate::prompt::{Delimiter, Prompt};
-pub use crate::select::{List, Select};
+pub use crate::select::{List, Select, SelectState};
+pub use crate::slider::Slider;
+pub use crate::spinner::Spinner;
 pub use crate::string_input::StringInput;
 pub use crate::text::Text;
 
@@ -38,6 +48,21 @@ pub(crate) fn no_filter(c: char) -> Option<char> {
 /// While it is not a guarantee that the terminal will be in raw mode, it is highly recommended that
 /// those implementing the render cycle call render while in raw mode.
 ///
+/// The [`Layout`] given to [`render`] starts where the terminal cursor is, which is
+/// `line_offset` columns after `offset_x` on the row `offset_y`. When a widget is done, it should
+/// leave the layout, and the cursor, where the next widget starts:
+/// - `offset_y` is the row after the last row it used, or the row it ended on if something can be
+///   rendered after it on the same row.
+/// - `line_offset` is the column it ended at relative to `offset_x`, which is 0 if it moved to the
+///   next row.
+/// - The other fields are left as they were given.
+///
+/// [`height`] must update the layout in the same way as [`render`] without drawing anything, and
+/// the rows it returns must be the rows `render` will use. Widgets should not use more than
+/// [`max_height`](Layout::max_height) rows, or draw past the [`width`](Layout::width). Widgets
+/// containing other widgets can use helpers like [`Layout::split_vertical`] and
+/// [`Layout::indent`] to give each child its part of the layout.
+///
 /// [`height`]: Widget::height
 /// [`render`]: Widget::render
 /// [`cursor_pos`]: Widget::cursor_pos
@@ -62,6 +87,43 @@ pub trait Widget {
 
     /// Handle a key input. It should return whether key was handled.
     fn handle_key(&mut self, key: KeyEvent) -> bool;
+
+    /// Handle a mouse input. It should return whether the event was handled.
+    ///
+    /// Mouse events are only received if the mouse is being captured, see
+    /// [`Input::mouse_capture`](crate::Input::mouse_capture). The position of the event is
+    /// absolute, so it can be compared with the layout the widget was last rendered with.
+    ///
+    /// By default, mouse events are not handled.
+    fn handle_mouse(&mut self, _mouse: MouseEvent) -> bool {
+        false
+    }
+
+    /// Handle pasted text. It should return whether the text was handled.
+    ///
+    /// Pasted text is only received with the `bracketed-paste` feature, see
+    /// [`Event::Paste`](crate::events::Event::Paste).
+    ///
+    /// By default, every character is handled as a key with [`handle_key`](Widget::handle_key).
+    fn handle_paste(&mut self, text: &str) -> bool {
+        let mut handled = false;
+        for c in text.chars() {
+            handled |= self.handle_key(KeyCode::Char(c).into());
+        }
+        handled
+    }
+
+    /// The rows which should be kept on screen when the widget is taller than the terminal, as
+    /// of the last render. Like [`cursor_pos`](Widget::cursor_pos), the rows are absolute.
+    ///
+    /// [`Input`](crate::Input) scrolls the widget to show these rows whenever a key is handled, for
+    /// example to follow the hovered element of a list.
+    ///
+    /// By default, this is the row of the cursor.
+    fn focused_rows(&mut self, layout: Layout) -> Range<u16> {
+        let (_, y) = self.cursor_pos(layout);
+        y..y.saturating_add(1)
+    }
 }
 
 impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
@@ -79,7 +141,7 @@ impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
             for _ in 0..max_width {
                 backend.write_all(b".")?;
             }
-        } else if textwrap::core::display_width(self) > max_width {
+        } else if crate::layout::display_width(self) as usize > max_width {
             let mut width = 0;
             let mut prev_whitespace_len = 0;
             let max_width = max_width - 3; // leave space for the '...'
@@ -0,0 +1,163 @@
+//! Renders widgets in random layouts to make sure the layout arithmetic never underflows.
+
+use std::io;
+
+use proptest::prelude::*;
+use requestty_ui::{
+    backend::{Backend, TestBackend},
+    events::KeyCode,
+    layout::{Layout, RenderRegion},
+    widgets::{self, List},
+    Widget,
+};
+
+fn layout() -> impl Strategy<Value = Layout> {
+    // A terminal must be at least 1x1, but everything else can be past the end of the terminal
+    (1..200u16, 1..60u16)
+        .prop_flat_map(|(width, height)| {
+            (
+                Just(width),
+                Just(height),
+                0..width * 2,
+                0..width * 2,
+                0..height * 2,
+                0..height * 2,
+                prop_oneof![
+                    Just(RenderRegion::Top),
+                    Just(RenderRegion::Middle),
+                    Just(RenderRegion::Bottom)
+                ],
+            )
+        })
+        .prop_map(
+            |(width, height, line_offset, offset_x, offset_y, max_height, render_region)| {
+                Layout::new(line_offset, (width, height).into())
+                    .with_offset(offset_x, offset_y)
+                    .with_max_height(max_height)
+                    .with_render_region(render_region)
+            },
+        )
+}
+
+fn check_widget<W: Widget>(widget: &mut W, layout: Layout) {
+    let mut backend = TestBackend::new_with_layout((layout.width, layout.height).into(), layout);
+
+    widget.height(&mut layout.clone());
+    widget.cursor_pos(layout);
+    widget.render(&mut layout.clone(), &mut backend).unwrap();
+}
+
+struct TextList(Vec<widgets::Text<String>>, usize);
+
+impl List for TextList {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        _: bool,
+        mut layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        self.0[index].render(&mut layout, backend)
+    }
+
+    fn is_selectable(&self, _: usize) -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        self.1
+    }
+
+    fn should_loop(&self) -> bool {
+        true
+    }
+
+    fn height_at(&mut self, index: usize, mut layout: Layout) -> u16 {
+        self.0[index].height(&mut layout)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_layout(layout in layout(), height in any::<u16>()) {
+        layout.line_width();
+        layout.available_width();
+        layout.offset_cursor((layout.width, layout.height));
+        layout.get_start(height);
+    }
+
+    #[test]
+    fn test_prompt(layout in layout(), message in ".{0,100}", hint in proptest::option::of(".{0,50}")) {
+        let mut prompt = widgets::Prompt::new(message);
+        if let Some(hint) = hint {
+            prompt = prompt.with_hint(hint);
+        }
+
+        check_widget(&mut prompt, layout);
+    }
+
+    #[test]
+    fn test_text(layout in layout(), text in "[a-z \n]{0,300}") {
+        check_widget(&mut widgets::Text::new(text), layout);
+    }
+
+    #[test]
+    fn test_string_input(layout in layout(), value in ".{0,300}", left in 0..300usize) {
+        let mut input = widgets::StringInput::new();
+        input.set_value(value);
+        for _ in 0..left {
+            input.handle_key(KeyCode::Left.into());
+        }
+
+        check_widget(&mut input, layout);
+    }
+
+    #[test]
+    fn test_multiline_text_input(
+        layout in layout(),
+        value in "[a-z \n]{0,300}",
+        max_height in 1..20u16,
+        up in 0..30usize,
+    ) {
+        let mut input = widgets::MultilineTextInput::new().max_height(max_height);
+        input.set_value(value);
+        for _ in 0..up {
+            input.handle_key(KeyCode::Up.into());
+        }
+
+        check_widget(&mut input, layout);
+    }
+
+    #[test]
+    fn test_slider(
+        layout in layout(),
+        (min, max) in (any::<i64>(), any::<i64>()).prop_map(|(a, b)| (a.min(b), a.max(b))),
+        value in any::<i64>(),
+    ) {
+        let mut slider = widgets::Slider::new(min, max);
+        slider.set_value(value);
+
+        check_widget(&mut slider, layout);
+    }
+
+    #[test]
+    fn test_select(
+        layout in layout(),
+        items in proptest::collection::vec("[a-z \n]{0,100}", 0..30),
+        page_size in 5..20usize,
+        down in 0..40usize,
+    ) {
+        let list = TextList(items.into_iter().map(widgets::Text::new).collect(), page_size);
+        let mut select = widgets::Select::new(list);
+
+        check_widget(&mut select, layout);
+        for _ in 0..down {
+            select.handle_key(KeyCode::Down.into());
+        }
+        check_widget(&mut select, layout);
+    }
+}
@@ -1,8 +1,12 @@
 use std::{
+    any::Any,
+    cmp::Ordering,
     collections::hash_map::{Entry, HashMap, IntoIter},
+    fmt,
     hash::Hash,
     iter::FromIterator,
     ops::{Deref, DerefMut},
+    sync::Arc,
 };
 
 use crate::question::OrderSelectItem;
@@ -11,6 +15,7 @@ use crate::question::OrderSelectItem;
 ///
 /// [`Question`]: crate::question::Question
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Answer {
     /// Strings will be returned by [`input`], [`password`] and [`editor`].
     ///
@@ -39,11 +44,43 @@ pub enum Answer {
     ///
     /// [`confirm`]: crate::question::Question::confirm
     Bool(bool),
+    /// Optional bools will be returned by [`confirm`] if abstaining is allowed with
+    /// [`allow_abstain`], in which case it is `None` if the user abstained.
+    ///
+    /// [`confirm`]: crate::question::Question::confirm
+    /// [`allow_abstain`]: crate::question::ConfirmBuilder::allow_abstain
+    OptionBool(Option<bool>),
     /// ListItems will be returned by [`multi_select`] and [`order_select`].
     ///
     /// [`multi_select`]: crate::question::Question::multi_select
     /// [`order_select`]: crate::question::Question::order_select
     ListItems(Vec<ListItem>),
+    /// Maps hold the answers to a group of questions, so that they can be nested under a single
+    /// name instead of sharing one keyspace with every other answer.
+    ///
+    /// They are not returned by any question, but are created by [`Answers::into_nested`], or can
+    /// be inserted directly. The answers inside them can be found with [`Answers::get_path`].
+    Map(Answers),
+    /// Lists hold the answers of each time the questions of a [`Repeat`] were asked, in order.
+    ///
+    /// [`Repeat`]: crate::Repeat
+    List(Vec<Answers>),
+    /// Skipped is recorded for questions which were not answered, if
+    /// [`PromptModule::record_skipped`] is set.
+    ///
+    /// A question is skipped if `when` prevents it from being asked, or if it is skipped by
+    /// pressing `Esc` with [`OnEsc::SkipQuestion`].
+    ///
+    /// [`PromptModule::record_skipped`]: crate::PromptModule::record_skipped
+    /// [`OnEsc::SkipQuestion`]: crate::OnEsc::SkipQuestion
+    Skipped,
+    /// Custom answers can be returned by [custom prompts](crate::prompt::Prompt) to give back
+    /// arbitrary data.
+    ///
+    /// With the `serde` feature, custom answers cannot be (de)serialized, and serializing one
+    /// returns an error.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(CustomAnswer),
 }
 
 impl Answer {
@@ -173,6 +210,27 @@ impl Answer {
         }
     }
 
+    /// Returns `true` if the answer is [`Answer::OptionBool`].
+    pub fn is_option_bool(&self) -> bool {
+        matches!(self, Self::OptionBool(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::OptionBool`], otherwise returns [`None`].
+    pub fn as_option_bool(&self) -> Option<Option<bool>> {
+        match self {
+            Self::OptionBool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Option<bool>)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_option_bool(self) -> Result<Option<bool>, Self> {
+        match self {
+            Self::OptionBool(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
     /// Returns `true` if the answer is [`Answer::ListItems`].
     pub fn is_list_items(&self) -> bool {
         matches!(self, Self::ListItems(..))
@@ -193,6 +251,74 @@ impl Answer {
             _ => Err(self),
         }
     }
+
+    /// Returns `true` if the answer is [`Answer::Map`].
+    pub fn is_map(&self) -> bool {
+        matches!(self, Self::Map(..))
+    }
+
+    /// Returns [`Some`] if it is a [`Answer::Map`], otherwise returns [`None`].
+    pub fn as_map(&self) -> Option<&Answers> {
+        match self {
+            Self::Map(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Answers)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_map(self) -> Result<Answers, Self> {
+        match self {
+            Self::Map(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::List`].
+    pub fn is_list(&self) -> bool {
+        matches!(self, Self::List(..))
+    }
+
+    /// Returns [`Some`] if it is a [`Answer::List`], otherwise returns [`None`].
+    pub fn as_list(&self) -> Option<&[Answers]> {
+        match self {
+            Self::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Vec<Answers>)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_list(self) -> Result<Vec<Answers>, Self> {
+        match self {
+            Self::List(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::Skipped`].
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, Self::Skipped)
+    }
+
+    /// Returns `true` if the answer is [`Answer::Custom`].
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(..))
+    }
+
+    /// Returns [`Some`] if it is a [`Answer::Custom`] holding a `T`, otherwise returns [`None`].
+    pub fn as_custom<T: Any>(&self) -> Option<&T> {
+        match self {
+            Self::Custom(v) => v.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(CustomAnswer)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_custom(self) -> Result<CustomAnswer, Self> {
+        match self {
+            Self::Custom(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
 }
 
 macro_rules! impl_from {
@@ -209,9 +335,13 @@ impl_from!(String => String);
 impl_from!(i64 => Int);
 impl_from!(f64 => Float);
 impl_from!(bool => Bool);
+impl_from!(Option<bool> => OptionBool);
 impl_from!(ExpandItem => ExpandItem);
 impl_from!(ListItem => ListItem);
 impl_from!(Vec<ListItem> => ListItems);
+impl_from!(Answers => Map);
+impl_from!(Vec<Answers> => List);
+impl_from!(CustomAnswer => Custom);
 
 impl From<Vec<OrderSelectItem>> for Answer {
     fn from(v: Vec<OrderSelectItem>) -> Self {
@@ -227,11 +357,29 @@ impl From<Vec<OrderSelectItem>> for Answer {
 /// [`select`]: crate::question::Question::select
 /// [`raw_select`]: crate::question::Question::raw_select
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListItem {
-    /// The index of the choice
+    /// The index of the choice in the list it was given in, including any separators
     pub index: usize,
     /// The content of the choice -- it is what was displayed to the user
     pub text: String,
+    /// The text of the nearest [`Separator`] above the choice, if any.
+    ///
+    /// It is `None` if there is no separator above the choice, or if the nearest one is a
+    /// [`DefaultSeparator`].
+    ///
+    /// [`Separator`]: crate::Separator
+    /// [`DefaultSeparator`]: crate::DefaultSeparator
+    pub section: Option<String>,
+    /// The value attached to the choice, if any.
+    ///
+    /// Values can be attached with `choice_with_value` on the [`select`], [`raw_select`] and
+    /// [`multi_select`] builders.
+    ///
+    /// [`select`]: crate::question::SelectBuilder::choice_with_value
+    /// [`raw_select`]: crate::question::RawSelectBuilder::choice_with_value
+    /// [`multi_select`]: crate::question::MultiSelectBuilder::choice_with_value
+    pub value: Option<String>,
 }
 
 impl<I: Into<String>> From<(usize, I)> for ListItem {
@@ -239,6 +387,8 @@ impl<I: Into<String>> From<(usize, I)> for ListItem {
         Self {
             index,
             text: text.into(),
+            section: None,
+            value: None,
         }
     }
 }
@@ -248,6 +398,8 @@ impl From<OrderSelectItem> for ListItem {
         ListItem {
             index: o.initial_index,
             text: o.text.text,
+            section: None,
+            value: None,
         }
     }
 }
@@ -259,26 +411,105 @@ impl From<OrderSelectItem> for ListItem {
 /// [`Choice`]: crate::Choice
 /// [`expand`]: crate::question::Question::expand
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpandItem {
-    /// The key associated with the choice
-    pub key: char,
+    /// The key associated with the choice, made of one or more characters
+    pub key: String,
     /// The content of the choice -- it is what was displayed to the user
     pub text: String,
 }
 
-impl<I: Into<String>> From<(char, I)> for ExpandItem {
-    fn from((key, text): (char, I)) -> Self {
+impl<K: Into<String>, I: Into<String>> From<(K, I)> for ExpandItem {
+    fn from((key, text): (K, I)) -> Self {
         Self {
-            key,
+            key: key.into(),
             text: text.into(),
         }
     }
 }
 
+/// Arbitrary data given as an [`Answer::Custom`] by a [custom prompt](crate::prompt::Prompt).
+///
+/// The data is reference counted, so cloning the answer is cheap and the clones share the data. Two
+/// custom answers are only equal if they share the same data, and they are not ordered otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{Answer, CustomAnswer};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Colour(u8, u8, u8);
+///
+/// let answer = Answer::from(CustomAnswer::new(Colour(255, 128, 0)));
+///
+/// assert!(answer.is_custom());
+/// assert_eq!(answer.as_custom::<Colour>(), Some(&Colour(255, 128, 0)));
+/// assert_eq!(answer.as_custom::<String>(), None);
+/// ```
+#[derive(Clone)]
+pub struct CustomAnswer(Arc<dyn Any + Send + Sync>);
+
+impl CustomAnswer {
+    /// Creates a new `CustomAnswer` holding the given value.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// Returns `true` if the value is a `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    /// Returns a reference to the value if it is a `T`, otherwise returns [`None`].
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+
+    /// Returns the shared value if it is a `T`, otherwise returns itself as an [`Err`].
+    pub fn downcast<T: Any + Send + Sync>(self) -> Result<Arc<T>, Self> {
+        if self.is::<T>() {
+            Ok(self.0.downcast().expect("the type was checked"))
+        } else {
+            Err(self)
+        }
+    }
+
+    fn ptr(&self) -> *const () {
+        Arc::as_ptr(&self.0) as *const ()
+    }
+}
+
+impl fmt::Debug for CustomAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomAnswer").field(&self.ptr()).finish()
+    }
+}
+
+impl PartialEq for CustomAnswer {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr() == other.ptr()
+    }
+}
+
+impl PartialOrd for CustomAnswer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
 /// A collections of answers of previously asked [`Question`]s.
 ///
+/// With the `serde` feature, it is (de)serialized as a map from the question names to the answers.
+///
 /// [`Question`]: crate::question::Question
 #[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Answers {
     answers: HashMap<String, Answer>,
 }
@@ -289,7 +520,123 @@ impl std::fmt::Debug for Answers {
     }
 }
 
+/// Answers are only ordered if they are equal, since the answers in them have no order.
+impl PartialOrd for Answers {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
 impl Answers {
+    /// Gets the answer at a path of names separated by `.`, looking inside [`Answer::Map`]s.
+    ///
+    /// Both flattened and nested answers are found, so `database.host` can be an answer with that
+    /// name, like the ones given by a [`Section`], or the answer `host` inside the map `database`.
+    ///
+    /// [`Section`]: crate::Section
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers};
+    ///
+    /// let database: Answers = vec![("host".to_owned(), Answer::String("localhost".into()))]
+    ///     .into_iter()
+    ///     .collect();
+    /// let answers: Answers = vec![
+    ///     ("database".to_owned(), Answer::Map(database)),
+    ///     ("network.port".to_owned(), Answer::Int(8080)),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(answers.get_path("database.host"), Some(&Answer::String("localhost".into())));
+    /// assert_eq!(answers.get_path("network.port"), Some(&Answer::Int(8080)));
+    /// assert_eq!(answers.get_path("database.port"), None);
+    /// ```
+    pub fn get_path(&self, path: &str) -> Option<&Answer> {
+        if let Some(answer) = self.answers.get(path) {
+            return Some(answer);
+        }
+
+        path.match_indices('.')
+            .find_map(|(i, _)| match self.answers.get(&path[..i]) {
+                Some(Answer::Map(map)) => map.get_path(&path[i + 1..]),
+                _ => None,
+            })
+    }
+
+    /// Nests the answers with names separated by `.` into [`Answer::Map`]s, so that `database.host`
+    /// becomes the answer `host` inside the map `database`.
+    ///
+    /// If there already is an answer with the name of a map which is not a map itself, for example
+    /// an answer named `database` along with `database.host`, the answers are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers};
+    ///
+    /// let answers: Answers = vec![
+    ///     ("database.host".to_owned(), Answer::String("localhost".into())),
+    ///     ("database.port".to_owned(), Answer::Int(5432)),
+    ///     ("name".to_owned(), Answer::String("app".into())),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let answers = answers.into_nested();
+    /// assert_eq!(answers.len(), 2);
+    /// assert_eq!(answers["database"].as_map().unwrap()["port"], Answer::Int(5432));
+    /// assert_eq!(answers.get_path("database.host"), Some(&Answer::String("localhost".into())));
+    /// ```
+    pub fn into_nested(self) -> Self {
+        let mut nested = HashMap::with_capacity(self.answers.len());
+        let mut groups: HashMap<String, Answers> = HashMap::new();
+
+        for (name, answer) in self.answers {
+            match name.split_once('.') {
+                Some((group, rest)) => {
+                    groups
+                        .entry(group.to_owned())
+                        .or_default()
+                        .insert(rest.to_owned(), answer);
+                }
+                None => {
+                    nested.insert(name, answer);
+                }
+            }
+        }
+
+        for (group, answers) in groups {
+            match nested.entry(group) {
+                Entry::Vacant(entry) => {
+                    entry.insert(Answer::Map(answers.into_nested()));
+                }
+                Entry::Occupied(mut entry) => match entry.get_mut() {
+                    Answer::Map(map) => {
+                        map.extend(answers);
+                        *map = std::mem::take(map).into_nested();
+                    }
+                    _ => {
+                        let prefix = entry.key().clone();
+                        nested.extend(
+                            answers
+                                .into_iter()
+                                .map(|(name, answer)| (format!("{}.{}", prefix, name), answer)),
+                        );
+                    }
+                },
+            }
+        }
+
+        Self { answers: nested }
+    }
+
     pub(crate) fn insert(&mut self, name: String, answer: Answer) -> &mut Answer {
         match self.answers.entry(name) {
             Entry::Occupied(entry) => {
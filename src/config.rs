@@ -0,0 +1,486 @@
+//! Configuration which applies to all prompts.
+//!
+//! A [`Config`] sets the defaults of every question asked after it is [applied](Config::apply),
+//! instead of repeating the same builder calls on each question. Any options set through the
+//! builders of a question take precedence over the configuration.
+//!
+//! # Examples
+//!
+//! ```
+//! use requestty::{Config, OnEsc};
+//!
+//! Config {
+//!     page_size: Some(10),
+//!     on_esc: Some(OnEsc::Terminate),
+//!     ..Config::default()
+//! }
+//! .apply();
+//! ```
+//!
+//! With the `config` feature, the configuration can also be read from `requestty.toml` in the
+//! user's config directory. This is `$XDG_CONFIG_HOME/requestty.toml` (or
+//! `$HOME/.config/requestty.toml` if `XDG_CONFIG_HOME` is not set) on unix, and
+//! `%APPDATA%\requestty.toml` on windows.
+//!
+//! The config file is not loaded automatically, `config::load` should be called at the start of
+//! the program.
+//!
+//! # Format
+//!
+//! All keys are optional, and unknown keys are ignored.
+//!
+//! ```toml
+//! # The default page size of list questions
+//! page_size = 10
+//! # Whether list questions should wrap around by default
+//! should_loop = false
+//! # Whether all questions should hide the cursor, including the ones which take text input
+//! hide_cursor = true
+//!
+//! [symbols]
+//! # The base symbol set, either "unicode" or "ascii". It is "unicode" if not given.
+//! set = "ascii"
+//! # Individual symbols can be overridden on top of the base set
+//! pointer = "→"
+//!
+//! # Colors not given here are the same as the default theme. Colors are either the name of a
+//! # color, an ansi value from 0 to 255, or an rgb hex code.
+//! [theme]
+//! prefix = "light_green"
+//! highlight = 208
+//! hint = "#808080"
+//!
+//! [keybindings]
+//! # What questions do when `Esc` is pressed, one of "terminate", "skip_question" or "ignore"
+//! on_esc = "skip_question"
+//! # The key which goes back to the previous question in prompt modules which allow it
+//! back = "ctrl+b"
+//! ```
+//!
+//! The names of the colors are the [`Color`](style::Color) variants in snake case, like
+//! `"dark_grey"`. The keys are the name of a key, like `"tab"`, `"f1"` or `"a"`, optionally
+//! prefixed by modifiers such as `"ctrl+"`, `"alt+"` and `"shift+"`.
+
+#[cfg(feature = "config")]
+use std::{convert::TryFrom, env, fmt, fs, io, path::PathBuf};
+
+use ui::{
+    events::KeyEvent,
+    style::{self, Theme},
+    symbols::{self, SymbolSet},
+    OnEsc,
+};
+#[cfg(feature = "config")]
+use ui::{
+    events::{KeyCode, KeyModifiers},
+    style::Color,
+};
+
+/// The configuration which applies to all prompts.
+///
+/// See the [module level documentation](self) for more information.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    /// The [`SymbolSet`] to use.
+    pub symbols: Option<SymbolSet>,
+    /// The default page size of list questions.
+    pub page_size: Option<usize>,
+    /// Whether list questions should wrap around by default.
+    pub should_loop: Option<bool>,
+    /// What questions do by default when `Esc` is pressed.
+    pub on_esc: Option<OnEsc>,
+    /// Whether all questions hide the cursor while they are asked. By default, only questions
+    /// which do not take text input hide it.
+    pub hide_cursor: Option<bool>,
+    /// The [`Theme`] used by questions which do not set one.
+    pub theme: Option<Theme>,
+    /// The key which goes back to the previous question in prompt modules which do not set it.
+    ///
+    /// See [`PromptModule::back_key`](crate::PromptModule::back_key).
+    pub back_key: Option<KeyEvent>,
+}
+
+impl Config {
+    /// Parses the configuration from a string in the [format](self#format) of the config file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{config::Config, symbols};
+    ///
+    /// let config = Config::from_toml(r#"
+    ///     page_size = 10
+    ///
+    ///     [symbols]
+    ///     set = "ascii"
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(config.page_size, Some(10));
+    /// assert_eq!(config.symbols, Some(symbols::ASCII));
+    /// ```
+    #[cfg(feature = "config")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "config")))]
+    pub fn from_toml(s: &str) -> Result<Self, Error> {
+        let value: toml::Value = s.parse()?;
+        let mut config = Config::default();
+
+        if let Some(page_size) = value.get("page_size") {
+            let page_size = page_size
+                .as_integer()
+                .filter(|&page_size| page_size >= 5)
+                .ok_or(Error::Invalid(
+                    "`page_size` must be an integer of at least 5",
+                ))?;
+
+            config.page_size = Some(page_size as usize);
+        }
+
+        if let Some(should_loop) = value.get("should_loop") {
+            config.should_loop = Some(
+                should_loop
+                    .as_bool()
+                    .ok_or(Error::Invalid("`should_loop` must be a boolean"))?,
+            );
+        }
+
+        if let Some(hide_cursor) = value.get("hide_cursor") {
+            config.hide_cursor = Some(
+                hide_cursor
+                    .as_bool()
+                    .ok_or(Error::Invalid("`hide_cursor` must be a boolean"))?,
+            );
+        }
+
+        if let Some(table) = value.get("symbols") {
+            let table = table
+                .as_table()
+                .ok_or(Error::Invalid("`symbols` must be a table"))?;
+
+            // The set does not depend on the current one, so that the same file always gives the
+            // same symbols
+            let mut set = match table.get("set").map(toml::Value::as_str) {
+                None | Some(Some("unicode")) => symbols::UNICODE,
+                Some(Some("ascii")) => symbols::ASCII,
+                Some(_) => {
+                    return Err(Error::Invalid(
+                        "`symbols.set` must be either \"unicode\" or \"ascii\"",
+                    ))
+                }
+            };
+
+            for (key, symbol) in [
+                ("prefix", &mut set.prefix),
+                ("pointer", &mut set.pointer),
+                ("arrow", &mut set.arrow),
+                ("completed", &mut set.completed),
+                ("middle_dot", &mut set.middle_dot),
+                ("cross", &mut set.cross),
+                ("box_top_right", &mut set.box_top_right),
+                ("box_top_left", &mut set.box_top_left),
+                ("box_bottom_right", &mut set.box_bottom_right),
+                ("box_bottom_left", &mut set.box_bottom_left),
+                ("box_horizontal", &mut set.box_horizontal),
+                ("box_vertical", &mut set.box_vertical),
+            ] {
+                if let Some(value) = table.get(key) {
+                    *symbol = parse_char(value).ok_or(Error::Invalid(
+                        "symbols must be strings of a single character",
+                    ))?;
+                }
+            }
+
+            config.symbols = Some(set);
+        }
+
+        if let Some(table) = value.get("theme") {
+            let table = table
+                .as_table()
+                .ok_or(Error::Invalid("`theme` must be a table"))?;
+
+            let mut theme = Theme::default();
+
+            for (key, color) in [
+                ("prefix", &mut theme.prefix),
+                ("highlight", &mut theme.highlight),
+                ("hint", &mut theme.hint),
+                ("error", &mut theme.error),
+                ("warning", &mut theme.warning),
+                ("selected", &mut theme.selected),
+            ] {
+                if let Some(value) = table.get(key) {
+                    *color = parse_color(value).ok_or(Error::Invalid(
+                        "colors must be the name of a color, an ansi value or an rgb hex code",
+                    ))?;
+                }
+            }
+
+            config.theme = Some(theme);
+        }
+
+        if let Some(table) = value.get("keybindings") {
+            let table = table
+                .as_table()
+                .ok_or(Error::Invalid("`keybindings` must be a table"))?;
+
+            if let Some(on_esc) = table.get("on_esc") {
+                config.on_esc =
+                    Some(match on_esc.as_str() {
+                        Some("terminate") => OnEsc::Terminate,
+                        Some("skip_question") => OnEsc::SkipQuestion,
+                        Some("ignore") => OnEsc::Ignore,
+                        _ => return Err(Error::Invalid(
+                            "`keybindings.on_esc` must be one of \"terminate\", \"skip_question\" \
+                             or \"ignore\"",
+                        )),
+                    });
+            }
+
+            if let Some(back) = table.get("back") {
+                config.back_key = Some(
+                    back.as_str()
+                        .and_then(parse_key)
+                        .ok_or(Error::Invalid("`keybindings.back` must be a key"))?,
+                );
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Applies the configuration to all prompts created after this call.
+    ///
+    /// The configuration applies to the whole process. Options which are not set are left as
+    /// they were.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    pub fn apply(self) {
+        if let Some(set) = self.symbols {
+            symbols::set(set);
+        }
+
+        if let Some(page_size) = self.page_size {
+            assert!(page_size >= 5, "page size can be a minimum of 5");
+            crate::question::set_default_page_size(page_size);
+        }
+
+        if let Some(should_loop) = self.should_loop {
+            crate::question::set_default_should_loop(should_loop);
+        }
+
+        if let Some(on_esc) = self.on_esc {
+            crate::question::set_default_on_esc(on_esc);
+        }
+
+        if let Some(hide_cursor) = self.hide_cursor {
+            crate::question::set_default_hide_cursor(hide_cursor);
+        }
+
+        if let Some(theme) = self.theme {
+            style::set_theme(theme);
+        }
+
+        if let Some(back_key) = self.back_key {
+            crate::prompt_module::set_default_back_key(back_key);
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+fn parse_char(value: &toml::Value) -> Option<char> {
+    let mut chars = value.as_str()?.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "config")]
+fn parse_color(value: &toml::Value) -> Option<Color> {
+    if let Some(ansi) = value.as_integer() {
+        return u8::try_from(ansi).ok().map(Color::Ansi);
+    }
+
+    let color = value.as_str()?;
+
+    if let Some(hex) = color.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    Some(match color {
+        "reset" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "grey" => Color::Grey,
+        "dark_grey" => Color::DarkGrey,
+        "light_red" => Color::LightRed,
+        "light_green" => Color::LightGreen,
+        "light_yellow" => Color::LightYellow,
+        "light_blue" => Color::LightBlue,
+        "light_magenta" => Color::LightMagenta,
+        "light_cyan" => Color::LightCyan,
+        "white" => Color::White,
+        _ => return None,
+    })
+}
+
+/// Parses a key such as `"ctrl+b"`, made of modifiers followed by the name of the key.
+#[cfg(feature = "config")]
+fn parse_key(key: &str) -> Option<KeyEvent> {
+    let (prefix, name) = match key.rsplit_once('+') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+
+    let mut modifiers = KeyModifiers::empty();
+    for modifier in prefix.into_iter().flat_map(|prefix| prefix.split('+')) {
+        modifiers |= match modifier {
+            "ctrl" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+    }
+
+    let code = match name {
+        "backspace" => KeyCode::Backspace,
+        "enter" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "page_up" => KeyCode::PageUp,
+        "page_down" => KeyCode::PageDown,
+        "tab" if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        "tab" => KeyCode::Tab,
+        "back_tab" => KeyCode::BackTab,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "esc" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        name => match name.strip_prefix('f').map(str::parse) {
+            Some(Ok(n)) if (1..=12).contains(&n) => KeyCode::F(n),
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return None,
+                }
+            }
+        },
+    };
+
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// The path of the config file, if the config directory could be found.
+#[cfg(feature = "config")]
+#[cfg_attr(docsrs, doc(cfg(feature = "config")))]
+pub fn path() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let dir = env::var_os("APPDATA").map(PathBuf::from);
+
+    #[cfg(not(windows))]
+    let dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+    dir.map(|dir| dir.join("requestty.toml"))
+}
+
+/// Loads the config file at [`path`] and [applies](Config::apply) it.
+///
+/// It is not an error for the config file to not exist, in which case nothing is changed. The
+/// loaded configuration is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// requestty::config::load().expect("invalid requestty config");
+///
+/// // All prompts asked from here on use the user's configuration
+/// ```
+#[cfg(feature = "config")]
+#[cfg_attr(docsrs, doc(cfg(feature = "config")))]
+pub fn load() -> Result<Config, Error> {
+    let path = match path() {
+        Some(path) => path,
+        None => return Ok(Config::default()),
+    };
+
+    let config = match fs::read_to_string(path) {
+        Ok(s) => Config::from_toml(&s)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+        Err(e) => return Err(e.into()),
+    };
+
+    config.clone().apply();
+    Ok(config)
+}
+
+/// The errors that can occur while loading the configuration.
+#[cfg(feature = "config")]
+#[cfg_attr(docsrs, doc(cfg(feature = "config")))]
+#[derive(Debug)]
+pub enum Error {
+    /// The config file could not be read.
+    IoError(io::Error),
+    /// The config file is not valid toml.
+    Parse(toml::de::Error),
+    /// The config file contains an invalid value.
+    Invalid(&'static str),
+}
+
+#[cfg(feature = "config")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::IoError(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            Error::Invalid(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IoError(e) => write!(fmt, "IoError: {}", e),
+            Error::Parse(e) => write!(fmt, "ParseError: {}", e),
+            Error::Invalid(e) => write!(fmt, "InvalidConfig: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Self::Parse(e)
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+
+use crate::{Answer, Answers, CustomAnswer, ExpandItem, ListItem};
+
+/// Types which can be built from [`Answers`].
+///
+/// With the `macros` feature, this can be derived for structs with named fields. Each field is
+/// taken from the answer to the question with the same name, which can be changed with
+/// `#[answer(name = "...")]`. The type of each field must implement [`FromAnswer`].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "macros", doc = "```")]
+#[cfg_attr(not(feature = "macros"), doc = "```ignore")]
+/// use requestty::{Answer, Answers, FromAnswers};
+///
+/// #[derive(FromAnswers)]
+/// struct Config {
+///     name: String,
+///     age: i64,
+///     colors: Vec<String>,
+///     #[answer(name = "network.proxy")]
+///     proxy: Option<String>,
+/// }
+///
+/// let answers: Answers = vec![
+///     ("name".to_owned(), Answer::String("Ferris".into())),
+///     ("age".to_owned(), Answer::Int(7)),
+///     ("colors".to_owned(), Answer::ListItems(vec![(1, "Orange").into()])),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let config = Config::from_answers(&answers)?;
+/// assert_eq!(config.name, "Ferris");
+/// assert_eq!(config.colors, ["Orange"]);
+/// assert_eq!(config.proxy, None);
+/// # Result::<_, requestty::FromAnswersError>::Ok(())
+/// ```
+pub trait FromAnswers: Sized {
+    /// Builds `Self` from the answers, failing if an answer is missing or has the wrong type.
+    fn from_answers(answers: &Answers) -> Result<Self, FromAnswersError>;
+}
+
+/// Types which can be taken from a single [`Answer`], used by [`FromAnswers`].
+pub trait FromAnswer: Sized {
+    /// What the answer is expected to be, for example `a string`, used in errors.
+    const EXPECTED: &'static str;
+
+    /// Converts the answer, returning `None` if it has the wrong type.
+    fn from_answer(answer: &Answer) -> Option<Self>;
+
+    /// The value to use if there is no answer or the question was skipped.
+    ///
+    /// By default, it is `None` and a missing answer is an error.
+    fn missing() -> Option<Self> {
+        None
+    }
+}
+
+/// The error returned by [`FromAnswers`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromAnswersError {
+    /// The question was not answered, or it was skipped.
+    Missing {
+        /// The name of the question
+        name: String,
+    },
+    /// The answer does not have the type of the field.
+    Mismatched {
+        /// The name of the question
+        name: String,
+        /// What the answer was expected to be
+        expected: &'static str,
+        /// What the answer was
+        found: &'static str,
+    },
+}
+
+impl std::error::Error for FromAnswersError {}
+
+impl fmt::Display for FromAnswersError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromAnswersError::Missing { name } => write!(fmt, "'{}' was not answered", name),
+            FromAnswersError::Mismatched {
+                name,
+                expected,
+                found,
+            } => write!(
+                fmt,
+                "expected the answer to '{}' to be {}, but it was {}",
+                name, expected, found
+            ),
+        }
+    }
+}
+
+/// Takes the answer to the question `name`, used by the derived [`FromAnswers`].
+#[doc(hidden)]
+pub fn take_answer<T: FromAnswer>(answers: &Answers, name: &str) -> Result<T, FromAnswersError> {
+    match answers.get_path(name) {
+        None | Some(Answer::Skipped) => T::missing().ok_or_else(|| FromAnswersError::Missing {
+            name: name.to_owned(),
+        }),
+        Some(answer) => T::from_answer(answer).ok_or_else(|| FromAnswersError::Mismatched {
+            name: name.to_owned(),
+            expected: T::EXPECTED,
+            found: describe(answer),
+        }),
+    }
+}
+
+fn describe(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::String(_) => "a string",
+        Answer::ListItem(_) => "a list item",
+        Answer::ExpandItem(_) => "an expand item",
+        Answer::Int(_) => "an int",
+        Answer::Float(_) => "a float",
+        Answer::Bool(_) => "a bool",
+        Answer::OptionBool(_) => "an optional bool",
+        Answer::ListItems(_) => "a list of list items",
+        Answer::Map(_) => "a map of answers",
+        Answer::List(_) => "a list of answers",
+        Answer::Skipped => "skipped",
+        Answer::Custom(_) => "a custom answer",
+    }
+}
+
+macro_rules! impl_from_answer {
+    ($ty:ty, $expected:literal, $answer:pat => $value:expr) => {
+        impl FromAnswer for $ty {
+            const EXPECTED: &'static str = $expected;
+
+            fn from_answer(answer: &Answer) -> Option<Self> {
+                match answer {
+                    $answer => Some($value),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_from_answer!(String, "a string", Answer::String(s) => s.clone());
+impl_from_answer!(i64, "an int", Answer::Int(i) => *i);
+impl_from_answer!(f64, "a float", Answer::Float(f) => *f);
+impl_from_answer!(bool, "a bool", Answer::Bool(b) => *b);
+impl_from_answer!(ListItem, "a list item", Answer::ListItem(item) => item.clone());
+impl_from_answer!(ExpandItem, "an expand item", Answer::ExpandItem(item) => item.clone());
+impl_from_answer!(CustomAnswer, "a custom answer", Answer::Custom(custom) => custom.clone());
+impl_from_answer!(Vec<ListItem>, "a list of list items", Answer::ListItems(items) => items.clone());
+impl_from_answer!(Answers, "a map of answers", Answer::Map(map) => map.clone());
+impl_from_answer!(Vec<Answers>, "a list of answers", Answer::List(list) => list.clone());
+impl_from_answer!(
+    Vec<String>,
+    "a list of list items",
+    Answer::ListItems(items) => items.iter().map(|item| item.text.clone()).collect()
+);
+
+impl FromAnswer for Answer {
+    const EXPECTED: &'static str = "any answer";
+
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        Some(answer.clone())
+    }
+}
+
+/// A missing or skipped answer is `None`.
+impl<T: FromAnswer> FromAnswer for Option<T> {
+    const EXPECTED: &'static str = T::EXPECTED;
+
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        T::from_answer(answer).map(Some)
+    }
+
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_answer() {
+        let mut answers = Answers::default();
+        answers.insert("name".into(), Answer::String("foo".into()));
+        answers.insert("skipped".into(), Answer::Skipped);
+        answers.insert(
+            "colors".into(),
+            Answer::ListItems(vec![(0, "red").into(), (2, "blue").into()]),
+        );
+
+        assert_eq!(take_answer::<String>(&answers, "name"), Ok("foo".into()));
+        assert_eq!(
+            take_answer::<Vec<String>>(&answers, "colors"),
+            Ok(vec!["red".into(), "blue".into()])
+        );
+        assert_eq!(take_answer::<Option<bool>>(&answers, "skipped"), Ok(None));
+        assert_eq!(take_answer::<Option<bool>>(&answers, "missing"), Ok(None));
+
+        let err = take_answer::<i64>(&answers, "skipped").unwrap_err();
+        assert_eq!(
+            err,
+            FromAnswersError::Missing {
+                name: "skipped".into()
+            }
+        );
+        assert_eq!(err.to_string(), "'skipped' was not answered");
+
+        let err = take_answer::<Option<i64>>(&answers, "name").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected the answer to 'name' to be an int, but it was a string"
+        );
+    }
+
+    #[test]
+    fn test_take_nested_answer() {
+        let mut database = Answers::default();
+        database.insert("host".into(), Answer::String("localhost".into()));
+
+        let mut answers = Answers::default();
+        answers.insert("database".into(), Answer::Map(database.clone()));
+
+        assert_eq!(
+            take_answer::<String>(&answers, "database.host"),
+            Ok("localhost".into())
+        );
+        assert_eq!(take_answer::<Answers>(&answers, "database"), Ok(database));
+
+        let err = take_answer::<bool>(&answers, "database").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected the answer to 'database' to be a bool, but it was a map of answers"
+        );
+    }
+}
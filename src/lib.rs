@@ -115,8 +115,48 @@
 //! - `termion`: Enabling this feature will use the [`termion`](https://crates.io/crates/termion)
 //!   library for terminal interactions such as drawing and receiving events.
 //!
+//! - `config`: Enabling this feature will allow you to load the [configuration](config) for all
+//!   prompts from a config file with [`config::load`](config::load).
+//!
+//! - `async`: Enabling this feature will allow you to prompt without blocking while waiting for
+//!   events, using [`PromptModule::prompt_all_async_with`] and an
+//!   [`AsyncEventIterator`](prompt::events::AsyncEventIterator). The returned futures are not `Send`, so
+//!   they must be run on the current thread, for example with `tokio::task::LocalSet`. Custom
+//!   prompts are still asked synchronously, and block the thread while waiting for events.
+//!
+//! - `event-stream`: Enabling this feature will enable `async` and `crossterm`, and provide
+//!   [`PromptModule::prompt_all_async`] which uses the `crossterm` event stream.
+//!
+//! - `serde`: Enabling this feature will implement `Serialize` and `Deserialize` for [`Answers`]
+//!   and the answer types, so that they can be saved to and loaded from formats like JSON.
+//!
+//! - `no-style`: Enabling this feature will draw the prompts without any colors or attributes, and
+//!   use the [`ASCII`](symbols::ASCII) symbol set by default. This is meant for small builds and
+//!   terminals which don't support styling. The prompts otherwise work the same.
+//!
+//! - `hyperlinks`: Enabling this feature will write text styled with
+//!   [`Stylize::hyperlink`](prompt::style::Stylize::hyperlink) as [OSC 8] hyperlinks, which can
+//!   be clicked in terminals that support them. Other terminals show the text as it is.
+//!
+//! - `bracketed-paste`: Enabling this feature will ask the terminal to mark pasted text, so that it
+//!   is inserted into text prompts all at once instead of one key at a time. This also stops line
+//!   breaks in the pasted text from submitting the prompt.
+//!
+//! - `signals`: Enabling this feature will restore the terminal if the process receives `SIGTERM`,
+//!   `SIGHUP` or `SIGQUIT` while a prompt is shown, so that it is not left in raw mode with the
+//!   cursor hidden. The signal then terminates the process as it otherwise would. This only has an
+//!   effect on unix.
+//!
+//! - `tracing`: Enabling this feature will emit [`tracing`] events while questions are asked. Each
+//!   question is asked in a `question` span with its `name` and `kind`, which has `DEBUG` events
+//!   for when it is asked, answered or skipped and whenever validation fails, and `TRACE` events for
+//!   every key handled. Answers are never part of the events, but the keys are, so the `TRACE`
+//!   level should not be recorded for prompts which ask for secrets.
+//!
 //! [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
+//! [`tracing`]: https://crates.io/crates/tracing
 //! [auto completions]: crate::question::InputBuilder::auto_complete
+//! [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
 //!
 //! # Examples
 //!
@@ -148,8 +188,14 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod answer;
+pub mod config;
+mod from_answers;
 mod prompt_module;
 pub mod question;
+pub mod test;
+pub mod validators;
+
+use std::fmt;
 
 use ui::{backend::Backend, events::EventIterator};
 
@@ -194,6 +240,48 @@ use ui::{backend::Backend, events::EventIterator};
 ///             sep "Separator text!",
 ///         ],
 ///     },
+///     OrderSelect {
+///         name: "order",
+///         // The choices of an `OrderSelect` can be reordered by the user, and the answer is the
+///         // choices in their new order.
+///         choices: ["First", "Second", "Third"],
+///     },
+/// ];
+/// ```
+///
+/// # Custom Prompts
+///
+/// Questions with a custom [`Prompt`](prompt::Prompt) can be written with the `Custom` kind,
+/// which takes the prompt in the `prompt` field. The `Plugin` kind is the same, except that it
+/// takes the prompt in the `plugin` field. Both correspond to [`Question::custom`].
+/// ```
+/// use requestty::{prompt::*, questions};
+///
+/// #[derive(Debug)]
+/// struct Answer42;
+///
+/// impl Prompt for Answer42 {
+///     fn ask(
+///         self,
+///         _message: String,
+///         _answers: &Answers,
+///         _backend: &mut dyn Backend,
+///         _events: &mut dyn EventIterator,
+///     ) -> requestty::Result<Option<Answer>> {
+///         Ok(Some(Answer::Int(42)))
+///     }
+/// }
+///
+/// let questions = questions![
+///     Custom {
+///         name: "custom",
+///         prompt: Answer42,
+///     },
+///     Plugin {
+///         name: "plugin",
+///         message: "What is the answer?",
+///         plugin: Answer42,
+///     },
 /// ];
 /// ```
 ///
@@ -218,20 +306,40 @@ use ui::{backend::Backend, events::EventIterator};
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 pub use r#macro::questions;
 
-pub use answer::{Answer, Answers, ExpandItem, ListItem};
-pub use prompt_module::PromptModule;
-pub use question::{Choice::Choice, Choice::DefaultSeparator, Choice::Separator, Question};
-pub use ui::{symbols, ErrorKind, OnEsc, Result};
+/// Derives [`FromAnswers`](trait@FromAnswers) for a struct with named fields.
+///
+/// See [`FromAnswers`](trait@FromAnswers) for more information.
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use r#macro::FromAnswers;
+
+pub use answer::{Answer, Answers, CustomAnswer, ExpandItem, ListItem};
+pub use config::Config;
+pub use from_answers::{FromAnswer, FromAnswers, FromAnswersError};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::from_answers::take_answer;
+}
+pub use prompt_module::{PromptIter, PromptModule, Then};
+pub use question::{
+    Choice::Choice, Choice::DefaultSeparator, Choice::Separator, Question, Repeat, Section,
+};
+pub use ui::{accessibility, symbols, ErrorKind, OnEsc, Result};
 
 /// A module that re-exports all the things required for writing custom [`Prompt`]s.
 ///
 /// [`Prompt`]: prompt::Prompt
 pub mod prompt {
-    pub use crate::{question::Prompt, Answer, Answers};
+    pub use crate::{
+        question::{write_finished, Prompt},
+        Answer, Answers,
+    };
     pub use ui::{
         backend::{self, Backend},
         events::{self, EventIterator},
-        style,
+        layout::{self, Layout, RenderRegion},
+        style, widgets, Widget,
     };
 }
 
@@ -260,6 +368,52 @@ pub fn prompt_one<'a, I: Into<Question<'a>>>(question: I) -> Result<Answer> {
     prompt_one_with(question.into(), &mut stdout, &mut events)
 }
 
+/// Prompt all the questions in the given iterator on the controlling terminal (`/dev/tty`).
+///
+/// Unlike [`prompt`](prompt()), the questions are shown and answered even if stdin or stdout are
+/// redirected, so that the answers can be written to stdout. It fails if the process has no
+/// controlling terminal.
+///
+/// # Examples
+///
+/// ```no_run
+/// use requestty::Question;
+///
+/// let answers = requestty::prompt_tty(vec![Question::input("name").build()])?;
+/// // stdout can be redirected to a file, without hiding the question
+/// println!("{}", answers["name"].as_string().unwrap());
+/// # Result::<_, requestty::ErrorKind>::Ok(())
+/// ```
+#[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(unix, any(feature = "crossterm", feature = "termion"))))
+)]
+pub fn prompt_tty<'a, Q>(questions: Q) -> Result<Answers>
+where
+    Q: IntoIterator<Item = Question<'a>>,
+{
+    PromptModule::new(questions.into_iter()).prompt_all_tty()
+}
+
+/// Prompt the given question on the controlling terminal (`/dev/tty`), even if stdin or stdout
+/// are redirected. See [`prompt_tty`] for more details.
+///
+/// # Panics
+///
+/// This will panic if `when` on the [`Question`] prevents the question from being asked.
+#[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(unix, any(feature = "crossterm", feature = "termion"))))
+)]
+pub fn prompt_one_tty<'a, I: Into<Question<'a>>>(question: I) -> Result<Answer> {
+    let mut backend = ui::backend::get_tty_backend()?;
+    let mut events = ui::events::get_tty_events()?;
+
+    prompt_one_with(question.into(), &mut backend, &mut events)
+}
+
 /// Prompt all the questions in the given iterator, with the given [`Backend`] and [`EventIterator`].
 pub fn prompt_with<'a, Q, B, E>(questions: Q, backend: &mut B, events: &mut E) -> Result<Answers>
 where
@@ -270,6 +424,44 @@ where
     PromptModule::new(questions.into_iter()).prompt_all_with(backend, events)
 }
 
+/// Answer all the questions in the given iterator with `answers`, without prompting.
+///
+/// Nothing is rendered, and questions which are not in `answers` fail with
+/// [`ErrorKind::MissingAnswer`], unless `when` prevents them from being asked. This is useful to
+/// run programs in environments without a user, such as CI pipelines. See
+/// [`PromptModule::interactive`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{Answer, Answers, ErrorKind, Question};
+///
+/// let answers: Answers = vec![("name".to_owned(), Answer::String("requestty".into()))]
+///     .into_iter()
+///     .collect();
+///
+/// let questions = vec![Question::input("name").build()];
+/// assert_eq!(requestty::prompt_with_answers(questions, answers.clone())?, answers);
+///
+/// let questions = vec![Question::input("age").build()];
+/// let err = requestty::prompt_with_answers(questions, answers).unwrap_err();
+/// assert!(matches!(err, ErrorKind::MissingAnswer(name) if name == "age"));
+/// # Result::<_, ErrorKind>::Ok(())
+/// ```
+pub fn prompt_with_answers<'a, Q>(questions: Q, answers: Answers) -> Result<Answers>
+where
+    Q: IntoIterator<Item = Question<'a>>,
+{
+    // Nothing is rendered or read when the module is not interactive
+    PromptModule::new(questions.into_iter())
+        .with_answers(answers)
+        .interactive(false)
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((1, 1).into()),
+            &mut ui::events::TestEvents::empty(),
+        )
+}
+
 /// Prompt the given question, with the given [`Backend`] and [`EventIterator`].
 ///
 /// # Panics
@@ -281,7 +473,113 @@ where
     B: Backend,
     E: EventIterator,
 {
-    let ans = question.into().ask(&Answers::default(), backend, events)?;
+    match question
+        .into()
+        .ask(&Answers::default(), backend, events, None)?
+    {
+        question::Asked::Answered(_, ans) => Ok(ans),
+        _ => panic!("The question wasn't asked"),
+    }
+}
+
+/// Prompt the given question starting from `initial`, with the given [`Backend`] and
+/// [`EventIterator`]. If it fails, what was entered so far is returned along with the error.
+///
+/// Only text based questions (`input`, `path`, `int`, `float`, `multiline_input` and
+/// `autocomplete`) have a partial answer, which is the text that was entered as an
+/// [`Answer::String`]. The partial answer can be given as `initial` to let the user resume from
+/// where they left off. Other questions ignore `initial`, and never have a partial answer.
+///
+/// # Panics
+///
+/// This will panic if `when` on the [`Question`] prevents the question from being asked.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{
+///     prompt::{
+///         backend::TestBackend,
+///         events::{KeyCode, KeyEvent, KeyModifiers, TestEvents},
+///     },
+///     Answer, ErrorKind, Question,
+/// };
+///
+/// let mut backend = TestBackend::new((50, 10).into());
+/// let mut events = TestEvents::new(vec![
+///     KeyCode::Char('h').into(),
+///     KeyCode::Char('i').into(),
+///     KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+/// ]);
+///
+/// let question = Question::input("greeting").build();
+/// let err = requestty::prompt_one_with_initial(question, None, &mut backend, &mut events)
+///     .unwrap_err();
+///
+/// assert!(matches!(err.error, ErrorKind::Interrupted));
+/// assert_eq!(err.partial, Some(Answer::String("hi".into())));
+///
+/// // Resume from what was typed before
+/// let mut events = TestEvents::new(vec![KeyCode::Char('!').into(), KeyCode::Enter.into()]);
+/// let question = Question::input("greeting").build();
+/// let answer = requestty::prompt_one_with_initial(
+///     question,
+///     err.partial.as_ref(),
+///     &mut backend,
+///     &mut events,
+/// )?;
+///
+/// assert_eq!(answer, Answer::String("hi!".into()));
+/// # Ok::<(), requestty::ErrorKind>(())
+/// ```
+pub fn prompt_one_with_initial<'a, Q, B, E>(
+    question: Q,
+    initial: Option<&Answer>,
+    backend: &mut B,
+    events: &mut E,
+) -> std::result::Result<Answer, Abandoned>
+where
+    Q: Into<Question<'a>>,
+    B: Backend,
+    E: EventIterator,
+{
+    let mut question = question.into();
+    if let Some(initial) = initial {
+        question.set_initial_answer(initial);
+    }
+
+    let mut partial = None;
+
+    match question.ask_with_partial(&Answers::default(), backend, events, None, &mut partial) {
+        Ok(question::Asked::Answered(_, ans)) => Ok(ans),
+        Ok(_) => panic!("The question wasn't asked"),
+        Err(error) => Err(Abandoned { error, partial }),
+    }
+}
+
+/// The error returned by [`prompt_one_with_initial`].
+#[derive(Debug)]
+pub struct Abandoned {
+    /// The error which stopped the question.
+    pub error: ErrorKind,
+    /// What was entered before the error, if the question is text based.
+    pub partial: Option<Answer>,
+}
+
+impl std::error::Error for Abandoned {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for Abandoned {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(fmt)
+    }
+}
 
-    Ok(ans.expect("The question wasn't asked").1)
+impl From<Abandoned> for ErrorKind {
+    fn from(abandoned: Abandoned) -> Self {
+        abandoned.error
+    }
 }
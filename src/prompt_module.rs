@@ -1,6 +1,37 @@
-use ui::{backend::Backend, events::EventIterator};
+use std::{
+    fmt,
+    iter::Chain,
+    sync::{Arc, Mutex},
+    vec,
+};
 
-use crate::{Answer, Answers, Question};
+use once_cell::sync::Lazy;
+
+#[cfg(feature = "async")]
+use ui::events::AsyncEventIterator;
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent},
+};
+
+#[cfg(feature = "async")]
+use crate::question::AsyncDriver;
+use crate::{
+    question::{block_on, Asked, Driver, SyncDriver},
+    Answer, Answers, Question, Repeat, Section,
+};
+
+static DEFAULT_BACK_KEY: Lazy<Mutex<KeyEvent>> = Lazy::new(|| Mutex::new(KeyCode::BackTab.into()));
+
+/// The key which goes back to the previous question in modules which do not set it explicitly.
+fn default_back_key() -> KeyEvent {
+    *DEFAULT_BACK_KEY.lock().expect("back key poisoned")
+}
+
+/// Set the key which goes back to the previous question in modules which do not set it explicitly.
+pub(crate) fn set_default_back_key(back_key: KeyEvent) {
+    *DEFAULT_BACK_KEY.lock().expect("back key poisoned") = back_key;
+}
 
 /// A collection of questions and answers for previously answered questions.
 ///
@@ -9,9 +40,25 @@ use crate::{Answer, Answers, Question};
 ///
 /// [`prompt`]: crate::prompt()
 #[derive(Debug, Clone, PartialEq)]
-pub struct PromptModule<Q> {
+pub struct PromptModule<Q: Iterator> {
     questions: Q,
     answers: Answers,
+    defaults: Answers,
+    record_skipped: bool,
+    type_ahead: bool,
+    interactive: bool,
+    numbering: Option<Numbering>,
+    index: usize,
+    /// Generates the questions added with [`then`](PromptModule::then) once they are reached
+    generate: Option<Generate<Q>>,
+    allow_back: bool,
+    back_key: KeyEvent,
+    quiet: bool,
+    /// The questions asked so far, in the order they were asked, so that they can be asked again
+    history: Vec<Taken<Q::Item>>,
+    /// Questions which have been gone back to, which are asked again before the rest. The last
+    /// one is asked first.
+    pending: Vec<Q::Item>,
 }
 
 impl<'a, Q> PromptModule<Q>
@@ -26,6 +73,18 @@ where
         Self {
             answers: Answers::default(),
             questions: questions.into_iter(),
+            defaults: Answers::default(),
+            record_skipped: false,
+            type_ahead: true,
+            interactive: true,
+            numbering: None,
+            index: 0,
+            generate: None,
+            allow_back: false,
+            back_key: default_back_key(),
+            quiet: false,
+            history: Vec::new(),
+            pending: Vec::new(),
         }
     }
 
@@ -35,6 +94,403 @@ where
         self
     }
 
+    /// Use the given answers, for example from a previous run, as the defaults of the questions
+    /// with the same names.
+    ///
+    /// Unlike [`with_answers`](PromptModule::with_answers), the questions are still asked, but the
+    /// user can just press `Enter` to keep the previous answer. The previous answer is used as the
+    /// text of `input`, `editor` and `multiline_input`, the number of `int`, `float` and `slider`,
+    /// the value of `confirm`, the key of `expand`, the selected choice of `select` and
+    /// `raw_select`, and the checked choices of `multi_select`.
+    ///
+    /// Answers of the wrong type, or for choices which no longer exist are ignored. `password`,
+    /// `order_select`, `autocomplete` and custom prompts are never prefilled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, PromptModule, Question};
+    ///
+    /// let previous: Answers = vec![("name".to_owned(), Answer::String("requestty".to_owned()))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let module = PromptModule::new(vec![Question::input("name").build()]).with_defaults(previous);
+    /// ```
+    pub fn with_defaults(mut self, defaults: Answers) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Record [`Answer::Skipped`] for questions which are skipped, instead of leaving them out of
+    /// the answers.
+    ///
+    /// A question is skipped if `when` prevents it from being asked, or if it is skipped by
+    /// pressing `Esc` with [`OnEsc::SkipQuestion`]. Questions which are not asked because they
+    /// already have an answer are left as they are.
+    ///
+    /// Note that this will affect any `when` or `ask_if_answered` that check if a skipped question
+    /// is in the answers.
+    ///
+    /// By default, this is `false`.
+    ///
+    /// [`OnEsc::SkipQuestion`]: crate::OnEsc::SkipQuestion
+    pub fn record_skipped(mut self, record_skipped: bool) -> Self {
+        self.record_skipped = record_skipped;
+        self
+    }
+
+    /// Give the keys typed before a question is shown to that question.
+    ///
+    /// Fast users may start typing the answer to the next question before it has been rendered.
+    /// If this is `false`, those keys are discarded once the question is shown instead.
+    ///
+    /// Regardless of this, if asking a question fails (for example, if it is aborted with
+    /// `Ctrl+C`), any keys typed ahead are discarded so that they are not given to whatever reads
+    /// the input next, such as the shell.
+    ///
+    /// Whether the keys can be discarded depends on the [`EventIterator`] used, see
+    /// [`EventIterator::discard_pending`].
+    ///
+    /// By default, this is `true`.
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.type_ahead = type_ahead;
+        self
+    }
+
+    /// Ask the questions on the terminal.
+    ///
+    /// If this is `false`, nothing is rendered and no events are read. Instead, questions which
+    /// already have an answer (for example, from [`with_answers`](PromptModule::with_answers)) use
+    /// it, even if they set `ask_if_answered`, and questions which `when` prevents from being asked
+    /// are skipped. Any other question fails with [`ErrorKind::MissingAnswer`]. This is useful to
+    /// run programs in environments without a user, such as CI pipelines.
+    ///
+    /// See also [`prompt_with_answers`](crate::prompt_with_answers).
+    ///
+    /// By default, this is `true`.
+    ///
+    /// [`ErrorKind::MissingAnswer`]: crate::ErrorKind::MissingAnswer
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Prefix the message of each question with its number, for example "3. What is your name?".
+    ///
+    /// The number of a question is its position in the module, starting at 1. Questions which are
+    /// skipped or already answered still take up a number.
+    ///
+    /// To change how the message is numbered, use [`numbered_with`](PromptModule::numbered_with).
+    ///
+    /// By default, this is `false`.
+    pub fn numbered(mut self, numbered: bool) -> Self {
+        self.numbering = if numbered {
+            Some(Numbering::Default)
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Number the message of each question with the given function.
+    ///
+    /// It is passed the number of the question, the total number of questions and the message
+    /// which would have been shown otherwise, and returns the message to show instead. The number
+    /// starts at 1, and the total is only known if the questions iterator has an exact
+    /// [`size_hint`](Iterator::size_hint).
+    ///
+    /// See also [`numbered`](PromptModule::numbered).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::int("age").build(),
+    /// ])
+    /// .numbered_with(|number, total, message| match total {
+    ///     Some(total) => format!("[{}/{}] {}", number, total, message),
+    ///     None => format!("[{}] {}", number, message),
+    /// });
+    /// ```
+    pub fn numbered_with<F>(mut self, numbering: F) -> Self
+    where
+        F: Fn(usize, Option<usize>, &str) -> String + Send + Sync + 'static,
+    {
+        self.numbering = Some(Numbering::Custom(Arc::new(numbering)));
+        self
+    }
+
+    /// Go back to the previous question when the [`back_key`](PromptModule::back_key) is pressed
+    /// during any question.
+    ///
+    /// The question that was being asked is cleared, and the previous question is asked again
+    /// below its old answer. The old answer is removed from the answers, and is used as the default
+    /// like with [`with_defaults`](PromptModule::with_defaults) instead. Pressing the key during
+    /// the first question asks it again. Answers given to [`prompt_each_with`](PromptModule::prompt_each_with) or yielded by
+    /// [`prompt_iter_with`](PromptModule::prompt_iter_with) are not taken back.
+    ///
+    /// A copy of each question is kept before it is asked, so that it can be asked again. Its
+    /// `when`, `message` and other functions are called again when it is, but questions generated
+    /// by [`then`](PromptModule::then) are not generated again. Custom prompts and
+    /// [`repeat`](PromptModule::repeat) groups cannot be copied, so it is not possible to go back
+    /// past them, or from them.
+    ///
+    /// By default, this is `false`.
+    pub fn allow_back(mut self, allow_back: bool) -> Self {
+        self.allow_back = allow_back;
+        self
+    }
+
+    /// The key which goes back to the previous question if [`allow_back`] is enabled.
+    ///
+    /// Modifiers which are reported along with the key are ignored, so `BackTab` still matches if
+    /// the terminal reports `Shift` with it. The key takes priority over the keys of the
+    /// questions, including `Esc`, so for example [`KeyCode::Esc`] can be used to go back.
+    ///
+    /// By default, this is [`KeyCode::BackTab`] (`Shift+Tab`), unless it is changed by the
+    /// [`Config`](crate::Config).
+    ///
+    /// [`allow_back`]: PromptModule::allow_back
+    /// [`KeyCode::Esc`]: crate::prompt::events::KeyCode::Esc
+    /// [`KeyCode::BackTab`]: crate::prompt::events::KeyCode::BackTab
+    pub fn back_key(mut self, back_key: KeyEvent) -> Self {
+        self.back_key = back_key;
+        self
+    }
+
+    /// Erase the line shown once each question is answered, instead of keeping it.
+    ///
+    /// This is the same as setting `hide_final(true)` on every question, for example
+    /// [`InputBuilder::hide_final`]. Once all the questions are answered, the terminal is left as
+    /// it was before they were asked.
+    ///
+    /// [`InputBuilder::hide_final`]: crate::question::InputBuilder::hide_final
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Add a [`Section`] of questions, which are asked after the questions already in the module.
+    ///
+    /// A header is shown before the first question of the section is asked, and the answers are
+    /// stored under the name of the section. See [`Section`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question, Section};
+    ///
+    /// let module = PromptModule::new(vec![Question::input("name").build()])
+    ///     .section(Section::new(
+    ///         "network",
+    ///         "Network settings",
+    ///         vec![Question::input("host").build()],
+    ///     ))
+    ///     .section(Section::new(
+    ///         "storage",
+    ///         "Storage settings",
+    ///         vec![Question::path("directory").build()],
+    ///     ));
+    /// ```
+    pub fn section(
+        self,
+        section: Section<'a>,
+    ) -> PromptModule<Chain<Q, vec::IntoIter<Question<'a>>>> {
+        self.chain(section.into_questions())
+    }
+
+    /// Add a [`Repeat`] group of questions, which is asked after the questions already in the
+    /// module.
+    ///
+    /// The questions are asked again until the user is done, and the answers of each time are
+    /// collected in an [`Answer::List`] under the name of the group. See [`Repeat`] for more
+    /// details.
+    pub fn repeat(self, repeat: Repeat<'a>) -> PromptModule<Chain<Q, vec::IntoIter<Question<'a>>>> {
+        self.chain(vec![repeat.into_question()].into_iter())
+    }
+
+    /// Add questions generated from the answers given so far, which are asked after the questions
+    /// already in the module.
+    ///
+    /// `generate` is called once all the questions before it have been asked, so it can create
+    /// questions based on any of their answers, for example one question for every choice selected
+    /// in a [`multi_select`](Question::multi_select). The generated questions are then asked like
+    /// the rest, and their answers are added to the answers of the module.
+    ///
+    /// More questions can be generated after these with another call to `then`.
+    ///
+    /// # Panics
+    ///
+    /// Sections and repeat groups cannot be added after `then`, and will panic. Return their
+    /// questions from `then` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![Question::multi_select("languages")
+    ///     .message("Which languages do you know?")
+    ///     .choices(vec!["Rust", "Python", "Go"])
+    ///     .build()])
+    /// .then(|answers| {
+    ///     answers["languages"]
+    ///         .as_list_items()
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|language| {
+    ///             Question::int(format!("{}-years", language.text))
+    ///                 .message(format!("How many years have you used {}?", language.text))
+    ///                 .build()
+    ///         })
+    ///         .collect()
+    /// });
+    /// ```
+    pub fn then<F>(self, generate: F) -> PromptModule<Then<'a, Q>>
+    where
+        F: FnOnce(&Answers) -> Vec<Question<'a>> + 'a,
+    {
+        PromptModule {
+            questions: Then {
+                questions: self.questions,
+                generate_previous: self.generate,
+                generate: Some(Box::new(generate)),
+                generated: Vec::new().into_iter(),
+            },
+            answers: self.answers,
+            defaults: self.defaults,
+            record_skipped: self.record_skipped,
+            type_ahead: self.type_ahead,
+            interactive: self.interactive,
+            numbering: self.numbering,
+            index: self.index,
+            generate: Some(Generate(Then::generate)),
+            allow_back: self.allow_back,
+            back_key: self.back_key,
+            quiet: self.quiet,
+            history: self.history,
+            pending: self.pending,
+        }
+    }
+
+    /// Adds `questions` after the questions already in the module.
+    fn chain(
+        self,
+        questions: vec::IntoIter<Question<'a>>,
+    ) -> PromptModule<Chain<Q, vec::IntoIter<Question<'a>>>> {
+        assert!(
+            self.generate.is_none(),
+            "questions cannot be added after `then`, return them from `then` instead"
+        );
+
+        PromptModule {
+            questions: self.questions.chain(questions),
+            answers: self.answers,
+            defaults: self.defaults,
+            record_skipped: self.record_skipped,
+            type_ahead: self.type_ahead,
+            interactive: self.interactive,
+            numbering: self.numbering,
+            index: self.index,
+            generate: None,
+            allow_back: self.allow_back,
+            back_key: self.back_key,
+            quiet: self.quiet,
+            history: self.history,
+            pending: self.pending,
+        }
+    }
+
+    /// The key given to the questions to go back, if going back is allowed.
+    fn active_back_key(&self) -> Option<KeyEvent> {
+        if self.allow_back {
+            Some(self.back_key)
+        } else {
+            None
+        }
+    }
+
+    /// Goes back from the `current` question to the previous answered question, so that it is
+    /// the next question. Its answer is moved to the defaults, and the questions skipped after it
+    /// are asked again as well. If no question has been answered since the last question which
+    /// cannot be asked again, the current question is the next question instead.
+    fn go_back(&mut self, current: Question<'a>) {
+        self.pending.push(current);
+        self.index -= 1;
+
+        let start = self
+            .history
+            .iter()
+            .rposition(|taken| taken.question.is_none())
+            .map_or(0, |barrier| barrier + 1);
+
+        let previous = match self.history[start..]
+            .iter()
+            .rposition(|taken| taken.answered)
+        {
+            Some(previous) => start + previous,
+            None => return,
+        };
+
+        for taken in self.history.drain(previous..).rev() {
+            if let Some(name) = taken.name {
+                if let Some(answer) = self.answers.remove(&name) {
+                    if taken.answered {
+                        self.defaults.insert(name, answer);
+                    }
+                }
+            }
+
+            // Only entries after the last barrier are drained, so they all have a question
+            if let Some(question) = taken.question {
+                self.pending.push(question);
+                self.index -= 1;
+            }
+        }
+    }
+
+    /// Takes the next question, numbering its message if required.
+    fn next_question(&mut self) -> Option<Question<'a>> {
+        // Questions which are asked again have already been numbered
+        if let Some(question) = self.pending.pop() {
+            self.index += 1;
+            return Some(question);
+        }
+
+        let mut question = loop {
+            if let Some(question) = self.questions.next() {
+                break question;
+            }
+
+            match self.generate {
+                Some(Generate(generate)) if generate(&mut self.questions, &self.answers) => {}
+                _ => return None,
+            }
+        };
+        self.index += 1;
+
+        if self.quiet {
+            question.set_hide_final();
+        }
+
+        if let Some(ref numbering) = self.numbering {
+            let number = self.index;
+            let total = match self.questions.size_hint() {
+                (lower, Some(upper)) if lower == upper => Some(number + lower),
+                _ => None,
+            };
+            let numbering = numbering.clone();
+
+            question.map_message(move |message| numbering.format(number, total, &message));
+        }
+
+        Some(question)
+    }
+
     /// Prompt a single question with the default [`Backend`] and [`EventIterator`].
     ///
     /// This may or may not actually prompt the question based on what `when` and `ask_if_answered`
@@ -61,15 +517,210 @@ where
         B: Backend,
         E: EventIterator,
     {
-        for question in self.questions.by_ref() {
-            if let Some((name, answer)) = question.ask(&self.answers, backend, events)? {
-                return Ok(Some(self.answers.insert(name, answer)));
+        match self.next_answer_with(backend, events)? {
+            Some((name, answer)) => Ok(Some(self.answers.insert(name, answer))),
+            None => Ok(None),
+        }
+    }
+
+    /// Asks questions until one is answered, returning its name and answer without adding it to
+    /// the answers.
+    fn next_answer_with<B, E>(
+        &mut self,
+        backend: &mut B,
+        events: &mut E,
+    ) -> crate::Result<Option<(String, Answer)>>
+    where
+        B: Backend,
+        E: EventIterator,
+    {
+        let mut driver = SyncDriver::new(events, self.type_ahead);
+        block_on(self.next_answer(backend, &mut driver))
+    }
+
+    /// Asks questions with the `driver` until one is answered, returning its name and answer
+    /// without adding it to the answers.
+    async fn next_answer<B, D>(
+        &mut self,
+        backend: &mut B,
+        driver: &mut D,
+    ) -> crate::Result<Option<(String, Answer)>>
+    where
+        B: Backend,
+        D: Driver,
+    {
+        while let Some(mut question) = self.next_question() {
+            // The copy is made before the defaults are set, since they may change if it is asked
+            // again
+            let copy = if self.interactive && self.allow_back {
+                question.try_clone()
+            } else {
+                None
+            };
+
+            question.set_default_from(&self.defaults);
+
+            if !self.interactive {
+                match question.resolve(&self.answers)? {
+                    Asked::Skipped(name) if self.record_skipped => {
+                        self.answers.insert(name, Answer::Skipped);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Questions which cannot be asked again cannot be gone back from either
+            let back_key = match copy {
+                Some(_) => self.active_back_key(),
+                None => None,
+            };
+
+            let asked = match question
+                .ask_with(&self.answers, backend, driver, back_key, &mut None)
+                .await
+            {
+                Ok(asked) => asked,
+                Err(ui::ErrorKind::Back) => {
+                    if let Some(copy) = copy {
+                        self.go_back(copy);
+                    }
+                    continue;
+                }
+                Err(e) => {
+                    // The original error is more useful than one from discarding the keys
+                    let _ = driver.discard_pending();
+                    return Err(e);
+                }
+            };
+
+            let (name, answered) = match asked {
+                Asked::Answered(name, answer) => {
+                    if self.allow_back {
+                        self.history.push(Taken {
+                            question: copy,
+                            name: Some(name.clone()),
+                            answered: true,
+                        });
+                    }
+                    return Ok(Some((name, answer)));
+                }
+                Asked::Skipped(name)
+                    if self.record_skipped && !self.answers.contains_key(&name) =>
+                {
+                    self.answers.insert(name.clone(), Answer::Skipped);
+                    (Some(name), false)
+                }
+                _ => (None, false),
+            };
+
+            if self.allow_back {
+                self.history.push(Taken {
+                    question: copy,
+                    name,
+                    answered,
+                });
             }
         }
 
         Ok(None)
     }
 
+    /// Returns an iterator which prompts the remaining questions with the given [`Backend`] and
+    /// [`EventIterator`], yielding the name and answer of each question as it is answered.
+    ///
+    /// The answers are also added to the answers of the module, so they are available to the
+    /// following questions and from [`into_answers`](PromptModule::into_answers). Questions which
+    /// are skipped or not asked are not yielded. The iterator stops after the first error.
+    ///
+    /// This is useful to save the answers as they are given, so that an interrupted prompt can be
+    /// resumed later by passing the saved answers to [`with_answers`](PromptModule::with_answers).
+    /// See also [`prompt_each_with`](PromptModule::prompt_each_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    /// # use requestty::prompt::{backend::TestBackend, events::{KeyCode, TestEvents}};
+    /// # let mut backend = TestBackend::new((20, 10).into());
+    /// # let mut events = TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+    ///
+    /// let mut module = PromptModule::new(vec![Question::input("name").build()]);
+    ///
+    /// for answer in module.prompt_iter_with(&mut backend, &mut events) {
+    ///     let (name, answer) = answer?;
+    ///     println!("{}: {:?}", name, answer);
+    /// }
+    ///
+    /// let answers = module.into_answers();
+    /// # assert_eq!(answers["name"].as_string(), Some("a"));
+    /// # Result::<_, requestty::ErrorKind>::Ok(())
+    /// ```
+    pub fn prompt_iter_with<'m, B, E>(
+        &'m mut self,
+        backend: &'m mut B,
+        events: &'m mut E,
+    ) -> PromptIter<'m, Q, B, E>
+    where
+        B: Backend,
+        E: EventIterator,
+    {
+        PromptIter {
+            module: Some(self),
+            backend,
+            events,
+        }
+    }
+
+    /// Prompt all remaining questions with the default [`Backend`] and [`EventIterator`], calling
+    /// `on_answer` after each question is answered.
+    ///
+    /// See [`prompt_each_with`](PromptModule::prompt_each_with) for more details.
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+    pub fn prompt_each<F>(self, on_answer: F) -> crate::Result<Answers>
+    where
+        F: FnMut(&str, &Answer, &Answers),
+    {
+        let stdout = std::io::stdout();
+        let mut stdout = ui::backend::get_backend(stdout.lock());
+        let mut events = ui::events::get_events();
+
+        self.prompt_each_with(&mut stdout, &mut events, on_answer)
+    }
+
+    /// Prompt all remaining questions with the given [`Backend`] and [`EventIterator`], calling
+    /// `on_answer` after each question is answered.
+    ///
+    /// `on_answer` is given the name of the question, its answer and all the answers so far
+    /// (including the new one). It is not called for questions which are skipped or not asked.
+    ///
+    /// This is useful to save the answers as they are given, so that an interrupted prompt can be
+    /// resumed later by passing the saved answers to [`with_answers`](PromptModule::with_answers).
+    /// See also [`prompt_iter_with`](PromptModule::prompt_iter_with).
+    ///
+    /// It consumes `self` and returns the answers to all the questions asked.
+    pub fn prompt_each_with<B, E, F>(
+        mut self,
+        backend: &mut B,
+        events: &mut E,
+        mut on_answer: F,
+    ) -> crate::Result<Answers>
+    where
+        B: Backend,
+        E: EventIterator,
+        F: FnMut(&str, &Answer, &Answers),
+    {
+        self.answers.reserve(self.questions.size_hint().0);
+
+        while let Some((name, answer)) = self.next_answer_with(backend, events)? {
+            self.answers.insert(name.clone(), answer);
+            on_answer(&name, &self.answers[&name], &self.answers);
+        }
+
+        Ok(self.answers)
+    }
+
     /// Prompt all remaining questions with the default [`Backend`] and [`EventIterator`].
     ///
     /// It consumes `self` and returns the answers to all the questions asked.
@@ -83,6 +734,23 @@ where
         self.prompt_all_with(&mut stdout, &mut events)
     }
 
+    /// Prompt all remaining questions on the controlling terminal (`/dev/tty`), even if stdin or
+    /// stdout are redirected.
+    ///
+    /// It consumes `self` and returns the answers to all the questions asked. See
+    /// [`prompt_tty`](crate::prompt_tty) for more details.
+    #[cfg(all(unix, any(feature = "crossterm", feature = "termion")))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(unix, any(feature = "crossterm", feature = "termion"))))
+    )]
+    pub fn prompt_all_tty(self) -> crate::Result<Answers> {
+        let mut backend = ui::backend::get_tty_backend()?;
+        let mut events = ui::events::get_tty_events()?;
+
+        self.prompt_all_with(&mut backend, &mut events)
+    }
+
     /// Prompt all remaining questions with the given [`Backend`] and [`EventIterator`].
     ///
     /// It consumes `self` and returns the answers to all the questions asked.
@@ -102,12 +770,281 @@ where
         Ok(self.answers)
     }
 
+    /// Prompt a single question with the default [`Backend`] and [`AsyncEventIterator`], without
+    /// blocking while waiting for events.
+    ///
+    /// This may or may not actually prompt the question based on what `when` and `ask_if_answered`
+    /// returns for that particular question.
+    ///
+    /// Custom prompts are still asked synchronously, and will block while waiting for events.
+    #[cfg(feature = "event-stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "event-stream")))]
+    pub async fn prompt_async(&mut self) -> crate::Result<Option<&mut Answer>> {
+        let mut stdout = ui::backend::get_backend(std::io::stdout());
+
+        self.prompt_async_with(&mut stdout, &mut ui::events::get_async_events())
+            .await
+    }
+
+    /// Prompt a single question with the given [`Backend`] and [`AsyncEventIterator`], without
+    /// blocking while waiting for events.
+    ///
+    /// This may or may not actually prompt the question based on what `when` and `ask_if_answered`
+    /// returns for that particular question.
+    ///
+    /// Custom prompts are still asked synchronously, and will block while waiting for events.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async_with<B, E>(
+        &mut self,
+        backend: &mut B,
+        events: &mut E,
+    ) -> crate::Result<Option<&mut Answer>>
+    where
+        B: Backend,
+        E: AsyncEventIterator,
+    {
+        let mut driver = AsyncDriver::new(events, self.type_ahead);
+
+        match self.next_answer(backend, &mut driver).await? {
+            Some((name, answer)) => Ok(Some(self.answers.insert(name, answer))),
+            None => Ok(None),
+        }
+    }
+
+    /// Prompt all remaining questions with the default [`Backend`] and [`AsyncEventIterator`],
+    /// without blocking while waiting for events.
+    ///
+    /// It consumes `self` and returns the answers to all the questions asked.
+    ///
+    /// Custom prompts are still asked synchronously, and will block while waiting for events.
+    #[cfg(feature = "event-stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "event-stream")))]
+    pub async fn prompt_all_async(self) -> crate::Result<Answers> {
+        let mut stdout = ui::backend::get_backend(std::io::stdout());
+        let mut events = ui::events::get_async_events();
+
+        self.prompt_all_async_with(&mut stdout, &mut events).await
+    }
+
+    /// Prompt all remaining questions with the given [`Backend`] and [`AsyncEventIterator`],
+    /// without blocking while waiting for events.
+    ///
+    /// It consumes `self` and returns the answers to all the questions asked.
+    ///
+    /// Custom prompts are still asked synchronously, and will block while waiting for events.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_all_async_with<B, E>(
+        mut self,
+        backend: &mut B,
+        events: &mut E,
+    ) -> crate::Result<Answers>
+    where
+        B: Backend,
+        E: AsyncEventIterator,
+    {
+        self.answers.reserve(self.questions.size_hint().0);
+
+        while self.prompt_async_with(backend, events).await?.is_some() {}
+
+        Ok(self.answers)
+    }
+
     /// Consumes `self` returning the answers to the previously asked questions.
     pub fn into_answers(self) -> Answers {
         self.answers
     }
 }
 
+/// An iterator which prompts the questions of a [`PromptModule`], yielding the name and answer of
+/// each question as it is answered.
+///
+/// This is created by [`PromptModule::prompt_iter_with`].
+pub struct PromptIter<'m, Q: Iterator, B, E> {
+    /// This is `None` once an error has been returned
+    module: Option<&'m mut PromptModule<Q>>,
+    backend: &'m mut B,
+    events: &'m mut E,
+}
+
+impl<Q, B, E> fmt::Debug for PromptIter<'_, Q, B, E>
+where
+    Q: Iterator + fmt::Debug,
+    Q::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PromptIter")
+            .field("module", &self.module)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Q, B, E> Iterator for PromptIter<'_, Q, B, E>
+where
+    Q: Iterator<Item = Question<'a>>,
+    B: Backend,
+    E: EventIterator,
+{
+    type Item = crate::Result<(String, Answer)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let module = self.module.as_mut()?;
+
+        match module.next_answer_with(self.backend, self.events) {
+            Ok(Some((name, answer))) => {
+                module.answers.insert(name.clone(), answer.clone());
+                Some(Ok((name, answer)))
+            }
+            Ok(None) => None,
+            Err(e) => {
+                self.module = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+type GenerateFn<'a> = dyn FnOnce(&Answers) -> Vec<Question<'a>> + 'a;
+
+/// The questions of a [`PromptModule`] with questions generated from its answers.
+///
+/// This is created by [`PromptModule::then`].
+pub struct Then<'a, Q> {
+    questions: Q,
+    /// Generates the questions of earlier calls to `then`, which are part of `questions`
+    generate_previous: Option<Generate<Q>>,
+    /// This is `None` once the questions have been generated
+    generate: Option<Box<GenerateFn<'a>>>,
+    generated: vec::IntoIter<Question<'a>>,
+}
+
+impl<'a, Q> Then<'a, Q> {
+    /// Generates the first questions which have not been generated yet, returning whether there
+    /// were any left to generate.
+    fn generate(&mut self, answers: &Answers) -> bool {
+        if let Some(Generate(generate_previous)) = self.generate_previous {
+            if generate_previous(&mut self.questions, answers) {
+                return true;
+            }
+        }
+
+        match self.generate.take() {
+            Some(generate) => {
+                self.generated = generate(answers).into_iter();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<Q: fmt::Debug> fmt::Debug for Then<'_, Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Then")
+            .field("questions", &self.questions)
+            .field("generated", &self.generated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Q> Iterator for Then<'a, Q>
+where
+    Q: Iterator<Item = Question<'a>>,
+{
+    type Item = Question<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.questions.next().or_else(|| self.generated.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.questions.size_hint();
+        let len = self.generated.len();
+
+        let upper = match upper {
+            // The number of questions which will be generated is not known
+            Some(upper) if self.generate.is_none() => upper.checked_add(len),
+            _ => None,
+        };
+
+        (lower.saturating_add(len), upper)
+    }
+}
+
+/// Generates the questions added with [`PromptModule::then`], returning whether there were any
+/// left to generate.
+struct Generate<Q>(fn(&mut Q, &Answers) -> bool);
+
+impl<Q> Clone for Generate<Q> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Q> Copy for Generate<Q> {}
+
+impl<Q> fmt::Debug for Generate<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Generate(_)")
+    }
+}
+
+impl<Q> PartialEq for Generate<Q> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+/// A question of a [`PromptModule`] which has been asked.
+#[derive(Debug, Clone, PartialEq)]
+struct Taken<T> {
+    /// A copy of the question made before it was asked, which is `None` if it cannot be asked
+    /// again
+    question: Option<T>,
+    /// The name of the answer it added, if any
+    name: Option<String>,
+    answered: bool,
+}
+
+type NumberingFn = dyn Fn(usize, Option<usize>, &str) -> String + Send + Sync;
+
+/// How the messages of the questions are numbered.
+#[derive(Clone)]
+enum Numbering {
+    /// "\<number\>. \<message\>"
+    Default,
+    Custom(Arc<NumberingFn>),
+}
+
+impl Numbering {
+    fn format(&self, number: usize, total: Option<usize>, message: &str) -> String {
+        match self {
+            Numbering::Default => format!("{}. {}", number, message),
+            Numbering::Custom(numbering) => numbering(number, total, message),
+        }
+    }
+}
+
+impl fmt::Debug for Numbering {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Numbering::Default => f.write_str("Default"),
+            Numbering::Custom(_) => f.write_str("Custom(_)"),
+        }
+    }
+}
+
+impl PartialEq for Numbering {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Numbering::Default, Numbering::Default) => true,
+            (Numbering::Custom(a), Numbering::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
 /// A macro to easily write a [`PromptModule`].
 ///
 /// # Usage
@@ -0,0 +1,47 @@
+//! Plain descriptions of the built-in prompts, which are printed instead of rendering them in
+//! [accessible mode](ui::accessibility).
+
+use ui::{backend::Backend, widgets};
+
+/// Writes the message of a question on its own line, before the prompt is described.
+pub(super) fn write_message<B: Backend>(message: &str, b: &mut B) -> ui::Result<()> {
+    b.write_all(message.as_bytes())?;
+    b.write_all(b"\n")?;
+    b.flush()?;
+    Ok(())
+}
+
+/// Describes text typed by the user, like `Text: hello`.
+pub(super) fn describe_text(value: &str, default: Option<&str>) -> String {
+    match default {
+        _ if !value.is_empty() => format!("Text: {}", value),
+        Some(default) => format!("Text: empty, default {}", default),
+        None => "Text: empty".to_owned(),
+    }
+}
+
+/// Describes the hovered element of a list, like `Selected: Option B (3 of 7)`.
+///
+/// `text` is given the index of the hovered element, and only the first line of what it returns
+/// is used.
+pub(super) fn describe_hovered<L, F>(label: &str, select: &widgets::Select<L>, text: F) -> String
+where
+    L: widgets::List,
+    F: FnOnce(&L, usize) -> String,
+{
+    let filter = if select.filter().is_empty() {
+        String::new()
+    } else {
+        format!(", filtered by '{}'", select.filter())
+    };
+
+    match select.position() {
+        Some((position, total)) => {
+            let text = text(&select.list, select.get_at());
+            let text = text.lines().next().unwrap_or_default();
+            format!("{}: {} ({} of {}{})", label, text, position, total, filter)
+        }
+        None if select.has_selectable() => format!("{}: nothing", label),
+        None => format!("No choices{}", filter),
+    }
+}
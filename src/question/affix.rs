@@ -0,0 +1,100 @@
+use std::io;
+
+use ui::{backend::Backend, layout::Layout, style::Stylize};
+
+/// Text shown before and after an input which cannot be edited, like a currency or a unit.
+///
+/// The widths are stored along with the text, since they are needed on every render.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Affixes {
+    prefix: Option<(String, u16)>,
+    suffix: Option<(String, u16)>,
+}
+
+fn with_width(text: String) -> (String, u16) {
+    let width = ui::layout::display_width(&text);
+    (text, width)
+}
+
+/// Moves the layout past `width` characters rendered at its current position, returning the number
+/// of lines moved down.
+fn skip(layout: &mut Layout, mut width: u16) -> u16 {
+    if width > layout.line_width() {
+        width -= layout.line_width();
+
+        layout.line_offset = width % layout.width;
+        layout.offset_y += 1 + width / layout.width;
+
+        1 + width / layout.width
+    } else {
+        layout.line_offset += width;
+        0
+    }
+}
+
+fn write<B: Backend>(affix: &Option<(String, u16)>, b: &mut B) -> io::Result<()> {
+    match affix {
+        Some((affix, _)) => b.write_styled(&affix.as_str().with(ui::style::theme().hint)),
+        None => Ok(()),
+    }
+}
+
+impl Affixes {
+    pub(super) fn set_prefix(&mut self, prefix: String) {
+        self.prefix = Some(with_width(prefix));
+    }
+
+    pub(super) fn set_suffix(&mut self, suffix: String) {
+        self.suffix = Some(with_width(suffix));
+    }
+
+    pub(super) fn suffix_width(&self) -> u16 {
+        self.suffix.as_ref().map_or(0, |(_, width)| *width)
+    }
+
+    /// Renders the prefix and moves the layout past it.
+    pub(super) fn render_prefix<B: Backend>(
+        &self,
+        layout: &mut Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        write(&self.prefix, b)?;
+        self.skip_prefix(layout);
+        Ok(())
+    }
+
+    /// Moves the layout past the prefix, returning the number of lines moved down.
+    pub(super) fn skip_prefix(&self, layout: &mut Layout) -> u16 {
+        match self.prefix {
+            Some((_, width)) => skip(layout, width),
+            None => 0,
+        }
+    }
+
+    /// Writes the suffix without updating any layout.
+    pub(super) fn write_suffix<B: Backend>(&self, b: &mut B) -> io::Result<()> {
+        write(&self.suffix, b)
+    }
+
+    /// Renders the suffix and moves the layout past it.
+    pub(super) fn render_suffix<B: Backend>(
+        &self,
+        layout: &mut Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        self.write_suffix(b)?;
+        self.skip_suffix(layout);
+        Ok(())
+    }
+
+    /// Moves the layout past the suffix, returning the number of lines moved down.
+    pub(super) fn skip_suffix(&self, layout: &mut Layout) -> u16 {
+        skip(layout, self.suffix_width())
+    }
+
+    /// Moves the layout past `width` characters followed by the suffix, returning the total number
+    /// of lines taken.
+    pub(super) fn skip_with_suffix(&self, layout: &mut Layout, width: u16) -> u16 {
+        1 + skip(layout, width + self.suffix_width())
+    }
+}
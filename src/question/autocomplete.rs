@@ -0,0 +1,781 @@
+use std::{
+    cell::RefCell,
+    fmt, io,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ui::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent},
+    style::Stylize,
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{
+    describe_hovered, describe_text, ChoiceList, Filter, Options, Transform, Validate,
+    ValidationMessage,
+};
+use crate::{Answer, Answers};
+
+/// A source of suggestions for an [`autocomplete`] question.
+///
+/// It is queried with the text entered so far whenever the text changes, and should return the
+/// suggestions which match it. It is implemented for closures with the same signature as
+/// [`suggestions`](SuggestionProvider::suggestions).
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{question::SuggestionProvider, Answers};
+///
+/// struct Fruits(Vec<&'static str>);
+///
+/// impl SuggestionProvider for Fruits {
+///     fn suggestions(&mut self, input: &str, _: &Answers) -> Vec<String> {
+///         self.0
+///             .iter()
+///             .filter(|fruit| fruit.starts_with(input))
+///             .map(|fruit| fruit.to_string())
+///             .collect()
+///     }
+/// }
+///
+/// let mut fruits = Fruits(vec!["apple", "apricot", "banana"]);
+/// assert_eq!(fruits.suggestions("ap", &Answers::default()), ["apple", "apricot"]);
+/// ```
+///
+/// [`autocomplete`]: crate::question::Question::autocomplete
+pub trait SuggestionProvider {
+    /// The suggestions for `input`, which is the text entered so far.
+    fn suggestions(&mut self, input: &str, answers: &Answers) -> Vec<String>;
+}
+
+impl<F> SuggestionProvider for F
+where
+    F: FnMut(&str, &Answers) -> Vec<String>,
+{
+    fn suggestions(&mut self, input: &str, answers: &Answers) -> Vec<String> {
+        self(input, answers)
+    }
+}
+
+type SuggestionsFn<'a> = dyn Fn(&str, &Answers) -> Vec<String> + 'a;
+
+#[derive(Clone)]
+pub(super) struct Autocomplete<'a> {
+    /// Gets suggestions from the [`SuggestionProvider`], which is shared by the copies of the
+    /// question
+    suggestions: Rc<SuggestionsFn<'a>>,
+    debounce: Duration,
+    initial_value: Option<String>,
+    page_size: usize,
+    should_loop: bool,
+    filter: Filter<'a, String>,
+    validate: Validate<'a, str>,
+    transform: Transform<'a, str>,
+}
+
+impl fmt::Debug for Autocomplete<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Autocomplete")
+            .field("debounce", &self.debounce)
+            .field("initial_value", &self.initial_value)
+            .field("page_size", &self.page_size)
+            .field("should_loop", &self.should_loop)
+            .field("filter", &self.filter)
+            .field("validate", &self.validate)
+            .field("transform", &self.transform)
+            .finish_non_exhaustive()
+    }
+}
+
+type SuggestionSelector = widgets::Select<ChoiceList<widgets::Text<String>>>;
+
+struct AutocompletePrompt<'a, 'c> {
+    prompt: widgets::Prompt<&'a str>,
+    autocomplete: Autocomplete<'c>,
+    input: widgets::StringInput,
+    /// The suggestions for the input, which is `None` if there are none
+    suggestions: Option<SuggestionSelector>,
+    /// When the input was changed, if the suggestions are waiting for the debounce
+    changed_at: Option<Instant>,
+    answers: &'a Answers,
+}
+
+impl AutocompletePrompt<'_, '_> {
+    fn update_suggestions(&mut self) {
+        self.changed_at = None;
+
+        let suggestions = (self.autocomplete.suggestions)(self.input.value(), self.answers);
+
+        self.suggestions = if suggestions.is_empty() {
+            None
+        } else {
+            let mut choices: ChoiceList<_> =
+                suggestions.into_iter().map(widgets::Text::new).collect();
+            choices.set_page_size(self.autocomplete.page_size);
+            choices.set_should_loop(self.autocomplete.should_loop);
+
+            Some(widgets::Select::new(choices))
+        };
+    }
+
+    fn input_changed(&mut self) {
+        if self.autocomplete.debounce == Duration::ZERO {
+            self.update_suggestions();
+        } else {
+            self.changed_at = Some(Instant::now());
+        }
+    }
+
+    /// Updates the suggestions if they are waiting for the debounce, and returns whether they
+    /// were updated.
+    fn flush_suggestions(&mut self) -> bool {
+        let waiting = self.changed_at.is_some();
+        if waiting {
+            self.update_suggestions();
+        }
+        waiting
+    }
+
+    fn hovered(&self) -> Option<&str> {
+        self.suggestions.as_ref().map(|suggestions| {
+            suggestions.list[suggestions.get_at()]
+                .as_ref()
+                .unwrap_choice()
+                .text
+                .as_str()
+        })
+    }
+
+    /// The hovered suggestion, or the text entered if there are no suggestions.
+    fn value(&self) -> &str {
+        self.hovered().unwrap_or_else(|| self.input.value())
+    }
+}
+
+impl Widget for AutocompletePrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)?;
+
+        if let Some(ref mut suggestions) = self.suggestions {
+            suggestions.render(layout, b)?;
+        }
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let mut height = self.prompt.height(layout) - 1 + self.input.height(layout);
+
+        if let Some(ref mut suggestions) = self.suggestions {
+            height += suggestions.height(layout) - 1;
+        }
+
+        height
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Tab => {
+                self.flush_suggestions();
+
+                match self.hovered().map(ToOwned::to_owned) {
+                    Some(hovered) => {
+                        self.input.set_value(hovered);
+                        self.input.set_at(usize::MAX);
+                        self.update_suggestions();
+                        true
+                    }
+                    None => false,
+                }
+            }
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown => {
+                // The suggestions being moved through should be the ones for the current text
+                let updated = self.flush_suggestions();
+
+                match self.suggestions {
+                    Some(ref mut suggestions) => suggestions.handle_key(key) || updated,
+                    None => updated,
+                }
+            }
+            _ if self.input.handle_key(key) => {
+                self.input_changed();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.input
+            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+    }
+}
+
+impl Prompt for AutocompletePrompt<'_, '_> {
+    type ValidateErr = ValidationMessage;
+    type Output = String;
+
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        // The suggestions are shown before one of them can be submitted
+        if self.flush_suggestions() {
+            return Ok(Validation::Continue);
+        }
+
+        if let Validate::Sync(ref mut validate) = self.autocomplete.validate {
+            let value = match self.suggestions {
+                Some(ref suggestions) => suggestions.list[suggestions.get_at()]
+                    .as_ref()
+                    .unwrap_choice()
+                    .text
+                    .as_str(),
+                None => self.input.value(),
+            };
+
+            validate(value, self.answers).into_result()?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn describe(&mut self) -> Option<String> {
+        let text = describe_text(self.input.value(), None);
+
+        Some(match self.suggestions {
+            Some(ref suggestions) => format!(
+                "{}. {}",
+                text,
+                describe_hovered("Suggestion", suggestions, |list, at| {
+                    list[at].as_ref().unwrap_choice().text.clone()
+                })
+            ),
+            None => text,
+        })
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
+
+    fn next_tick(&self) -> Option<Duration> {
+        self.changed_at.map(|changed_at| {
+            self.autocomplete
+                .debounce
+                .saturating_sub(changed_at.elapsed())
+        })
+    }
+
+    fn tick(&mut self) -> bool {
+        match self.changed_at {
+            Some(changed_at) if changed_at.elapsed() >= self.autocomplete.debounce => {
+                self.update_suggestions();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn finish(self) -> Self::Output {
+        let ans = self.value().to_owned();
+
+        match self.autocomplete.filter {
+            Filter::Sync(filter) => filter(ans, self.answers),
+            Filter::None => ans,
+        }
+    }
+}
+
+impl<'c> Autocomplete<'c> {
+    fn new(provider: Box<dyn SuggestionProvider + 'c>) -> Self {
+        let provider = RefCell::new(provider);
+
+        Self {
+            suggestions: Rc::new(move |input: &str, answers: &Answers| {
+                provider.borrow_mut().suggestions(input, answers)
+            }),
+            debounce: Duration::ZERO,
+            initial_value: None,
+            page_size: super::choice::default_page_size(),
+            should_loop: super::choice::default_should_loop(),
+            filter: Filter::None,
+            validate: Validate::None,
+            transform: Transform::None,
+        }
+    }
+
+    pub(super) fn set_initial_answer(&mut self, answer: &Answer) {
+        if let Answer::String(initial_value) = answer {
+            self.initial_value = Some(initial_value.clone());
+        }
+    }
+
+    fn into_prompt<'a>(
+        mut self,
+        message: &'a str,
+        answers: &'a Answers,
+    ) -> AutocompletePrompt<'a, 'c> {
+        let mut input = widgets::StringInput::default();
+
+        if let Some(initial_value) = self.initial_value.take() {
+            input.set_value(initial_value);
+            input.set_at(usize::MAX);
+        }
+
+        let mut prompt = AutocompletePrompt {
+            prompt: widgets::Prompt::new(message),
+            autocomplete: self,
+            input,
+            suggestions: None,
+            changed_at: None,
+            answers,
+        };
+        prompt.update_suggestions();
+
+        prompt
+    }
+
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
+        mut self,
+        message: String,
+        answers: &Answers,
+        b: &mut B,
+        driver: &mut D,
+        partial: &mut Option<Answer>,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let input = ui::Input::new(self.into_prompt(&message, answers), b);
+        let ans = driver.run(input).await.map_err(|recovered| {
+            *partial = Some(Answer::String(recovered.prompt.input.value().to_owned()));
+            recovered.error
+        })?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans.as_string().expect("answer must be a string").to_owned();
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, str>,
+        message: String,
+        ans: Option<String>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b
+            .write_styled(&ans.as_str().with(ui::style::theme().highlight))?)
+    }
+}
+
+/// The builder for the [`autocomplete`] prompt.
+///
+/// The suggestions from the [`SuggestionProvider`] are shown below the text, and are updated as
+/// the user types. `Up` and `Down` move through the suggestions, `Tab` replaces the text with the
+/// hovered suggestion, and `Enter` submits the hovered suggestion. If there are no suggestions,
+/// the text itself is submitted.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let autocomplete = Question::autocomplete("fruit", |input: &str, _: &_| {
+///     ["apple", "apricot", "banana"]
+///         .iter()
+///         .filter(|fruit| fruit.starts_with(input))
+///         .map(|fruit| fruit.to_string())
+///         .collect()
+/// })
+/// .message("What is your favourite fruit?")
+/// .build();
+/// ```
+///
+/// [`autocomplete`]: crate::question::Question::autocomplete
+#[derive(Debug)]
+pub struct AutocompleteBuilder<'a> {
+    opts: Options<'a>,
+    autocomplete: Autocomplete<'a>,
+}
+
+impl<'a> AutocompleteBuilder<'a> {
+    pub(crate) fn new(name: String, provider: Box<dyn SuggestionProvider + 'a>) -> Self {
+        AutocompleteBuilder {
+            opts: Options::new(name),
+            autocomplete: Autocomplete::new(provider),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .message("What is your favourite fruit?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("likes-fruit") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
+    }
+
+    /// How long to wait after the user stops typing before the suggestions are updated.
+    ///
+    /// This is useful if the [`SuggestionProvider`] is slow, so that it is not queried on every
+    /// key press. Suggestions which are waiting for the debounce are updated right away if the
+    /// user moves through them or submits the prompt.
+    ///
+    /// The debounce needs [`EventIterator::wait_for_event`] to wait for the user to stop typing.
+    /// With event iterators which don't support it, and when the question is asked
+    /// asynchronously, suggestions are only updated when the user moves through them or submits
+    /// the prompt.
+    ///
+    /// If `debounce` is not set, it will default to 0, and the suggestions are updated after every
+    /// key press.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .debounce(Duration::from_millis(200))
+    ///     .build();
+    /// ```
+    ///
+    /// [`EventIterator::wait_for_event`]: crate::prompt::EventIterator::wait_for_event
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.autocomplete.debounce = debounce;
+        self
+    }
+
+    /// Set the text that the input starts with, which can then be edited by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .initial_value("ap")
+    ///     .build();
+    /// ```
+    pub fn initial_value<I: Into<String>>(mut self, initial_value: I) -> Self {
+        self.autocomplete.initial_value = Some(initial_value.into());
+        self
+    }
+
+    /// The maximum height that can be taken by the suggestions
+    ///
+    /// If the total height exceeds the page size, the suggestions will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.autocomplete.page_size = page_size;
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last suggestion.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.autocomplete.should_loop = should_loop;
+        self
+    }
+
+    crate::impl_filter_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .filter(|fruit, previous_answers| fruit.to_lowercase())
+    ///     .build();
+    /// ```
+    String; autocomplete
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .validate(|fruit, previous_answers| if fruit.is_empty() {
+    ///         Err("Please enter a fruit".to_owned())
+    ///     } else {
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    /// ```
+    str; autocomplete
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("fruit", |_: &str, _: &_| Vec::new())
+    ///     .transform(|fruit, previous_answers, backend| {
+    ///         write!(backend, "{}!", fruit)
+    ///     })
+    ///     .build();
+    /// ```
+    str; autocomplete
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(
+            self.opts,
+            super::QuestionKind::Autocomplete(self.autocomplete),
+        )
+    }
+}
+
+impl<'a> From<AutocompleteBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: AutocompleteBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ui::{backend::TestBackend, layout::Layout};
+
+    use super::*;
+
+    fn fruits() -> Autocomplete<'static> {
+        Autocomplete::new(Box::new(|input: &str, _: &Answers| {
+            ["apple", "apricot", "banana", "blueberry", "cherry"]
+                .iter()
+                .filter(|fruit| fruit.starts_with(input))
+                .map(|fruit| fruit.to_string())
+                .collect()
+        }))
+    }
+
+    fn type_str(prompt: &mut AutocompletePrompt<'_, '_>, s: &str) {
+        for c in s.chars() {
+            assert!(prompt.handle_key(KeyCode::Char(c).into()));
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = fruits().into_prompt("message", &answers);
+
+        let mut layout = base_layout;
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_line_offset(0).with_offset(0, 6));
+        ui::assert_backend_snapshot!(backend);
+
+        type_str(&mut prompt, "b");
+        assert!(prompt.handle_key(KeyCode::Down.into()));
+
+        layout = base_layout;
+        backend.reset_with_layout(layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_line_offset(0).with_offset(0, 3));
+        ui::assert_backend_snapshot!(backend);
+
+        type_str(&mut prompt, "x");
+
+        layout = base_layout;
+        backend.reset_with_layout(layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_line_offset(19));
+        ui::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_tab() {
+        let answers = Answers::default();
+        let mut prompt = fruits().into_prompt("message", &answers);
+
+        type_str(&mut prompt, "ap");
+        assert!(prompt.handle_key(KeyCode::Down.into()));
+        assert!(prompt.handle_key(KeyCode::Tab.into()));
+        assert_eq!(prompt.input.value(), "apricot");
+        assert_eq!(prompt.hovered(), Some("apricot"));
+
+        type_str(&mut prompt, "s");
+        assert_eq!(prompt.hovered(), None);
+        assert!(!prompt.handle_key(KeyCode::Tab.into()));
+        assert_eq!(prompt.finish(), "apricots");
+    }
+
+    #[test]
+    fn test_debounce() {
+        let answers = Answers::default();
+        let mut autocomplete = fruits();
+        autocomplete.debounce = Duration::from_secs(3600);
+        let mut prompt = autocomplete.into_prompt("message", &answers);
+
+        assert_eq!(prompt.next_tick(), None);
+        type_str(&mut prompt, "c");
+        assert_eq!(prompt.hovered(), Some("apple"));
+        assert!(prompt.next_tick().unwrap() > Duration::from_secs(3500));
+        assert!(!prompt.tick());
+
+        assert!(matches!(prompt.validate(), Ok(Validation::Continue)));
+        assert_eq!(prompt.hovered(), Some("cherry"));
+        assert_eq!(prompt.next_tick(), None);
+        assert!(matches!(prompt.validate(), Ok(Validation::Finish)));
+
+        prompt.autocomplete.debounce = Duration::from_millis(1);
+        type_str(&mut prompt, "x");
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(prompt.next_tick(), Some(Duration::ZERO));
+        assert!(prompt.tick());
+        assert_eq!(prompt.hovered(), None);
+    }
+}
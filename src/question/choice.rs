@@ -1,12 +1,41 @@
 use std::{
+    borrow::Cow,
     io,
     ops::{Index, IndexMut},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
 };
 
-use ui::{style::Color, widgets::List, Widget};
+use ui::{
+    style::{Color, Stylize},
+    widgets::{self, List, Text},
+    Widget,
+};
 
 use crate::ExpandItem;
 
+static DEFAULT_PAGE_SIZE: AtomicUsize = AtomicUsize::new(15);
+static DEFAULT_SHOULD_LOOP: AtomicBool = AtomicBool::new(true);
+
+/// The page size used by lists which do not set it explicitly.
+pub(super) fn default_page_size() -> usize {
+    DEFAULT_PAGE_SIZE.load(Ordering::Relaxed)
+}
+
+/// Whether lists which do not set it explicitly should loop.
+pub(super) fn default_should_loop() -> bool {
+    DEFAULT_SHOULD_LOOP.load(Ordering::Relaxed)
+}
+
+/// Set the page size used by lists which do not set it explicitly.
+pub(crate) fn set_default_page_size(page_size: usize) {
+    DEFAULT_PAGE_SIZE.store(page_size, Ordering::Relaxed);
+}
+
+/// Set whether lists which do not set it explicitly should loop.
+pub(crate) fn set_default_should_loop(should_loop: bool) {
+    DEFAULT_SHOULD_LOOP.store(should_loop, Ordering::Relaxed);
+}
+
 #[derive(Clone)]
 pub(crate) struct SelectList<T> {
     pub(crate) choices: Vec<T>,
@@ -15,6 +44,17 @@ pub(crate) struct SelectList<T> {
     // note: default is not an option usize because it adds an extra usize of space
     has_default: bool,
     should_loop: bool,
+    jump_by_section: bool,
+    mouse_capture: bool,
+    filterable: bool,
+    jump_to_prefix: bool,
+    truncate_answer: bool,
+    columns: usize,
+    hover_detail: bool,
+    // note: this is indexed the same as choices, but is only as long as the last choice with a value
+    values: Vec<Option<String>>,
+    // note: like `values`, this is only as long as the last choice with a description
+    descriptions: Vec<Option<String>>,
     is_selectable: fn(&T) -> bool,
 }
 
@@ -26,6 +66,15 @@ impl<T: std::fmt::Debug> std::fmt::Debug for SelectList<T> {
             .field("default", &self.default)
             .field("has_default", &self.has_default)
             .field("should_loop", &self.should_loop)
+            .field("jump_by_section", &self.jump_by_section)
+            .field("mouse_capture", &self.mouse_capture)
+            .field("filterable", &self.filterable)
+            .field("jump_to_prefix", &self.jump_to_prefix)
+            .field("truncate_answer", &self.truncate_answer)
+            .field("columns", &self.columns)
+            .field("hover_detail", &self.hover_detail)
+            .field("values", &self.values)
+            .field("descriptions", &self.descriptions)
             .finish()
     }
 }
@@ -34,10 +83,19 @@ impl<T> SelectList<T> {
     pub(crate) fn new(f: fn(&T) -> bool) -> Self {
         Self {
             choices: Vec::new(),
-            page_size: 15,
+            page_size: default_page_size(),
             default: 0,
             has_default: false,
-            should_loop: true,
+            should_loop: default_should_loop(),
+            jump_by_section: false,
+            mouse_capture: false,
+            filterable: false,
+            jump_to_prefix: false,
+            truncate_answer: true,
+            columns: 1,
+            hover_detail: false,
+            values: Vec::new(),
+            descriptions: Vec::new(),
             is_selectable: f,
         }
     }
@@ -65,6 +123,36 @@ impl<T> SelectList<T> {
         self.should_loop
     }
 
+    /// Get a reference to the choice list's jump by section.
+    pub(crate) fn jump_by_section(&self) -> bool {
+        self.jump_by_section
+    }
+
+    /// Get a reference to the choice list's mouse capture.
+    pub(crate) fn mouse_capture(&self) -> bool {
+        self.mouse_capture
+    }
+
+    /// Get a reference to the choice list's filterable.
+    pub(crate) fn filterable(&self) -> bool {
+        self.filterable
+    }
+
+    /// Get a reference to the choice list's jump to prefix.
+    pub(crate) fn jump_to_prefix(&self) -> bool {
+        self.jump_to_prefix
+    }
+
+    /// Get a reference to the choice list's truncate answer.
+    pub(crate) fn truncate_answer(&self) -> bool {
+        self.truncate_answer
+    }
+
+    /// Get a reference to the choice list's columns.
+    pub(crate) fn columns(&self) -> usize {
+        self.columns
+    }
+
     /// Set the choice list's default.
     pub(crate) fn set_default(&mut self, default: usize) {
         self.default = default;
@@ -80,6 +168,41 @@ impl<T> SelectList<T> {
     pub(crate) fn set_should_loop(&mut self, should_loop: bool) {
         self.should_loop = should_loop;
     }
+
+    /// Set the choice list's jump by section.
+    pub(crate) fn set_jump_by_section(&mut self, jump_by_section: bool) {
+        self.jump_by_section = jump_by_section;
+    }
+
+    /// Set the choice list's mouse capture.
+    pub(crate) fn set_mouse_capture(&mut self, mouse_capture: bool) {
+        self.mouse_capture = mouse_capture;
+    }
+
+    /// Set the choice list's filterable.
+    pub(crate) fn set_filterable(&mut self, filterable: bool) {
+        self.filterable = filterable;
+    }
+
+    /// Set the choice list's jump to prefix.
+    pub(crate) fn set_jump_to_prefix(&mut self, jump_to_prefix: bool) {
+        self.jump_to_prefix = jump_to_prefix;
+    }
+
+    /// Set the choice list's truncate answer.
+    pub(crate) fn set_truncate_answer(&mut self, truncate_answer: bool) {
+        self.truncate_answer = truncate_answer;
+    }
+
+    /// Set the choice list's columns.
+    pub(crate) fn set_columns(&mut self, columns: usize) {
+        self.columns = columns;
+    }
+
+    /// Set the choice list's hover detail.
+    pub(crate) fn set_hover_detail(&mut self, hover_detail: bool) {
+        self.hover_detail = hover_detail;
+    }
 }
 
 impl<T> Index<usize> for SelectList<T> {
@@ -105,13 +228,13 @@ impl<T: Widget> List for SelectList<T> {
         b: &mut B,
     ) -> io::Result<()> {
         if hovered {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(ui::style::theme().highlight)?;
             write!(b, "{} ", ui::symbols::current().pointer)?;
         } else {
             b.write_all(b"  ")?;
 
             if !self.is_selectable(index) {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(ui::style::theme().hint)?;
             }
         }
 
@@ -133,6 +256,14 @@ impl<T: Widget> List for SelectList<T> {
         self.should_loop
     }
 
+    fn jump_by_section(&self) -> bool {
+        self.jump_by_section
+    }
+
+    fn columns(&self) -> usize {
+        self.columns
+    }
+
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
         layout.offset_x += 2;
 
@@ -154,6 +285,95 @@ impl<T> std::iter::FromIterator<T> for ChoiceList<T> {
     }
 }
 
+impl<T> ChoiceList<T> {
+    /// Gets the text of the nearest [`Choice::Separator`] before the given index, if any.
+    ///
+    /// A [`Choice::DefaultSeparator`] is treated as the end of a section.
+    pub(crate) fn section_at(&self, index: usize) -> Option<String> {
+        self.choices[..index]
+            .iter()
+            .rev()
+            .find(|choice| choice.is_separator())
+            .and_then(|choice| match choice {
+                Choice::Separator(s) => Some(s.clone()),
+                _ => None,
+            })
+    }
+
+    /// Attaches a value to the choice at the given index, which is given back in the answer.
+    pub(crate) fn set_value(&mut self, index: usize, value: String) {
+        if self.values.len() <= index {
+            self.values.resize(index + 1, None);
+        }
+        self.values[index] = Some(value);
+    }
+
+    /// Takes the value attached to the choice at the given index, if any.
+    pub(crate) fn take_value(&mut self, index: usize) -> Option<String> {
+        self.values.get_mut(index).and_then(Option::take)
+    }
+
+    /// Attaches a description to the choice at the given index, which is shown while it is
+    /// hovered.
+    pub(crate) fn set_description(&mut self, index: usize, description: String) {
+        if self.descriptions.len() <= index {
+            self.descriptions.resize(index + 1, None);
+        }
+        self.descriptions[index] = Some(description);
+    }
+
+    /// The description attached to the choice at the given index, if any.
+    pub(crate) fn description(&self, index: usize) -> Option<&str> {
+        self.descriptions.get(index)?.as_deref()
+    }
+}
+
+impl ChoiceList<Text<String>> {
+    /// The text that the filter is matched against for the choice at an index.
+    pub(crate) fn filter_text(&self, index: usize) -> Option<&str> {
+        match self.choices[index] {
+            Choice::Choice(ref text) => Some(&text.text),
+            _ => None,
+        }
+    }
+
+    /// The full text of the choice at an index, shown below the list if `hover_detail` is set.
+    pub(crate) fn hover_detail_text(&self, index: usize) -> Option<&str> {
+        if self.hover_detail {
+            self.filter_text(index)
+        } else {
+            None
+        }
+    }
+}
+
+/// The hint shown by filterable lists, which includes the filter once the user has typed
+/// something.
+pub(crate) fn filter_hint(filter: &str, empty_hint: &'static str) -> Cow<'static, str> {
+    if filter.is_empty() {
+        Cow::Borrowed(empty_hint)
+    } else {
+        Cow::Owned(format!("Filter: {}", filter))
+    }
+}
+
+/// Writes the answer of a list on its finished line in the highlight colour. If `truncate` is set,
+/// the answer is cut short so that the line doesn't wrap.
+pub(crate) fn write_answer<B: ui::backend::Backend>(
+    message: &str,
+    answer: &str,
+    truncate: bool,
+    b: &mut B,
+) -> io::Result<()> {
+    let answer = if truncate {
+        widgets::Prompt::truncate_finished_answer(&message, answer, b.size()?.width)
+    } else {
+        Cow::Borrowed(answer)
+    };
+
+    b.write_styled(&answer.as_ref().with(ui::style::theme().highlight))
+}
+
 impl<T> Default for ChoiceList<T> {
     fn default() -> Self {
         Self::new(Choice::is_choice)
@@ -172,7 +392,23 @@ pub enum Choice<T> {
     /// If the text is more than one line, it will be cut-off.
     Separator(String),
     /// A separator which prints a line: "──────────────"
+    ///
+    /// The line is drawn with the [`box_horizontal`] character of the current symbol set.
+    ///
+    /// [`box_horizontal`]: crate::symbols::SymbolSet::box_horizontal
     DefaultSeparator,
+    /// A choice which is shown, but cannot be picked, such as an option which is unavailable right
+    /// now. It is not selectable and is skipped over when users navigate.
+    ///
+    /// It is shown in the [`hint`] colour, with the reason after the text in parentheses.
+    ///
+    /// [`hint`]: ui::style::Theme::hint
+    Disabled {
+        /// The text of the choice.
+        text: T,
+        /// Why the choice cannot be picked.
+        reason: String,
+    },
 }
 
 impl<T> Choice<T> {
@@ -183,6 +419,22 @@ impl<T> Choice<T> {
             Choice::Choice(c) => Choice::Choice(f(c)),
             Choice::Separator(s) => Choice::Separator(s),
             Choice::DefaultSeparator => Choice::DefaultSeparator,
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text: f(text),
+                reason,
+            },
+        }
+    }
+
+    /// Maps the choice like [`map`](Choice::map), but also gives `f` the reason of a
+    /// [`Choice::Disabled`], so that it can be shown with the text.
+    pub(crate) fn map_with_reason<U, F: FnOnce(T, Option<&str>) -> U>(self, f: F) -> Choice<U> {
+        match self {
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text: f(text, Some(&reason)),
+                reason,
+            },
+            choice => choice.map(|c| f(c, None)),
         }
     }
 
@@ -193,28 +445,43 @@ impl<T> Choice<T> {
 
     /// Returns `true` if the choice is a separator.
     pub fn is_separator(&self) -> bool {
-        !self.is_choice()
+        matches!(self, Choice::Separator(_) | Choice::DefaultSeparator)
+    }
+
+    /// Returns `true` if the choice is a [`Choice::Disabled`].
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, Choice::Disabled { .. })
     }
 
     /// Converts `&Choice<T>` to `Choice<&T>`.
     ///
-    /// This will clone the [`Choice::Separator`] if any.
+    /// This will clone the text of the [`Choice::Separator`] or the reason of the
+    /// [`Choice::Disabled`] if any.
     pub fn as_ref(&self) -> Choice<&T> {
         match self {
             Choice::Choice(t) => Choice::Choice(t),
             Choice::Separator(s) => Choice::Separator(s.clone()),
             Choice::DefaultSeparator => Choice::DefaultSeparator,
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text,
+                reason: reason.clone(),
+            },
         }
     }
 
     /// Converts `&mut Choice<T>` to `Choice<&mut T>`.
     ///
-    /// This will clone the [`Choice::Separator`] if any.
+    /// This will clone the text of the [`Choice::Separator`] or the reason of the
+    /// [`Choice::Disabled`] if any.
     pub fn as_mut(&mut self) -> Choice<&mut T> {
         match self {
             Choice::Choice(t) => Choice::Choice(t),
             Choice::Separator(s) => Choice::Separator(s.clone()),
             Choice::DefaultSeparator => Choice::DefaultSeparator,
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text,
+                reason: reason.clone(),
+            },
         }
     }
 
@@ -226,17 +493,28 @@ impl<T> Choice<T> {
     pub fn unwrap_choice(self) -> T {
         match self {
             Choice::Choice(c) => c,
-            _ => panic!("Called unwrap_choice on separator"),
+            _ => panic!("Called unwrap_choice on separator or disabled choice"),
         }
     }
 }
 
+/// The text that is shown for a choice, where the text of a [`Choice::Disabled`] is followed by the
+/// reason in parentheses.
+pub(crate) fn shown_text(text: String, reason: Option<&str>) -> Text<String> {
+    match reason {
+        Some(reason) => Text::new(format!("{} ({})", text, reason)),
+        None => Text::new(text),
+    }
+}
+
 #[inline]
-pub(crate) fn get_sep_str<T>(separator: &Choice<T>) -> &str {
+pub(crate) fn get_sep_str<T>(separator: &Choice<T>) -> Cow<'_, str> {
     match separator {
-        Choice::Choice(_) => unreachable!(),
-        Choice::Separator(s) => s,
-        Choice::DefaultSeparator => "──────────────",
+        Choice::Choice(_) | Choice::Disabled { .. } => unreachable!(),
+        Choice::Separator(s) => Cow::Borrowed(s),
+        Choice::DefaultSeparator => {
+            Cow::Owned(ui::symbols::current().box_horizontal.to_string().repeat(14))
+        }
     }
 }
 
@@ -247,14 +525,14 @@ impl<T: ui::Widget> ui::Widget for Choice<T> {
         backend: &mut B,
     ) -> io::Result<()> {
         match self {
-            Choice::Choice(c) => c.render(layout, backend),
+            Choice::Choice(c) | Choice::Disabled { text: c, .. } => c.render(layout, backend),
             sep => get_sep_str(sep).render(layout, backend),
         }
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
         match self {
-            Choice::Choice(c) => c.height(layout),
+            Choice::Choice(c) | Choice::Disabled { text: c, .. } => c.height(layout),
             _ => 1,
         }
     }
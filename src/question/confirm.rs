@@ -1,39 +1,262 @@
-use std::io;
+use std::{borrow::Cow, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
+    events::{KeyCode, KeyEvent, KeyModifiers},
     style::Stylize,
     widgets, Prompt, Validation, Widget,
 };
 
-use super::{Options, TransformByVal as Transform};
+use super::{describe_text, Options, TransformByVal as Transform};
 use crate::{Answer, Answers};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct Confirm<'a> {
     default: Option<bool>,
+    allow_abstain: bool,
+    labels: Labels,
     transform: Transform<'a, bool>,
 }
 
+/// The words and keys which are used to answer yes or no.
+#[derive(Debug, Clone)]
+struct Labels {
+    yes: Cow<'static, str>,
+    no: Cow<'static, str>,
+    yes_key: char,
+    no_key: char,
+}
+
+impl Default for Labels {
+    fn default() -> Self {
+        Self {
+            yes: Cow::Borrowed("Yes"),
+            no: Cow::Borrowed("No"),
+            yes_key: 'y',
+            no_key: 'n',
+        }
+    }
+}
+
+impl Labels {
+    /// The hint shown after the message, which is made of the keys with the key of the default
+    /// answer capitalised, such as `Y/n`.
+    ///
+    /// The hint is only allocated if the keys are not the default ones.
+    fn hint(&self, default: Option<bool>, allow_abstain: bool) -> Cow<'static, str> {
+        if (self.yes_key, self.no_key) == ('y', 'n') {
+            return Cow::Borrowed(match (default, allow_abstain) {
+                (Some(true), false) => "Y/n",
+                (Some(false), false) => "y/N",
+                (None, false) => "y/n",
+                (Some(true), true) => "Y/n/c",
+                (Some(false), true) => "y/N/c",
+                (None, true) => "y/n/c",
+            });
+        }
+
+        let key = |key: char, is_default: bool| -> String {
+            if is_default {
+                key.to_uppercase().collect()
+            } else {
+                key.to_lowercase().collect()
+            }
+        };
+
+        let mut hint = format!(
+            "{}/{}",
+            key(self.yes_key, default == Some(true)),
+            key(self.no_key, default == Some(false)),
+        );
+
+        if allow_abstain {
+            hint.push_str("/c");
+        }
+
+        Cow::Owned(hint)
+    }
+
+    fn label(&self, ans: bool) -> &str {
+        if ans {
+            &self.yes
+        } else {
+            &self.no
+        }
+    }
+}
+
 struct ConfirmPrompt<'a> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<&'a str, Cow<'static, str>>,
     confirm: Confirm<'a>,
-    input: widgets::CharInput,
+    input: widgets::StringInput,
+}
+
+const CANCEL: &str = "cancel";
+
+/// Returns the rest of `word` if `input` is a prefix of it, ignoring case.
+fn strip_prefix_ignore_case<'w>(word: &'w str, input: &str) -> Option<&'w str> {
+    let mut word_chars = word.chars();
+
+    for c in input.chars() {
+        match word_chars.next() {
+            Some(w) if w.to_lowercase().eq(c.to_lowercase()) => {}
+            _ => return None,
+        }
+    }
+
+    Some(word_chars.as_str())
+}
+
+/// Whether `input` is just the given `key`, ignoring case.
+fn is_key(input: &str, key: char) -> bool {
+    let mut chars = input.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c.to_lowercase().eq(key.to_lowercase()),
+        _ => false,
+    }
+}
+
+impl ConfirmPrompt<'_> {
+    /// Returns the answer the current input is a prefix of along with the remaining part of the
+    /// word, or `None` if it is empty or does not match any word. Abstaining is `Some(None)`.
+    fn get_match(&self) -> Option<(Option<bool>, &str)> {
+        self.match_input(self.input.value())
+    }
+
+    /// Returns the answer `input` is a prefix of along with the remaining part of the word.
+    ///
+    /// The input also matches an answer if it is just the key of the answer, in which case the
+    /// remaining part is empty unless the key is also the start of the word.
+    fn match_input(&self, input: &str) -> Option<(Option<bool>, &str)> {
+        let labels = &self.confirm.labels;
+
+        if input.is_empty() {
+            None
+        } else if let Some(rest) = strip_prefix_ignore_case(&labels.yes, input) {
+            Some((Some(true), rest))
+        } else if let Some(rest) = strip_prefix_ignore_case(&labels.no, input) {
+            Some((Some(false), rest))
+        } else if let Some(rest) =
+            strip_prefix_ignore_case(CANCEL, input).filter(|_| self.confirm.allow_abstain)
+        {
+            Some((None, rest))
+        } else if is_key(input, labels.yes_key) {
+            Some((Some(true), ""))
+        } else if is_key(input, labels.no_key) {
+            Some((Some(false), ""))
+        } else {
+            None
+        }
+    }
+
+    fn check_complete_word(&mut self) -> bool {
+        let value = match self.get_match() {
+            Some((_, rest)) if !rest.is_empty() => format!("{}{}", self.input.value(), rest),
+            _ => return false,
+        };
+
+        // The cursor is placed by characters, not columns
+        let len = value.chars().count();
+        self.input.set_value(value);
+        self.input.set_at(len);
+        true
+    }
+
+    /// Like with a single key, a letter typed at the end which does not continue the word replaces
+    /// the input if it starts an answer on its own, and is ignored otherwise.
+    fn replace_with_letter(&mut self, c: char) -> bool {
+        let mut value = self.input.value().to_owned();
+        value.push(c);
+
+        if self.match_input(&value).is_some() {
+            self.input.set_value(value);
+        } else if self.match_input(c.encode_utf8(&mut [0; 4])).is_some() {
+            self.input.set_value(c.to_string());
+        } else {
+            return false;
+        }
+
+        self.input.set_at(self.input.value().chars().count());
+        true
+    }
 }
 
 impl Widget for ConfirmPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        let mut original_layout = *layout;
         self.prompt.render(layout, b)?;
-        self.input.render(layout, b)
+
+        let is_valid = self.input.value().is_empty() || self.get_match().is_some();
+
+        // if the current input is not a prefix of either word, then we show its wrong by using the
+        // error colour
+        if !is_valid {
+            b.set_fg(ui::style::theme().error)?;
+        }
+        self.input.render(layout, b)?;
+        if !is_valid {
+            b.set_fg(ui::style::Color::Reset)?;
+        }
+
+        if let Some((_, rest)) = self.get_match().filter(|(_, rest)| !rest.is_empty()) {
+            b.set_fg(ui::style::theme().hint)?;
+            write!(b, "{}", rest)?;
+            b.set_fg(ui::style::Color::Reset)?;
+            // We need to update the layout to reflect the rest of the word that is rendered.
+            self.height(&mut original_layout);
+            *layout = original_layout;
+        }
+
+        Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.input.height(layout) - 1
+        let mut height = self.prompt.height(layout) + self.input.height(layout) - 1;
+
+        if let Some((_, rest)) = self.get_match() {
+            let width = ui::layout::display_width(rest);
+
+            if width > layout.line_width() {
+                layout.line_offset = width - layout.line_width();
+                layout.offset_y += 1;
+                height += 1;
+            } else {
+                layout.line_offset += width;
+            }
+        }
+
+        height
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        self.input.handle_key(key)
+        if key.code == KeyCode::Tab && self.check_complete_word() {
+            return true;
+        }
+
+        if key.code == KeyCode::Esc && self.confirm.allow_abstain {
+            self.input.set_value(CANCEL.to_owned());
+            self.input.set_at(CANCEL.len());
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char(c)
+                if c.is_alphabetic()
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                    && self.input.get_at() == self.input.value().chars().count() =>
+            {
+                return self.replace_with_letter(c);
+            }
+            _ => {}
+        }
+
+        if self.input.handle_key(key) {
+            true
+        } else {
+            key.code == KeyCode::Right && self.check_complete_word()
+        }
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -42,70 +265,164 @@ impl Widget for ConfirmPrompt<'_> {
     }
 }
 
-fn only_yn(c: char) -> Option<char> {
-    match c {
-        'y' | 'Y' | 'n' | 'N' => Some(c),
-        _ => None,
+fn only_letters(c: char) -> Option<char> {
+    if c.is_alphabetic() {
+        Some(c)
+    } else {
+        None
     }
 }
 
 impl Prompt for ConfirmPrompt<'_> {
-    type ValidateErr = &'static str;
-    type Output = bool;
+    type ValidateErr = String;
+    type Output = Option<bool>;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if self.input.value().is_some() || self.confirm.default.is_some() {
+        let labels = &self.confirm.labels;
+
+        if self.get_match().is_some()
+            || (self.input.value().is_empty() && self.confirm.default.is_some())
+        {
             Ok(Validation::Finish)
+        } else if self.confirm.allow_abstain {
+            Err(format!(
+                "Please enter {}, {} or cancel",
+                labels.yes.to_lowercase(),
+                labels.no.to_lowercase()
+            ))
         } else {
-            Err("Please enter y or n")
+            Err(format!(
+                "Please enter {} or {}",
+                labels.yes.to_lowercase(),
+                labels.no.to_lowercase()
+            ))
         }
     }
 
+    fn describe(&mut self) -> Option<String> {
+        let default = self
+            .confirm
+            .default
+            .map(|default| self.confirm.labels.label(default).to_lowercase());
+        Some(describe_text(self.input.value(), default.as_deref()))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.clone()
+    }
+
     fn finish(self) -> Self::Output {
-        match self.input.value() {
-            Some('y') | Some('Y') => true,
-            Some('n') | Some('N') => false,
-            _ => self
-                .confirm
-                .default
-                .expect("Validation would fail if there was no answer and no default"),
+        match self.get_match() {
+            Some((ans, _)) => ans,
+            None => Some(
+                self.confirm
+                    .default
+                    .expect("Validation would fail if there was no answer and no default"),
+            ),
         }
     }
 }
 
 impl<'a> Confirm<'a> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        match *answer {
+            Answer::Bool(default) | Answer::OptionBool(Some(default)) => {
+                self.default = Some(default)
+            }
+            _ => {}
+        }
+    }
+
     fn into_confirm_prompt(self, message: &'a str) -> ConfirmPrompt<'a> {
-        let hint = match self.default {
-            Some(true) => "Y/n",
-            Some(false) => "y/N",
-            None => "y/n",
-        };
+        let hint = self.labels.hint(self.default, self.allow_abstain);
 
         ConfirmPrompt {
             prompt: widgets::Prompt::new(message).with_hint(hint),
             confirm: self,
-            input: widgets::CharInput::with_filter_map(only_yn),
+            input: widgets::StringInput::with_filter_map(only_letters),
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let allow_abstain = self.allow_abstain;
+        let labels = self.labels.clone();
+
+        let input = ui::Input::new(self.into_confirm_prompt(&message), b);
+        let ans = driver.run(input).await?;
+
+        Self::write_finished(transform, message, ans, allow_abstain, &labels, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = match *ans {
+            Answer::Bool(ans) => Some(ans),
+            Answer::OptionBool(ans) => ans,
+            _ => panic!("answer must be a bool or an optional bool"),
+        };
+        let transform = self.transform.take();
+        let allow_abstain = self.allow_abstain;
+        let labels = self.labels.clone();
+
+        Self::write_finished(
+            transform,
+            message,
+            Some(ans),
+            allow_abstain,
+            &labels,
+            answers,
+            b,
+        )
+        .map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, bool>,
+        message: String,
+        ans: Option<Option<bool>>,
+        allow_abstain: bool,
+        labels: &Labels,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
+        let ans = match ans {
+            // The transform only takes yes or no, so abstaining is always written the same way
+            Some(None) => {
+                if !crate::question::hide_final() {
+                    widgets::Prompt::write_finished_message(&message, false, b)?;
+                    b.write_styled(&"Cancel".with(ui::style::theme().highlight))?;
+                    b.write_all(b"\n")?;
+                }
+                b.flush()?;
+
+                return Ok(Some(Answer::OptionBool(None)));
+            }
+            Some(Some(ans)) => Some(ans),
+            None => None,
+        };
 
-        let ans = ui::Input::new(self.into_confirm_prompt(&message), b)
-            .on_esc(on_esc)
-            .run(events)?;
+        let res: ui::Result<Option<Answer>> =
+            crate::write_final!(transform, message, ans, answers, b, |ans| {
+                b.write_styled(&labels.label(ans).with(ui::style::theme().highlight))?;
+            });
 
-        crate::write_final!(transform, message, ans, answers, b, |ans| {
-            let ans = if ans { "Yes" } else { "No" };
-            b.write_styled(&ans.cyan())?;
-        })
+        if allow_abstain {
+            Ok(res?.map(|ans| Answer::OptionBool(ans.as_bool())))
+        } else {
+            res
+        }
     }
 }
 
@@ -190,6 +507,57 @@ impl<'a> ConfirmBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the confirm
@@ -210,6 +578,114 @@ impl<'a> ConfirmBuilder<'a> {
         self
     }
 
+    /// Allow abstaining from the question by entering `cancel` (or just `c`), or by pressing
+    /// `Esc`, which fills in `cancel`.
+    ///
+    /// With this set, the answer is an [`Answer::OptionBool`] instead of an [`Answer::Bool`], which
+    /// is `None` if the user abstained. This is useful for flows like apply / skip / abort, without
+    /// having to use a [`select`](crate::question::Question::select).
+    ///
+    /// `Esc` is only passed to the prompt if [`on_esc`](ConfirmBuilder::on_esc) is
+    /// [`OnEsc::Ignore`](crate::OnEsc::Ignore), which is the default. The
+    /// [`transform`](ConfirmBuilder::transform) is not called for an abstained answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("apply")
+    ///     .message("Apply the changes?")
+    ///     .allow_abstain(true)
+    ///     .build();
+    /// ```
+    pub fn allow_abstain(mut self, allow_abstain: bool) -> Self {
+        self.confirm.allow_abstain = allow_abstain;
+        self
+    }
+
+    /// Set the words used for the answers, which are `Yes` and `No` by default.
+    ///
+    /// The user can type the words (or the start of them) to answer, and they are shown as the
+    /// answer once the question is finished. The words are matched ignoring case, and only letters
+    /// can be typed. The words can be used to localise the prompt, or to make the answers fit the
+    /// question better, such as "apply" and "skip". The [keys](ConfirmBuilder::keys) should
+    /// usually be changed along with the words.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the words are the same, or if either of them is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .message("Voulez-vous rester anonyme ?")
+    ///     .labels("Oui", "Non")
+    ///     .keys('o', 'n')
+    ///     .build();
+    /// ```
+    pub fn labels<Y, N>(mut self, yes: Y, no: N) -> Self
+    where
+        Y: Into<Cow<'static, str>>,
+        N: Into<Cow<'static, str>>,
+    {
+        let (yes, no) = (yes.into(), no.into());
+
+        assert!(
+            !yes.is_empty() && !no.is_empty(),
+            "the labels of a confirm cannot be empty"
+        );
+        assert!(
+            yes.to_lowercase() != no.to_lowercase(),
+            "the labels of a confirm must be different"
+        );
+
+        self.confirm.labels.yes = yes;
+        self.confirm.labels.no = no;
+        self
+    }
+
+    /// Set the keys which answer yes and no when typed on their own, which are `y` and `n` by
+    /// default.
+    ///
+    /// The keys are shown in the hint, with the key of the [default](ConfirmBuilder::default)
+    /// answer capitalised, such as `(o/N)`. Like the [labels](ConfirmBuilder::labels), they are
+    /// matched ignoring case. If a key is the start of a label, the rest of the label is shown as
+    /// a hint once it is typed.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the keys are the same, or if either of them is not a letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("apply")
+    ///     .message("Apply the changes?")
+    ///     .labels("Apply", "Skip")
+    ///     .keys('a', 's')
+    ///     .build();
+    /// ```
+    pub fn keys(mut self, yes: char, no: char) -> Self {
+        assert!(
+            yes.is_alphabetic() && no.is_alphabetic(),
+            "the keys of a confirm must be letters"
+        );
+        assert!(
+            !yes.to_lowercase().eq(no.to_lowercase()),
+            "the keys of a confirm must be different"
+        );
+
+        self.confirm.labels.yes_key = yes;
+        self.confirm.labels.no_key = no;
+        self
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -273,7 +749,8 @@ mod tests {
         let mut backend = TestBackend::new_with_layout(size, base_layout);
 
         for confirm in confirms.iter_mut() {
-            let offsets = [21, 22, 22];
+            // The rest of the word is shown after the typed letter
+            let offsets = [21, 24, 23];
             let keys = [
                 KeyEvent::from(KeyCode::Char('y')),
                 KeyCode::Char('n').into(),
@@ -315,7 +792,8 @@ mod tests {
         let base_layout = Layout::new(5, size);
 
         for confirm in confirms.iter_mut() {
-            let offsets = [21, 22, 22];
+            // The rest of the word is shown after the typed letter
+            let offsets = [21, 24, 23];
             let keys = [
                 KeyEvent::from(KeyCode::Char('y')),
                 KeyCode::Char('n').into(),
@@ -335,6 +813,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hint() {
+        let labels = Labels::default();
+        assert!(matches!(
+            labels.hint(Some(true), false),
+            Cow::Borrowed("Y/n")
+        ));
+        assert!(matches!(labels.hint(None, true), Cow::Borrowed("y/n/c")));
+
+        let labels = Labels {
+            yes_key: 'o',
+            ..Labels::default()
+        };
+        assert_eq!(labels.hint(Some(false), true), "o/N/c");
+    }
+
+    #[test]
+    fn test_words() {
+        let mut confirm = confirm(None, "message");
+
+        for c in "yE".chars() {
+            assert!(confirm.handle_key(KeyCode::Char(c).into()));
+        }
+        assert_eq!(confirm.get_match(), Some((Some(true), "s")));
+
+        // A letter which does not continue the word starts a new one
+        assert!(confirm.handle_key(KeyCode::Char('n').into()));
+        assert_eq!(confirm.input.value(), "n");
+        assert!(confirm.handle_key(KeyCode::Char('o').into()));
+        assert_eq!(confirm.get_match(), Some((Some(false), "")));
+        assert!(confirm.handle_key(KeyCode::Char('Y').into()));
+        assert_eq!(confirm.input.value(), "Y");
+
+        // Letters typed before the end are inserted as they are
+        assert!(confirm.handle_key(KeyCode::Left.into()));
+        assert!(confirm.handle_key(KeyCode::Char('n').into()));
+        assert_eq!(confirm.input.value(), "nY");
+        assert_eq!(confirm.get_match(), None);
+        assert_eq!(confirm.validate(), Err("Please enter yes or no".to_owned()));
+    }
+
+    #[test]
+    fn test_labels() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        let mut confirm = Confirm {
+            default: Some(false),
+            ..Default::default()
+        };
+        confirm.labels = Labels {
+            yes: "Appliquer".into(),
+            no: "Ignorer".into(),
+            yes_key: 'o',
+            no_key: 'n',
+        };
+        let mut confirm = confirm.into_confirm_prompt("message");
+
+        let mut layout = base_layout;
+        assert!(confirm.render(&mut layout, &mut backend).is_ok());
+        ui::assert_backend_snapshot!(backend);
+
+        // The keys answer on their own, and the start of the words are completed
+        assert!(confirm.handle_key(KeyCode::Char('o').into()));
+        assert_eq!(confirm.get_match(), Some((Some(true), "")));
+        assert!(confirm.handle_key(KeyCode::Backspace.into()));
+        assert!(confirm.handle_key(KeyCode::Char('I').into()));
+        assert_eq!(confirm.get_match(), Some((Some(false), "gnorer")));
+        // Letters which do not start either word are ignored
+        assert!(!confirm.handle_key(KeyCode::Char('x').into()));
+        assert_eq!(confirm.input.value(), "I");
+        assert!(confirm.handle_key(KeyCode::Tab.into()));
+        assert_eq!(confirm.input.value(), "Ignorer");
+        assert_eq!(confirm.finish(), Some(false));
+
+        let labels = Labels {
+            yes: "Appliquer".into(),
+            no: "Ignorer".into(),
+            yes_key: 'o',
+            no_key: 'n',
+        };
+        let mut backend = TestBackend::new(size);
+        Confirm::write_finished(
+            Transform::None,
+            "message".into(),
+            Some(Some(false)),
+            false,
+            &labels,
+            &Answers::default(),
+            &mut backend,
+        )
+        .unwrap();
+        ui::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_wide_labels() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+
+        let mut confirm = confirm(None, "message");
+        confirm.confirm.labels = Labels {
+            yes: "はい".into(),
+            no: "いいえ".into(),
+            ..Labels::default()
+        };
+
+        // The typed `い` and the rest of the word `いえ` take two columns per character
+        assert!(confirm.handle_key(KeyCode::Char('い').into()));
+        assert_eq!(confirm.get_match(), Some((Some(false), "いえ")));
+        let mut layout = base_layout;
+        assert_eq!(confirm.height(&mut layout), 1);
+        assert_eq!(layout, base_layout.with_line_offset(27));
+    }
+
     #[test]
     fn test_cursor_pos() {
         let size = (50, 20).into();
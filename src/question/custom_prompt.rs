@@ -1,4 +1,10 @@
-use ui::{backend::Backend, events::EventIterator};
+#[cfg(feature = "async")]
+use ui::events::AsyncEventIterator;
+use ui::{
+    backend::Backend,
+    events::EventIterator,
+    style::{Color, Stylize},
+};
 
 use super::{Options, Question, QuestionKind};
 use crate::{Answer, Answers};
@@ -11,7 +17,14 @@ use crate::{Answer, Answers};
 /// the rest of the in-built questions.
 ///
 /// You can use the `requestty-ui` crate to build the prompts. You can see the implementations of
-/// the in-built questions for examples on how to use it.
+/// the in-built questions for examples on how to use it. The widgets are also re-exported in
+/// [`prompt::widgets`], and [`write_finished`] writes the line shown once the question is answered
+/// the same way as the in-built questions.
+///
+/// Custom prompts are always asked synchronously. When they are asked with the asynchronous
+/// methods of the `async` feature, they block the current thread while waiting for events.
+///
+/// [`prompt::widgets`]: crate::prompt::widgets
 ///
 /// See also [`Question::custom`]
 pub trait Prompt: std::fmt::Debug {
@@ -23,6 +36,98 @@ pub trait Prompt: std::fmt::Debug {
         backend: &mut dyn Backend,
         events: &mut dyn EventIterator,
     ) -> ui::Result<Option<Answer>>;
+
+    /// Write a previously given answer after the finished message of the prompt.
+    ///
+    /// This is used by [`Question::render_finished`]. By default, the answer is written in cyan,
+    /// with list items separated by commas. Nothing is written for [`Answer::Map`],
+    /// [`Answer::List`] or [`Answer::Custom`], so prompts giving custom answers should override
+    /// this.
+    fn write_answer(
+        &self,
+        answer: &Answer,
+        _answers: &Answers,
+        backend: &mut dyn Backend,
+    ) -> ui::Result<()> {
+        backend.set_fg(ui::style::theme().highlight)?;
+
+        match answer {
+            Answer::String(s) => write!(backend, "{}", s)?,
+            Answer::ListItem(item) => write!(backend, "{}", item.text)?,
+            Answer::ExpandItem(item) => write!(backend, "{}", item.text)?,
+            Answer::Int(i) => write!(backend, "{}", i)?,
+            Answer::Float(f) => write!(backend, "{}", f)?,
+            Answer::Bool(b) | Answer::OptionBool(Some(b)) => {
+                write!(backend, "{}", if *b { "Yes" } else { "No" })?
+            }
+            Answer::OptionBool(None) => backend.write_all(b"Cancel")?,
+            Answer::ListItems(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i != 0 {
+                        backend.write_all(b", ")?;
+                    }
+                    write!(backend, "{}", item.text)?;
+                }
+            }
+            Answer::Skipped | Answer::Map(_) | Answer::List(_) | Answer::Custom(_) => {}
+        }
+
+        backend.set_fg(Color::Reset)?;
+        Ok(())
+    }
+}
+
+/// Writes the line shown once a question has been answered, in the same style as the in-built
+/// questions.
+///
+/// The `message` is followed by the `answer` in the highlight colour of the current
+/// [`Theme`](ui::style::Theme). If the `answer` is `None`, the question is shown as skipped. The
+/// line is ended and the `backend` is flushed.
+///
+/// Nothing is written if the question is asked with [`hide_final`](CustomPromptBuilder::hide_final),
+/// so custom prompts which use this honour it without doing anything else.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::prompt::{self, Answer, Answers, Backend, EventIterator};
+///
+/// #[derive(Debug)]
+/// struct MyPrompt { /* ... */ }
+///
+/// impl prompt::Prompt for MyPrompt {
+///     fn ask(
+///         self,
+///         message: String,
+///         answers: &Answers,
+///         backend: &mut dyn Backend,
+///         events: &mut dyn EventIterator,
+///     ) -> requestty::Result<Option<Answer>> {
+///         // ...
+///
+///         prompt::write_finished(&message, Some("answer"), backend)?;
+///         Ok(Some(Answer::String("answer".to_owned())))
+///     }
+/// }
+/// ```
+pub fn write_finished(
+    message: &str,
+    answer: Option<&str>,
+    mut backend: &mut dyn Backend,
+) -> ui::Result<()> {
+    if !super::hide_final() {
+        ui::widgets::Prompt::write_finished_message(&message, answer.is_none(), &mut backend)?;
+
+        match answer {
+            Some(answer) => backend.write_styled(&answer.with(ui::style::theme().highlight))?,
+            None => backend.write_styled(&"Skipped".dark_grey())?,
+        }
+
+        backend.write_all(b"\n")?;
+    }
+
+    backend.flush()?;
+    Ok(())
 }
 
 /// The same trait as `Prompt`, except it take `&mut self` instead of `self`.
@@ -40,6 +145,13 @@ pub(super) trait CustomPromptInteral: std::fmt::Debug {
         backend: &mut dyn Backend,
         events: &mut dyn EventIterator,
     ) -> ui::Result<Option<Answer>>;
+
+    fn write_answer(
+        &self,
+        answer: &Answer,
+        answers: &Answers,
+        backend: &mut dyn Backend,
+    ) -> ui::Result<()>;
 }
 
 impl<T: Prompt> CustomPromptInteral for Option<T> {
@@ -54,6 +166,52 @@ impl<T: Prompt> CustomPromptInteral for Option<T> {
             .expect("Prompt::ask called twice")
             .ask(message, answers, backend, events)
     }
+
+    fn write_answer(
+        &self,
+        answer: &Answer,
+        answers: &Answers,
+        backend: &mut dyn Backend,
+    ) -> ui::Result<()> {
+        self.as_ref()
+            .expect("Prompt::ask called before Prompt::write_answer")
+            .write_answer(answer, answers, backend)
+    }
+}
+
+/// An [`EventIterator`] which blocks the current thread until the wrapped [`AsyncEventIterator`]
+/// has an event.
+///
+/// Custom prompts can only be asked synchronously, so this is used to ask them with asynchronous
+/// events.
+#[cfg(feature = "async")]
+pub(super) struct BlockingEvents<E>(pub(super) E);
+
+#[cfg(feature = "async")]
+impl<E: AsyncEventIterator> EventIterator for BlockingEvents<E> {
+    fn next_event(&mut self) -> std::io::Result<ui::events::KeyEvent> {
+        struct ThreadWaker(std::thread::Thread);
+
+        impl std::task::Wake for ThreadWaker {
+            fn wake(self: std::sync::Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut cx = std::task::Context::from_waker(&waker);
+
+        loop {
+            match self.0.poll_next_event(&mut cx) {
+                std::task::Poll::Ready(event) => return event,
+                std::task::Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    fn discard_pending(&mut self) -> std::io::Result<()> {
+        self.0.discard_pending()
+    }
 }
 
 /// The builder for [custom questions].
@@ -209,6 +367,145 @@ impl<'a> CustomPromptBuilder<'a> {
     ///     .ask_if_answered(true)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::{self, style::{Color, Theme}}, Question};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn new() -> MyPrompt {
+    /// #         MyPrompt {}
+    /// #     }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &prompt::Answers,
+    ///         backend: &mut dyn prompt::Backend,
+    ///         events: &mut dyn prompt::EventIterator,
+    ///     ) -> requestty::Result<Option<prompt::Answer>> {
+    ///         // ...
+    /// #         todo!()
+    ///     }
+    /// }
+    ///
+    /// let prompt = Question::custom("my-prompt", MyPrompt::new())
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt, Question};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn new() -> MyPrompt {
+    /// #         MyPrompt {}
+    /// #     }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &prompt::Answers,
+    ///         backend: &mut dyn prompt::Backend,
+    ///         events: &mut dyn prompt::EventIterator,
+    ///     ) -> requestty::Result<Option<prompt::Answer>> {
+    ///         // ...
+    /// #         todo!()
+    ///     }
+    /// }
+    ///
+    /// let prompt = Question::custom("my-prompt", MyPrompt::new())
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt, Question};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn new() -> MyPrompt {
+    /// #         MyPrompt {}
+    /// #     }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &prompt::Answers,
+    ///         backend: &mut dyn prompt::Backend,
+    ///         events: &mut dyn prompt::EventIterator,
+    ///     ) -> requestty::Result<Option<prompt::Answer>> {
+    ///         // ...
+    /// #         todo!()
+    ///     }
+    /// }
+    ///
+    /// let prompt = Question::custom("my-prompt", MyPrompt::new())
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt, Question};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn new() -> MyPrompt {
+    /// #         MyPrompt {}
+    /// #     }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &prompt::Answers,
+    ///         backend: &mut dyn prompt::Backend,
+    ///         events: &mut dyn prompt::EventIterator,
+    ///     ) -> requestty::Result<Option<prompt::Answer>> {
+    ///         // ...
+    /// #         todo!()
+    ///     }
+    /// }
+    ///
+    /// let prompt = Question::custom("my-prompt", MyPrompt::new())
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Consumes the builder returning a [`Question`]
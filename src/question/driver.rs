@@ -0,0 +1,261 @@
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+};
+
+#[cfg(feature = "async")]
+use ui::events::AsyncEventIterator;
+use ui::{
+    backend::Backend,
+    events::{Event, EventIterator, KeyEvent},
+    Input, OnEsc, Prompt, Recovered,
+};
+
+#[cfg(feature = "async")]
+use super::custom_prompt::BlockingEvents;
+
+/// The future returned by [`Driver::run`].
+pub(crate) type Run<'d, P> =
+    Pin<Box<dyn Future<Output = Result<Option<<P as Prompt>::Output>, Recovered<P>>> + 'd>>;
+
+/// Runs the [`Input`] of a question with the events the question is asked with.
+///
+/// Every kind of question is asked by a single `async fn`, which builds its prompt and gives it to
+/// the driver. The same code is then used to ask questions synchronously with [`SyncDriver`] and
+/// asynchronously with [`AsyncDriver`].
+pub(crate) trait Driver {
+    /// Displays the prompt and processes events until it is finished, like
+    /// [`Input::run_with_recovery`].
+    fn run<'d, P, B>(&'d mut self, input: Input<P, B>) -> Run<'d, P>
+    where
+        P: Prompt + 'd,
+        B: Backend + 'd;
+
+    /// The events as an [`EventIterator`], for custom prompts which can only be asked
+    /// synchronously.
+    fn events(&mut self) -> &mut dyn EventIterator;
+
+    /// Discards the events which have been received but not read yet, like
+    /// [`EventIterator::discard_pending`].
+    fn discard_pending(&mut self) -> io::Result<()>;
+}
+
+/// Gets the output of a future which only waits on a [`SyncDriver`].
+///
+/// # Panics
+///
+/// Panics if the future is not ready the first time it is polled.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Arc::new(NoopWaker).into();
+    let mut cx = Context::from_waker(&waker);
+
+    match Box::pin(future).as_mut().poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("questions asked synchronously never wait"),
+    }
+}
+
+/// Runs inputs synchronously, so the futures of questions asked with it never have to wait.
+pub(crate) struct SyncDriver<'e, E>(TypeAheadEvents<'e, E>);
+
+impl<'e, E: EventIterator> SyncDriver<'e, E> {
+    /// Creates a driver which reads the `events`. The keys typed ahead of each input are discarded
+    /// unless `type_ahead` is true.
+    pub(crate) fn new(events: &'e mut E, type_ahead: bool) -> Self {
+        SyncDriver(TypeAheadEvents::new(events, type_ahead))
+    }
+}
+
+impl<E: EventIterator> Driver for SyncDriver<'_, E> {
+    fn run<'d, P, B>(&'d mut self, input: Input<P, B>) -> Run<'d, P>
+    where
+        P: Prompt + 'd,
+        B: Backend + 'd,
+    {
+        self.0.start();
+        Box::pin(std::future::ready(input.run_with_recovery(&mut self.0)))
+    }
+
+    fn events(&mut self) -> &mut dyn EventIterator {
+        self.0.start();
+        &mut self.0
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        EventIterator::discard_pending(&mut self.0)
+    }
+}
+
+/// Runs inputs without blocking while waiting for events.
+///
+/// Custom prompts are still asked synchronously, and block the current thread while waiting for
+/// events.
+#[cfg(feature = "async")]
+pub(crate) struct AsyncDriver<'e, E>(BlockingEvents<TypeAheadEvents<'e, E>>);
+
+#[cfg(feature = "async")]
+impl<'e, E: AsyncEventIterator> AsyncDriver<'e, E> {
+    /// Creates a driver which reads the `events`. The keys typed ahead of each input are discarded
+    /// unless `type_ahead` is true.
+    pub(crate) fn new(events: &'e mut E, type_ahead: bool) -> Self {
+        AsyncDriver(BlockingEvents(TypeAheadEvents::new(events, type_ahead)))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<E: AsyncEventIterator> Driver for AsyncDriver<'_, E> {
+    fn run<'d, P, B>(&'d mut self, input: Input<P, B>) -> Run<'d, P>
+    where
+        P: Prompt + 'd,
+        B: Backend + 'd,
+    {
+        self.0 .0.start();
+        Box::pin(input.run_async_with_recovery(&mut self.0 .0))
+    }
+
+    fn events(&mut self) -> &mut dyn EventIterator {
+        self.0 .0.start();
+        &mut self.0
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        AsyncEventIterator::discard_pending(&mut self.0 .0)
+    }
+}
+
+/// Runs inputs with another driver, after setting the keys which leave the question before it is
+/// answered and whether the cursor is hidden.
+///
+/// Custom prompts do not know about these options.
+pub(super) struct WithInputOptions<'d, D> {
+    pub(super) driver: &'d mut D,
+    pub(super) on_esc: OnEsc,
+    pub(super) back_key: Option<KeyEvent>,
+    /// Hides the cursor even for questions which do not do it themselves.
+    pub(super) hide_cursor: bool,
+}
+
+impl<D: Driver> Driver for WithInputOptions<'_, D> {
+    fn run<'d, P, B>(&'d mut self, input: Input<P, B>) -> Run<'d, P>
+    where
+        P: Prompt + 'd,
+        B: Backend + 'd,
+    {
+        let mut input = input.on_esc(self.on_esc).back_key(self.back_key);
+        if self.hide_cursor {
+            input = input.hide_cursor();
+        }
+
+        self.driver.run(input)
+    }
+
+    fn events(&mut self) -> &mut dyn EventIterator {
+        self.driver.events()
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.driver.discard_pending()
+    }
+}
+
+/// The events read by the drivers, which discards the keys typed ahead of each input if type ahead
+/// is disabled.
+///
+/// The keys are only discarded when the input first reads an event. By then, the input has been
+/// rendered and the terminal is in raw mode, so even a partially typed line is discarded.
+struct TypeAheadEvents<'e, E> {
+    events: &'e mut E,
+    type_ahead: bool,
+    discard: bool,
+}
+
+impl<'e, E> TypeAheadEvents<'e, E> {
+    fn new(events: &'e mut E, type_ahead: bool) -> Self {
+        Self {
+            events,
+            type_ahead,
+            discard: false,
+        }
+    }
+
+    /// Called when an input starts, so that the keys typed before it are discarded.
+    fn start(&mut self) {
+        self.discard = !self.type_ahead;
+    }
+}
+
+impl<E: EventIterator> TypeAheadEvents<'_, E> {
+    fn discard_type_ahead(&mut self) -> io::Result<()> {
+        if std::mem::take(&mut self.discard) {
+            self.events.discard_pending()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: EventIterator> EventIterator for TypeAheadEvents<'_, E> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        self.discard_type_ahead()?;
+        self.events.next_event()
+    }
+
+    fn next_any_event(&mut self) -> io::Result<Event> {
+        self.discard_type_ahead()?;
+        self.events.next_any_event()
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.discard = false;
+        self.events.discard_pending()
+    }
+
+    fn wait_for_event(&mut self, timeout: std::time::Duration) -> io::Result<bool> {
+        self.discard_type_ahead()?;
+        self.events.wait_for_event(timeout)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<E: AsyncEventIterator> TypeAheadEvents<'_, E> {
+    fn discard_type_ahead_async(&mut self) -> io::Result<()> {
+        if std::mem::take(&mut self.discard) {
+            self.events.discard_pending()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<E: AsyncEventIterator> AsyncEventIterator for TypeAheadEvents<'_, E> {
+    fn poll_next_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<KeyEvent>> {
+        if let Err(e) = self.discard_type_ahead_async() {
+            return Poll::Ready(Err(e));
+        }
+
+        self.events.poll_next_event(cx)
+    }
+
+    fn poll_next_any_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Event>> {
+        if let Err(e) = self.discard_type_ahead_async() {
+            return Poll::Ready(Err(e));
+        }
+
+        self.events.poll_next_any_event(cx)
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.discard = false;
+        self.events.discard_pending()
+    }
+}
@@ -0,0 +1,161 @@
+use std::{cell::RefCell, fmt, ops::Range, rc::Rc};
+
+use ui::widgets::Text;
+
+use super::{Choice, ChoiceList};
+
+/// A source of choices for a [`select`] or [`multi_select`] which are only fetched once they are
+/// shown.
+///
+/// Only the number of choices is needed up front. The rest are fetched a page at a time as the user
+/// moves through the list, which makes it possible to prompt over tens of thousands of choices, for
+/// example from a database or an API, without loading all of them before asking.
+///
+/// Since the choices are not known up front, every choice is given a single line and longer ones
+/// are cut off. Lists with dynamic choices also cannot be [filtered] or laid out in [columns].
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Range;
+///
+/// use requestty::{question::DynamicList, Question};
+///
+/// struct Orders;
+///
+/// impl DynamicList for Orders {
+///     fn len(&self) -> usize {
+///         50_000
+///     }
+///
+///     fn fetch(&mut self, range: Range<usize>) -> Vec<String> {
+///         // This could query a database for just these orders instead
+///         range.map(|i| format!("Order #{}", i + 1)).collect()
+///     }
+/// }
+///
+/// let select = Question::select("order")
+///     .message("Which order do you want to refund?")
+///     .dynamic_choices(Orders)
+///     .build();
+/// ```
+///
+/// [`select`]: crate::question::Question::select
+/// [`multi_select`]: crate::question::Question::multi_select
+/// [filtered]: crate::question::SelectBuilder::filterable
+/// [columns]: crate::question::SelectBuilder::columns
+pub trait DynamicList {
+    /// The number of choices in the list.
+    ///
+    /// This is only called once, when the list is given to the builder.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no choices in the list.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fetches the text of the choices at the indices in `range`, in order.
+    ///
+    /// The range is never empty and never extends past the [`len`](DynamicList::len) of the list.
+    /// Exactly one text must be returned for every index in the range. Choices which were fetched
+    /// before are left out of the range, unless they are between choices which were not.
+    fn fetch(&mut self, range: Range<usize>) -> Vec<String>;
+}
+
+/// The [`DynamicList`] of a list, and which of its choices have been fetched.
+#[derive(Clone)]
+pub(super) struct DynamicChoices<'a> {
+    /// Fetches from the list, which is shared by the copies of the question
+    fetch: Rc<dyn Fn(Range<usize>) -> Vec<String> + 'a>,
+    /// The index of the first choice of the `list` in the [`ChoiceList`]
+    offset: usize,
+    /// Whether each choice of the `list` has been fetched
+    fetched: Vec<bool>,
+}
+
+impl fmt::Debug for DynamicChoices<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicChoices")
+            .field("offset", &self.offset)
+            .field("fetched", &self.fetched)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> DynamicChoices<'a> {
+    /// Adds a placeholder to the end of `choices` for every choice of the `list`, which is replaced
+    /// once it is fetched.
+    pub(super) fn new<L: DynamicList + 'a>(
+        list: L,
+        choices: &mut ChoiceList<Text<String>>,
+    ) -> Self {
+        let len = list.len();
+        let list = RefCell::new(list);
+        let offset = choices.len();
+        choices
+            .choices
+            .resize_with(offset + len, || Choice::Choice(Text::new(String::new())));
+
+        Self {
+            fetch: Rc::new(move |range| list.borrow_mut().fetch(range)),
+            offset,
+            fetched: vec![false; len],
+        }
+    }
+
+    /// Whether the choice at `index` of the [`ChoiceList`] is one of the choices of the `list`.
+    pub(super) fn contains(&self, index: usize) -> bool {
+        self.offset <= index && index < self.offset + self.fetched.len()
+    }
+
+    /// Fetches the choices at the `indices` of the [`ChoiceList`] which have not been fetched yet.
+    pub(super) fn load(&mut self, indices: Range<usize>, choices: &mut ChoiceList<Text<String>>) {
+        let start = indices.start.max(self.offset) - self.offset;
+        let end = indices
+            .end
+            .saturating_sub(self.offset)
+            .min(self.fetched.len());
+
+        let start = match (start..end).find(|&i| !self.fetched[i]) {
+            Some(start) => start,
+            None => return,
+        };
+        let end = (start..end)
+            .rfind(|&i| !self.fetched[i])
+            .expect("there is at least one choice to fetch")
+            + 1;
+
+        let texts = (self.fetch)(start..end);
+        assert_eq!(
+            texts.len(),
+            end - start,
+            "`DynamicList::fetch` must return a text for every index in the range"
+        );
+
+        for (i, text) in (start..end).zip(texts) {
+            choices[self.offset + i] = Choice::Choice(Text::new(text));
+            self.fetched[i] = true;
+        }
+    }
+
+    /// Fetches every selected choice, so that they can be given in the answer.
+    pub(super) fn load_selected(
+        &mut self,
+        selected: &[bool],
+        choices: &mut ChoiceList<Text<String>>,
+    ) {
+        let end = self.offset + self.fetched.len();
+        let mut i = self.offset;
+
+        while i < end {
+            if selected[i] && !self.fetched[i - self.offset] {
+                let run_end = (i..end).find(|&j| !selected[j]).unwrap_or(end);
+                self.load(i..run_end, choices);
+                i = run_end;
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
@@ -1,20 +1,24 @@
 use std::{
+    cell::RefCell,
     env,
-    fs::File,
+    fs::{File, OpenOptions},
     io::{self, Read, Seek, SeekFrom, Write},
     process::Command,
+    rc::Rc,
 };
 
-use ui::{backend::Backend, events::EventIterator, style::Stylize, widgets, Validation, Widget};
+use ui::{backend::Backend, style::Stylize, widgets, Validation, Widget};
 
-use super::{Filter, Options, Transform, Validate};
+use super::{Filter, Options, Transform, Validate, ValidationMessage};
 use crate::{Answer, Answers, Question};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(super) struct Editor<'a> {
     extension: Option<String>,
     default: Option<String>,
-    editor: Command,
+    /// The editor is shared by the copies of the question, since a [`Command`] cannot be cloned
+    editor: Rc<RefCell<EditorCommand>>,
+    reopen_on_error: bool,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     transform: Transform<'a, str>,
@@ -23,9 +27,10 @@ pub(super) struct Editor<'a> {
 impl<'a> Default for Editor<'a> {
     fn default() -> Self {
         Self {
-            editor: get_editor(),
+            editor: EditorCommand::new(get_editor()),
             extension: None,
             default: None,
+            reopen_on_error: false,
             filter: Filter::None,
             validate: Validate::None,
             transform: Transform::None,
@@ -60,14 +65,90 @@ fn get_editor() -> Command {
     command
 }
 
+/// The command which opens the editor, along with the file it is opened with once it is created.
+#[derive(Debug)]
+struct EditorCommand {
+    command: Command,
+    /// The file is kept until every copy of the question is dropped, since its path has been added
+    /// to the arguments of the command. It is deleted on drop.
+    path: Option<tempfile::TempPath>,
+}
+
+impl EditorCommand {
+    fn new(command: Command) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(EditorCommand {
+            command,
+            path: None,
+        }))
+    }
+}
+
 struct EditorPrompt<'a, 'e> {
     prompt: widgets::Prompt<&'a str>,
     file: File,
     ans: String,
+    /// The validation error written at the top of the file when the editor was reopened
+    header: Option<String>,
     editor: Editor<'e>,
     answers: &'a Answers,
 }
 
+impl EditorPrompt<'_, '_> {
+    /// Opens the editor, and reads what was written once it is closed.
+    fn edit(&mut self) -> io::Result<()> {
+        if !self.editor.editor.borrow_mut().command.status()?.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Could not open editor",
+            ));
+        }
+
+        self.ans.clear();
+        self.file.read_to_string(&mut self.ans)?;
+        self.file.seek(SeekFrom::Start(0))?;
+
+        if let Some(ref header) = self.header {
+            let len = header_len(&self.ans, header);
+            self.ans.drain(..len);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the error as comments at the top of the file, followed by the previous answer.
+    fn write_error(&mut self, err: &ValidationMessage) -> io::Result<()> {
+        let mut header = String::new();
+        for (i, line) in err.as_str().lines().enumerate() {
+            header += if i == 0 { "# Error: " } else { "# " };
+            header += line;
+            header.push('\n');
+        }
+
+        self.file.set_len(0)?;
+        self.file.write_all(header.as_bytes())?;
+        self.file.write_all(self.ans.as_bytes())?;
+        self.file.flush()?;
+        self.file.seek(SeekFrom::Start(0))?;
+
+        self.header = Some(header);
+        Ok(())
+    }
+}
+
+/// The length of the lines at the start of `content` which are part of `header`, so that the
+/// header is removed even if only some of it was deleted.
+fn header_len(content: &str, header: &str) -> usize {
+    content
+        .split_inclusive('\n')
+        .take_while(|line| {
+            header
+                .lines()
+                .any(|header_line| header_line == line.trim_end())
+        })
+        .map(str::len)
+        .sum()
+}
+
 impl Widget for EditorPrompt<'_, '_> {
     fn render<B: Backend>(
         &mut self,
@@ -85,7 +166,7 @@ impl Widget for EditorPrompt<'_, '_> {
         // Cannot return this directly since we want to remove the extra space at the end
         let (x, y) = self.prompt.cursor_pos(layout);
         if x == 0 {
-            (layout.width - 1, y - 1)
+            (layout.width.saturating_sub(1), y.saturating_sub(1))
         } else {
             (x - 1, y)
         }
@@ -96,32 +177,42 @@ impl Widget for EditorPrompt<'_, '_> {
     }
 }
 
-fn map_err(err: io::Error) -> widgets::Text<String> {
-    widgets::Text::new(err.to_string())
+fn map_err(err: io::Error) -> ValidationMessage {
+    err.to_string().into()
 }
 
 impl ui::Prompt for EditorPrompt<'_, '_> {
-    type ValidateErr = widgets::Text<String>;
+    type ValidateErr = ValidationMessage;
     type Output = String;
 
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if !self.editor.editor.status().map_err(map_err)?.success() {
-            return Err(map_err(io::Error::new(
-                io::ErrorKind::Other,
-                "Could not open editor",
-            )));
+        loop {
+            self.edit().map_err(map_err)?;
+
+            let validate = match self.editor.validate {
+                Validate::Sync(ref mut validate) => validate,
+                Validate::None => return Ok(Validation::Finish),
+            };
+
+            match validate(&self.ans, self.answers).into_result() {
+                Err(err) if self.editor.reopen_on_error && err.severity == ui::Severity::Error => {
+                    self.write_error(&err).map_err(map_err)?
+                }
+                res => return res.map(|_| Validation::Finish),
+            }
         }
+    }
 
-        self.ans.clear();
-        self.file.read_to_string(&mut self.ans).map_err(map_err)?;
-        self.file.seek(SeekFrom::Start(0)).map_err(map_err)?;
-
-        if let Validate::Sync(ref mut validate) = self.editor.validate {
-            validate(&self.ans, self.answers)
-                .map_err(|err| map_err(io::Error::new(io::ErrorKind::InvalidInput, err)))?;
-        }
+    fn describe(&mut self) -> Option<String> {
+        Some("Press <enter> to open the editor".to_owned())
+    }
 
-        Ok(Validation::Finish)
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
     }
 
     fn finish(self) -> Self::Output {
@@ -133,21 +224,37 @@ impl ui::Prompt for EditorPrompt<'_, '_> {
 }
 
 impl Editor<'_> {
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
-        mut self,
-        message: String,
-        on_esc: ui::OnEsc,
-        answers: &Answers,
-        b: &mut B,
-        events: &mut E,
-    ) -> ui::Result<Option<Answer>> {
-        let mut builder = tempfile::Builder::new();
-
-        if let Some(ref extension) = self.extension {
-            builder.suffix(extension);
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::String(default) = answer {
+            self.default = Some(default.clone());
         }
+    }
 
-        let mut file = builder.tempfile()?;
+    /// Creates the temporary file to be edited, and adds its path to the editor's arguments.
+    ///
+    /// If the question was asked before, the file created then is emptied and used again.
+    fn create_file(&self) -> io::Result<File> {
+        let mut editor = self.editor.borrow_mut();
+
+        let mut file = match editor.path {
+            Some(ref path) => OpenOptions::new()
+                .read(true)
+                .write(true)
+                .truncate(true)
+                .open(path)?,
+            None => {
+                let mut builder = tempfile::Builder::new();
+
+                if let Some(ref extension) = self.extension {
+                    builder.suffix(extension);
+                }
+
+                let (file, path) = builder.tempfile()?.into_parts();
+                editor.command.arg(&path);
+                editor.path = Some(path);
+                file
+            }
+        };
 
         if let Some(ref default) = self.default {
             file.write_all(default.as_bytes())?;
@@ -155,15 +262,20 @@ impl Editor<'_> {
             file.flush()?;
         }
 
-        let transform = self.transform.take();
-
-        let (file, path) = file.into_parts();
+        Ok(file)
+    }
 
-        // `path` cannot be passed by ownership as it needs to live until the prompt has finished
-        // asking. On drop, path will delete the file
-        self.editor.arg(&path);
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
+        mut self,
+        message: String,
+        answers: &Answers,
+        b: &mut B,
+        driver: &mut D,
+    ) -> ui::Result<Option<Answer>> {
+        let file = self.create_file()?;
+        let transform = self.transform.take();
 
-        let ans = ui::Input::new(
+        let input = ui::Input::new(
             EditorPrompt {
                 prompt: widgets::Prompt::new(&*message)
                     .with_hint("Press <enter> to launch your preferred editor.")
@@ -171,15 +283,38 @@ impl Editor<'_> {
                 editor: self,
                 file,
                 ans: String::new(),
+                header: None,
                 answers,
             },
             b,
-        )
-        .on_esc(on_esc)
-        .run(events)?;
+        );
+        let ans = driver.run(input).await?;
 
+        Self::write_finished(transform, message, ans, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans.as_string().expect("answer must be a string").to_owned();
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, str>,
+        message: String,
+        ans: Option<String>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
         crate::write_final!(transform, message, ans [ref], answers, b, |_ans| b
-            .write_styled(&"Received".dark_grey())?)
+            .write_styled(&"Received".with(ui::style::theme().hint))?)
     }
 }
 
@@ -270,6 +405,57 @@ impl<'a> EditorBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let editor = Question::editor("description")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the file
@@ -311,6 +497,37 @@ impl<'a> EditorBuilder<'a> {
         self
     }
 
+    /// Reopen the editor when the answer fails [`validate`](Self::validate), until it is valid or
+    /// the editor exits with an error.
+    ///
+    /// The editor is reopened with the previous answer, along with the error written as lines
+    /// starting with `#` at the top of the file. These lines are removed from the answer once the
+    /// editor is closed. Warnings and information do not reopen the editor.
+    ///
+    /// By default, this is `false`, and the error is shown below the question instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("config")
+    ///     .extension(".toml")
+    ///     .reopen_on_error(true)
+    ///     .validate(|config, previous_answers| {
+    ///         if config.contains("name =") {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("line 1: `name` is required".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn reopen_on_error(mut self, reopen_on_error: bool) -> Self {
+        self.editor.reopen_on_error = reopen_on_error;
+        self
+    }
+
     /// Use a specific editor instead of the default editor
     ///
     /// If unspecified, the editor is determined by the `$VISUAL` or `$EDITOR` environment
@@ -330,7 +547,7 @@ impl<'a> EditorBuilder<'a> {
     ///     .build();
     /// ```
     pub fn editor<E: Into<Command>>(mut self, editor: E) -> Self {
-        self.editor.editor = editor.into();
+        self.editor.editor = EditorCommand::new(editor.into());
         self
     }
 
@@ -4,14 +4,16 @@ use ui::{backend::Backend, widgets::Text};
 
 use super::{Expand, ExpandText};
 use crate::{
-    question::{Choice, Options},
+    question::{shown_text, Choice, Options},
     ExpandItem,
 };
 
 /// The builder for a [`expand`] prompt.
 ///
-/// The keys are ascii case-insensitive characters. The 'h' option is added by the prompt and
-/// shouldn't be defined.
+/// The keys are ascii case-insensitive, and are usually a single character. When there are too many
+/// choices for single characters, keys can have multiple characters, which are selected by typing
+/// them out -- a key is picked as soon as only one key starts with what was typed. The 'h' option
+/// is added by the prompt and shouldn't be defined.
 ///
 /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
 /// but [`Choice::Separator`]s can only be single line.
@@ -45,7 +47,7 @@ use crate::{
 pub struct ExpandBuilder<'a> {
     opts: Options<'a>,
     expand: Expand<'a>,
-    keys: HashSet<char>,
+    keys: HashSet<String>,
 }
 
 impl<'a> ExpandBuilder<'a> {
@@ -104,13 +106,65 @@ impl<'a> ExpandBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default key for the expand
     ///
     /// If no key is entered by the user and they press `Enter`, the default key is used.
     ///
-    /// If `default` is unspecified, it defaults to the 'h' key.
+    /// If `default` is unspecified, it defaults to the 'h' key. It can be a [`char`] or a
+    /// [`String`] for keys with multiple characters.
     ///
     /// # Panics
     ///
@@ -128,8 +182,8 @@ impl<'a> ExpandBuilder<'a> {
     ///     .default('d')
     ///     .build();
     /// ```
-    pub fn default(mut self, default: char) -> Self {
-        self.expand.default = default;
+    pub fn default<K: Into<String>>(mut self, default: K) -> Self {
+        self.expand.default = default.into();
         self
     }
 
@@ -181,14 +235,15 @@ impl<'a> ExpandBuilder<'a> {
 
     /// Inserts a [`Choice`] with the given key and text
     ///
-    /// See [`expand`] for more information.
+    /// The key can be a [`char`] or a [`String`] for keys with multiple characters. See [`expand`]
+    /// for more information.
     ///
     /// [`Choice`]: crate::question::Choice::Choice
     /// [`expand`]: crate::question::Question::expand
     ///
     /// # Panics
     ///
-    /// It will panic if the key is 'h' or a duplicate.
+    /// It will panic if the key is empty, 'h' or a duplicate.
     ///
     /// # Examples
     ///
@@ -197,19 +252,11 @@ impl<'a> ExpandBuilder<'a> {
     ///
     /// let expand = Question::expand("overwrite")
     ///     .choice('x', "Abort")
+    ///     .choice("ab", "Abort all")
     ///     .build();
     /// ```
-    pub fn choice<I: Into<String>>(mut self, mut key: char, text: I) -> Self {
-        key = key.to_ascii_lowercase();
-
-        if key == 'h' {
-            panic!("Reserved key 'h'");
-        }
-        if self.keys.contains(&key) {
-            panic!("Duplicate key '{}'", key);
-        }
-
-        self.keys.insert(key);
+    pub fn choice<K: Into<String>, I: Into<String>>(mut self, key: K, text: I) -> Self {
+        let key = add_key(&mut self.keys, &mut self.expand.key_width, key.into());
 
         self.expand.choices.choices.push(Choice::Choice(ExpandText {
             key,
@@ -273,7 +320,7 @@ impl<'a> ExpandBuilder<'a> {
     ///
     /// # Panics
     ///
-    /// It will panic if the key of any choice is 'h' or a duplicate.
+    /// It will panic if the key of any choice is empty, 'h' or a duplicate.
     ///
     /// # Examples
     ///
@@ -299,22 +346,14 @@ impl<'a> ExpandBuilder<'a> {
             ..
         } = self;
 
+        let key_width = &mut expand.key_width;
+
         expand.choices.choices.extend(choices.into_iter().map(|c| {
-            c.into().map(|ExpandItem { text, mut key }| {
-                key = key.to_ascii_lowercase();
-                if key == 'h' {
-                    panic!("Reserved key 'h'");
-                }
-                if keys.contains(&key) {
-                    panic!("Duplicate key '{}'", key);
-                }
-                keys.insert(key);
-
-                ExpandText {
-                    text: Text::new(text),
-                    key,
-                }
-            })
+            c.into()
+                .map_with_reason(|ExpandItem { text, key }, reason| ExpandText {
+                    key: add_key(keys, key_width, key),
+                    text: shown_text(text, reason),
+                })
         }));
 
         self
@@ -361,3 +400,23 @@ impl<'a> From<ExpandBuilder<'a>> for crate::question::Question<'a> {
         builder.build()
     }
 }
+
+/// Lowercases the key and checks that it is valid, adding it to the keys seen so far.
+fn add_key(keys: &mut HashSet<String>, key_width: &mut u16, mut key: String) -> String {
+    key.make_ascii_lowercase();
+
+    if key.is_empty() {
+        panic!("Empty key");
+    }
+    if key == "h" {
+        panic!("Reserved key 'h'");
+    }
+    if keys.contains(&key) {
+        panic!("Duplicate key '{}'", key);
+    }
+
+    *key_width = (*key_width).max(ui::layout::display_width(&key));
+    keys.insert(key.clone());
+
+    key
+}
@@ -2,7 +2,7 @@ use std::io;
 
 use ui::{
     backend::{Backend, MoveDirection},
-    events::{EventIterator, KeyEvent},
+    events::{KeyCode, KeyEvent},
     style::{Color, Stylize},
     widgets::{self, Text},
     Prompt, Validation, Widget,
@@ -17,9 +17,9 @@ mod builder;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct ExpandText {
-    key: char,
+    key: String,
     text: Text<String>,
 }
 
@@ -45,61 +45,88 @@ impl Widget for ExpandText {
     }
 }
 
-#[derive(Debug)]
+/// What the characters typed so far refer to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Typed {
+    /// Nothing has been typed
+    Nothing,
+    /// The choice at the given index, either by its full key or the only key starting with it
+    Choice(usize),
+    /// The help option
+    Help,
+    /// The start of multiple keys
+    Partial,
+}
+
+const HELP: &str = "Help, list all options";
+
+#[derive(Debug, Clone)]
 pub(super) struct Expand<'a> {
     choices: super::ChoiceList<ExpandText>,
-    selected: Option<char>,
-    default: char,
+    selected: Typed,
+    default: String,
+    /// The width of the longest key, used to align the expanded list
+    key_width: u16,
     transform: Transform<'a, ExpandItem>,
 }
 
 impl<'a> Default for Expand<'a> {
     fn default() -> Self {
         Expand {
-            default: 'h',
-            selected: None,
+            default: "h".into(),
+            selected: Typed::Nothing,
+            key_width: 1,
             choices: Default::default(),
             transform: Transform::None,
         }
     }
 }
 
-struct ExpandPrompt<'a, F> {
+struct ExpandPrompt<'a> {
     prompt: widgets::Prompt<&'a str, &'a str>,
     select: widgets::Select<Expand<'a>>,
-    input: widgets::CharInput<F>,
+    input: widgets::StringInput,
     expanded: bool,
 }
 
-impl<F: Fn(char) -> Option<char>> ExpandPrompt<'_, F> {
-    fn selected(&mut self) -> Option<&mut ExpandText> {
-        let key = self.input.value()?;
+impl ExpandPrompt<'_> {
+    fn selected(&mut self) -> Typed {
+        self.select.list.resolve(self.input.value())
+    }
 
-        self.select
-            .list
-            .choices
-            .choices
-            .iter_mut()
-            .filter_map(|choice| match choice {
-                Choice::Choice(choice) => Some(choice),
-                _ => None,
-            })
-            .find(|item| item.key == key)
+    /// Adds `c` to the key typed so far, or starts a new key with it if no key would match.
+    fn type_char(&mut self, c: char) -> bool {
+        let c = c.to_ascii_lowercase();
+        let list = &self.select.list;
+
+        let mut key = self.input.value().to_owned();
+        key.push(c);
+
+        if !list.is_key_prefix(&key) {
+            key = c.to_string();
+
+            if !list.is_key_prefix(&key) {
+                return false;
+            }
+        }
+
+        self.input.replace_with(|_| key);
+        true
     }
 
-    fn finish_with(self, c: char) -> ExpandItem {
+    fn finish_with(self, typed: Typed) -> ExpandItem {
+        let index = match typed {
+            Typed::Choice(index) => index,
+            _ => unreachable!("Validation would fail unless an option was chosen"),
+        };
+
         let item = self
             .select
             .into_inner()
             .choices
             .choices
-            .into_iter()
-            .filter_map(|choice| match choice {
-                Choice::Choice(choice) => Some(choice),
-                _ => None,
-            })
-            .find(|item| item.key == c)
-            .expect("Validation would fail unless an option was chosen");
+            .swap_remove(index)
+            .unwrap_choice();
 
         ExpandItem {
             text: item.text.text,
@@ -108,31 +135,90 @@ impl<F: Fn(char) -> Option<char>> ExpandPrompt<'_, F> {
     }
 }
 
-impl<F: Fn(char) -> Option<char>> Prompt for ExpandPrompt<'_, F> {
+impl Prompt for ExpandPrompt<'_> {
     type ValidateErr = &'static str;
     type Output = ExpandItem;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        match self.input.value().unwrap_or(self.select.list.default) {
-            'h' => {
+        match self.selected() {
+            Typed::Nothing if self.select.list.default != "h" => Ok(Validation::Finish),
+            Typed::Nothing | Typed::Help => {
                 self.expanded = true;
-                self.input.clear_value();
-                self.select.list.selected = None;
+                self.input.set_value(String::new());
+                self.select.list.selected = Typed::Nothing;
                 Ok(Validation::Continue)
             }
-            _ => Ok(Validation::Finish),
+            Typed::Choice(_) => Ok(Validation::Finish),
+            Typed::Partial => Err("Please finish typing the key"),
         }
     }
 
+    fn describe(&mut self) -> Option<String> {
+        let value = self.input.value();
+
+        if !value.is_empty() {
+            let list = &self.select.list;
+
+            return Some(match list.resolve(value) {
+                Typed::Choice(index) => {
+                    let item = list.choices[index].as_ref().unwrap_choice();
+                    let text = item.text.text.lines().next().unwrap_or_default();
+                    format!("Selected: {}) {}", item.key, text)
+                }
+                Typed::Partial => format!("Typed: {}, {}", value, list.partial_text(value)),
+                _ => format!("Selected: h) {}", HELP),
+            });
+        }
+
+        let choices = self
+            .select
+            .list
+            .choices
+            .choices
+            .iter()
+            .filter_map(|choice| match choice {
+                Choice::Choice(item) => Some((item.key.as_str(), item.text.text.as_str())),
+                _ => None,
+            })
+            .chain(std::iter::once(("h", HELP)));
+
+        Some(if self.expanded {
+            choices
+                .map(|(key, text)| format!("{}) {}\n", key, text))
+                .collect()
+        } else {
+            let keys: Vec<_> = choices.map(|(key, _)| key).collect();
+            format!(
+                "Keys: {}, default {}",
+                keys.join(", "),
+                self.select.list.default
+            )
+        })
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        (*err).to_owned()
+    }
+
     fn finish(self) -> Self::Output {
-        let c = self.input.value().unwrap_or(self.select.list.default);
-        self.finish_with(c)
+        let list = &self.select.list;
+        let typed = match self.input.value() {
+            "" => list.resolve(&list.default),
+            value => list.resolve(value),
+        };
+        self.finish_with(typed)
     }
 }
 
 const ANSWER_PROMPT: &[u8] = b"  Answer: ";
 
-impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
+/// The padding before `key` to right align it with keys that are up to `key_width` wide. Unlike
+/// `{:>width$}`, this takes the width of the key into account instead of the number of characters.
+fn key_pad(key_width: u16, key: &str) -> usize {
+    key_width.saturating_sub(ui::layout::display_width(key)) as usize
+}
+
+impl ui::Widget for ExpandPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
         self.prompt.render(layout, b)?;
         if self.expanded {
@@ -143,22 +229,34 @@ impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
         } else {
             self.input.render(layout, b)?;
 
-            if self.input.value().is_some() {
+            if !self.input.value().is_empty() {
                 b.move_cursor(MoveDirection::NextLine(1))?;
-                b.write_styled(&ui::symbols::current().arrow.cyan())?;
+                b.write_styled(
+                    &ui::symbols::current()
+                        .arrow
+                        .with(ui::style::theme().highlight),
+                )?;
                 b.write_all(b" ")?;
 
                 layout.offset_y += 1;
                 layout.offset_x += 2;
 
                 match self.selected() {
-                    Some(item) => {
-                        item.render(layout, b)?;
+                    Typed::Choice(index) => {
+                        self.select.list.choices[index]
+                            .as_mut()
+                            .unwrap_choice()
+                            .render(layout, b)?;
+                        b.move_cursor(MoveDirection::Column(0))?;
+                    }
+                    Typed::Partial => {
+                        let list = &self.select.list;
+                        Text::new(list.partial_text(self.input.value())).render(layout, b)?;
                         b.move_cursor(MoveDirection::Column(0))?;
                     }
-                    None => {
+                    _ => {
                         layout.offset_y += 1;
-                        b.write_all(b"Help, list all options")?;
+                        b.write_all(HELP.as_bytes())?;
                         b.move_cursor(MoveDirection::NextLine(1))?;
                     }
                 }
@@ -175,18 +273,22 @@ impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
         if self.expanded {
             // Don't need to add 1 for the answer prompt, since this will over count by 1 anyways
             let height = self.prompt.height(layout) + self.select.height(layout);
-            layout.line_offset = ANSWER_PROMPT.len() as u16 + self.input.value().is_some() as u16;
+            layout.line_offset = ANSWER_PROMPT.len() as u16;
+            self.input.height(layout);
             height
-        } else if self.input.value().is_some() {
+        } else if !self.input.value().is_empty() {
             let height = self.prompt.height(layout) - 1 + self.input.height(layout);
 
             layout.offset_y += 1;
             layout.line_offset = 0;
 
-            // selected will return None if the help option is selected
-            let selected_height = match self.selected().map(|c| c.height(layout)) {
-                Some(height) => height,
-                None => {
+            let selected_height = match self.selected() {
+                Typed::Choice(index) => self.select.list.choices[index].height(layout),
+                Typed::Partial => {
+                    let list = &self.select.list;
+                    Text::new(list.partial_text(self.input.value())).height(layout)
+                }
+                _ => {
                     layout.offset_y += 1;
                     1
                 }
@@ -199,8 +301,20 @@ impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        if self.input.handle_key(key) {
-            self.select.list.selected = self.input.value();
+        let handled = match key.code {
+            KeyCode::Char(c) => self.type_char(c),
+            KeyCode::Backspace | KeyCode::Delete if !self.input.value().is_empty() => {
+                self.input.replace_with(|mut value| {
+                    value.pop();
+                    value
+                });
+                true
+            }
+            _ => false,
+        };
+
+        if handled {
+            self.select.list.selected = self.selected();
             true
         } else if self.expanded {
             self.select.handle_key(key)
@@ -230,7 +344,7 @@ impl widgets::List for Expand<'_> {
         &mut self,
         index: usize,
         _: bool,
-        layout: ui::layout::Layout,
+        mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
         if index == self.choices.len() {
@@ -239,8 +353,23 @@ impl widgets::List for Expand<'_> {
 
         match &mut self.choices[index] {
             Choice::Choice(_) => self.render_choice(Some(index), layout, b),
+            Choice::Disabled { text, .. } => {
+                // Disabled choices are shown with their key, but it cannot be used to pick them
+                b.set_fg(ui::style::theme().hint)?;
+                write!(
+                    b,
+                    "  {:pad$}{}) ",
+                    "",
+                    text.key,
+                    pad = key_pad(self.key_width, &text.key)
+                )?;
+
+                layout.offset_x += self.key_width + 4;
+                text.render(&mut layout, b)?;
+                b.set_fg(Color::Reset)
+            }
             separator => {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(ui::style::theme().hint)?;
                 b.write_all(b"   ")?;
                 super::get_sep_str(separator).render(&mut layout.with_line_offset(3), b)?;
                 b.set_fg(Color::Reset)
@@ -257,7 +386,7 @@ impl widgets::List for Expand<'_> {
             // Help option
             1
         } else {
-            layout.offset_x += 5;
+            layout.offset_x += self.key_width + 4;
             self.choices[index].height(&mut layout)
         }
     }
@@ -275,12 +404,69 @@ impl widgets::List for Expand<'_> {
     }
 }
 
-impl Expand<'_> {
+impl<'a> Expand<'a> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::ExpandItem(item) = answer {
+            let prev_default = std::mem::replace(&mut self.default, item.key.clone());
+
+            // The choices may have changed since the answer was given
+            if !self.has_valid_default() {
+                self.default = prev_default;
+            }
+        }
+    }
+
     fn has_valid_default(&self) -> bool {
-        self.default == 'h'
-            || self.choices.choices.iter().any(
-                |c| matches!(c, Choice::Choice(ExpandText { key, .. }) if *key == self.default),
-            )
+        self.keys().any(|(_, key)| key == self.default)
+    }
+
+    /// The keys of all the choices with their indices, followed by the 'h' key without one.
+    fn keys(&self) -> impl Iterator<Item = (Option<usize>, &str)> {
+        self.choices
+            .choices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, choice)| match choice {
+                Choice::Choice(choice) => Some((Some(i), choice.key.as_str())),
+                _ => None,
+            })
+            .chain(std::iter::once((None, "h")))
+    }
+
+    fn is_key_prefix(&self, prefix: &str) -> bool {
+        self.keys().any(|(_, key)| key.starts_with(prefix))
+    }
+
+    fn resolve(&self, typed: &str) -> Typed {
+        fn to_typed(index: Option<usize>) -> Typed {
+            index.map(Typed::Choice).unwrap_or(Typed::Help)
+        }
+
+        if typed.is_empty() {
+            return Typed::Nothing;
+        }
+
+        if let Some((index, _)) = self.keys().find(|(_, key)| *key == typed) {
+            return to_typed(index);
+        }
+
+        let mut matching = self.keys().filter(|(_, key)| key.starts_with(typed));
+
+        match (matching.next(), matching.next()) {
+            (Some((index, _)), None) => to_typed(index),
+            _ => Typed::Partial,
+        }
+    }
+
+    /// Lists the keys starting with what was typed, like `One of: ab, ac`.
+    fn partial_text(&self, typed: &str) -> String {
+        let keys: Vec<_> = self
+            .keys()
+            .map(|(_, key)| key)
+            .filter(|key| key.starts_with(typed))
+            .collect();
+
+        format!("One of: {}", keys.join(", "))
     }
 
     fn render_choice<B: Backend>(
@@ -289,27 +475,40 @@ impl Expand<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
-        let key = match index {
-            Some(index) => self.choices[index].as_ref().unwrap_choice().key,
-            None => 'h',
+        let (key, typed) = match index {
+            Some(index) => (
+                self.choices[index].as_ref().unwrap_choice().key.as_str(),
+                Typed::Choice(index),
+            ),
+            None => ("h", Typed::Help),
         };
 
-        let hovered = self.selected.map(|c| c == key).unwrap_or(false);
+        let hovered = self.selected == typed;
 
         if hovered {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(ui::style::theme().highlight)?;
         }
 
-        write!(b, "  {}) ", key)?;
+        write!(
+            b,
+            "  {:pad$}{}) ",
+            "",
+            key,
+            pad = key_pad(self.key_width, key)
+        )?;
 
-        layout.offset_x += 5;
+        let offset = self.key_width + 4;
+        layout.offset_x += offset;
 
         match index {
             Some(index) => self.choices[index]
                 .as_mut()
                 .unwrap_choice()
                 .render(&mut layout, b)?,
-            None => "Help, list all options".render(&mut layout.with_line_offset(5), b)?,
+            None => {
+                let mut help = HELP;
+                help.render(&mut layout.with_line_offset(offset), b)?
+            }
         }
 
         if hovered {
@@ -319,55 +518,82 @@ impl Expand<'_> {
         Ok(())
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    /// The keys with the default in uppercase, separated by '/' if any key has multiple
+    /// characters.
+    fn get_hint(&self) -> String {
+        let separator = if self.key_width > 1 { "/" } else { "" };
+
+        let keys: Vec<_> = self
+            .keys()
+            .map(|(_, key)| {
+                if key == self.default {
+                    key.to_ascii_uppercase()
+                } else {
+                    key.to_owned()
+                }
+            })
+            .collect();
+
+        keys.join(separator)
+    }
+
+    fn into_expand_prompt<'p>(self, message: &'p str, hint: &'p str) -> ExpandPrompt<'p>
+    where
+        'a: 'p,
+    {
+        ExpandPrompt {
+            prompt: widgets::Prompt::new(message).with_hint(hint),
+            input: widgets::StringInput::new(),
+            select: widgets::Select::new(self),
+            expanded: false,
+        }
+    }
+
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
-        let help_key = if self.default == 'h' { 'H' } else { 'h' };
+        let hint = self.get_hint();
+        let transform = self.transform.take();
 
-        let hint: String = self
-            .choices
-            .choices
-            .iter()
-            .filter_map(|choice| match choice {
-                Choice::Choice(choice) if self.default == choice.key => {
-                    Some(choice.key.to_ascii_uppercase())
-                }
-                Choice::Choice(choice) => Some(choice.key.to_ascii_lowercase()),
-                _ => None,
-            })
-            .chain(std::iter::once(help_key))
-            .collect();
+        let input = ui::Input::new(self.into_expand_prompt(&message, &hint), b);
+        let ans = driver.run(input).await?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
 
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans
+            .as_expand_item()
+            .expect("answer must be an expand item")
+            .clone();
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(
-            ExpandPrompt {
-                prompt: widgets::Prompt::new(&*message).with_hint(&hint),
-                input: widgets::CharInput::with_filter_map(|c| {
-                    let c = c.to_ascii_lowercase();
-                    hint.chars()
-                        .find(|o| o.eq_ignore_ascii_case(&c))
-                        .and(Some(c))
-                }),
-                select: widgets::Select::new(self),
-                expanded: false,
-            },
-            b,
-        )
-        .on_esc(on_esc)
-        .run(events)?;
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
 
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, ExpandItem>,
+        message: String,
+        ans: Option<ExpandItem>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
             &ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
-                .cyan()
+                .with(ui::style::theme().highlight)
         )?)
     }
 }
@@ -9,6 +9,12 @@ fn test_panic_reserved_key() {
     ExpandBuilder::new("name".into()).choice('h', "help");
 }
 
+#[test]
+#[should_panic(expected = "Empty key")]
+fn test_panic_empty_key() {
+    ExpandBuilder::new("name".into()).choice("", "empty");
+}
+
 #[test]
 #[should_panic(expected = "Duplicate key 'k'")]
 fn test_panic_duplicate() {
@@ -63,17 +69,7 @@ macro_rules! expand {
                 _ => unreachable!(),
             };
 
-        let mut $expand = ExpandPrompt {
-            prompt: widgets::Prompt::new($message).with_hint(&hint),
-            input: widgets::CharInput::with_filter_map(|c| {
-                let c = c.to_ascii_lowercase();
-                hint.chars()
-                    .find(|o| o.eq_ignore_ascii_case(&c))
-                    .and(Some(c))
-            }),
-            select: widgets::Select::new(expand),
-            expanded: false,
-        };
+        let mut $expand = expand.into_expand_prompt($message, &hint);
     };
 }
 
@@ -224,3 +220,48 @@ fn test_cursor_pos() {
     assert!(expand.handle_key(KeyCode::Char('c').into()));
     assert_eq!(expand.cursor_pos(layout), (11, 10));
 }
+
+#[test]
+fn test_multi_char_keys() {
+    let expand = match ExpandBuilder::new("name".into())
+        .choice('a', "Choice A")
+        .choice("ba", "Choice BA")
+        .choice("bb", "Choice BB")
+        .choice("hx", "Choice HX")
+        .default("bb")
+        .build()
+        .kind
+    {
+        QuestionKind::Expand(e) => e,
+        _ => unreachable!(),
+    };
+
+    let hint = expand.get_hint();
+    assert_eq!(hint, "a/ba/BB/hx/h");
+
+    let mut expand = expand.into_expand_prompt("message", &hint);
+
+    assert!(expand.handle_key(KeyCode::Char('b').into()));
+    assert_eq!(expand.selected(), Typed::Partial);
+    assert_eq!(expand.validate(), Err("Please finish typing the key"));
+
+    assert!(expand.handle_key(KeyCode::Char('A').into()));
+    assert_eq!(expand.input.value(), "ba");
+    assert_eq!(expand.selected(), Typed::Choice(1));
+
+    // no key starts with "baa", so a new key is started
+    assert!(expand.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(expand.input.value(), "a");
+    assert!(!expand.handle_key(KeyCode::Char('z').into()));
+
+    assert!(expand.handle_key(KeyCode::Backspace.into()));
+    assert!(expand.handle_key(KeyCode::Char('h').into()));
+    assert_eq!(expand.selected(), Typed::Help);
+    assert!(expand.handle_key(KeyCode::Char('x').into()));
+    assert_eq!(expand.selected(), Typed::Choice(3));
+
+    assert!(expand.handle_key(KeyCode::Backspace.into()));
+    assert!(expand.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(expand.validate(), Ok(Validation::Finish));
+    assert_eq!(expand.finish(), ExpandItem::from(("bb", "Choice BB")));
+}
@@ -1,24 +1,42 @@
-use std::fmt;
+use std::{cell::RefCell, fmt, rc::Rc};
 
 use ui::backend::Backend;
 
-use super::Completions;
+use super::{Completions, ValidationResult};
 use crate::Answers;
 
 macro_rules! handler {
-    ($name:ident, $fn_trait:ident ( $($type:ty),* ) -> $return:ty) => {
+    ($name:ident, FnMut ( $($arg:ident: $type:ty),* ) -> $return:ty) => {
+        /// The function is shared by the copies of a question, so that it can be asked again.
         pub(super) enum $name<'a, T> {
-            Sync(Box<dyn $fn_trait( $($type),* ) -> $return + 'a>),
+            Sync(Rc<dyn Fn( $($type),* ) -> $return + 'a>),
             None,
         }
 
         impl<'a, T> $name<'a, T> {
+            pub(super) fn new<F>(f: F) -> Self
+            where
+                F: FnMut( $($type),* ) -> $return + 'a,
+            {
+                let f = RefCell::new(f);
+                Self::Sync(Rc::new(move |$($arg: $type),*| (f.borrow_mut())($($arg),*)))
+            }
+
             #[allow(unused)]
             pub(super) fn take(&mut self) -> Self {
                 std::mem::replace(self, Self::None)
             }
         }
 
+        impl<T> Clone for $name<'_, T> {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Sync(f) => Self::Sync(Rc::clone(f)),
+                    Self::None => Self::None,
+                }
+            }
+        }
+
         impl<T> Default for $name<'_, T> {
             fn default() -> Self {
                 Self::None
@@ -36,19 +54,37 @@ macro_rules! handler {
     };
 
     // The type signature of the function must only contain &T
-    ($name:ident, ?Sized $fn_trait:ident ( $($type:ty),* ) -> $return:ty) => {
+    ($name:ident, ?Sized FnMut ( $($arg:ident: $type:ty),* ) -> $return:ty) => {
+        /// The function is shared by the copies of a question, so that it can be asked again.
         pub(super) enum $name<'a, T: ?Sized> {
-            Sync(Box<dyn $fn_trait( $($type),* ) -> $return + 'a>),
+            Sync(Rc<dyn Fn( $($type),* ) -> $return + 'a>),
             None,
         }
 
         impl<'a, T: ?Sized> $name<'a, T> {
+            pub(super) fn new<F>(f: F) -> Self
+            where
+                F: FnMut( $($type),* ) -> $return + 'a,
+            {
+                let f = RefCell::new(f);
+                Self::Sync(Rc::new(move |$($arg: $type),*| (f.borrow_mut())($($arg),*)))
+            }
+
             #[allow(unused)]
             pub(super) fn take(&mut self) -> Self {
                 std::mem::replace(self, Self::None)
             }
         }
 
+        impl<T: ?Sized> Clone for $name<'_, T> {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Sync(f) => Self::Sync(Rc::clone(f)),
+                    Self::None => Self::None,
+                }
+            }
+        }
+
         impl<T: ?Sized> Default for $name<'_, T> {
             fn default() -> Self {
                 Self::None
@@ -66,14 +102,17 @@ macro_rules! handler {
     };
 }
 
-handler!(Filter, FnOnce(T, &Answers) -> T);
-handler!(AutoComplete, FnMut(T, &Answers) -> Completions<T>);
-handler!(Validate, ?Sized FnMut(&T, &Answers) -> Result<(), String>);
-handler!(ValidateByVal, FnMut(T, &Answers) -> Result<(), String>);
-handler!(ValidateOnKey, ?Sized FnMut(&T, &Answers) -> bool);
-handler!(ValidateOnKeyByVal, FnMut(T, &Answers) -> bool);
-handler!(Transform, ?Sized FnOnce(&T, &Answers, &mut dyn Backend) -> std::io::Result<()>);
+handler!(Filter, FnMut(ans: T, answers: &Answers) -> T);
+handler!(AutoComplete, FnMut(ans: T, answers: &Answers) -> Completions<T>);
+handler!(Validate, ?Sized FnMut(ans: &T, answers: &Answers) -> ValidationResult);
+handler!(ValidateByVal, FnMut(ans: T, answers: &Answers) -> ValidationResult);
+handler!(ValidateOnKey, ?Sized FnMut(ans: &T, answers: &Answers) -> Result<(), String>);
+handler!(ValidateOnKeyByVal, FnMut(ans: T, answers: &Answers) -> Result<(), String>);
+handler!(
+    Transform,
+    ?Sized FnMut(ans: &T, answers: &Answers, backend: &mut dyn Backend) -> std::io::Result<()>
+);
 handler!(
     TransformByVal,
-    FnOnce(T, &Answers, &mut dyn Backend) -> std::io::Result<()>
+    FnMut(ans: T, answers: &Answers, backend: &mut dyn Backend) -> std::io::Result<()>
 );
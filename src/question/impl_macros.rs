@@ -7,7 +7,7 @@ macro_rules! impl_filter_builder {
         /// Function to change the final submitted value before it is displayed to the user and
         /// added to the [`Answers`].
         ///
-        /// It is a [`FnOnce`] that is given the answer and the previous [`Answers`], and should
+        /// It is a [`FnMut`] that is given the answer and the previous [`Answers`], and should
         /// return the new answer.
         ///
         /// This will be called after the answer has been validated.
@@ -18,9 +18,9 @@ macro_rules! impl_filter_builder {
         $(#[$meta])+
         pub fn filter<F>(mut self, filter: F) -> Self
         where
-            F: FnOnce($t, &$crate::Answers) -> $t + 'a,
+            F: FnMut($t, &$crate::Answers) -> $t + 'a,
         {
-            self.$inner.filter = $crate::question::Filter::Sync(Box::new(filter));
+            self.$inner.filter = $crate::question::Filter::new(filter);
             self
         }
     };
@@ -58,8 +58,7 @@ macro_rules! impl_auto_complete_builder {
         where
             F: FnMut($t, &$crate::Answers) -> Completions<$t> + 'a,
         {
-            self.$inner.auto_complete =
-                $crate::question::AutoComplete::Sync(Box::new(auto_complete));
+            self.$inner.auto_complete = $crate::question::AutoComplete::new(auto_complete);
             self
         }
     };
@@ -91,11 +90,38 @@ macro_rules! impl_validate_builder {
         ///
         ///
         $(#[$meta])*
-        pub fn validate<F>(mut self, filter: F) -> Self
+        pub fn validate<F>(mut self, mut filter: F) -> Self
         where
             F: FnMut($t, &$crate::Answers) -> Result<(), String> + 'a,
         {
-            self.$inner.validate = $crate::question::$handler::Sync(Box::new(filter));
+            self.$inner.validate = $crate::question::$handler::new(
+                move |value: $t, answers: &$crate::Answers| filter(value, answers).into(),
+            );
+            self
+        }
+
+        /// Function to validate the submitted value before it's returned, which can also return
+        /// warnings and information that don't prevent it from being submitted.
+        ///
+        /// It is a [`FnMut`] that is given the answer and the previous [`Answers`], and should
+        /// return a [`ValidationResult`]. A [`ValidationResult::Error`] prevents the answer from
+        /// being submitted, like an [`Err`] returned to [`validate`](Self::validate). A
+        /// [`ValidationResult::Warning`] or [`ValidationResult::Info`] is shown to the user, and
+        /// the answer is accepted if it is submitted again without any changes.
+        ///
+        /// This will be called when the user presses the `Enter` key. It replaces the function
+        /// given to [`validate`](Self::validate), and vice versa.
+        ///
+        /// [`Answers`]: crate::Answers
+        /// [`ValidationResult`]: crate::question::ValidationResult
+        /// [`ValidationResult::Error`]: crate::question::ValidationResult::Error
+        /// [`ValidationResult::Warning`]: crate::question::ValidationResult::Warning
+        /// [`ValidationResult::Info`]: crate::question::ValidationResult::Info
+        pub fn validate_with_severity<F>(mut self, validate: F) -> Self
+        where
+            F: FnMut($t, &$crate::Answers) -> $crate::question::ValidationResult + 'a,
+        {
+            self.$inner.validate = $crate::question::$handler::new(validate);
             self
         }
     };
@@ -131,11 +157,33 @@ macro_rules! impl_validate_on_key_builder {
         ///
         ///
         $(#[$meta])*
-        pub fn validate_on_key<F>(mut self, filter: F) -> Self
+        pub fn validate_on_key<F>(mut self, mut filter: F) -> Self
         where
             F: FnMut($t, &$crate::Answers) -> bool + 'a,
         {
-            self.$inner.validate_on_key = $crate::question::$handler::Sync(Box::new(filter));
+            self.$inner.validate_on_key = $crate::question::$handler::new(
+                move |value: $t, answers: &$crate::Answers| {
+                    if filter(value, answers) {
+                        Ok(())
+                    } else {
+                        Err(String::new())
+                    }
+                },
+            );
+            self
+        }
+
+        /// Like [`validate_on_key`](Self::validate_on_key), but the function returns an error
+        /// message if the value is invalid. The message is shown beneath the input as the user
+        /// types, and is cleared once the value is valid.
+        ///
+        /// Like `validate_on_key`, this validation is purely cosmetic and cannot prevent a user
+        /// submission.
+        pub fn validate_on_key_with_message<F>(mut self, filter: F) -> Self
+        where
+            F: FnMut($t, &$crate::Answers) -> Result<(), String> + 'a,
+        {
+            self.$inner.validate_on_key = $crate::question::$handler::new(filter);
             self
         }
     };
@@ -157,10 +205,12 @@ macro_rules! impl_transform_builder {
     ($(#[$meta:meta])+ impl $t:ty; $inner:ident $handler:ident) => {
         /// Change the way the answer looks when displayed to the user.
         ///
-        /// It is a [`FnOnce`] that is given the answer, previous [`Answers`] and the [`Backend`] to
+        /// It is a [`FnMut`] that is given the answer, previous [`Answers`] and the [`Backend`] to
         /// display the answer on. After the `transform` is called, a new line is also added.
         ///
-        /// It will only be called once the user finishes answering the question.
+        /// It will only be called once the user finishes answering the question. It is called
+        /// again if the question is answered again after going back to it in a
+        /// [`PromptModule`](crate::PromptModule).
         ///
         /// [`Answers`]: crate::Answers
         /// [`Backend`]: crate::prompt::Backend
@@ -169,9 +219,9 @@ macro_rules! impl_transform_builder {
         $(#[$meta])*
         pub fn transform<F>(mut self, transform: F) -> Self
         where
-            F: FnOnce($t, &$crate::Answers, &mut dyn Backend) -> std::io::Result<()> + 'a,
+            F: FnMut($t, &$crate::Answers, &mut dyn Backend) -> std::io::Result<()> + 'a,
         {
-            self.$inner.transform = $crate::question::$handler::Sync(Box::new(transform));
+            self.$inner.transform = $crate::question::$handler::new(transform);
             self
         }
     };
@@ -181,19 +231,25 @@ macro_rules! impl_transform_builder {
 #[macro_export]
 macro_rules! write_final {
     ($transform:expr, $message:expr, $ans:ident $([$tt:tt])?, $answers:expr, $backend:expr, |$ident:ident| $custom:expr) => {{
-        ui::widgets::Prompt::write_finished_message(&$message, $ans.is_none(), $backend)?;
-
-        // Weird reborrowing trick to make sure ans is not moved when $tt is ref, but is copied when
-        // $tt is not there
-        match (&$ans, $transform) {
-            (&Some($($tt)? ans), Transform::Sync(transform)) => transform(ans, $answers, $backend)?,
-            (&Some($($tt)? $ident), _) => $custom,
-            (None, _) => {
-                $backend.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
+        // The prompt has already been cleared, so a hidden answer is erased by not writing it
+        if !$crate::question::hide_final() {
+            ui::widgets::Prompt::write_finished_message(&$message, $ans.is_none(), $backend)?;
+
+            // Weird reborrowing trick to make sure ans is not moved when $tt is ref, but is copied
+            // when $tt is not there
+            match (&$ans, $transform) {
+                (&Some($($tt)? ans), Transform::Sync(transform)) => {
+                    transform(ans, $answers, $backend)?
+                }
+                (&Some($($tt)? $ident), _) => $custom,
+                (None, _) => {
+                    $backend.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
+                }
             }
+
+            $backend.write_all(b"\n")?;
         }
 
-        $backend.write_all(b"\n")?;
         $backend.flush()?;
 
         Ok($ans.map($crate::answer::Answer::from))
@@ -1,6 +1,6 @@
 use ui::backend::Backend;
 
-use super::Input;
+use super::{History, Input};
 use crate::question::{Completions, Options};
 
 /// The builder for an [`input`] prompt.
@@ -88,6 +88,57 @@ impl<'a> InputBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let input = Question::input("name")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the input
@@ -113,6 +164,90 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// Set the text shown in the hint colour while the input is empty, like an example of the
+    /// expected answer.
+    ///
+    /// Unlike [`default`], the placeholder is never taken as the answer, and it disappears as soon
+    /// as the user starts typing. If both are set, the [`default`] is shown instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("email")
+    ///     .placeholder("e.g. user@example.com")
+    ///     .build();
+    /// ```
+    ///
+    /// [`default`]: InputBuilder::default
+    pub fn placeholder<I: Into<String>>(mut self, placeholder: I) -> Self {
+        let placeholder = placeholder.into();
+        let width = ui::layout::display_width(&placeholder);
+        self.input.placeholder = Some((placeholder, width));
+        self
+    }
+
+    /// Set the text that the input starts with, which can then be edited by the user.
+    ///
+    /// Unlike [`default`], the text is already typed in when the prompt is shown, with the cursor
+    /// at its end. This is useful to edit an existing value. The text is validated like any other
+    /// answer, and if the user clears it, the [`default`] is used if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .initial_value("John Doe")
+    ///     .build();
+    /// ```
+    ///
+    /// [`default`]: InputBuilder::default
+    pub fn initial_value<I: Into<String>>(mut self, initial_value: I) -> Self {
+        self.input.initial_value = Some(initial_value.into());
+        self
+    }
+
+    /// Text shown before the input, like a currency symbol.
+    ///
+    /// The prefix is rendered in the hint colour, cannot be edited by the user and is not part of
+    /// the answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("price")
+    ///     .prefix("$ ")
+    ///     .build();
+    /// ```
+    pub fn prefix<I: Into<String>>(mut self, prefix: I) -> Self {
+        self.input.affixes.set_prefix(prefix.into());
+        self
+    }
+
+    /// Text shown after the input, like a unit.
+    ///
+    /// The suffix is rendered in the hint colour, cannot be edited by the user and is not part of
+    /// the answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("size")
+    ///     .suffix(" MB")
+    ///     .build();
+    /// ```
+    pub fn suffix<I: Into<String>>(mut self, suffix: I) -> Self {
+        self.input.affixes.set_suffix(suffix.into());
+        self
+    }
+
     crate::impl_auto_complete_builder! {
     /// # Examples
     ///
@@ -182,6 +317,30 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// A [`History`] of previous answers, which can be recalled by pressing `Up` and `Down`.
+    ///
+    /// The answer typed by the user is added to the history. The same history can be shared by
+    /// many questions by cloning it. `Up` and `Down` are only used for the history while the
+    /// [`auto_complete`] selection list is not shown.
+    ///
+    /// [`auto_complete`]: InputBuilder::auto_complete
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::History, Question};
+    ///
+    /// let history = History::new();
+    ///
+    /// let input = Question::input("command")
+    ///     .history(history.clone())
+    ///     .build();
+    /// ```
+    pub fn history(mut self, history: History) -> Self {
+        self.input.history = Some(history);
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -0,0 +1,122 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// The answers previously given to [`input`] questions, which can be recalled with `Up` and
+/// `Down`, like in a shell.
+///
+/// A `History` is shared by cloning it, so the same history can be given to many questions. The
+/// answer typed for a question is added to its history, unless it is empty or the same as the
+/// latest entry.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{question::History, Question};
+///
+/// let history = History::with_max_len(100);
+///
+/// let questions = vec![
+///     Question::input("first").history(history.clone()).build(),
+///     Question::input("second").history(history.clone()).build(),
+/// ];
+/// ```
+///
+/// [`input`]: crate::question::Question::input
+#[derive(Debug, Clone, Default)]
+pub struct History(Arc<Mutex<Entries>>);
+
+#[derive(Debug, Default)]
+struct Entries {
+    entries: VecDeque<String>,
+    max_len: Option<usize>,
+}
+
+impl History {
+    /// Creates an empty history that keeps every entry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty history that keeps at most `max_len` entries, forgetting the oldest ones
+    /// first.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if `max_len` is 0.
+    pub fn with_max_len(max_len: usize) -> Self {
+        assert!(max_len > 0, "max_len must be at least 1");
+
+        Self(Arc::new(Mutex::new(Entries {
+            entries: VecDeque::new(),
+            max_len: Some(max_len),
+        })))
+    }
+
+    /// Adds an entry to the end of the history.
+    ///
+    /// Empty entries and entries which are the same as the latest one are ignored.
+    pub fn push<I: Into<String>>(&self, entry: I) {
+        let entry = entry.into();
+        let mut inner = self.lock();
+
+        if entry.is_empty() || inner.entries.back() == Some(&entry) {
+            return;
+        }
+
+        if inner.max_len == Some(inner.entries.len()) {
+            inner.entries.pop_front();
+        }
+
+        inner.entries.push_back(entry);
+    }
+
+    /// The entries of the history, from the oldest to the latest.
+    pub fn entries(&self) -> Vec<String> {
+        self.lock().entries.iter().cloned().collect()
+    }
+
+    /// The number of entries in the history.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if the history has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all the entries.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        // The entries are always left in a valid state, so a panic elsewhere does not matter
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push() {
+        let history = History::with_max_len(2);
+        let shared = history.clone();
+
+        history.push("a");
+        history.push("");
+        history.push("a");
+        assert_eq!(shared.entries(), ["a"]);
+
+        history.push("b");
+        history.push("c");
+        assert_eq!(shared.entries(), ["b", "c"]);
+
+        shared.clear();
+        assert!(history.is_empty());
+    }
+}
@@ -2,44 +2,61 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyCode, KeyEvent},
+    events::{KeyCode, KeyEvent},
     style::Stylize,
     widgets, Prompt, Validation, Widget,
 };
 
-use super::{AutoComplete, ChoiceList, Filter, Transform, Validate, ValidateOnKey};
+use super::{
+    describe_hovered, describe_text, Affixes, AutoComplete, ChoiceList, Filter, KeyValidation,
+    Transform, Validate, ValidateOnKey, ValidationMessage,
+};
 use crate::{Answer, Answers};
 
 pub use builder::InputBuilder;
+pub use history::History;
+pub use path::PathBuilder;
 
 mod builder;
+mod history;
+mod path;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(super) struct Input<'a> {
+    /// The default along with the number of characters in it
     default: Option<(String, usize)>,
+    /// The placeholder along with its width
+    placeholder: Option<(String, u16)>,
+    initial_value: Option<String>,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
     transform: Transform<'a, str>,
     auto_complete: AutoComplete<'a, String>,
+    affixes: Affixes,
     page_size: usize,
     should_loop: bool,
+    history: Option<History>,
 }
 
 impl<'a> Default for Input<'a> {
     fn default() -> Self {
         Self {
             default: None,
+            placeholder: None,
+            initial_value: None,
             filter: Filter::None,
             validate: Validate::None,
             validate_on_key: ValidateOnKey::None,
             transform: Transform::None,
             auto_complete: AutoComplete::None,
-            page_size: 15,
-            should_loop: true,
+            affixes: Affixes::default(),
+            page_size: super::choice::default_page_size(),
+            should_loop: super::choice::default_should_loop(),
+            history: None,
         }
     }
 }
@@ -54,7 +71,13 @@ struct InputPrompt<'i, 'a> {
     /// auto complete options. The select must not be used directly, and instead by used
     /// through `select`. See `select_op`s documentation for more.
     select: Option<CompletionSelector>,
-    is_valid: bool,
+    key_validation: KeyValidation,
+    /// The entries of the history when the prompt was started
+    history: Vec<String>,
+    /// The entry of the history being shown, if the user has moved into it
+    history_at: Option<usize>,
+    /// The text typed before moving into the history, which is restored after the latest entry
+    draft: String,
     answers: &'a Answers,
 }
 
@@ -96,12 +119,85 @@ impl InputPrompt<'_, '_> {
         None
     }
 
+    /// Returns the text shown in the hint colour after the input, and the width of the input and
+    /// the text together. This is the remaining default, or the placeholder if the input is empty
+    /// and there is no default.
+    fn get_hint(&self) -> Option<(&str, u16)> {
+        if let Some(remaining) = self.get_remaining_default() {
+            let default = &self.input_opts.default.as_ref().unwrap().0;
+            return Some((remaining, ui::layout::display_width(default)));
+        }
+
+        match self.input_opts.placeholder {
+            Some((ref placeholder, width))
+                if self.select.is_none() && self.input.value().is_empty() =>
+            {
+                Some((placeholder, width))
+            }
+            _ => None,
+        }
+    }
+
+    /// Replaces the input with an older or newer entry of the history, and returns whether it
+    /// moved.
+    fn recall(&mut self, older: bool) -> bool {
+        let len = self.history.len();
+
+        let value = match (self.history_at, older) {
+            (None, true) if len > 0 => {
+                self.draft = self.input.value().to_owned();
+                self.history_at = Some(len - 1);
+                self.history[len - 1].clone()
+            }
+            (Some(at), true) if at > 0 => {
+                self.history_at = Some(at - 1);
+                self.history[at - 1].clone()
+            }
+            (Some(at), false) if at + 1 < len => {
+                self.history_at = Some(at + 1);
+                self.history[at + 1].clone()
+            }
+            (Some(_), false) => {
+                self.history_at = None;
+                std::mem::take(&mut self.draft)
+            }
+            _ => return false,
+        };
+
+        self.input.set_value(value);
+        self.input.set_at(usize::MAX);
+
+        if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
+            self.key_validation
+                .set(validate(self.input.value(), self.answers));
+        }
+
+        true
+    }
+
+    /// The height of the prompt without the message of the on key validation.
+    fn input_height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let mut height =
+            self.prompt.height(layout) - 1 + self.input_opts.affixes.skip_prefix(layout);
+
+        if let Some((_, width)) = self.get_hint() {
+            height += self.input_opts.affixes.skip_with_suffix(layout, width);
+        } else {
+            height += self.input.height(layout) + self.input_opts.affixes.skip_suffix(layout);
+        }
+
+        if let Some(picker_height) = self.maybe_select_op(|select| select.height(layout)) {
+            height += picker_height - 1;
+        }
+        height
+    }
+
     fn check_complete_default(&mut self) -> bool {
         if self.get_remaining_default().is_some() {
             let (default, default_len) = self.input_opts.default.as_ref().unwrap();
             self.input.set_value(default.clone());
             self.input.set_at(*default_len);
-            self.is_valid = true;
+            self.key_validation.set_valid();
 
             true
         } else {
@@ -114,58 +210,48 @@ impl Widget for InputPrompt<'_, '_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
         let mut original_layout = *layout;
         self.prompt.render(layout, b)?;
+        self.input_opts.affixes.render_prefix(layout, b)?;
 
         // if the current input does not satisfy the on key validation, then we show its wrong by
-        // using the red colour
-        if !self.is_valid {
-            b.set_fg(ui::style::Color::Red)?;
+        // using the error colour
+        if !self.key_validation.is_valid() {
+            b.set_fg(ui::style::theme().error)?;
         }
         self.input.render(layout, b)?;
-        if !self.is_valid {
+        if !self.key_validation.is_valid() {
             b.set_fg(ui::style::Color::Reset)?;
         }
 
-        if let Some(default) = self.get_remaining_default() {
-            b.set_fg(ui::style::Color::DarkGrey)?;
-            write!(b, "{}", default)?;
+        if let Some((hint, _)) = self.get_hint() {
+            b.set_fg(ui::style::theme().hint)?;
+            write!(b, "{}", hint)?;
             b.set_fg(ui::style::Color::Reset)?;
+            self.input_opts.affixes.write_suffix(b)?;
             // We need to update the layout to reflect the rest of the hint that is rendered.
             // Instead of doing the math to compute where the cursor ends after rendering, we use
             // the height function which already calculates it.
-            self.height(&mut original_layout);
+            self.input_height(&mut original_layout);
             *layout = original_layout;
         } else {
+            self.input_opts.affixes.render_suffix(layout, b)?;
             self.maybe_select_op(|select| select.render(layout, b))
                 .transpose()?;
         }
 
+        if self.select.is_none() {
+            self.key_validation.render(layout, b)?;
+        }
+
         Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        let mut height = self.prompt.height(layout) - 1;
-
-        if self.get_remaining_default().is_some() {
-            let mut width = self.input_opts.default.as_ref().unwrap().1 as u16;
+        let mut height = self.input_height(layout);
 
-            if width > layout.line_width() {
-                width -= layout.line_width();
-
-                layout.line_offset = width % layout.width;
-                layout.offset_y += 1 + width / layout.width;
-
-                height += 2 + width / layout.width;
-            } else {
-                layout.line_offset += width;
-                height += 1;
-            }
-        } else {
-            height = self.input.height(layout);
+        if self.select.is_none() {
+            height += self.key_validation.height(layout);
         }
 
-        if let Some(picker_height) = self.maybe_select_op(|select| select.height(layout)) {
-            height += picker_height - 1;
-        }
         height
     }
 
@@ -212,7 +298,8 @@ impl Widget for InputPrompt<'_, '_> {
 
         if self.input.handle_key(key) {
             if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
-                self.is_valid = validate(self.input.value(), self.answers);
+                self.key_validation
+                    .set(validate(self.input.value(), self.answers));
             }
 
             self.select = None;
@@ -221,23 +308,54 @@ impl Widget for InputPrompt<'_, '_> {
             return true;
         }
 
+        if self.select.is_none() && self.input_opts.history.is_some() {
+            match key.code {
+                KeyCode::Up => return self.recall(true),
+                KeyCode::Down => return self.recall(false),
+                _ => {}
+            }
+        }
+
         self.maybe_select_op(|select| select.handle_key(key))
             .unwrap_or(false)
     }
 
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if !self.input.handle_paste(text) {
+            return false;
+        }
+
+        if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
+            self.key_validation
+                .set(validate(self.input.value(), self.answers));
+        }
+
+        self.select = None;
+        true
+    }
+
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-        self.input
-            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+        let mut layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+        self.input_opts.affixes.skip_prefix(&mut layout);
+        self.input.cursor_pos(layout)
     }
 }
 
 impl Prompt for InputPrompt<'_, '_> {
-    type ValidateErr = widgets::Text<String>;
+    type ValidateErr = ValidationMessage;
     type Output = String;
 
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
     fn finish(self) -> Self::Output {
         let mut ans = self.input.finish();
 
+        if let Some(ref history) = self.input_opts.history {
+            history.push(ans.clone());
+        }
+
         if ans.is_empty() {
             if let Some((default, _)) = self.input_opts.default {
                 ans = default;
@@ -262,40 +380,117 @@ impl Prompt for InputPrompt<'_, '_> {
         }
 
         if let Validate::Sync(ref mut validate) = self.input_opts.validate {
-            validate(self.input.value(), self.answers)?;
+            validate(self.input.value(), self.answers).into_result()?;
         }
 
         Ok(Validation::Finish)
     }
+
+    fn describe(&mut self) -> Option<String> {
+        if let Some(ref select) = self.select {
+            return Some(describe_hovered("Completion", select, |list, at| {
+                list[at].as_ref().unwrap_choice().text.clone()
+            }));
+        }
+
+        let default = self.input_opts.default.as_ref().map(|(d, _)| d.as_str());
+        Some(describe_text(self.input.value(), default))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
 }
 
 impl<'i> Input<'i> {
-    fn into_input_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> InputPrompt<'i, 'a> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::String(default) = answer {
+            self.default = Some((default.clone(), default.chars().count()));
+        }
+    }
+
+    pub(super) fn set_initial_answer(&mut self, answer: &Answer) {
+        if let Answer::String(initial_value) = answer {
+            self.initial_value = Some(initial_value.clone());
+        }
+    }
+
+    fn into_input_prompt<'a>(
+        mut self,
+        message: &'a str,
+        answers: &'a Answers,
+    ) -> InputPrompt<'i, 'a> {
+        let mut input = widgets::StringInput::default();
+        let mut key_validation = KeyValidation::default();
+
+        if let Some(initial_value) = self.initial_value.take() {
+            let len = initial_value.chars().count();
+            input.set_value(initial_value);
+            input.set_at(len);
+
+            if let ValidateOnKey::Sync(ref mut validate) = self.validate_on_key {
+                key_validation.set(validate(input.value(), answers));
+            }
+        }
+
+        let history = match self.history {
+            Some(ref history) => history.entries(),
+            None => Vec::new(),
+        };
+
         InputPrompt {
             prompt: widgets::Prompt::new(message),
             input_opts: self,
-            input: widgets::StringInput::default(),
+            input,
             select: None,
-            is_valid: true,
+            key_validation,
+            history,
+            history_at: None,
+            draft: String::new(),
             answers,
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
+        partial: &mut Option<Answer>,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_input_prompt(&message, answers), b)
-            .on_esc(on_esc)
-            .run(events)?;
+        let input = ui::Input::new(self.into_input_prompt(&message, answers), b);
+        let ans = driver.run(input).await.map_err(|recovered| {
+            *partial = Some(Answer::String(recovered.prompt.input.value().to_owned()));
+            recovered.error
+        })?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
 
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans.as_string().expect("answer must be a string").to_owned();
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, str>,
+        message: String,
+        ans: Option<String>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| b
-            .write_styled(&ans.as_str().cyan())?)
+            .write_styled(&ans.as_str().with(ui::style::theme().highlight))?)
     }
 }
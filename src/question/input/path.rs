@@ -0,0 +1,551 @@
+use std::{
+    ffi::OsStr,
+    path::{self, Path, PathBuf},
+};
+
+use ui::backend::Backend;
+
+use super::Input;
+use crate::question::{
+    completions, AutoComplete, Completions, Filter, Options, Validate, ValidationResult,
+};
+
+/// The builder for a [`path`] prompt.
+///
+/// The path is typed in like an [`input`] prompt, with `Tab` completing the entries of the
+/// directory being typed. A leading `~` is expanded to the home directory of the user, both when
+/// completing and in the answer.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let path = Question::path("config")
+///     .message("Where is your config file?")
+///     .must_exist(true)
+///     .extensions(["toml"])
+///     .build();
+/// ```
+///
+/// [`path`]: crate::question::Question::path
+/// [`input`]: crate::question::Question::input
+#[derive(Debug)]
+pub struct PathBuilder<'a> {
+    opts: Options<'a>,
+    input: Input<'a>,
+    must_exist: bool,
+    extensions: Vec<String>,
+}
+
+impl<'a> PathBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        PathBuilder {
+            opts: Options::new(name),
+            input: Default::default(),
+            must_exist: false,
+            extensions: Vec::new(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .message("Where should the project be created?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let path = Question::path("dest")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("in_place") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let path = Question::path("dest")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers, OnEsc};
+    ///
+    /// let path = Question::path("dest")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let path = Question::path("dest")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set a default value for the path
+    ///
+    /// If set and the user presses `Enter` without typing any text, the `default` is taken as the
+    /// answer.
+    ///
+    /// If `default` is used, validation is skipped, but `~` is still expanded and `filter` is
+    /// still called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .default("~/projects")
+    ///     .build();
+    /// ```
+    pub fn default<I: Into<String>>(mut self, default: I) -> Self {
+        let default = default.into();
+        let len = default.chars().count();
+        self.input.default = Some((default, len));
+        self
+    }
+
+    /// Set the path that the input starts with, which can then be edited by the user.
+    ///
+    /// See [`InputBuilder::initial_value`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .initial_value("./")
+    ///     .build();
+    /// ```
+    ///
+    /// [`InputBuilder::initial_value`]: crate::question::InputBuilder::initial_value
+    pub fn initial_value<I: Into<String>>(mut self, initial_value: I) -> Self {
+        self.input.initial_value = Some(initial_value.into());
+        self
+    }
+
+    /// Whether the path must already exist for it to be submitted.
+    ///
+    /// If `must_exist` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .must_exist(true)
+    ///     .build();
+    /// ```
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.must_exist = must_exist;
+        self
+    }
+
+    /// The extensions allowed for the file, without the leading `.`.
+    ///
+    /// Paths to files with other extensions cannot be submitted, and are not suggested when
+    /// completing. Directories are always allowed so that the user can navigate through them. If
+    /// `extensions` is not set, any file is allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("image")
+    ///     .extensions(["png", "jpg"])
+    ///     .build();
+    /// ```
+    pub fn extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extensions = extensions
+            .into_iter()
+            .map(|ext| {
+                let ext = ext.into();
+                match ext.strip_prefix('.') {
+                    Some(ext) => ext.to_owned(),
+                    None => ext,
+                }
+            })
+            .collect();
+        self
+    }
+
+    /// The maximum height that can be taken by the list of completions
+    ///
+    /// If the total height exceeds the page size, the list will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.input.page_size = page_size;
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last completion.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.input.should_loop = should_loop;
+        self
+    }
+
+    crate::impl_filter_builder! {
+    /// The path given to `filter` has `~` expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .filter(|path, previous_answers| path.trim_end_matches('/').to_owned())
+    ///     .build();
+    /// ```
+    String; input
+    }
+
+    crate::impl_validate_builder! {
+    /// The path given to `validate` has `~` expanded. It is only called if the path passes the
+    /// [`must_exist`] and [`extensions`] checks.
+    ///
+    /// [`must_exist`]: PathBuilder::must_exist
+    /// [`extensions`]: PathBuilder::extensions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .validate(|path, previous_answers| if path.contains(' ') {
+    ///         Err("The path cannot contain spaces".to_owned())
+    ///     } else {
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    /// ```
+    str; input
+    }
+
+    crate::impl_validate_on_key_builder! {
+    /// The path given to `validate_on_key` is exactly what has been typed, without `~` expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .validate_on_key(|path, previous_answers| !path.contains(' '))
+    ///     .build();
+    /// ```
+    str; input
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .transform(|path, previous_answers, backend| {
+    ///         write!(backend, "Creating the project in {}", path)
+    ///     })
+    ///     .build();
+    /// ```
+    str; input
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(mut self) -> crate::question::Question<'a> {
+        let extensions = self.extensions.clone();
+        self.input.auto_complete = AutoComplete::new(move |path, _| complete(path, &extensions));
+
+        let must_exist = self.must_exist;
+        let extensions = self.extensions;
+        let validate = self.input.validate.take();
+        self.input.validate = Validate::new(move |path, answers| {
+            let path = expand_tilde(path);
+
+            if must_exist && !path.exists() {
+                return ValidationResult::Error(format!("`{}` does not exist", path.display()));
+            }
+
+            if !path.is_dir() && !has_extension(&path, &extensions) {
+                let extensions: Vec<_> = extensions.iter().map(|ext| format!(".{}", ext)).collect();
+                return ValidationResult::Error(format!(
+                    "`{}` must have one of the extensions {}",
+                    path.display(),
+                    extensions.join(", ")
+                ));
+            }
+
+            match validate {
+                Validate::Sync(ref validate) => validate(&path.to_string_lossy(), answers),
+                Validate::None => ValidationResult::Valid,
+            }
+        });
+
+        let filter = self.input.filter.take();
+        self.input.filter = Filter::new(move |path: String, answers: &crate::Answers| {
+            let path = expand_tilde(&path).to_string_lossy().into_owned();
+
+            match filter {
+                Filter::Sync(ref filter) => filter(path, answers),
+                Filter::None => path,
+            }
+        });
+
+        crate::question::Question::new(self.opts, crate::question::QuestionKind::Input(self.input))
+    }
+}
+
+impl<'a> From<PathBuilder<'a>> for crate::question::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: PathBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+
+    std::env::var_os(var)
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Replaces a leading `~` with the home directory. Paths like `~user` are left as is.
+fn expand_tilde(path: &str) -> PathBuf {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(path::is_separator) => rest,
+        _ => return PathBuf::from(path),
+    };
+
+    match home_dir() {
+        Some(home) if rest.is_empty() => home,
+        Some(home) => home.join(&rest[1..]),
+        None => PathBuf::from(path),
+    }
+}
+
+fn has_extension(path: &Path, extensions: &[String]) -> bool {
+    if extensions.is_empty() {
+        return true;
+    }
+
+    match path.extension().and_then(OsStr::to_str) {
+        Some(ext) => extensions.iter().any(|allowed| allowed == ext),
+        None => false,
+    }
+}
+
+/// Completes the last component of `path` with the entries of its directory.
+///
+/// The completions keep the path as it was typed, so a `~` is only expanded to read the directory.
+/// Directories end with a separator so that their entries can be completed next, and hidden entries
+/// are only suggested if the component starts with a `.`.
+fn complete(path: String, extensions: &[String]) -> Completions<String> {
+    if path == "~" {
+        return completions![format!("~{}", path::MAIN_SEPARATOR)];
+    }
+
+    let split = path.rfind(path::is_separator).map_or(0, |i| i + 1);
+    let (dir, start) = path.split_at(split);
+
+    let entries = match expand_tilde(if dir.is_empty() { "." } else { dir }).read_dir() {
+        Ok(entries) => entries,
+        Err(_) => return completions![path],
+    };
+
+    let mut completions: Completions<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+
+            if !name.starts_with(start) || (name.starts_with('.') && !start.starts_with('.')) {
+                None
+            } else if entry.path().is_dir() {
+                Some(format!("{}{}{}", dir, name, path::MAIN_SEPARATOR))
+            } else if has_extension(name.as_ref(), extensions) {
+                Some(format!("{}{}", dir, name))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if completions.is_empty() {
+        return completions![path];
+    }
+
+    completions.sort();
+    completions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_tilde() {
+        let home = match home_dir() {
+            Some(home) => home,
+            None => return,
+        };
+
+        assert_eq!(expand_tilde("~"), home);
+        assert_eq!(expand_tilde("~/a/b"), home.join("a/b"));
+        assert_eq!(expand_tilde("~user/a"), PathBuf::from("~user/a"));
+        assert_eq!(expand_tilde("a/~"), PathBuf::from("a/~"));
+    }
+
+    #[test]
+    fn test_has_extension() {
+        let extensions = ["rs".to_owned(), "toml".to_owned()];
+
+        assert!(has_extension("a.rs".as_ref(), &extensions));
+        assert!(has_extension("a/b.toml".as_ref(), &extensions));
+        assert!(!has_extension("a.md".as_ref(), &extensions));
+        assert!(!has_extension("rs".as_ref(), &extensions));
+        assert!(has_extension("a.md".as_ref(), &[]));
+    }
+
+    #[test]
+    fn test_complete() {
+        let sep = path::MAIN_SEPARATOR;
+
+        assert_eq!(&complete("~".into(), &[])[..], [format!("~{}", sep)]);
+        assert_eq!(
+            &complete("src/question/inp".into(), &[])[..],
+            [format!("src/question/input{}", sep)]
+        );
+        assert_eq!(
+            &complete("src/question/input/".into(), &[])[..],
+            [
+                "src/question/input/builder.rs",
+                "src/question/input/history.rs",
+                "src/question/input/mod.rs",
+                "src/question/input/path.rs",
+                "src/question/input/tests.rs",
+            ]
+        );
+        assert_eq!(
+            &complete("src/question/input/m".into(), &[])[..],
+            ["src/question/input/mod.rs"]
+        );
+        assert_eq!(
+            &complete("src/question/input/".into(), &["toml".into()])[..],
+            ["src/question/input/"]
+        );
+        assert_eq!(
+            &complete("src/does-not-exist/".into(), &[])[..],
+            ["src/does-not-exist/"]
+        );
+    }
+}
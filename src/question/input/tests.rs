@@ -20,7 +20,7 @@ fn inputs(answers: &Answers) -> [(InputPrompt<'static, '_>, u16); NINPUTS] {
         ),
         (
             Input {
-                auto_complete: AutoComplete::Sync(Box::new(|s, _| {
+                auto_complete: AutoComplete::new(|s: String, _| {
                     let mut completions: Completions<_> = ('a'..='d')
                         .map(|c| {
                             let mut s = s.clone();
@@ -30,7 +30,7 @@ fn inputs(answers: &Answers) -> [(InputPrompt<'static, '_>, u16); NINPUTS] {
                         .collect();
                     completions.push(s + "e");
                     completions
-                })),
+                }),
                 ..Input::default()
             }
             .into_input_prompt("message", &answers),
@@ -81,7 +81,7 @@ fn test_render() {
     ui::assert_backend_snapshot!(format!("{}-3", INPUT_IDS[AUTO_COMPLETE_IDX]), backend);
 
     assert!(prompt.handle_key(KeyCode::Tab.into()));
-    assert_eq!(prompt.validate(), Ok(Validation::Continue));
+    assert!(matches!(prompt.validate(), Ok(Validation::Continue)));
 
     layout = base_layout;
     backend.reset_with_layout(layout);
@@ -130,7 +130,7 @@ fn test_height() {
     assert_eq!(layout, base_layout.with_offset(0, 6).with_line_offset(0));
 
     assert!(prompt.handle_key(KeyCode::Tab.into()));
-    assert_eq!(prompt.validate(), Ok(Validation::Continue));
+    assert!(matches!(prompt.validate(), Ok(Validation::Continue)));
 
     layout = base_layout;
     assert_eq!(prompt.height(&mut layout), 1);
@@ -169,7 +169,79 @@ fn test_cursor_pos() {
     assert_eq!(prompt.cursor_pos(layout), (line_offset + 6, 0));
 
     assert!(prompt.handle_key(KeyCode::Tab.into()));
-    assert_eq!(prompt.validate(), Ok(Validation::Continue));
+    assert!(matches!(prompt.validate(), Ok(Validation::Continue)));
 
     assert_eq!(prompt.cursor_pos(layout), (line_offset + 6, 0));
 }
+
+#[test]
+fn test_affixes() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let mut input = Input::default();
+    input.affixes.set_prefix("$ ".into());
+    input.affixes.set_suffix(" MB".into());
+    let mut prompt = input.into_input_prompt("message", &answers);
+
+    assert_eq!(prompt.cursor_pos(base_layout), (19, 0));
+    prompt.input.set_value("12".into());
+    prompt.input.set_at(2);
+    assert_eq!(prompt.cursor_pos(base_layout), (21, 0));
+
+    let mut layout = base_layout;
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_line_offset(24));
+
+    let mut layout = base_layout;
+    assert_eq!(prompt.height(&mut layout), 1);
+    assert_eq!(layout, base_layout.with_line_offset(24));
+
+    prompt.input.set_value("input".repeat(10));
+    prompt.input.set_at(50);
+    assert_eq!(prompt.cursor_pos(base_layout), (19, 1));
+
+    let mut layout = base_layout;
+    assert_eq!(prompt.height(&mut layout), 2);
+    assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(22));
+}
+
+#[test]
+fn test_history() {
+    let answers = Answers::default();
+    let history = History::new();
+    history.push("first");
+    history.push("second");
+
+    let mut prompt = Input {
+        history: Some(history.clone()),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    prompt.input.set_value("draft".into());
+
+    assert!(prompt.handle_key(KeyCode::Up.into()));
+    assert_eq!(prompt.input.value(), "second");
+    assert!(prompt.handle_key(KeyCode::Up.into()));
+    assert_eq!(prompt.input.value(), "first");
+    assert_eq!(prompt.input.get_at(), 5);
+    // there are no older entries
+    assert!(!prompt.handle_key(KeyCode::Up.into()));
+
+    assert!(prompt.handle_key(KeyCode::Down.into()));
+    assert!(prompt.handle_key(KeyCode::Down.into()));
+    assert_eq!(prompt.input.value(), "draft");
+    assert!(!prompt.handle_key(KeyCode::Down.into()));
+
+    assert!(prompt.handle_key(KeyCode::Up.into()));
+    assert_eq!(prompt.finish(), "second");
+    assert_eq!(history.entries(), ["first", "second"]);
+
+    // Up and Down are not used without a history
+    let mut prompt = Input::default().into_input_prompt("message", &answers);
+    assert!(!prompt.handle_key(KeyCode::Up.into()));
+}
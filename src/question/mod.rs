@@ -1,5 +1,8 @@
 //! A module that contains things related to [`Question`]s.
 
+mod accessibility;
+mod affix;
+mod autocomplete;
 mod choice;
 mod confirm;
 mod editor;
@@ -9,49 +12,86 @@ mod handler;
 mod impl_macros;
 mod input;
 mod multi_select;
+mod multiline_input;
 mod number;
 mod order_select;
 #[macro_use]
 mod options;
 mod custom_prompt;
+mod driver;
+mod dynamic_list;
 mod password;
 mod raw_select;
+mod repeat;
+mod section;
 mod select;
+mod slider;
+mod validation;
 
+pub use autocomplete::{AutocompleteBuilder, SuggestionProvider};
 pub use choice::Choice;
 pub use confirm::ConfirmBuilder;
-pub use custom_prompt::{CustomPromptBuilder, Prompt};
+pub use custom_prompt::{write_finished, CustomPromptBuilder, Prompt};
+pub use dynamic_list::DynamicList;
 pub use editor::EditorBuilder;
 pub use expand::ExpandBuilder;
-pub use input::InputBuilder;
+pub use input::{History, InputBuilder, PathBuilder};
 pub use multi_select::MultiSelectBuilder;
+pub use multiline_input::MultilineInputBuilder;
 pub use number::{FloatBuilder, IntBuilder};
 pub use order_select::{builder::OrderSelectBuilder, OrderSelectItem};
 pub use password::PasswordBuilder;
-pub use raw_select::RawSelectBuilder;
-pub use select::SelectBuilder;
+pub use raw_select::{RawSelectBuilder, RawSelectIndex};
+pub use repeat::Repeat;
+pub use section::Section;
+pub use select::{KeyedSelectBuilder, SelectBuilder};
+pub use slider::SliderBuilder;
+pub use validation::ValidationResult;
 
-use ui::{backend::Backend, events::EventIterator};
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyEvent},
+};
 
 use crate::{Answer, Answers};
-use choice::{get_sep_str, ChoiceList};
+use accessibility::{describe_hovered, describe_text};
+use affix::Affixes;
+use choice::{filter_hint, get_sep_str, shown_text, write_answer, ChoiceList};
+pub(crate) use choice::{set_default_page_size, set_default_should_loop};
 use custom_prompt::CustomPromptInteral;
+#[cfg(feature = "async")]
+pub(crate) use driver::AsyncDriver;
+use driver::WithInputOptions;
+pub(crate) use driver::{block_on, Driver, SyncDriver};
 use handler::{
     AutoComplete, Filter, Transform, TransformByVal, Validate, ValidateByVal, ValidateOnKey,
     ValidateOnKeyByVal,
 };
-use options::Options;
+pub(crate) use options::{set_default_hide_cursor, set_default_on_esc};
+use options::{AfterAsk, Getter, Hook, Options};
+use validation::{KeyValidation, ValidationMessage};
 
 /// A `Question` that can be asked.
 ///
-/// There are 12 variants.
+/// There are 16 variants.
 ///
 /// - [`input`](Question::input)
+/// - [`path`](Question::path)
 /// - [`password`](Question::password)
 /// - [`editor`](Question::editor)
+/// - [`multiline_input`](Question::multiline_input)
+/// - [`autocomplete`](Question::autocomplete)
 /// - [`confirm`](Question::confirm)
 /// - [`int`](Question::int)
 /// - [`float`](Question::float)
+/// - [`slider`](Question::slider)
 /// - [`expand`](Question::expand)
 /// - [`select`](Question::select)
 /// - [`raw_select`](Question::raw_select)
@@ -136,6 +176,32 @@ impl Question<'static> {
         InputBuilder::new(name.into())
     }
 
+    /// Prompt that takes a path to a file or directory.
+    ///
+    /// Pressing `Tab` completes the entries of the directory being typed, and a leading `~` is
+    /// expanded to the home directory of the user. The path can be checked to exist with
+    /// [`must_exist`], and to have one of the given [`extensions`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("dest")
+    ///     .message("Where should the project be created?")
+    ///     .default("~/projects")
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: PathBuilder
+    /// [`must_exist`]: PathBuilder::must_exist
+    /// [`extensions`]: PathBuilder::extensions
+    pub fn path<N: Into<String>>(name: N) -> PathBuilder<'static> {
+        PathBuilder::new(name.into())
+    }
+
     /// Prompt that takes user input and hides it.
     ///
     /// How it looks if you set a mask:
@@ -201,6 +267,67 @@ impl Question<'static> {
         EditorBuilder::new(name.into())
     }
 
+    /// Prompt that takes multiple lines of text, without leaving the terminal.
+    ///
+    /// `Enter` inserts a new line, and the text is submitted with `Alt+Enter` or `Ctrl+D`. Once the
+    /// text is taller than the [`max_height`], it is scrolled to keep the cursor visible.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .message("What is your address?")
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: MultilineInputBuilder
+    /// [`max_height`]: MultilineInputBuilder::max_height
+    pub fn multiline_input<N: Into<String>>(name: N) -> MultilineInputBuilder<'static> {
+        MultilineInputBuilder::new(name.into())
+    }
+
+    /// Prompt that takes text, suggesting answers for it as the user types.
+    ///
+    /// The suggestions come from the `provider`, which is queried with the text entered so far
+    /// whenever it changes. The user can move through the suggestions with `Up` and `Down`, fill
+    /// the text with the hovered suggestion using `Tab`, and submit it with `Enter`. If there are
+    /// no suggestions, the text itself is submitted. A slow provider can be queried less often with
+    /// [`debounce`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let countries = ["Australia", "Austria", "Brazil", "Canada"];
+    ///
+    /// let autocomplete = Question::autocomplete("country", move |input: &str, _: &_| {
+    ///     countries
+    ///         .iter()
+    ///         .filter(|country| country.to_lowercase().starts_with(&input.to_lowercase()))
+    ///         .map(|country| country.to_string())
+    ///         .collect()
+    /// })
+    /// .message("Which country are you from?")
+    /// .build();
+    /// ```
+    ///
+    /// [`builder`]: AutocompleteBuilder
+    /// [`debounce`]: AutocompleteBuilder::debounce
+    pub fn autocomplete<'a, N, P>(name: N, provider: P) -> AutocompleteBuilder<'a>
+    where
+        N: Into<String>,
+        P: SuggestionProvider + 'a,
+    {
+        AutocompleteBuilder::new(name.into(), Box::new(provider))
+    }
+
     /// Prompt that returns `true` or `false`.
     ///
     /// <img
@@ -293,10 +420,37 @@ impl Question<'static> {
         FloatBuilder::new(name.into())
     }
 
+    /// Prompt that picks a [`i64`] in a range using a horizontal gauge.
+    ///
+    /// The value is adjusted with `Left` and `Right` by the [`step`], and with `PageDown` and
+    /// `PageUp` by a tenth of the [`range`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .message("How loud should the music be?")
+    ///     .range(0..=100)
+    ///     .step(5)
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: SliderBuilder
+    /// [`step`]: SliderBuilder::step
+    /// [`range`]: SliderBuilder::range
+    pub fn slider<N: Into<String>>(name: N) -> SliderBuilder<'static> {
+        SliderBuilder::new(name.into())
+    }
+
     /// Prompt that allows the user to select from a list of options by key
     ///
-    /// The keys are ascii case-insensitive characters. The 'h' option is added by the prompt and
-    /// shouldn't be defined.
+    /// The keys are ascii case-insensitive, and are usually a single character. When there are too
+    /// many choices for single characters, keys can have multiple characters, which are selected
+    /// by typing them out. The 'h' option is added by the prompt and shouldn't be defined.
     ///
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
     /// but [`Choice::Separator`]s can only be single line.
@@ -365,6 +519,52 @@ impl Question<'static> {
         SelectBuilder::new(name.into())
     }
 
+    /// Prompt that allows the user to select from a list of options, each of which carries a value.
+    ///
+    /// It looks and behaves the same as a [`select`](Question::select), but the answer is the value
+    /// of the chosen option as an [`Answer::Custom`], instead of a [`ListItem`]. The value can be
+    /// taken out with [`Answer::as_custom`] or [`Answer::try_into_custom`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{
+    ///     prompt::events::{KeyCode, TestEvents},
+    ///     Question,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Env {
+    ///     Staging,
+    ///     Production,
+    /// }
+    ///
+    /// let select = Question::custom_keyed_select("env")
+    ///     .message("Where do you want to deploy?")
+    ///     .choice_value("Staging", Env::Staging)
+    ///     .choice_value("Production", Env::Production)
+    ///     .build();
+    ///
+    /// # let mut backend = requestty::prompt::backend::TestBackend::new((50, 10).into());
+    /// let mut events = TestEvents::new(vec![KeyCode::Down.into(), KeyCode::Enter.into()]);
+    /// let answer = requestty::prompt_one_with(select, &mut backend, &mut events)?;
+    ///
+    /// assert_eq!(answer.as_custom::<Env>(), Some(&Env::Production));
+    /// # Result::<_, requestty::ErrorKind>::Ok(())
+    /// ```
+    ///
+    /// [`builder`]: KeyedSelectBuilder
+    /// [`ListItem`]: crate::ListItem
+    pub fn custom_keyed_select<V, N>(name: N) -> KeyedSelectBuilder<'static, V>
+    where
+        V: std::any::Any + PartialEq + Send + Sync,
+        N: Into<String>,
+    {
+        KeyedSelectBuilder::new(name.into())
+    }
+
     /// Prompt that allows the user to select from a list of options with indices
     ///
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
@@ -438,6 +638,9 @@ impl Question<'static> {
     ///
     /// The choices are [`String`]s and can be multiline.
     ///
+    /// An option is taken by pressing `Space`, after which it is moved with the arrow keys or by
+    /// typing the position to move it to. Pressing `Space` again places it.
+    ///
     /// <img
     ///   src="https://raw.githubusercontent.com/lutetium-vanadium/requestty/master/assets/order-select.gif"
     ///   style="max-height: 20rem"
@@ -517,6 +720,7 @@ enum QuestionKind<'a> {
     Input(input::Input<'a>),
     Int(number::Int<'a>),
     Float(number::Float<'a>),
+    Slider(slider::Slider<'a>),
     Confirm(confirm::Confirm<'a>),
     Select(select::Select<'a>),
     RawSelect(raw_select::RawSelect<'a>),
@@ -525,24 +729,203 @@ enum QuestionKind<'a> {
     OrderSelect(order_select::OrderSelect<'a>),
     Password(password::Password<'a>),
     Editor(editor::Editor<'a>),
+    MultilineInput(multiline_input::MultilineInput<'a>),
+    Autocomplete(autocomplete::Autocomplete<'a>),
     Custom(Box<dyn CustomPromptInteral + 'a>),
 }
 
-impl Question<'_> {
-    pub(crate) fn ask<B: Backend, I: EventIterator>(
-        self,
+impl QuestionKind<'_> {
+    /// The name of the kind of question, as it is given in traces.
+    #[cfg(feature = "tracing")]
+    fn name(&self) -> &'static str {
+        match self {
+            QuestionKind::Input(_) => "input",
+            QuestionKind::Int(_) => "int",
+            QuestionKind::Float(_) => "float",
+            QuestionKind::Slider(_) => "slider",
+            QuestionKind::Confirm(_) => "confirm",
+            QuestionKind::Select(_) => "select",
+            QuestionKind::RawSelect(_) => "raw_select",
+            QuestionKind::Expand(_) => "expand",
+            QuestionKind::MultiSelect(_) => "multi_select",
+            QuestionKind::OrderSelect(_) => "order_select",
+            QuestionKind::Password(_) => "password",
+            QuestionKind::Editor(_) => "editor",
+            QuestionKind::MultilineInput(_) => "multiline_input",
+            QuestionKind::Autocomplete(_) => "autocomplete",
+            QuestionKind::Custom(_) => "custom",
+        }
+    }
+
+    /// Copies the kind of question, which is not possible for custom prompts since they are
+    /// consumed when they are asked.
+    fn try_clone(&self) -> Option<Self> {
+        Some(match self {
+            QuestionKind::Input(i) => QuestionKind::Input(i.clone()),
+            QuestionKind::Int(i) => QuestionKind::Int(i.clone()),
+            QuestionKind::Float(f) => QuestionKind::Float(f.clone()),
+            QuestionKind::Slider(s) => QuestionKind::Slider(s.clone()),
+            QuestionKind::Confirm(c) => QuestionKind::Confirm(c.clone()),
+            QuestionKind::Select(l) => QuestionKind::Select(l.clone()),
+            QuestionKind::RawSelect(r) => QuestionKind::RawSelect(r.clone()),
+            QuestionKind::Expand(e) => QuestionKind::Expand(e.clone()),
+            QuestionKind::MultiSelect(c) => QuestionKind::MultiSelect(c.clone()),
+            QuestionKind::OrderSelect(c) => QuestionKind::OrderSelect(c.clone()),
+            QuestionKind::Password(p) => QuestionKind::Password(p.clone()),
+            QuestionKind::Editor(e) => QuestionKind::Editor(e.clone()),
+            QuestionKind::MultilineInput(m) => QuestionKind::MultilineInput(m.clone()),
+            QuestionKind::Autocomplete(a) => QuestionKind::Autocomplete(a.clone()),
+            QuestionKind::Custom(_) => return None,
+        })
+    }
+
+    /// Uses a previous answer as the default. Answers of the wrong type are ignored.
+    ///
+    /// Passwords are never prefilled, and neither are `order_select` and custom prompts since they
+    /// have no default.
+    fn set_default_answer(&mut self, answer: &Answer) {
+        match self {
+            QuestionKind::Input(i) => i.set_default_answer(answer),
+            QuestionKind::Int(i) => i.set_default_answer(answer),
+            QuestionKind::Float(f) => f.set_default_answer(answer),
+            QuestionKind::Slider(s) => s.set_default_answer(answer),
+            QuestionKind::Confirm(c) => c.set_default_answer(answer),
+            QuestionKind::Select(l) => l.set_default_answer(answer),
+            QuestionKind::RawSelect(r) => r.set_default_answer(answer),
+            QuestionKind::Expand(e) => e.set_default_answer(answer),
+            QuestionKind::MultiSelect(c) => c.set_default_answer(answer),
+            QuestionKind::Editor(e) => e.set_default_answer(answer),
+            QuestionKind::MultilineInput(m) => m.set_default_answer(answer),
+            QuestionKind::OrderSelect(_)
+            | QuestionKind::Password(_)
+            | QuestionKind::Autocomplete(_)
+            | QuestionKind::Custom(_) => {}
+        }
+    }
+
+    /// Uses an answer, usually the partial answer from an earlier attempt, as the text that the
+    /// question starts with. Only text based questions have an initial text.
+    fn set_initial_answer(&mut self, answer: &Answer) {
+        match self {
+            QuestionKind::Input(i) => i.set_initial_answer(answer),
+            QuestionKind::Int(i) => i.set_initial_answer(answer),
+            QuestionKind::Float(f) => f.set_initial_answer(answer),
+            QuestionKind::MultilineInput(m) => m.set_initial_answer(answer),
+            QuestionKind::Autocomplete(a) => a.set_initial_answer(answer),
+            _ => {}
+        }
+    }
+}
+
+/// Everything needed to ask a question, once it has been decided that it should be asked.
+struct AskParts<'a> {
+    name: String,
+    message: String,
+    on_esc: ui::OnEsc,
+    theme: Option<ui::style::Theme>,
+    after_ask: Option<AfterAsk<'a>>,
+    hide_final: bool,
+    kind: QuestionKind<'a>,
+}
+
+impl AskParts<'_> {
+    /// Returns the outcome of asking the question, calling `after_ask` if it was answered.
+    fn finish(
+        name: String,
+        after_ask: Option<AfterAsk<'_>>,
         answers: &Answers,
-        b: &mut B,
-        events: &mut I,
-    ) -> ui::Result<Option<(String, Answer)>> {
+        res: Option<Answer>,
+    ) -> Asked {
+        #[cfg(feature = "tracing")]
+        match res {
+            Some(_) => ui::tracing::debug!("answer produced"),
+            None => ui::tracing::debug!("question skipped"),
+        }
+
+        match res {
+            Some(res) => {
+                if let Some(Hook(after_ask)) = after_ask {
+                    after_ask(answers, &res);
+                }
+                Asked::Answered(name, res)
+            }
+            None => Asked::Skipped(name),
+        }
+    }
+}
+
+/// What happened when a question was asked.
+#[derive(Debug)]
+pub(crate) enum Asked {
+    /// The question was answered.
+    Answered(String, Answer),
+    /// The question was skipped, either because of `when` or by pressing `Esc`.
+    Skipped(String),
+    /// The question was not asked since it already has an answer.
+    AlreadyAnswered,
+}
+
+impl<'a> Question<'a> {
+    /// Copies the question before it is asked, so that it can be asked again. Custom prompts
+    /// cannot be copied.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Question {
+            kind: self.kind.try_clone()?,
+            opts: self.opts.clone(),
+        })
+    }
+
+    /// Uses the answer to this question in `defaults` as its default, if there is one.
+    pub(crate) fn set_default_from(&mut self, defaults: &Answers) {
+        if let Some(answer) = defaults.get(&self.opts.name) {
+            self.kind.set_default_answer(answer);
+        }
+    }
+
+    /// Erases the line shown once the question is answered, like
+    /// [`hide_final`](InputBuilder::hide_final).
+    pub(crate) fn set_hide_final(&mut self) {
+        self.opts.hide_final = true;
+    }
+
+    /// Uses `initial` as the text that the question starts with, if it is a text based question.
+    pub(crate) fn set_initial_answer(&mut self, initial: &Answer) {
+        self.kind.set_initial_answer(initial);
+    }
+
+    /// Changes the message with `f` right before the question is prompted.
+    ///
+    /// `f` is given the message that would have been shown otherwise, including the default of
+    /// "\<name\>:" if there is no message.
+    pub(crate) fn map_message<F>(&mut self, mut f: F)
+    where
+        F: FnMut(String) -> String + 'a,
+    {
+        let message = self.opts.message.take();
+        let name = self.opts.name.clone();
+
+        self.opts.message = Some(Getter::from(move |answers: &Answers| {
+            f(message
+                .as_ref()
+                .map(|message| message.get(answers))
+                .unwrap_or_else(|| name.clone() + ":"))
+        }));
+    }
+
+    /// Returns `Err` with the outcome if the question should not be asked.
+    fn into_ask_parts(self, answers: &Answers) -> Result<AskParts<'a>, Asked> {
         // Already asked
         if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
-            return Ok(None);
+            return Err(Asked::AlreadyAnswered);
         }
 
         // Shouldn't be asked
         if !self.opts.when.get(answers) {
-            return Ok(None);
+            return Err(Asked::Skipped(self.opts.name));
+        }
+
+        if let Some(Hook(ref before_ask)) = self.opts.before_ask {
+            before_ask(answers);
         }
 
         let name = self.opts.name;
@@ -551,24 +934,306 @@ impl Question<'_> {
             .message
             .map(|message| message.get(answers))
             .unwrap_or_else(|| name.clone() + ":");
-        let on_esc = self.opts.on_esc.get(answers);
-
-        let res = match self.kind {
-            QuestionKind::Input(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Int(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Float(f) => f.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Confirm(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Select(l) => l.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::RawSelect(r) => r.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Expand(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::MultiSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::OrderSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Password(p) => p.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Editor(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Custom(mut o) => o.ask(message, answers, b, events)?,
+
+        Ok(AskParts {
+            message,
+            name,
+            on_esc: self.opts.on_esc.get(answers),
+            theme: self.opts.theme,
+            after_ask: self.opts.after_ask,
+            hide_final: self.opts.hide_final,
+            kind: self.kind,
+        })
+    }
+
+    /// Resolves the question from the existing answers without asking it, failing if it would
+    /// have been asked.
+    pub(crate) fn resolve(self, answers: &Answers) -> ui::Result<Asked> {
+        if answers.contains_key(&self.opts.name) {
+            Ok(Asked::AlreadyAnswered)
+        } else if !self.opts.when.get(answers) {
+            Ok(Asked::Skipped(self.opts.name))
+        } else {
+            Err(ui::ErrorKind::MissingAnswer(self.opts.name))
+        }
+    }
+
+    /// Asks the question, failing with [`ErrorKind::Back`](ui::ErrorKind::Back) if the `back_key`
+    /// is pressed. Custom prompts do not know about the `back_key`.
+    pub(crate) fn ask<B: Backend, I: EventIterator>(
+        self,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut I,
+        back_key: Option<KeyEvent>,
+    ) -> ui::Result<Asked> {
+        self.ask_with_partial(answers, b, events, back_key, &mut None)
+    }
+
+    /// Asks the question like [`ask`](Question::ask). If a text based question fails, `partial` is
+    /// set to the text that was entered before it failed.
+    pub(crate) fn ask_with_partial<B: Backend, I: EventIterator>(
+        self,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut I,
+        back_key: Option<KeyEvent>,
+        partial: &mut Option<Answer>,
+    ) -> ui::Result<Asked> {
+        block_on(self.ask_with(
+            answers,
+            b,
+            &mut SyncDriver::new(events, true),
+            back_key,
+            partial,
+        ))
+    }
+
+    /// Asks the question like [`ask_with_partial`](Question::ask_with_partial), running its prompt
+    /// with the `driver`.
+    pub(crate) async fn ask_with<B: Backend, D: Driver>(
+        mut self,
+        answers: &Answers,
+        b: &mut B,
+        driver: &mut D,
+        back_key: Option<KeyEvent>,
+        partial: &mut Option<Answer>,
+    ) -> ui::Result<Asked> {
+        if let Some(section_start) = self.opts.section_start.take() {
+            section_start.begin(answers, b)?;
+        }
+
+        let AskParts {
+            name,
+            message,
+            on_esc,
+            theme,
+            after_ask,
+            hide_final,
+            kind,
+        } = match self.into_ask_parts(answers) {
+            Ok(parts) => parts,
+            Err(asked) => return Ok(asked),
         };
 
-        Ok(res.map(|res| (name, res)))
+        // The span cannot be entered across awaits, so it is entered by the future on every poll
+        #[cfg(feature = "tracing")]
+        let span = question_span(&name, &kind);
+
+        if ui::accessibility::enabled() && !matches!(kind, QuestionKind::Custom(_)) {
+            accessibility::write_message(&message, b)?;
+        }
+
+        let driver = &mut WithInputOptions {
+            driver,
+            on_esc,
+            back_key,
+            hide_cursor: options::default_hide_cursor(),
+        };
+
+        let future = WithOverrides::new(theme, hide_final, async move {
+            ui::Result::Ok(match kind {
+                QuestionKind::Input(i) => i.ask(message, answers, b, driver, partial).await?,
+                QuestionKind::Int(i) => i.ask(message, answers, b, driver, partial).await?,
+                QuestionKind::Float(f) => f.ask(message, answers, b, driver, partial).await?,
+                QuestionKind::Slider(s) => s.ask(message, answers, b, driver).await?,
+                QuestionKind::Confirm(c) => c.ask(message, answers, b, driver).await?,
+                QuestionKind::Select(l) => l.ask(message, answers, b, driver).await?,
+                QuestionKind::RawSelect(r) => r.ask(message, answers, b, driver).await?,
+                QuestionKind::Expand(e) => e.ask(message, answers, b, driver).await?,
+                QuestionKind::MultiSelect(c) => c.ask(message, answers, b, driver).await?,
+                QuestionKind::OrderSelect(c) => c.ask(message, answers, b, driver).await?,
+                QuestionKind::Password(p) => p.ask(message, answers, b, driver).await?,
+                QuestionKind::Editor(e) => e.ask(message, answers, b, driver).await?,
+                QuestionKind::MultilineInput(m) => {
+                    m.ask(message, answers, b, driver, partial).await?
+                }
+                QuestionKind::Autocomplete(a) => {
+                    a.ask(message, answers, b, driver, partial).await?
+                }
+                QuestionKind::Custom(mut o) => o.ask(message, answers, b, driver.events())?,
+            })
+        });
+        #[cfg(feature = "tracing")]
+        let future = future.in_span(span.clone());
+        let res = future.await?;
+
+        #[cfg(feature = "tracing")]
+        let _entered = span.entered();
+
+        Ok(AskParts::finish(name, after_ask, answers, res))
+    }
+
+    /// Prints the line shown once this question has been answered, for a previously given
+    /// `answer`.
+    ///
+    /// The same message, [`transform`] and formatting that are used when the question is asked are
+    /// used. This is useful to re-print a summary of the answers later, for example after clearing
+    /// the screen.
+    ///
+    /// [`transform`]: InputBuilder::transform
+    ///
+    /// # Panics
+    ///
+    /// Panics if `answer` is not of the kind that this question gives. For example, passing an
+    /// [`Answer::Int`] to an input question will panic. [`Answer::Skipped`] can be passed to any
+    /// question, and is shown the same way as a question skipped with `Esc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::backend::TestBackend, Answer, Answers, Question};
+    ///
+    /// let mut backend = TestBackend::new((50, 5).into());
+    ///
+    /// Question::input("name")
+    ///     .message("What is your name?")
+    ///     .build()
+    ///     .render_finished(
+    ///         &Answer::String("Ferris".into()),
+    ///         &Answers::default(),
+    ///         &mut backend,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn render_finished<B: Backend>(
+        self,
+        answer: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let name = self.opts.name;
+        let message = self
+            .opts
+            .message
+            .map(|message| message.get(answers))
+            .unwrap_or_else(|| name + ":");
+        let kind = self.kind;
+
+        if answer.is_skipped() {
+            return with_theme(self.opts.theme, || {
+                ui::widgets::Prompt::write_finished_message(&message, true, b)?;
+                b.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
+                b.write_all(b"\n")?;
+                b.flush()?;
+                Ok(())
+            });
+        }
+
+        with_theme(self.opts.theme, || match kind {
+            QuestionKind::Input(i) => i.render_finished(message, answer, answers, b),
+            QuestionKind::Int(i) => i.render_finished(message, answer, answers, b),
+            QuestionKind::Float(f) => f.render_finished(message, answer, answers, b),
+            QuestionKind::Slider(s) => s.render_finished(message, answer, answers, b),
+            QuestionKind::Confirm(c) => c.render_finished(message, answer, answers, b),
+            QuestionKind::Select(l) => l.render_finished(message, answer, answers, b),
+            QuestionKind::RawSelect(r) => r.render_finished(message, answer, answers, b),
+            QuestionKind::Expand(e) => e.render_finished(message, answer, answers, b),
+            QuestionKind::MultiSelect(c) => c.render_finished(message, answer, answers, b),
+            QuestionKind::OrderSelect(c) => c.render_finished(message, answer, answers, b),
+            QuestionKind::Password(p) => p.render_finished(message, answer, answers, b),
+            QuestionKind::Editor(e) => e.render_finished(message, answer, answers, b),
+            QuestionKind::MultilineInput(m) => m.render_finished(message, answer, answers, b),
+            QuestionKind::Autocomplete(a) => a.render_finished(message, answer, answers, b),
+            QuestionKind::Custom(o) => {
+                ui::widgets::Prompt::write_finished_message(&message, false, b)?;
+                o.write_answer(answer, answers, b)?;
+                b.write_all(b"\n")?;
+                b.flush()?;
+                Ok(())
+            }
+        })
+    }
+}
+
+/// Runs `f` with the given theme if there is one, otherwise with the current theme.
+fn with_theme<T, F: FnOnce() -> T>(theme: Option<ui::style::Theme>, f: F) -> T {
+    match theme {
+        Some(theme) => ui::style::with_theme(theme, f),
+        None => f(),
+    }
+}
+
+thread_local! {
+    // `const` initializers for thread locals are not supported by the MSRV
+    #[allow(clippy::missing_const_for_thread_local)]
+    static HIDE_FINAL: Cell<bool> = Cell::new(false);
+}
+
+/// Whether the question being asked should erase the line shown once it is answered, instead of
+/// writing it.
+pub(crate) fn hide_final() -> bool {
+    HIDE_FINAL.with(Cell::get)
+}
+
+/// Runs `f` with the given theme like [`with_theme`], and with [`hide_final`] set to `hide_final`.
+///
+/// The previous value of [`hide_final`] is restored once `f` returns.
+fn with_overrides<T, F: FnOnce() -> T>(
+    theme: Option<ui::style::Theme>,
+    hide_final: bool,
+    f: F,
+) -> T {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            HIDE_FINAL.with(|hide| hide.set(self.0));
+        }
+    }
+
+    let _restore = Restore(HIDE_FINAL.with(|hide| hide.replace(hide_final)));
+    with_theme(theme, f)
+}
+
+/// Creates the span in which a question is asked, and emits the event that it is being asked.
+#[cfg(feature = "tracing")]
+fn question_span(name: &str, kind: &QuestionKind<'_>) -> ui::tracing::Span {
+    let span = ui::tracing::debug_span!("question", name, kind = kind.name());
+    span.in_scope(|| ui::tracing::debug!("question asked"));
+    span
+}
+
+/// A future which runs the inner future with the given overrides, like [`with_overrides`].
+///
+/// The overrides are thread local, so they are set for each poll instead of once.
+struct WithOverrides<F> {
+    theme: Option<ui::style::Theme>,
+    hide_final: bool,
+    #[cfg(feature = "tracing")]
+    span: ui::tracing::Span,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> WithOverrides<F> {
+    fn new(theme: Option<ui::style::Theme>, hide_final: bool, future: F) -> Self {
+        Self {
+            theme,
+            hide_final,
+            #[cfg(feature = "tracing")]
+            span: ui::tracing::Span::none(),
+            future: Box::pin(future),
+        }
+    }
+
+    /// Enters `span` whenever the inner future is polled.
+    #[cfg(feature = "tracing")]
+    fn in_span(mut self, span: ui::tracing::Span) -> Self {
+        self.span = span;
+        self
+    }
+}
+
+impl<F: Future> Future for WithOverrides<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let theme = self.theme;
+        let hide_final = self.hide_final;
+        #[cfg(feature = "tracing")]
+        let _entered = self.span.clone().entered();
+        let future = self.future.as_mut();
+        with_overrides(theme, hide_final, || future.poll(cx))
     }
 }
 
@@ -2,7 +2,7 @@ use ui::{backend::Backend, widgets::Text};
 
 use super::MultiSelect;
 use crate::{
-    question::{Choice, Options},
+    question::{dynamic_list::DynamicChoices, shown_text, Choice, DynamicList, Options},
     ListItem,
 };
 
@@ -10,6 +10,11 @@ use crate::{
 ///
 /// Unlike the other list based prompts, this has a per choice boolean default.
 ///
+/// A range of choices can be selected by holding `Shift` while pressing `Up` or `Down`. Every
+/// choice between the last toggled choice and the hovered one is given the same state as the last
+/// toggled choice. If no choice has been toggled, the hovered choice is selected and the range
+/// starts from it.
+///
 /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
 /// but [`Choice::Separator`]s can only be single line.
 ///
@@ -97,6 +102,57 @@ impl<'a> MultiSelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// The maximum height that can be taken by the list
@@ -143,6 +199,177 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Whether `PageUp` and `PageDown` should jump to the previous and next section instead of by
+    /// a page.
+    ///
+    /// Sections are groups of choices delimited by [`Separator`]s. When this is enabled, `[` and
+    /// `]` can also be used to jump to the previous and next section.
+    ///
+    /// If `jump_by_section` is not set, it will default to `false`.
+    ///
+    /// [`Separator`]: crate::question::Choice::Separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .jump_by_section(true)
+    ///     .build();
+    /// ```
+    pub fn jump_by_section(mut self, jump_by_section: bool) -> Self {
+        self.multi_select
+            .choices
+            .set_jump_by_section(jump_by_section);
+        self
+    }
+
+    /// Whether to capture the mouse while the prompt is active.
+    ///
+    /// When enabled, scrolling moves the cursor and clicking on a choice hovers it and toggles it. While
+    /// the mouse is captured, the terminal cannot be used to select text.
+    ///
+    /// If `mouse_capture` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .mouse_capture(true)
+    ///     .build();
+    /// ```
+    pub fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.multi_select.choices.set_mouse_capture(mouse_capture);
+        self
+    }
+
+    /// Whether to truncate the answer on the finished line so that it fits in the terminal.
+    ///
+    /// When enabled, the answer is cut short with `...` instead of wrapping onto more lines. The selected choices are separated by commas.
+    ///
+    /// If `truncate_answer` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .truncate_answer(false)
+    ///     .build();
+    /// ```
+    pub fn truncate_answer(mut self, truncate_answer: bool) -> Self {
+        self.multi_select
+            .choices
+            .set_truncate_answer(truncate_answer);
+        self
+    }
+
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// When enabled, typing narrows down the choices to those which fuzzy match what was typed,
+    /// i.e. those which contain the typed characters in the same order, ignoring case.
+    /// `Backspace` removes the last typed character. Separators are hidden while filtering.
+    ///
+    /// Since letters are typed into the filter, `a` and `i` cannot be used to toggle all choices
+    /// or invert the selection while filtering is enabled.
+    ///
+    /// If `filterable` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.multi_select.choices.set_filterable(filterable);
+        self
+    }
+
+    /// The number of columns to lay the choices out in.
+    ///
+    /// When there is more than one column, the choices are laid out in a grid similar to shell
+    /// completions, which is useful for long lists of short choices. Each choice takes a single
+    /// line, and `Left` and `Right` move between the columns. If `columns` is 0, the most columns
+    /// in which every choice fits on a single line are used. Fewer columns are used if the
+    /// terminal is too narrow.
+    ///
+    /// If `columns` is not set, it will default to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .columns(0)
+    ///     .build();
+    /// ```
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.multi_select.choices.set_columns(columns);
+        self
+    }
+
+    /// Whether to show the full text of the hovered choice below the list.
+    ///
+    /// When the choices are laid out in more than one [column](Self::columns), each choice is cut
+    /// off after a single line. With `hover_detail`, the full text of the hovered choice is shown
+    /// in a dimmed line below the list, wrapping if needed, which updates as the user moves.
+    ///
+    /// If `hover_detail` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .columns(3)
+    ///     .hover_detail(true)
+    ///     .build();
+    /// ```
+    pub fn hover_detail(mut self, hover_detail: bool) -> Self {
+        self.multi_select.choices.set_hover_detail(hover_detail);
+        self
+    }
+
+    /// Whether the answer should be ordered by when each choice was selected, instead of by their
+    /// position in the list.
+    ///
+    /// This is useful when the order matters, for example when asking for the top 3 choices in
+    /// order of preference. Choices which are selected by default, or several at once, such as
+    /// when toggling all of them, are taken to be selected in the order of the list. Unselecting a
+    /// choice and selecting it again moves it to the end.
+    ///
+    /// If `track_selection_order` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("toppings")
+    ///     .message("Pick your favourite toppings, in order")
+    ///     .choices(vec!["Cheese", "Olives", "Mushrooms", "Peppers"])
+    ///     .track_selection_order(true)
+    ///     .build();
+    /// ```
+    pub fn track_selection_order(mut self, track_selection_order: bool) -> Self {
+        self.multi_select.selection_order = if track_selection_order {
+            Some(Vec::new())
+        } else {
+            None
+        };
+        self
+    }
+
     /// Inserts a [`Choice`] with given text and its default checked state as `false`.
     ///
     /// If you want to set the default checked state, use [`choice_with_default`].
@@ -191,6 +418,95 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with the given text, and a value which is given back in the
+    /// [`ListItem`] if it is selected.
+    ///
+    /// This is useful to find out what was selected without relying on the text or the index of
+    /// the choice.
+    ///
+    /// See [`multi_select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`ListItem`]: crate::ListItem
+    /// [`multi_select`]: crate::question::Question::multi_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choice_with_value("Mozzarella", "mozzarella")
+    ///     .build();
+    /// ```
+    pub fn choice_with_value<I: Into<String>, V: Into<String>>(self, text: I, value: V) -> Self {
+        let mut this = self.choice(text);
+        let index = this.multi_select.choices.len() - 1;
+        this.multi_select.choices.set_value(index, value.into());
+        this
+    }
+
+    /// Inserts a [`Choice`] with the given text, and a description which is shown below the list
+    /// while the choice is hovered.
+    ///
+    /// The description is shown in a dimmed colour, wrapping if needed, and updates as the user
+    /// moves. It is shown instead of the [`hover_detail`](Self::hover_detail) of the choice.
+    ///
+    /// See [`multi_select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`multi_select`]: crate::question::Question::multi_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choice_with_description("Mozzarella", "Soft and mild")
+    ///     .build();
+    /// ```
+    pub fn choice_with_description<I: Into<String>, D: Into<String>>(
+        self,
+        text: I,
+        description: D,
+    ) -> Self {
+        let mut this = self.choice(text);
+        let index = this.multi_select.choices.len() - 1;
+        this.multi_select
+            .choices
+            .set_description(index, description.into());
+        this
+    }
+
+    /// Inserts a [`Disabled`] choice with the given text, which is shown with the reason it cannot be
+    /// picked.
+    ///
+    /// See [`multi_select`] for more information.
+    ///
+    /// [`Disabled`]: crate::question::Choice::Disabled
+    /// [`multi_select`]: crate::question::Question::multi_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choice("Mozzarella")
+    ///     .disabled_choice("Gorgonzola", "Out of stock")
+    ///     .build();
+    /// ```
+    pub fn disabled_choice<I: Into<String>, R: Into<String>>(mut self, text: I, reason: R) -> Self {
+        let reason = reason.into();
+        self.multi_select.choices.choices.push(Choice::Disabled {
+            text: shown_text(text.into(), Some(&reason)),
+            reason,
+        });
+        self.multi_select.selected.push(false);
+        self
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`multi_select`] for more information.
@@ -270,10 +586,67 @@ impl<'a> MultiSelectBuilder<'a> {
         T: Into<Choice<String>>,
         I: IntoIterator<Item = T>,
     {
+        self.multi_select.choices.choices.extend(
+            choices
+                .into_iter()
+                .map(|c| c.into().map_with_reason(shown_text)),
+        );
         self.multi_select
-            .choices
-            .choices
-            .extend(choices.into_iter().map(|c| c.into().map(Text::new)));
+            .selected
+            .resize(self.multi_select.choices.len(), false);
+        self
+    }
+
+    /// Inserts the choices of a [`DynamicList`], which are only fetched once they are shown.
+    ///
+    /// This is useful when there are too many choices to load all of them before asking, such as
+    /// the rows of a database. Every dynamic choice is not selected by default. Filtering and
+    /// [`columns`](Self::columns) are disabled for a list with dynamic choices, and each choice is
+    /// given a single line. Other choices can still be inserted before and after the dynamic ones,
+    /// but the indices in the [`ListItem`]s count all of the choices.
+    ///
+    /// Choices which are selected without being shown, for example by toggling all of them, are
+    /// fetched once the question is answered.
+    ///
+    /// See [`multi_select`] and [`DynamicList`] for more information.
+    ///
+    /// [`ListItem`]: crate::ListItem
+    /// [`multi_select`]: crate::question::Question::multi_select
+    ///
+    /// # Panics
+    ///
+    /// This will panic if a `DynamicList` was already inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Range;
+    ///
+    /// use requestty::{question::DynamicList, Question};
+    ///
+    /// struct Packages;
+    ///
+    /// impl DynamicList for Packages {
+    ///     fn len(&self) -> usize {
+    ///         10_000
+    ///     }
+    ///
+    ///     fn fetch(&mut self, range: Range<usize>) -> Vec<String> {
+    ///         range.map(|i| format!("package-{}", i)).collect()
+    ///     }
+    /// }
+    ///
+    /// let multi_select = Question::multi_select("packages")
+    ///     .dynamic_choices(Packages)
+    ///     .build();
+    /// ```
+    pub fn dynamic_choices<L: DynamicList + 'a>(mut self, list: L) -> Self {
+        assert!(
+            self.multi_select.dynamic.is_none(),
+            "only one `DynamicList` can be inserted"
+        );
+
+        self.multi_select.dynamic = Some(DynamicChoices::new(list, &mut self.multi_select.choices));
         self.multi_select
             .selected
             .resize(self.multi_select.choices.len(), false);
@@ -334,6 +707,16 @@ impl<'a> MultiSelectBuilder<'a> {
                         .push(Choice::DefaultSeparator);
                     self.multi_select.selected.push(false);
                 }
+                Choice::Disabled {
+                    text: (text, _),
+                    reason,
+                } => {
+                    self.multi_select.choices.choices.push(Choice::Disabled {
+                        text: shown_text(text, Some(&reason)),
+                        reason,
+                    });
+                    self.multi_select.selected.push(false);
+                }
             }
         }
         self
@@ -1,14 +1,18 @@
-use std::io;
+use std::{borrow::Cow, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyCode, KeyEvent},
+    events::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    layout::RenderRegion,
     style::Color,
     widgets::{self, Text},
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Filter, Transform, Validate};
+use super::{
+    describe_hovered, dynamic_list::DynamicChoices, filter_hint, write_answer, Choice, Filter,
+    Transform, Validate, ValidationMessage,
+};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::MultiSelectBuilder;
@@ -18,10 +22,13 @@ mod builder;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct MultiSelect<'a> {
     choices: super::ChoiceList<Text<String>>,
     selected: Vec<bool>,
+    /// The selected choices in the order they were selected, if the order is tracked
+    selection_order: Option<Vec<usize>>,
+    dynamic: Option<DynamicChoices<'a>>,
     filter: Filter<'a, Vec<bool>>,
     validate: Validate<'a, [bool]>,
     transform: Transform<'a, [ListItem]>,
@@ -29,53 +36,152 @@ pub(super) struct MultiSelect<'a> {
 
 fn set_seperators_false(selected: &mut [bool], choices: &[Choice<Text<String>>]) {
     for (i, choice) in choices.iter().enumerate() {
-        selected[i] &= !choice.is_separator();
+        selected[i] &= choice.is_choice();
     }
 }
 
+const HINT: &str = "Press <space> to select, <a> to toggle all, <i> to invert selection";
+const FILTER_HINT: &str = "Type to filter, press <space> to select";
+
 struct MultiSelectPrompt<'a, 'c> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<&'a str, Cow<'static, str>>,
     select: widgets::Select<MultiSelect<'c>>,
     answers: &'a Answers,
+    /// The choice a range selected with `Shift+Up` or `Shift+Down` starts from, which is the last
+    /// toggled choice.
+    anchor: Option<usize>,
+}
+
+impl MultiSelectPrompt<'_, '_> {
+    /// Gives every shown choice from the `anchor` to the hovered choice the same state as the
+    /// `anchor`.
+    fn select_range(&mut self, anchor: usize) {
+        let at = self.select.get_at();
+        let state = self.select.list.selected[anchor];
+        let (start, end) = if anchor <= at {
+            (anchor, at)
+        } else {
+            (at, anchor)
+        };
+
+        for index in start..=end {
+            if self.select.is_shown(index) && self.select.list.choices[index].is_choice() {
+                self.select.list.selected[index] = state;
+            }
+        }
+    }
+}
+
+/// Appends the choices which were selected since the `order` was last updated to it, in the order
+/// of the list, and removes the ones which are no longer selected.
+fn update_selection_order(
+    order: &mut Vec<usize>,
+    selected: &[bool],
+    choices: &[Choice<Text<String>>],
+) {
+    let is_selected = |i: usize| selected[i] && choices[i].is_choice();
+
+    let mut in_order = vec![false; selected.len()];
+    order.retain(|&i| is_selected(i));
+    for &i in order.iter() {
+        in_order[i] = true;
+    }
+
+    order.extend((0..selected.len()).filter(|&i| is_selected(i) && !in_order[i]));
 }
 
 fn create_list_items(
     selected: Vec<bool>,
-    choices: super::ChoiceList<Text<String>>,
+    mut choices: super::ChoiceList<Text<String>>,
 ) -> Vec<ListItem> {
+    let mut section = None;
+    let list = std::mem::take(&mut choices.choices);
+
     selected
         .into_iter()
         .enumerate()
-        .zip(choices.choices.into_iter())
+        .zip(list.into_iter())
         .filter_map(|((index, is_selected), text)| match (is_selected, text) {
             (true, Choice::Choice(text)) => Some(ListItem {
                 index,
                 text: text.text,
+                section: section.clone(),
+                value: choices.take_value(index),
             }),
-            _ => None,
+            (_, Choice::Separator(s)) => {
+                section = Some(s);
+                None
+            }
+            (_, Choice::DefaultSeparator) => {
+                section = None;
+                None
+            }
+            (false, Choice::Choice(_)) | (_, Choice::Disabled { .. }) => None,
         })
         .collect()
 }
 
 impl Prompt for MultiSelectPrompt<'_, '_> {
-    type ValidateErr = widgets::Text<String>;
+    type ValidateErr = ValidationMessage;
     type Output = Vec<ListItem>;
 
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
         if let Validate::Sync(ref mut validate) = self.select.list.validate {
             set_seperators_false(
                 &mut self.select.list.selected,
                 &self.select.list.choices.choices,
             );
-            validate(&self.select.list.selected, self.answers)?;
+            validate(&self.select.list.selected, self.answers).into_result()?;
         }
         Ok(Validation::Finish)
     }
 
+    fn describe(&mut self) -> Option<String> {
+        let list = &self.select.list;
+        let count = list
+            .selected
+            .iter()
+            .zip(list.choices.choices.iter())
+            .filter(|(&selected, choice)| selected && choice.is_choice())
+            .count();
+
+        let hovered = describe_hovered("Hovered", &self.select, |list, at| {
+            let state = if list.selected[at] {
+                "selected"
+            } else {
+                "not selected"
+            };
+            let text = list.choices[at]
+                .as_ref()
+                .unwrap_choice()
+                .text
+                .lines()
+                .next();
+            match list.choices.description(at) {
+                Some(description) => {
+                    format!("{} ({}), {}", text.unwrap_or_default(), description, state)
+                }
+                None => format!("{}, {}", text.unwrap_or_default(), state),
+            }
+        });
+
+        Some(format!("{}, {} selected", hovered, count))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
+
     fn finish(self) -> Self::Output {
         let MultiSelect {
             mut selected,
-            choices,
+            selection_order,
+            mut choices,
+            dynamic,
             filter,
             ..
         } = self.select.into_inner();
@@ -86,7 +192,28 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
             selected = filter(selected, self.answers);
         }
 
-        create_list_items(selected, choices)
+        // The filter may have changed which choices are selected
+        let selection_order = selection_order.map(|mut order| {
+            update_selection_order(&mut order, &selected, &choices.choices);
+            order
+        });
+
+        // Choices can be selected without being shown, for example by toggling all of them
+        if let Some(mut dynamic) = dynamic {
+            dynamic.load_selected(&selected, &mut choices);
+        }
+
+        let mut items = create_list_items(selected, choices);
+
+        if let Some(order) = selection_order {
+            let mut rank = vec![0; items.last().map_or(0, |item| item.index + 1)];
+            for (i, &index) in order.iter().enumerate() {
+                rank[index] = i;
+            }
+            items.sort_by_key(|item| rank[item.index]);
+        }
+
+        items
     }
 }
 
@@ -101,31 +228,100 @@ impl Widget for MultiSelectPrompt<'_, '_> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        // When filtering, `a` and `i` are typed into the filter instead
+        let filterable = self.select.list.choices.filterable();
+
         match key.code {
             KeyCode::Char(' ') => {
+                if !self.select.has_selectable() {
+                    return false;
+                }
+
                 let index = self.select.get_at();
                 self.select.list.selected[index] = !self.select.list.selected[index];
+                self.select.list.update_selection_order();
+                self.anchor = Some(index);
+                return true;
             }
-            KeyCode::Char('i') => {
+            KeyCode::Up | KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                if !self.select.has_selectable() {
+                    return false;
+                }
+
+                let anchor = match self.anchor {
+                    Some(anchor) => anchor,
+                    None => {
+                        let at = self.select.get_at();
+                        self.select.list.selected[at] = true;
+                        at
+                    }
+                };
+
+                self.select.handle_key(key);
+                self.select_range(anchor);
+                self.select.list.update_selection_order();
+                self.anchor = Some(anchor);
+                return true;
+            }
+            KeyCode::Char('i') if !filterable => {
                 self.select.list.selected.iter_mut().for_each(|s| *s = !*s);
+                self.select.list.update_selection_order();
             }
-            KeyCode::Char('a') => {
+            KeyCode::Char('a') if !filterable => {
                 let select_state = self.select.list.selected.iter().any(|s| !s);
                 self.select
                     .list
                     .selected
                     .iter_mut()
                     .for_each(|s| *s = select_state);
+                self.select.list.update_selection_order();
+            }
+            _ => {
+                let filter_len = self.select.filter().len();
+
+                if !self.select.handle_key(key) {
+                    return false;
+                }
+
+                if self.select.filter().len() != filter_len {
+                    self.prompt
+                        .set_hint(Some(filter_hint(self.select.filter(), FILTER_HINT)));
+                }
             }
-            _ => return self.select.handle_key(key),
         }
 
+        self.anchor = None;
         true
     }
 
+    /// Clicking on a choice hovers and toggles it.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        // The clicked index must be found before handling the event, since the page may move
+        let clicked = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.select.index_at_row(mouse.row),
+            _ => None,
+        };
+
+        let handled = self.select.handle_mouse(mouse);
+
+        match clicked {
+            Some(index) if index == self.select.get_at() => {
+                self.select.list.selected[index] = !self.select.list.selected[index];
+                self.select.list.update_selection_order();
+                self.anchor = Some(index);
+                true
+            }
+            _ => handled,
+        }
+    }
+
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
         self.select.cursor_pos(layout)
     }
+
+    fn focused_rows(&mut self, layout: ui::layout::Layout) -> std::ops::Range<u16> {
+        self.select.focused_rows(layout)
+    }
 }
 
 impl widgets::List for MultiSelect<'_> {
@@ -137,8 +333,9 @@ impl widgets::List for MultiSelect<'_> {
         b: &mut B,
     ) -> io::Result<()> {
         let symbol_set = ui::symbols::current();
+        let theme = ui::style::theme();
         if hovered {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(theme.highlight)?;
             write!(b, "{} ", symbol_set.pointer)?;
         } else {
             b.write_all(b"  ")?;
@@ -146,23 +343,32 @@ impl widgets::List for MultiSelect<'_> {
 
         if self.is_selectable(index) {
             if self.selected[index] {
-                b.set_fg(Color::LightGreen)?;
+                b.set_fg(theme.selected)?;
             } else {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(theme.hint)?;
             }
 
             write!(b, "{} ", symbol_set.completed)?;
 
             if hovered {
-                b.set_fg(Color::Cyan)?;
+                b.set_fg(theme.highlight)?;
             } else {
                 b.set_fg(Color::Reset)?;
             }
         } else {
-            b.set_fg(Color::DarkGrey)?;
+            b.set_fg(theme.hint)?;
+
+            if self.choices[index].is_disabled() {
+                // Disabled choices have no checkbox, but are lined up with the other choices
+                b.write_all(b"  ")?;
+            }
         }
 
         layout.offset_x += 4;
+        if self.is_dynamic(index) {
+            // Only the first line of a dynamic choice is shown, since it is given a single line
+            layout.render_region = RenderRegion::Top;
+        }
 
         self.choices[index].render(&mut layout, b)?;
 
@@ -170,10 +376,21 @@ impl widgets::List for MultiSelect<'_> {
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        !self.choices[index].is_separator()
+        self.choices[index].is_choice()
+    }
+
+    fn load_range(&mut self, indices: std::ops::Range<usize>) {
+        if let Some(ref mut dynamic) = self.dynamic {
+            dynamic.load(indices, &mut self.choices);
+        }
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
+        // The heights are measured before the dynamic choices are fetched
+        if self.is_dynamic(index) {
+            return 1;
+        }
+
         layout.offset_x += 4;
         self.choices[index].height(&mut layout)
     }
@@ -189,65 +406,153 @@ impl widgets::List for MultiSelect<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn jump_by_section(&self) -> bool {
+        self.choices.jump_by_section()
+    }
+
+    fn columns(&self) -> usize {
+        self.choices.columns()
+    }
+
+    fn filterable(&self) -> bool {
+        self.choices.filterable()
+    }
+
+    fn filter_text(&self, index: usize) -> Option<&str> {
+        self.choices.filter_text(index)
+    }
+
+    fn hover_detail(&self, index: usize) -> Option<&str> {
+        self.choices.hover_detail_text(index)
+    }
+
+    fn description(&self, index: usize) -> Option<&str> {
+        self.choices.description(index)
+    }
 }
 
 impl<'c> MultiSelect<'c> {
+    fn update_selection_order(&mut self) {
+        if let Some(ref mut order) = self.selection_order {
+            update_selection_order(order, &self.selected, &self.choices.choices);
+        }
+    }
+
+    fn is_dynamic(&self, index: usize) -> bool {
+        matches!(self.dynamic, Some(ref dynamic) if dynamic.contains(index))
+    }
+
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::ListItems(items) = answer {
+            self.selected
+                .iter_mut()
+                .for_each(|selected| *selected = false);
+
+            for item in items {
+                // The choices may have changed since the answer was given, and separators are
+                // unselected before asking
+                if let Some(selected) = self.selected.get_mut(item.index) {
+                    *selected = true;
+                }
+            }
+
+            // An answer with a tracked order is already in the order the choices were selected
+            if let Some(ref mut order) = self.selection_order {
+                let len = self.selected.len();
+                *order = items
+                    .iter()
+                    .map(|item| item.index)
+                    .filter(|&index| index < len)
+                    .collect();
+            }
+        }
+    }
+
     fn into_multi_select_prompt<'a>(
-        self,
+        mut self,
         message: &'a str,
         answers: &'a Answers,
     ) -> MultiSelectPrompt<'a, 'c> {
+        if self.dynamic.is_some() {
+            // Filtering and laying out the choices in columns need all of the choices
+            self.choices.set_filterable(false);
+            self.choices.set_columns(1);
+        }
+
+        // Choices which are selected by default are taken to be selected in the order of the list
+        self.update_selection_order();
+
+        let hint = if self.choices.filterable() {
+            FILTER_HINT
+        } else {
+            HINT
+        };
+
         MultiSelectPrompt {
-            prompt: widgets::Prompt::new(message)
-                .with_hint("Press <space> to select, <a> to toggle all, <i> to invert selection"),
+            prompt: widgets::Prompt::new(message).with_hint(Cow::Borrowed(hint)),
             select: widgets::Select::new(self),
             answers,
+            anchor: None,
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let mouse_capture = self.choices.mouse_capture();
+        let truncate_answer = self.choices.truncate_answer();
 
-        let ans = ui::Input::new(self.into_multi_select_prompt(&message, answers), b)
+        let input = ui::Input::new(self.into_multi_select_prompt(&message, answers), b)
             .hide_cursor()
-            .on_esc(on_esc)
-            .run(events)?;
+            .mouse_capture(mouse_capture);
+        let ans = driver.run(input).await?;
+
+        Self::write_finished(transform, message, ans, truncate_answer, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans
+            .as_list_items()
+            .expect("answer must be list items")
+            .to_vec();
+        let transform = self.transform.take();
+        let truncate_answer = self.choices.truncate_answer();
+
+        Self::write_finished(transform, message, Some(ans), truncate_answer, answers, b).map(|_| ())
+    }
 
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, [ListItem]>,
+        message: String,
+        ans: Option<Vec<ListItem>>,
+        truncate_answer: bool,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
-            b.set_fg(Color::Cyan)?;
-            print_comma_separated(
-                ans.iter().map(|item| {
+            let text = ans
+                .iter()
+                .map(|item| {
                     item.text
                         .lines()
                         .next()
                         .expect("There must be at least one line in a `str`")
-                }),
-                b,
-            )?;
-            b.set_fg(Color::Reset)?;
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            write_answer(&message, &text, truncate_answer, b)?;
         })
     }
 }
-
-fn print_comma_separated<'a, B: Backend>(
-    iter: impl Iterator<Item = &'a str>,
-    b: &mut B,
-) -> io::Result<()> {
-    let mut iter = iter.peekable();
-
-    while let Some(item) = iter.next() {
-        b.write_all(item.as_bytes())?;
-        if iter.peek().is_some() {
-            b.write_all(b", ")?;
-        }
-    }
-
-    Ok(())
-}
@@ -1,8 +1,10 @@
+use std::{cell::RefCell, ops::Range};
+
 use rand::prelude::*;
 use rand_chacha::ChaCha12Rng;
 use ui::{backend::TestBackend, layout::Layout};
 
-use crate::question::{Question, QuestionKind};
+use crate::question::{DynamicList, Question, QuestionKind};
 
 use super::*;
 
@@ -176,3 +178,266 @@ test_multi_select!(with_default {
         KeyCode::Char('a').into(),
     ]
 });
+
+#[test]
+fn test_mouse() {
+    fn click(row: u16) -> MouseEvent {
+        MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 0, row)
+    }
+
+    let size = (100, 20).into();
+    let mut backend = TestBackend::new(size);
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into()).choices(vec!["first", "second", "third"]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    assert!(multi_select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .is_ok());
+
+    // clicking a choice hovers and toggles it
+    assert!(multi_select.handle_mouse(click(2)));
+    assert_eq!(multi_select.select.get_at(), 1);
+    assert_eq!(multi_select.select.list.selected, [false, true, false]);
+
+    // clicking the hovered choice toggles it
+    assert!(multi_select.handle_mouse(click(2)));
+    assert_eq!(multi_select.select.list.selected, [false, false, false]);
+
+    // the message is not a choice
+    assert!(!multi_select.handle_mouse(click(0)));
+
+    assert!(multi_select.handle_mouse(MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)));
+    assert_eq!(multi_select.select.get_at(), 2);
+    assert_eq!(multi_select.select.list.selected, [false, false, false]);
+}
+
+#[test]
+fn test_filterable() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choices(vec!["apple", "banana", "cherry"])
+            .filterable(true),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    // `a` and `i` are typed into the filter
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert!(multi_select.handle_key(KeyCode::Char('n').into()));
+    assert_eq!(multi_select.select.filter(), "an");
+    assert_eq!(multi_select.select.get_at(), 1);
+    assert_eq!(multi_select.select.list.selected, [false, false, false]);
+
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(multi_select.select.list.selected, [false, true, false]);
+
+    assert!(multi_select.handle_key(KeyCode::Char('x').into()));
+    assert!(!multi_select.handle_key(KeyCode::Char(' ').into()));
+
+    assert!(multi_select.handle_key(KeyCode::Backspace.into()));
+    assert!(multi_select.handle_key(KeyCode::Backspace.into()));
+    assert!(multi_select.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(multi_select.select.get_at(), 0);
+
+    let items = multi_select.finish();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].index, 1);
+}
+
+#[test]
+fn test_select_range() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choices(vec!["0", "1", "2"])
+            .separator("-")
+            .choices(vec!["4", "5"]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    let shift = |code| KeyEvent::new(code, KeyModifiers::SHIFT);
+
+    // without a toggled choice, the range starts from the hovered choice
+    assert!(multi_select.handle_key(shift(KeyCode::Down)));
+    assert!(multi_select.handle_key(shift(KeyCode::Down)));
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, true, true, false, false, false]
+    );
+
+    // separators are skipped
+    assert!(multi_select.handle_key(shift(KeyCode::Down)));
+    assert_eq!(multi_select.select.get_at(), 4);
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, true, true, false, true, false]
+    );
+
+    // the range takes the state of the last toggled choice
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(shift(KeyCode::Up)));
+    assert!(multi_select.handle_key(shift(KeyCode::Up)));
+    assert_eq!(multi_select.select.get_at(), 2);
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, true, false, false, false, false]
+    );
+}
+
+#[test]
+fn test_track_selection_order() {
+    let size = (50, 20).into();
+    let mut layout = Layout::new(5, size);
+    let mut backend = TestBackend::new(size);
+    let answers = Answers::default();
+    let indices = |items: Vec<ListItem>| items.iter().map(|item| item.index).collect::<Vec<_>>();
+    let builder = || {
+        MultiSelectBuilder::new("name".into())
+            .choices(vec!["0", "1"])
+            .separator("-")
+            .choice_with_default("3", true)
+            .choices(vec!["4", "5"])
+            .track_selection_order(true)
+    };
+
+    let mut multi_select =
+        unwrap_multi_select(builder()).into_multi_select_prompt("message", &answers);
+    assert!(multi_select.render(&mut layout, &mut backend).is_ok());
+    let mut press = |code| assert!(multi_select.handle_key(KeyEvent::from(code)));
+
+    press(KeyCode::End);
+    press(KeyCode::Char(' '));
+    press(KeyCode::Home);
+    press(KeyCode::Char(' '));
+    press(KeyCode::Down);
+    press(KeyCode::Char(' '));
+    // unselecting and selecting again moves the choice to the end
+    press(KeyCode::End);
+    press(KeyCode::Char(' '));
+    press(KeyCode::Char(' '));
+    // the default is taken to be selected first
+    assert_eq!(indices(multi_select.finish()), [3, 0, 1, 5]);
+
+    // choices selected at once are in the order of the list
+    let mut multi_select =
+        unwrap_multi_select(builder()).into_multi_select_prompt("message", &answers);
+    assert!(multi_select.render(&mut layout, &mut backend).is_ok());
+    assert!(multi_select.handle_key(KeyCode::End.into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(indices(multi_select.finish()), [3, 5, 0, 1, 4]);
+
+    // the order of a previous answer is kept
+    let mut multi_select = unwrap_multi_select(builder());
+    multi_select.set_default_answer(&Answer::ListItems(vec![
+        ListItem {
+            index: 4,
+            text: "4".into(),
+            section: Some("-".into()),
+            value: None,
+        },
+        ListItem {
+            index: 0,
+            text: "0".into(),
+            section: None,
+            value: None,
+        },
+    ]));
+    let multi_select = multi_select.into_multi_select_prompt("message", &answers);
+    assert_eq!(indices(multi_select.finish()), [4, 0]);
+
+    // without tracking, the answer is in the order of the list
+    let mut multi_select = unwrap_multi_select(builder().track_selection_order(false))
+        .into_multi_select_prompt("message", &answers);
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(indices(multi_select.finish()), [0, 3]);
+}
+
+/// A list of numbered items which records the ranges that are fetched
+struct Items<'f>(&'f RefCell<Vec<Range<usize>>>);
+
+impl DynamicList for Items<'_> {
+    fn len(&self) -> usize {
+        1000
+    }
+
+    fn fetch(&mut self, range: Range<usize>) -> Vec<String> {
+        self.0.borrow_mut().push(range.clone());
+        range.map(|i| format!("Item {}", i)).collect()
+    }
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_dynamic_choices() {
+    let size = (50, 20).into();
+    let mut layout = Layout::new(5, size);
+    let mut backend = TestBackend::new(size);
+    let answers = Answers::default();
+    let fetched = RefCell::new(Vec::new());
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choice("First")
+            .dynamic_choices(Items(&fetched))
+            .choice("Last"),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    assert_eq!(multi_select.select.list.selected.len(), 1002);
+    assert!(multi_select.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(*fetched.borrow(), [0..13]);
+
+    // Choices which were selected without being shown are fetched for the answer
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    let items = multi_select.finish();
+    assert_eq!(*fetched.borrow(), [0..13, 13..1000]);
+    assert_eq!(items.len(), 1002);
+    assert_eq!(items[0].text, "First");
+    assert_eq!(items[500].text, "Item 499");
+    assert_eq!(items[1001].text, "Last");
+}
+
+#[test]
+fn test_disabled_choices() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choice("Mozzarella")
+            .disabled_choice("Gorgonzola", "Out of stock")
+            .choices_with_default(vec![
+                Choice::Disabled {
+                    text: ("Brie".into(), true),
+                    reason: "Out of stock".into(),
+                },
+                ("Cheddar", false).into(),
+            ]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    // Disabled choices cannot be selected, even if they are selected by default
+    assert_eq!(
+        multi_select.select.list.selected,
+        [false, false, false, false]
+    );
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(multi_select.select.get_at(), 3);
+
+    let mut layout = base_layout;
+    assert!(multi_select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+
+    let items = multi_select.finish();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].index, 0);
+    assert_eq!(items[1].index, 3);
+}
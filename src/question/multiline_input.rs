@@ -0,0 +1,469 @@
+use std::io;
+
+use ui::{backend::Backend, events::KeyEvent, style::Stylize, widgets, Validation, Widget};
+
+use super::{Filter, Options, Transform, Validate, ValidationMessage};
+use crate::{Answer, Answers};
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct MultilineInput<'a> {
+    default: Option<String>,
+    max_height: Option<u16>,
+    filter: Filter<'a, String>,
+    validate: Validate<'a, str>,
+    transform: Transform<'a, str>,
+}
+
+const HINT: &str = "Press <alt+enter> or <ctrl+d> to submit";
+
+struct MultilineInputPrompt<'a, 'm> {
+    prompt: widgets::Prompt<&'a str>,
+    multiline_input: MultilineInput<'m>,
+    input: widgets::MultilineTextInput,
+    answers: &'a Answers,
+}
+
+impl ui::Prompt for MultilineInputPrompt<'_, '_> {
+    type ValidateErr = ValidationMessage;
+    type Output = String;
+
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
+    fn is_submit_key(&self, key: KeyEvent) -> bool {
+        widgets::MultilineTextInput::is_submit_key(key)
+    }
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Validate::Sync(ref mut validate) = self.multiline_input.validate {
+            validate(&self.input.value(), self.answers).into_result()?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn describe(&mut self) -> Option<String> {
+        Some(format!("{}. Text:\n{}", HINT, self.input.value()))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
+
+    fn finish(self) -> Self::Output {
+        let mut ans = self.input.finish();
+
+        if let Filter::Sync(filter) = self.multiline_input.filter {
+            ans = filter(ans, self.answers)
+        }
+
+        ans
+    }
+}
+
+impl Widget for MultilineInputPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.prompt.height(layout) + self.input.height(layout) - 1
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input.handle_key(key)
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.input
+            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+    }
+}
+
+impl<'m> MultilineInput<'m> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::String(default) = answer {
+            self.default = Some(default.clone());
+        }
+    }
+
+    /// The initial text is the same as the default, since the default is already editable.
+    pub(super) fn set_initial_answer(&mut self, answer: &Answer) {
+        self.set_default_answer(answer);
+    }
+
+    fn into_prompt<'a>(
+        mut self,
+        message: &'a str,
+        answers: &'a Answers,
+    ) -> MultilineInputPrompt<'a, 'm> {
+        let mut input = widgets::MultilineTextInput::new();
+
+        if let Some(max_height) = self.max_height {
+            input = input.max_height(max_height);
+        }
+        if let Some(default) = self.default.take() {
+            input.set_value(default);
+        }
+
+        MultilineInputPrompt {
+            prompt: widgets::Prompt::new(message)
+                .with_hint(HINT)
+                .with_delim(widgets::Delimiter::None),
+            multiline_input: self,
+            input,
+            answers,
+        }
+    }
+
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
+        mut self,
+        message: String,
+        answers: &Answers,
+        b: &mut B,
+        driver: &mut D,
+        partial: &mut Option<Answer>,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let input = ui::Input::new(self.into_prompt(&message, answers), b);
+        let ans = driver.run(input).await.map_err(|recovered| {
+            *partial = Some(Answer::String(recovered.prompt.input.value()));
+            recovered.error
+        })?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans.as_string().expect("answer must be a string").to_owned();
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, str>,
+        message: String,
+        ans: Option<String>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
+        crate::write_final!(transform, message, ans [ref], answers, b, |_ans| b
+            .write_styled(&"Received".with(ui::style::theme().hint))?)
+    }
+}
+
+/// The builder for the [`multiline_input`] prompt.
+///
+/// Unlike [`editor`], the text is entered in the terminal. `Enter` inserts a new line, and the text
+/// is submitted with `Alt+Enter` or `Ctrl+D`.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let multiline_input = Question::multiline_input("address")
+///     .message("What is your address?")
+///     .max_height(5)
+///     .build();
+/// ```
+///
+/// [`multiline_input`]: crate::question::Question::multiline_input
+/// [`editor`]: crate::question::Question::editor
+#[derive(Debug)]
+pub struct MultilineInputBuilder<'a> {
+    opts: Options<'a>,
+    multiline_input: MultilineInput<'a>,
+}
+
+impl<'a> MultilineInputBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        MultilineInputBuilder {
+            opts: Options::new(name),
+            multiline_input: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .message("What is your address?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("deliver") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set the text that the input starts with, which can then be edited by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .default("221B Baker Street\nLondon")
+    ///     .build();
+    /// ```
+    pub fn default<I: Into<String>>(mut self, default: I) -> Self {
+        self.multiline_input.default = Some(default.into());
+        self
+    }
+
+    /// The maximum number of rows the text can take, after which it will scroll.
+    ///
+    /// The `max_height` must be a minimum of 1. If `max_height` is not set, it will default to 10.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `max_height` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .max_height(5)
+    ///     .build();
+    /// ```
+    pub fn max_height(mut self, max_height: u16) -> Self {
+        assert!(max_height >= 1, "max height can be a minimum of 1");
+
+        self.multiline_input.max_height = Some(max_height);
+        self
+    }
+
+    crate::impl_filter_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .filter(|address, previous_answers| address.trim().to_owned())
+    ///     .build();
+    /// ```
+    String; multiline_input
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .validate(|address, previous_answers| if address.lines().count() >= 2 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Please enter the full address".to_owned())
+    ///     })
+    ///     .build();
+    /// ```
+    str; multiline_input
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multiline_input = Question::multiline_input("address")
+    ///     .transform(|address, previous_answers, backend| {
+    ///         write!(backend, "{}", address.replace('\n', ", "))
+    ///     })
+    ///     .build();
+    /// ```
+    str; multiline_input
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(
+            self.opts,
+            super::QuestionKind::MultilineInput(self.multiline_input),
+        )
+    }
+}
+
+impl<'a> From<MultilineInputBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: MultilineInputBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let size = (100, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = MultilineInput::default().into_prompt("message", &answers);
+
+        let mut layout = base_layout;
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_line_offset(0).with_offset(0, 2));
+        ui::assert_backend_snapshot!(backend);
+
+        for c in "line 1".chars() {
+            assert!(prompt.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(prompt.handle_key(KeyCode::Enter.into()));
+        assert!(prompt.handle_key(KeyCode::Char('2').into()));
+
+        layout = base_layout;
+        backend.reset_with_layout(layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_line_offset(0).with_offset(0, 3));
+        ui::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_height() {
+        let size = (100, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = MultilineInput {
+            default: Some("a\nb\nc\nd".into()),
+            max_height: Some(3),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 4);
+        assert_eq!(layout, base_layout.with_line_offset(0).with_offset(0, 4));
+    }
+
+    #[test]
+    fn test_cursor_pos() {
+        let size = (100, 20).into();
+        let layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = MultilineInput {
+            default: Some("a\nbc".into()),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        assert_eq!(prompt.cursor_pos(layout), (2, 2));
+    }
+}
@@ -4,9 +4,13 @@ use super::{Float, Int};
 use crate::question::Options;
 
 macro_rules! builder {
-    ($(#[$meta:meta])* struct $builder_name:ident : $type:ident -> $inner_ty:ty, $litral:expr;
+    ($(#[$meta:meta])* struct $builder_name:ident : $type:ident -> $inner_ty:ty, $litral:expr, $zero:expr;
      declare = $declare:expr;
      default = $default:expr;
+     initial_value = $initial_value:expr;
+     min = $min:expr;
+     max = $max:expr;
+     step = $step:expr;
      filter = $filter:expr;
      validate = $validate:expr;
      validate_on_key = $validate_on_key:expr;
@@ -73,6 +77,57 @@ macro_rules! builder {
             ///     .on_esc(OnEsc::Terminate)
             ///     .build();
             /// ```
+
+            theme
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::{prompt::style::{Color, Theme}, Question};
+            ///
+            #[doc = $declare]
+            ///     .theme(Theme {
+            ///         highlight: Color::Magenta,
+            ///         ..Theme::default()
+            ///     })
+            ///     .build();
+            /// ```
+
+            before_ask
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .before_ask(|previous_answers| {
+            ///         println!("{} questions answered so far", previous_answers.len());
+            ///     })
+            ///     .build();
+            /// ```
+
+            after_ask
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .after_ask(|previous_answers, answer| {
+            ///         println!("Answered with {:?}", answer);
+            ///     })
+            ///     .build();
+            /// ```
+
+            hide_final
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .hide_final(true)
+            ///     .build();
+            /// ```
             }
 
             /// Set a default value
@@ -97,6 +152,121 @@ macro_rules! builder {
                 self
             }
 
+            /// Set the number that the input starts with, which can then be edited by the user.
+            ///
+            /// Unlike [`default`](Self::default), the number is already typed in when the prompt
+            /// is shown, with the cursor at its end. This is useful to edit an existing value.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            #[doc = $initial_value]
+            ///     .build();
+            /// ```
+            pub fn initial_value(mut self, initial_value: $inner_ty) -> Self {
+                self.inner.initial_value = Some(initial_value.to_string());
+                self
+            }
+
+            /// Text shown before the number, like a currency symbol.
+            ///
+            /// The prefix is rendered in the hint colour, cannot be edited by the user and is not
+            /// part of the answer.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .prefix("$ ")
+            ///     .build();
+            /// ```
+            pub fn prefix<I: Into<String>>(mut self, prefix: I) -> Self {
+                self.inner.affixes.set_prefix(prefix.into());
+                self
+            }
+
+            /// Text shown after the number, like a unit.
+            ///
+            /// The suffix is rendered in the hint colour, cannot be edited by the user and is not
+            /// part of the answer.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .suffix(" MB")
+            ///     .build();
+            /// ```
+            pub fn suffix<I: Into<String>>(mut self, suffix: I) -> Self {
+                self.inner.affixes.set_suffix(suffix.into());
+                self
+            }
+
+            /// Set the smallest number that can be entered.
+            ///
+            /// Pressing the arrow keys does not go below it, and any smaller number entered fails
+            /// validation. The range of allowed numbers is shown as a hint next to the message.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            #[doc = $min]
+            ///     .build();
+            /// ```
+            pub fn min(mut self, min: $inner_ty) -> Self {
+                self.inner.min = Some(min);
+                self
+            }
+
+            /// Set the largest number that can be entered.
+            ///
+            /// Pressing the arrow keys does not go above it, and any larger number entered fails
+            /// validation. The range of allowed numbers is shown as a hint next to the message.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            #[doc = $max]
+            ///     .build();
+            /// ```
+            pub fn max(mut self, max: $inner_ty) -> Self {
+                self.inner.max = Some(max);
+                self
+            }
+
+            /// Set how much the number changes when the up and down arrow keys are pressed.
+            ///
+            /// Page up and page down change the number by 10 steps. The step defaults to 1, and
+            /// must be positive.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            #[doc = $step]
+            ///     .build();
+            /// ```
+            pub fn step(mut self, step: $inner_ty) -> Self {
+                assert!(step > $zero, "step must be positive");
+                self.inner.step = Some(step);
+                self
+            }
+
             crate::impl_filter_builder! {
             /// # Examples
             ///
@@ -218,9 +388,13 @@ builder! {
 ///
 /// [`from_str`]: https://doc.rust-lang.org/std/primitive.i64.html#method.from_str
 /// [`int`]: crate::question::Question::int
-struct IntBuilder: Int -> i64, 10;
+struct IntBuilder: Int -> i64, 10, 0;
 declare  = r#"let int = Question::int("int")"#;
 default  = "    .default(10)";
+initial_value = "    .initial_value(42)";
+min      = "    .min(0)";
+max      = "    .max(100)";
+step     = "    .step(5)";
 filter   = "    .filter(|n, previous_answers| n + 10)";
 validate = "        if n.is_positive() {";
 validate_on_key = "     .validate_on_key(|n, previous_answers| n.is_positive())";
@@ -257,9 +431,13 @@ builder! {
 ///
 /// [`float`]: crate::question::Question::float
 /// [`from_str`]: https://doc.rust-lang.org/std/primitive.f64.html#method.from_str
-struct FloatBuilder: Float -> f64, 10.0;
+struct FloatBuilder: Float -> f64, 10.0, 0.0;
 declare  = r#"let float = Question::float("float")"#;
 default  = "    .default(10.0)";
+initial_value = "    .initial_value(4.2)";
+min      = "    .min(0.0)";
+max      = "    .max(1.0)";
+step     = "    .step(0.1)";
 filter   = "    .filter(|n, previous_answers| (n * 10000.0).round() / 10000.0)";
 validate = "        if n.is_sign_positive() {";
 validate_on_key = "     .validate_on_key(|n, previous_answers| n.is_sign_positive())";
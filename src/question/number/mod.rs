@@ -2,14 +2,14 @@ use std::{fmt::Write, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyCode, KeyEvent},
+    events::{KeyCode, KeyEvent},
     style::Color,
     widgets, Prompt, Validation, Widget,
 };
 
 use super::{
-    Filter, TransformByVal as Transform, ValidateByVal as Validate,
-    ValidateOnKeyByVal as ValidateOnKey,
+    describe_text, Affixes, Filter, KeyValidation, TransformByVal as Transform,
+    ValidateByVal as Validate, ValidateOnKeyByVal as ValidateOnKey, ValidationMessage,
 };
 use crate::{Answer, Answers};
 
@@ -22,33 +22,58 @@ mod builder;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct Float<'a> {
     default: Option<(f64, String)>,
+    initial_value: Option<String>,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: Option<f64>,
     filter: Filter<'a, f64>,
     validate: Validate<'a, f64>,
     validate_on_key: ValidateOnKey<'a, f64>,
     transform: Transform<'a, f64>,
+    affixes: Affixes,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct Int<'a> {
     default: Option<(i64, String)>,
+    initial_value: Option<String>,
+    min: Option<i64>,
+    max: Option<i64>,
+    step: Option<i64>,
     filter: Filter<'a, i64>,
     validate: Validate<'a, i64>,
     validate_on_key: ValidateOnKey<'a, i64>,
     transform: Transform<'a, i64>,
+    affixes: Affixes,
 }
 
 impl Int<'_> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::Int(default) = *answer {
+            self.default = Some((default, default.to_string()));
+        }
+    }
+
     fn write<B: Backend>(i: i64, b: &mut B) -> io::Result<()> {
-        b.set_fg(Color::Cyan)?;
+        b.set_fg(ui::style::theme().highlight)?;
         write!(b, "{}", i)?;
         b.set_fg(Color::Reset)
     }
 
-    fn delta(i: i64, delta: i64) -> i64 {
-        i.wrapping_add(delta)
+    fn delta(&self, i: i64, steps: i64) -> i64 {
+        let delta = self.step.unwrap_or(1).saturating_mul(steps);
+
+        match (self.min, self.max) {
+            // Without any bounds, going past the end wraps around to the other end
+            (None, None) => i.wrapping_add(delta),
+            (min, max) => {
+                let i = i.saturating_add(delta);
+                max.map_or(i, |max| i.min(max)).max(min.unwrap_or(i64::MIN))
+            }
+        }
     }
 
     fn filter_map(c: char) -> Option<char> {
@@ -61,8 +86,14 @@ impl Int<'_> {
 }
 
 impl Float<'_> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::Float(default) = *answer {
+            self.default = Some((default, default.to_string()));
+        }
+    }
+
     fn write<B: Backend>(f: f64, b: &mut B) -> io::Result<()> {
-        b.set_fg(Color::Cyan)?;
+        b.set_fg(ui::style::theme().highlight)?;
         if f.log10().abs() > 19.0 {
             write!(b, "{:e}", f)?;
         } else {
@@ -71,8 +102,10 @@ impl Float<'_> {
         b.set_fg(Color::Reset)
     }
 
-    fn delta(f: f64, delta: i64) -> f64 {
-        f + delta as f64
+    fn delta(&self, f: f64, steps: i64) -> f64 {
+        let f = f + self.step.unwrap_or(1.0) * steps as f64;
+        let f = self.max.map_or(f, |max| f.min(max));
+        self.min.map_or(f, |min| f.max(min))
     }
 
     fn filter_map(c: char) -> Option<char> {
@@ -90,7 +123,7 @@ macro_rules! impl_number_prompt {
             prompt: widgets::Prompt<&'a str, String>,
             number: $type<'n>,
             input: widgets::StringInput,
-            is_valid: bool,
+            key_validation: KeyValidation,
             answers: &'a Answers,
         }
 
@@ -102,6 +135,22 @@ macro_rules! impl_number_prompt {
                     .map_err(|e| e.to_string())
             }
 
+            /// The error shown if `n` is outside the range set by `min` and `max`.
+            fn range_error(&self, n: $inner_ty) -> Option<String> {
+                match (self.number.min, self.number.max) {
+                    (Some(min), Some(max)) if n < min || n > max => {
+                        Some(format!("Please enter a number between {} and {}", min, max))
+                    }
+                    (Some(min), None) if n < min => {
+                        Some(format!("Please enter a number of at least {}", min))
+                    }
+                    (None, Some(max)) if n > max => {
+                        Some(format!("Please enter a number of at most {}", max))
+                    }
+                    _ => None,
+                }
+            }
+
             fn get_remaining_default(&self) -> Option<&str> {
                 if let Some((_, ref default)) = self.number.default {
                     let input = self.input.value();
@@ -118,7 +167,7 @@ macro_rules! impl_number_prompt {
                     let default = &self.number.default.as_ref().unwrap().1;
                     self.input.set_value(default.clone());
                     self.input.set_at(default.len());
-                    self.is_valid = true;
+                    self.key_validation.set_valid();
 
                     true
                 } else {
@@ -126,11 +175,28 @@ macro_rules! impl_number_prompt {
                 }
             }
 
+            /// The height of the prompt without the message of the on key validation.
+            fn input_height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+                let mut height =
+                    self.prompt.height(layout) - 1 + self.number.affixes.skip_prefix(layout);
+
+                if self.get_remaining_default().is_some() {
+                    let width = self.number.default.as_ref().unwrap().1.len() as u16;
+                    height += self.number.affixes.skip_with_suffix(layout, width);
+                } else {
+                    height += self.input.height(layout) + self.number.affixes.skip_suffix(layout);
+                }
+
+                height
+            }
+
             fn validate_on_key(&mut self, n: $inner_ty) {
-                if let ValidateOnKey::Sync(ref mut validate) = self.number.validate_on_key {
-                    self.is_valid = validate(n, self.answers);
+                if self.range_error(n).is_some() {
+                    self.key_validation.set_invalid();
+                } else if let ValidateOnKey::Sync(ref mut validate) = self.number.validate_on_key {
+                    self.key_validation.set(validate(n, self.answers));
                 } else {
-                    self.is_valid = true;
+                    self.key_validation.set_valid();
                 }
             }
         }
@@ -143,60 +209,44 @@ macro_rules! impl_number_prompt {
             ) -> io::Result<()> {
                 let mut original_layout = *layout;
                 self.prompt.render(layout, b)?;
+                self.number.affixes.render_prefix(layout, b)?;
 
                 // if the current input does not satisfy the on key validation, then we show its wrong by
-                // using the red colour
-                if !self.is_valid {
-                    b.set_fg(ui::style::Color::Red)?;
+                // using the error colour
+                if !self.key_validation.is_valid() {
+                    b.set_fg(ui::style::theme().error)?;
                 }
                 self.input.render(layout, b)?;
-                if !self.is_valid {
+                if !self.key_validation.is_valid() {
                     b.set_fg(ui::style::Color::Reset)?;
                 }
 
                 if let Some(default) = self.get_remaining_default() {
-                    b.set_fg(ui::style::Color::DarkGrey)?;
+                    b.set_fg(ui::style::theme().hint)?;
                     write!(b, "{}", default)?;
                     b.set_fg(ui::style::Color::Reset)?;
+                    self.number.affixes.write_suffix(b)?;
                     // We need to update the layout to reflect the rest of the hint that is
                     // rendered. Instead of doing the math to compute where the cursor ends after
                     // rendering, we use the height function which already calculates it.
-                    self.height(&mut original_layout);
+                    self.input_height(&mut original_layout);
                     *layout = original_layout;
+                } else {
+                    self.number.affixes.render_suffix(layout, b)?;
                 }
 
-                Ok(())
+                self.key_validation.render(layout, b)
             }
 
             fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-                let mut height = self.prompt.height(layout) - 1;
-
-                if self.get_remaining_default().is_some() {
-                    let mut width = self.number.default.as_ref().unwrap().1.len() as u16;
-
-                    if width > layout.line_width() {
-                        width -= layout.line_width();
-
-                        layout.line_offset = width % layout.width;
-                        layout.offset_y += 1 + width / layout.width;
-
-                        height += 2 + width / layout.width;
-                    } else {
-                        layout.line_offset += width;
-                        height += 1;
-                    }
-                } else {
-                    height = self.input.height(layout);
-                }
-
-                height
+                self.input_height(layout) + self.key_validation.height(layout)
             }
 
             fn handle_key(&mut self, key: KeyEvent) -> bool {
                 if self.input.handle_key(key) {
                     match self.parse() {
                         Ok(n) => self.validate_on_key(n),
-                        Err(_) => self.is_valid = false,
+                        Err(_) => self.key_validation.set_invalid(),
                     }
 
                     return true;
@@ -205,10 +255,10 @@ macro_rules! impl_number_prompt {
                 }
 
                 let n = match (key.code, self.parse()) {
-                    (KeyCode::PageUp, Ok(n)) => $type::delta(n, 10),
-                    (KeyCode::PageDown, Ok(n)) => $type::delta(n, -10),
-                    (KeyCode::Up, Ok(n)) => $type::delta(n, 1),
-                    (KeyCode::Down, Ok(n)) => $type::delta(n, -1),
+                    (KeyCode::PageUp, Ok(n)) => self.number.delta(n, 10),
+                    (KeyCode::PageDown, Ok(n)) => self.number.delta(n, -10),
+                    (KeyCode::Up, Ok(n)) => self.number.delta(n, 1),
+                    (KeyCode::Down, Ok(n)) => self.number.delta(n, -1),
                     _ => return false,
                 };
 
@@ -224,28 +274,46 @@ macro_rules! impl_number_prompt {
             }
 
             fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-                self.input
-                    .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+                let mut layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+                self.number.affixes.skip_prefix(&mut layout);
+                self.input.cursor_pos(layout)
             }
         }
 
         impl Prompt for $prompt_name<'_, '_> {
-            type ValidateErr = widgets::Text<String>;
+            type ValidateErr = ValidationMessage;
             type Output = $inner_ty;
 
+            fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+                err.severity
+            }
+
             fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
                 if self.input.value().is_empty() && self.number.default.is_some() {
                     return Ok(Validation::Finish);
                 }
                 let n = self.parse()?;
 
+                if let Some(err) = self.range_error(n) {
+                    return Err(err.into());
+                }
+
                 if let Validate::Sync(ref mut validate) = self.number.validate {
-                    validate(n, self.answers)?;
+                    validate(n, self.answers).into_result()?;
                 }
 
                 Ok(Validation::Finish)
             }
 
+            fn describe(&mut self) -> Option<String> {
+                let default = self.number.default.as_ref().map(|(_, d)| d.as_str());
+                Some(describe_text(self.input.value(), default))
+            }
+
+            fn describe_error(&self, err: &Self::ValidateErr) -> String {
+                err.as_str().to_owned()
+            }
+
             fn finish(self) -> Self::Output {
                 let n = match self.number.default {
                     Some((default, _)) if self.input.value().is_empty() => default,
@@ -267,36 +335,86 @@ impl_number_prompt!(IntPrompt, Int, i64);
 impl_number_prompt!(FloatPrompt, Float, f64);
 
 macro_rules! impl_ask {
-    ($t:ident, $prompt_name:ident) => {
+    ($t:ident, $prompt_name:ident, $inner_ty:ty, $as_inner:ident) => {
         impl<'n> $t<'n> {
             fn into_prompt<'a>(
-                self,
+                mut self,
                 message: &'a str,
                 answers: &'a Answers,
             ) -> $prompt_name<'n, 'a> {
-                $prompt_name {
-                    prompt: widgets::Prompt::new(message),
+                let initial_value = self.initial_value.take();
+                let hint = match (self.min, self.max) {
+                    (Some(min), Some(max)) => Some(format!("{} to {}", min, max)),
+                    (Some(min), None) => Some(format!("at least {}", min)),
+                    (None, Some(max)) => Some(format!("at most {}", max)),
+                    (None, None) => None,
+                };
+
+                let mut prompt = $prompt_name {
+                    prompt: widgets::Prompt::new(message).with_optional_hint(hint),
                     input: widgets::StringInput::with_filter_map(Self::filter_map),
-                    is_valid: true,
+                    key_validation: KeyValidation::default(),
                     number: self,
                     answers,
+                };
+
+                if let Some(initial_value) = initial_value {
+                    let len = initial_value.len();
+                    prompt.input.set_value(initial_value);
+                    prompt.input.set_at(len);
+                    if let Ok(n) = prompt.parse() {
+                        prompt.validate_on_key(n);
+                    }
                 }
+
+                prompt
             }
 
-            pub(crate) fn ask<B: Backend, E: EventIterator>(
+            pub(super) fn set_initial_answer(&mut self, answer: &Answer) {
+                if let Answer::String(initial_value) = answer {
+                    self.initial_value = Some(initial_value.clone());
+                }
+            }
+
+            pub(crate) async fn ask<B: Backend, D: super::Driver>(
                 mut self,
                 message: String,
-                on_esc: ui::OnEsc,
                 answers: &Answers,
                 b: &mut B,
-                events: &mut E,
+                driver: &mut D,
+                partial: &mut Option<Answer>,
             ) -> ui::Result<Option<Answer>> {
                 let transform = self.transform.take();
 
-                let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-                    .on_esc(on_esc)
-                    .run(events)?;
+                let input = ui::Input::new(self.into_prompt(&message, answers), b);
+                let ans = driver.run(input).await.map_err(|recovered| {
+                    *partial = Some(Answer::String(recovered.prompt.input.value().to_owned()));
+                    recovered.error
+                })?;
+
+                Self::write_finished(transform, message, ans, answers, b)
+            }
+
+            pub(crate) fn render_finished<B: Backend>(
+                mut self,
+                message: String,
+                ans: &Answer,
+                answers: &Answers,
+                b: &mut B,
+            ) -> ui::Result<()> {
+                let ans = ans.$as_inner().expect("answer must be a number");
+                let transform = self.transform.take();
+
+                Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+            }
 
+            fn write_finished<B: Backend>(
+                transform: Transform<'_, $inner_ty>,
+                message: String,
+                ans: Option<$inner_ty>,
+                answers: &Answers,
+                b: &mut B,
+            ) -> ui::Result<Option<Answer>> {
                 crate::write_final!(transform, message, ans, answers, b, |ans| Self::write(
                     ans, b
                 )?)
@@ -305,5 +423,5 @@ macro_rules! impl_ask {
     };
 }
 
-impl_ask!(Int, IntPrompt);
-impl_ask!(Float, FloatPrompt);
+impl_ask!(Int, IntPrompt, i64, as_int);
+impl_ask!(Float, FloatPrompt, f64, as_float);
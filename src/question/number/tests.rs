@@ -92,9 +92,99 @@ macro_rules! test_numbers {
                     assert_eq!(prompt.cursor_pos(layout), (17, 1));
                 }
             }
+
+            #[test]
+            fn test_affixes() {
+                let size = (50, 20).into();
+                let base_layout = Layout::new(5, size);
+                let answers = Answers::default();
+
+                let defaults = [(None, 22), (Some($default), 25)];
+
+                for &(default, line_offset) in defaults.iter() {
+                    let mut number = $prompt_name {
+                        default: default.map(|n| (n, n.to_string())),
+                        ..Default::default()
+                    };
+                    number.affixes.set_prefix("$ ".into());
+                    number.affixes.set_suffix(" MB".into());
+                    let mut prompt = number.into_prompt("message", &answers);
+
+                    assert_eq!(prompt.cursor_pos(base_layout), (19, 0));
+
+                    let mut layout = base_layout;
+                    assert_eq!(prompt.height(&mut layout), 1);
+                    assert_eq!(layout, base_layout.with_line_offset(line_offset));
+
+                    prompt.input.set_value("3".repeat(50));
+                    prompt.input.set_at(50);
+                    assert_eq!(prompt.cursor_pos(base_layout), (19, 1));
+
+                    let mut layout = base_layout;
+                    assert_eq!(prompt.height(&mut layout), 2);
+                    assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(22));
+                }
+            }
         }
     };
 }
 
 test_numbers!(mod int { Int, 333 });
 test_numbers!(mod float { Float, 3.3 });
+
+#[test]
+fn test_int_range() {
+    let answers = Answers::default();
+    let mut prompt = Int {
+        min: Some(0),
+        max: Some(12),
+        step: Some(5),
+        initial_value: Some("10".into()),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+
+    assert_eq!(prompt.prompt.hint().map(String::as_str), Some("0 to 12"));
+
+    assert!(prompt.handle_key(KeyCode::Up.into()));
+    assert_eq!(prompt.input.value(), "12");
+    assert!(prompt.handle_key(KeyCode::Down.into()));
+    assert_eq!(prompt.input.value(), "7");
+    assert!(prompt.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(prompt.input.value(), "0");
+    assert!(prompt.key_validation.is_valid());
+    assert!(prompt.validate().is_ok());
+
+    prompt.input.set_value("20".into());
+    assert!(prompt.handle_key(KeyCode::Char('0').into()));
+    assert!(!prompt.key_validation.is_valid());
+    assert_eq!(
+        prompt.validate().unwrap_err().as_str(),
+        "Please enter a number between 0 and 12"
+    );
+}
+
+#[test]
+fn test_float_range() {
+    let answers = Answers::default();
+    let mut prompt = Float {
+        max: Some(1.0),
+        step: Some(0.5),
+        initial_value: Some("0.75".into()),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+
+    assert_eq!(prompt.prompt.hint().map(String::as_str), Some("at most 1"));
+
+    assert!(prompt.handle_key(KeyCode::Down.into()));
+    assert_eq!(prompt.input.value(), "0.25");
+    assert!(prompt.handle_key(KeyCode::PageUp.into()));
+    assert_eq!(prompt.input.value(), "1");
+
+    prompt.input.set_value("1.5".into());
+    assert_eq!(
+        prompt.validate().unwrap_err().as_str(),
+        "Please enter a number of at most 1"
+    );
+}
@@ -1,16 +1,27 @@
-use std::fmt;
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::Rc,
+    sync::atomic::{AtomicBool, AtomicU8, Ordering},
+};
 
-use ui::OnEsc;
+use ui::{style::Theme, OnEsc};
 
-use crate::Answers;
+use super::section::SectionStart;
+use crate::{Answer, Answers};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct Options<'a> {
     pub(crate) name: String,
     pub(crate) message: Option<Getter<'a, String>>,
     pub(crate) when: Getter<'a, bool>,
     pub(crate) ask_if_answered: bool,
     pub(crate) on_esc: Getter<'a, OnEsc>,
+    pub(crate) theme: Option<Theme>,
+    pub(crate) before_ask: Option<BeforeAsk<'a>>,
+    pub(crate) after_ask: Option<AfterAsk<'a>>,
+    pub(crate) hide_final: bool,
+    pub(crate) section_start: Option<SectionStart<'a>>,
 }
 
 impl<'a> Options<'a> {
@@ -20,11 +31,84 @@ impl<'a> Options<'a> {
             message: None,
             when: true.into(),
             ask_if_answered: false,
-            on_esc: OnEsc::Ignore.into(),
+            on_esc: default_on_esc().into(),
+            theme: None,
+            before_ask: None,
+            after_ask: None,
+            hide_final: false,
+            section_start: None,
         }
     }
 }
 
+static DEFAULT_ON_ESC: AtomicU8 = AtomicU8::new(2);
+
+/// What questions which do not set `on_esc` explicitly do when `Esc` is pressed.
+fn default_on_esc() -> OnEsc {
+    match DEFAULT_ON_ESC.load(Ordering::Relaxed) {
+        0 => OnEsc::Terminate,
+        1 => OnEsc::SkipQuestion,
+        _ => OnEsc::Ignore,
+    }
+}
+
+/// Set what questions which do not set `on_esc` explicitly do when `Esc` is pressed.
+pub(crate) fn set_default_on_esc(on_esc: OnEsc) {
+    let on_esc = match on_esc {
+        OnEsc::Terminate => 0,
+        OnEsc::SkipQuestion => 1,
+        OnEsc::Ignore => 2,
+    };
+    DEFAULT_ON_ESC.store(on_esc, Ordering::Relaxed);
+}
+
+static DEFAULT_HIDE_CURSOR: AtomicBool = AtomicBool::new(false);
+
+/// Whether all questions hide the cursor, instead of only the ones which do not take text input.
+pub(super) fn default_hide_cursor() -> bool {
+    DEFAULT_HIDE_CURSOR.load(Ordering::Relaxed)
+}
+
+/// Set whether all questions hide the cursor, instead of only the ones which do not take text
+/// input.
+pub(crate) fn set_default_hide_cursor(hide_cursor: bool) {
+    DEFAULT_HIDE_CURSOR.store(hide_cursor, Ordering::Relaxed);
+}
+
+/// A function called around prompting a question, for its side effects.
+pub(crate) struct Hook<F: ?Sized>(pub(crate) Rc<F>);
+
+pub(crate) type BeforeAsk<'a> = Hook<dyn Fn(&Answers) + 'a>;
+pub(crate) type AfterAsk<'a> = Hook<dyn Fn(&Answers, &Answer) + 'a>;
+
+impl<'a> BeforeAsk<'a> {
+    pub(crate) fn new<F: FnMut(&Answers) + 'a>(f: F) -> Self {
+        let f = RefCell::new(f);
+        Hook(Rc::new(move |answers: &Answers| (f.borrow_mut())(answers)))
+    }
+}
+
+impl<'a> AfterAsk<'a> {
+    pub(crate) fn new<F: FnMut(&Answers, &Answer) + 'a>(f: F) -> Self {
+        let f = RefCell::new(f);
+        Hook(Rc::new(move |answers: &Answers, answer: &Answer| {
+            (f.borrow_mut())(answers, answer)
+        }))
+    }
+}
+
+impl<F: ?Sized> Clone for Hook<F> {
+    fn clone(&self) -> Self {
+        Hook(Rc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Hook<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Hook(_)")
+    }
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! impl_options_builder {
@@ -33,10 +117,14 @@ macro_rules! impl_options_builder {
     (message $(#[$message_meta:meta])*
      when $(#[$when_meta:meta])*
      ask_if_answered $(#[$ask_if_answered_meta:meta])*
-     $(on_esc $(#[$on_esc_meta:meta])*)?) => {
+     $(on_esc $(#[$on_esc_meta:meta])*)?
+     theme $(#[$theme_meta:meta])*
+     before_ask $(#[$before_ask_meta:meta])*
+     after_ask $(#[$after_ask_meta:meta])*
+     hide_final $(#[$hide_final_meta:meta])*) => {
         /// The message to display when the prompt is rendered in the terminal.
         ///
-        /// It can be either a [`String`] or a [`FnOnce`] that returns a [`String`]. If it is a
+        /// It can be either a [`String`] or a [`FnMut`] that returns a [`String`]. If it is a
         /// function, it is passed all the previous [`Answers`], and will be called right before the
         /// question is prompted to the user.
         ///
@@ -56,7 +144,7 @@ macro_rules! impl_options_builder {
 
         /// Whether to ask the question (`true`) or not (`false`).
         ///
-        /// It can be either a [`bool`] or a [`FnOnce`] that returns a [`bool`]. If it is a
+        /// It can be either a [`bool`] or a [`FnMut`] that returns a [`bool`]. If it is a
         /// function, it is passed all the previous [`Answers`], and will be called right before the
         /// question is prompted to the user.
         ///
@@ -96,7 +184,7 @@ macro_rules! impl_options_builder {
         $(
         /// Configure what to do when the user presses the `Esc` key.
         ///
-        /// It can be either a [`OnEsc`] or a [`FnOnce`] that returns a [`OnEsc`]. If it is a
+        /// It can be either a [`OnEsc`] or a [`FnMut`] that returns a [`OnEsc`]. If it is a
         /// function, it is passed all the previous [`Answers`], and will be called right before the
         /// question is prompted to the user.
         ///
@@ -115,20 +203,107 @@ macro_rules! impl_options_builder {
             self
         }
         )?
+
+        /// The [`Theme`] used to render this question.
+        ///
+        /// It only applies to this question, and overrides the theme set with [`set_theme`] while
+        /// this question is prompted.
+        ///
+        /// If it is not given, the current theme is used.
+        ///
+        /// [`Theme`]: ui::style::Theme
+        /// [`set_theme`]: ui::style::set_theme
+        ///
+        $(#[$theme_meta])*
+        pub fn theme(mut self, theme: ui::style::Theme) -> Self {
+            self.opts.theme = Some(theme);
+            self
+        }
+
+        /// Function to call right before the question is prompted to the user.
+        ///
+        /// It is a [`FnMut`] that is given the previous [`Answers`]. It is only called if the
+        /// question is actually going to be asked, that is after [`when`] and [`ask_if_answered`]
+        /// have been checked. The [`message`] and other options given as functions are computed
+        /// after it, so they can use any data that it fetches.
+        ///
+        /// [`Answers`]: crate::Answers
+        /// [`when`]: Self::when
+        /// [`ask_if_answered`]: Self::ask_if_answered
+        /// [`message`]: Self::message
+        ///
+        ///
+        $(#[$before_ask_meta])*
+        pub fn before_ask<F>(mut self, before_ask: F) -> Self
+        where
+            F: FnMut(&$crate::Answers) + 'a,
+        {
+            self.opts.before_ask = Some($crate::question::options::BeforeAsk::new(before_ask));
+            self
+        }
+
+        /// Function to call once the user has answered the question.
+        ///
+        /// It is a [`FnMut`] that is given the previous [`Answers`] and the new [`Answer`], which
+        /// has not been added to the [`Answers`] yet. It is not called if the question is skipped.
+        ///
+        /// [`Answers`]: crate::Answers
+        /// [`Answer`]: crate::Answer
+        ///
+        ///
+        $(#[$after_ask_meta])*
+        pub fn after_ask<F>(mut self, after_ask: F) -> Self
+        where
+            F: FnMut(&$crate::Answers, &$crate::Answer) + 'a,
+        {
+            self.opts.after_ask = Some($crate::question::options::AfterAsk::new(after_ask));
+            self
+        }
+
+        /// Erase the line shown once the question is answered, instead of keeping it.
+        ///
+        /// By default, the message and the answer are left on the terminal after the question is
+        /// answered. If this is `true`, nothing is left behind, so that the terminal is clean for
+        /// whatever is printed next. Skipped questions are erased as well.
+        ///
+        /// To hide the answers of all the questions in a [`PromptModule`], use
+        /// [`PromptModule::quiet`].
+        ///
+        /// If this is not given, it defaults to `false`.
+        ///
+        /// [`PromptModule`]: crate::PromptModule
+        /// [`PromptModule::quiet`]: crate::PromptModule::quiet
+        ///
+        ///
+        $(#[$hide_final_meta])*
+        pub fn hide_final(mut self, hide_final: bool) -> Self {
+            self.opts.hide_final = hide_final;
+            self
+        }
     };
 }
 
 /// Optionally dynamically get a value.
 ///
-/// It can either be a [`FnOnce`] that results in a value, or the value itself.
+/// It can either be a [`FnMut`] that results in a value, or the value itself. The function is
+/// shared by the copies of a question, so that it is called again when the question is asked again.
 ///
 /// This should not need to be constructed manually, as it is used with the [`Into`] trait.
 #[allow(missing_docs)]
 pub enum Getter<'a, T> {
-    Function(Box<dyn FnOnce(&Answers) -> T + 'a>),
+    Function(Rc<dyn Fn(&Answers) -> T + 'a>),
     Value(T),
 }
 
+impl<T: Clone> Clone for Getter<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Getter::Function(f) => Getter::Function(Rc::clone(f)),
+            Getter::Value(v) => Getter::Value(v.clone()),
+        }
+    }
+}
+
 impl<T: fmt::Debug> fmt::Debug for Getter<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -138,11 +313,18 @@ impl<T: fmt::Debug> fmt::Debug for Getter<'_, T> {
     }
 }
 
-impl<T> Getter<'_, T> {
-    pub(crate) fn get(self, answers: &Answers) -> T {
+impl<'a, T> Getter<'a, T> {
+    fn from_fn<F: FnMut(&Answers) -> T + 'a>(f: F) -> Self {
+        let f = RefCell::new(f);
+        Getter::Function(Rc::new(move |answers: &Answers| (f.borrow_mut())(answers)))
+    }
+}
+
+impl<T: Clone> Getter<'_, T> {
+    pub(crate) fn get(&self, answers: &Answers) -> T {
         match self {
             Getter::Function(f) => f(answers),
-            Getter::Value(v) => v,
+            Getter::Value(v) => v.clone(),
         }
     }
 }
@@ -171,29 +353,29 @@ impl_getter_from_val!(String, char, c => c.to_string());
 
 impl<'a, F> From<F> for Getter<'a, String>
 where
-    F: FnOnce(&Answers) -> String + 'a,
+    F: FnMut(&Answers) -> String + 'a,
 {
     fn from(f: F) -> Self {
-        Getter::Function(Box::new(f))
+        Getter::from_fn(f)
     }
 }
 
 impl_getter_from_val!(bool, bool);
 impl<'a, F> From<F> for Getter<'a, bool>
 where
-    F: FnOnce(&Answers) -> bool + 'a,
+    F: FnMut(&Answers) -> bool + 'a,
 {
     fn from(f: F) -> Self {
-        Getter::Function(Box::new(f))
+        Getter::from_fn(f)
     }
 }
 
 impl_getter_from_val!(OnEsc, OnEsc);
 impl<'a, F> From<F> for Getter<'a, OnEsc>
 where
-    F: FnOnce(&Answers) -> OnEsc + 'a,
+    F: FnMut(&Answers) -> OnEsc + 'a,
 {
     fn from(f: F) -> Self {
-        Getter::Function(Box::new(f))
+        Getter::from_fn(f)
     }
 }
@@ -101,6 +101,65 @@ impl<'a> OrderSelectBuilder<'a> {
         ///     //...
         ///     .build();
         /// ```
+
+        theme
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::{prompt::style::{Color, Theme}, Question};
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .theme(Theme {
+        ///         highlight: Color::Magenta,
+        ///         ..Theme::default()
+        ///     })
+        ///     //...
+        ///     .build();
+        /// ```
+
+        before_ask
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .before_ask(|previous_answers| {
+        ///         println!("{} questions answered so far", previous_answers.len());
+        ///     })
+        ///     //...
+        ///     .build();
+        /// ```
+
+        after_ask
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .after_ask(|previous_answers, answer| {
+        ///         println!("Answered with {:?}", answer);
+        ///     })
+        ///     //...
+        ///     .build();
+        /// ```
+
+        hide_final
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .hide_final(true)
+        ///     //...
+        ///     .build();
+        /// ```
     }
 
     /// The maximum height that can be taken by the list
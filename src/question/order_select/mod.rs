@@ -1,8 +1,8 @@
-use std::io;
+use std::{borrow::Cow, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
+    events::{KeyCode, KeyEvent},
     style::Color,
     widgets::{self, Text},
     Prompt, Widget,
@@ -10,7 +10,9 @@ use ui::{
 
 use super::{
     choice::SelectList,
+    describe_hovered,
     handler::{Filter, Transform, Validate},
+    ValidationMessage,
 };
 use crate::{Answer, Answers};
 
@@ -19,11 +21,13 @@ pub(super) mod builder;
 #[cfg(test)]
 mod tests;
 
+const HINT: &str = "Press <space> to take and place an option";
+
 // =============================================================================
 //
 // =============================================================================
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(super) struct OrderSelect<'a> {
     choices: SelectList<OrderSelectItem>,
     max_index_width: usize,
@@ -60,13 +64,14 @@ impl widgets::List for OrderSelect<'_> {
         b: &mut B,
     ) -> std::io::Result<()> {
         let symbol_set = ui::symbols::current();
+        let theme = ui::style::theme();
 
         if hovered {
             if self.moving {
-                b.set_bg(Color::Cyan)?;
+                b.set_bg(theme.highlight)?;
                 b.set_fg(Color::Black)?;
             } else {
-                b.set_fg(Color::Cyan)?;
+                b.set_fg(theme.highlight)?;
             }
 
             write!(b, "{} ", symbol_set.pointer)?;
@@ -118,30 +123,59 @@ impl<'c> OrderSelect<'c> {
         answers: &'a Answers,
     ) -> OrderSelectPrompt<'a, 'c> {
         OrderSelectPrompt {
-            prompt: widgets::Prompt::new(message)
-                .with_hint("Press <space> to take and place an option"),
+            prompt: widgets::Prompt::new(message).with_hint(Cow::Borrowed(HINT)),
             select: widgets::Select::new(self),
+            position: String::new(),
             answers,
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_order_select_prompt(&message, answers), b)
-            .hide_cursor()
-            .on_esc(on_esc)
-            .run(events)?;
+        let input =
+            ui::Input::new(self.into_order_select_prompt(&message, answers), b).hide_cursor();
+        let ans = driver.run(input).await?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
 
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans
+            .as_list_items()
+            .expect("answer must be list items")
+            .iter()
+            .map(|item| OrderSelectItem {
+                initial_index: item.index,
+                text: Text::new(item.text.clone()),
+            })
+            .collect();
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, [OrderSelectItem]>,
+        message: String,
+        ans: Option<Vec<OrderSelectItem>>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(ui::style::theme().highlight)?;
             print_comma_separated(
                 ans.iter().map(|item| {
                     item.text()
@@ -174,15 +208,82 @@ fn print_comma_separated<'a, B: Backend>(
 // =============================================================================
 
 struct OrderSelectPrompt<'a, 'c> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<&'a str, Cow<'static, str>>,
     select: widgets::Select<OrderSelect<'c>>,
+    /// The position typed while moving a choice, which the choice has been moved to.
+    position: String,
     answers: &'a Answers,
 }
 
+impl OrderSelectPrompt<'_, '_> {
+    /// Moves the choice at `from` to `to`, shifting the choices in between.
+    fn move_choice(&mut self, from: usize, to: usize) {
+        let choices = &mut self.select.list.choices.choices;
+
+        if from < to {
+            choices[from..=to].rotate_left(1);
+        } else {
+            choices[to..=from].rotate_right(1);
+        }
+    }
+
+    /// Moves the choice being held to the position typed so far, followed by `digit`.
+    ///
+    /// If that position is past the end of the list, `digit` is used as the start of a new
+    /// position instead. If that is also not a valid position, the key is not handled.
+    fn type_position(&mut self, digit: char) -> bool {
+        let len = self.select.list.choices.len();
+        let parse = |position: &str| {
+            position
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n >= 1 && n <= len)
+        };
+
+        let mut position = format!("{}{}", self.position, digit);
+        if parse(&position).is_none() {
+            position = digit.to_string();
+        }
+
+        match parse(&position) {
+            Some(n) => {
+                self.position = position;
+                self.move_to(n - 1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the choice being held to `at`, and shows the typed position in the hint.
+    fn move_to(&mut self, at: usize) {
+        self.move_choice(self.select.get_at(), at);
+        self.select.set_at(at);
+        self.update_hint();
+    }
+
+    fn update_hint(&mut self) {
+        let hint = if self.position.is_empty() {
+            Cow::Borrowed(HINT)
+        } else {
+            Cow::Owned(format!(
+                "Press <space> to place the option at position {}",
+                self.position
+            ))
+        };
+
+        self.prompt.set_hint(Some(hint));
+    }
+}
+
 impl Prompt for OrderSelectPrompt<'_, '_> {
-    type ValidateErr = widgets::Text<String>;
+    type ValidateErr = ValidationMessage;
     type Output = Vec<OrderSelectItem>;
 
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
     fn finish(self) -> Self::Output {
         let OrderSelect {
             choices, filter, ..
@@ -199,10 +300,26 @@ impl Prompt for OrderSelectPrompt<'_, '_> {
 
     fn validate(&mut self) -> Result<ui::Validation, Self::ValidateErr> {
         if let Validate::Sync(ref mut validate) = self.select.list.validate {
-            validate(&self.select.list.choices.choices, self.answers)?;
+            validate(&self.select.list.choices.choices, self.answers).into_result()?;
         }
         Ok(ui::Validation::Finish)
     }
+
+    fn describe(&mut self) -> Option<String> {
+        let label = if self.select.list.moving {
+            "Moving"
+        } else {
+            "Hovered"
+        };
+
+        Some(describe_hovered(label, &self.select, |list, at| {
+            list.choices[at].text().to_owned()
+        }))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
 }
 
 impl Widget for OrderSelectPrompt<'_, '_> {
@@ -223,7 +340,33 @@ impl Widget for OrderSelectPrompt<'_, '_> {
         self.select.cursor_pos(layout)
     }
 
+    fn focused_rows(&mut self, layout: ui::layout::Layout) -> std::ops::Range<u16> {
+        self.select.focused_rows(layout)
+    }
+
     fn handle_key(&mut self, key: ui::events::KeyEvent) -> bool {
+        if self.select.list.moving {
+            match key.code {
+                KeyCode::Char(digit @ '0'..='9') => return self.type_position(digit),
+                KeyCode::Backspace if !self.position.is_empty() => {
+                    self.position.pop();
+                    // The typed position is always valid, and so is any prefix of it since it
+                    // cannot start with a 0.
+                    match self.position.parse::<usize>() {
+                        Ok(n) => self.move_to(n - 1),
+                        Err(_) => self.update_hint(),
+                    }
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        if !self.position.is_empty() {
+            self.position.clear();
+            self.update_hint();
+        }
+
         let prev_at = self.select.get_at();
 
         if let ui::events::KeyCode::Char(' ') = key.code {
@@ -231,12 +374,7 @@ impl Widget for OrderSelectPrompt<'_, '_> {
         } else if self.select.handle_key(key) {
             if self.select.list.moving {
                 let new_at = self.select.get_at();
-
-                if prev_at < new_at {
-                    self.select.list.choices.choices[prev_at..=new_at].rotate_left(1);
-                } else {
-                    self.select.list.choices.choices[new_at..=prev_at].rotate_right(1);
-                }
+                self.move_choice(prev_at, new_at);
             }
         } else {
             return false;
@@ -130,3 +130,51 @@ test_order_select!(pagination {
         );
     height = 17;
 });
+
+test_order_select!(type_position {
+    order_select = unwrap_order_select(
+            OrderSelectBuilder::new("name".into()).choices(choices(12)),
+        );
+    height = 14;
+    events = [
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyCode::Char('1').into(),
+        KeyCode::Char('1').into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('2').into(),
+        KeyCode::Char('5').into(),
+        KeyCode::Char(' ').into(),
+    ];
+});
+
+#[test]
+fn test_type_position() {
+    let answers = Answers::default();
+    let mut order_select =
+        unwrap_order_select(OrderSelectBuilder::new("name".into()).choices(choices(12)))
+            .into_order_select_prompt("message", &answers);
+
+    // positions can only be typed while moving a choice
+    assert!(!order_select.handle_key(KeyCode::Char('3').into()));
+    assert!(order_select.handle_key(KeyCode::Char(' ').into()));
+
+    assert!(!order_select.handle_key(KeyCode::Char('0').into()));
+    assert!(order_select.handle_key(KeyCode::Char('1').into()));
+    assert!(order_select.handle_key(KeyCode::Char('2').into()));
+    assert!(!order_select.handle_key(KeyCode::Char('0').into()));
+    assert_eq!(order_select.position, "12");
+    assert_eq!(order_select.select.get_at(), 11);
+
+    assert!(order_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(order_select.position.is_empty());
+
+    let order: Vec<_> = order_select
+        .finish()
+        .iter()
+        .map(|item| item.text().to_owned())
+        .collect();
+    assert_eq!(
+        order,
+        ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "0"]
+    );
+}
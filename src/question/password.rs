@@ -1,18 +1,16 @@
 use std::io;
 
-use ui::{
-    backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Stylize,
-    widgets, Validation, Widget,
-};
+use ui::{backend::Backend, events::KeyEvent, style::Stylize, widgets, Validation, Widget};
 
-use super::{Filter, Options, Transform, Validate, ValidateOnKey};
+use super::{
+    Filter, KeyValidation, Options, Transform, Validate, ValidateOnKey, ValidationMessage,
+};
 use crate::{Answer, Answers};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct Password<'a> {
     mask: Option<char>,
+    reveal_last: bool,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
@@ -23,22 +21,38 @@ struct PasswordPrompt<'a, 'p> {
     prompt: widgets::Prompt<&'a str>,
     password: Password<'p>,
     input: widgets::StringInput,
-    is_valid: bool,
+    key_validation: KeyValidation,
     answers: &'a Answers,
 }
 
 impl ui::Prompt for PasswordPrompt<'_, '_> {
-    type ValidateErr = widgets::Text<String>;
+    type ValidateErr = ValidationMessage;
     type Output = String;
 
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
         if let Validate::Sync(ref mut validate) = self.password.validate {
-            validate(self.input.value(), self.answers)?;
+            validate(self.input.value(), self.answers).into_result()?;
         }
 
         Ok(Validation::Finish)
     }
 
+    fn describe(&mut self) -> Option<String> {
+        // The password itself is never printed
+        Some(match self.input.value().chars().count() {
+            1 => "Password: 1 character".to_owned(),
+            n => format!("Password: {} characters", n),
+        })
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
+
     fn finish(self) -> Self::Output {
         let mut ans = self.input.finish();
 
@@ -55,20 +69,21 @@ impl Widget for PasswordPrompt<'_, '_> {
         self.prompt.render(layout, b)?;
 
         // if the current input does not satisfy the on key validation, then we show its wrong by
-        // using the red colour
-        if !self.is_valid {
-            b.set_fg(ui::style::Color::Red)?;
+        // using the error colour
+        if !self.key_validation.is_valid() {
+            b.set_fg(ui::style::theme().error)?;
         }
         self.input.render(layout, b)?;
-        if !self.is_valid {
+        if !self.key_validation.is_valid() {
             b.set_fg(ui::style::Color::Reset)?;
         }
 
-        Ok(())
+        self.key_validation.render(layout, b)
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
         self.prompt.height(layout) + self.input.height(layout) - 1
+            + self.key_validation.height(layout)
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
@@ -76,7 +91,8 @@ impl Widget for PasswordPrompt<'_, '_> {
 
         match self.password.validate_on_key {
             ValidateOnKey::Sync(ref mut validate) if handled => {
-                self.is_valid = validate(self.input.value(), self.answers);
+                self.key_validation
+                    .set(validate(self.input.value(), self.answers));
             }
             _ => {}
         }
@@ -100,34 +116,57 @@ impl<'p> Password<'p> {
                 } else {
                     None
                 }),
-            input: widgets::StringInput::default().password(self.mask),
-            is_valid: true,
+            input: widgets::StringInput::default()
+                .password(self.mask)
+                .reveal_last(self.reveal_last),
+            key_validation: KeyValidation::default(),
             password: self,
             answers,
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-            .on_esc(on_esc)
-            .run(events)?;
+        let input = ui::Input::new(self.into_prompt(&message, answers), b);
+        let ans = driver.run(input).await?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
 
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans.as_string().expect("answer must be a string").to_owned();
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, str>,
+        message: String,
+        ans: Option<String>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
         crate::write_final!(
             transform,
             message,
             ans [ref],
             answers,
             b,
-            |_ans| b.write_styled(&"[hidden]".dark_grey())?
+            |_ans| b.write_styled(&"[hidden]".with(ui::style::theme().hint))?
         )
     }
 }
@@ -223,6 +262,57 @@ impl<'a> PasswordBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let password = Question::password("password")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a mask to print instead of the characters
@@ -244,6 +334,26 @@ impl<'a> PasswordBuilder<'a> {
         self
     }
 
+    /// Show the most recently typed character until the next key press, before it is masked.
+    ///
+    /// This only has an effect if a [`mask`](Self::mask) is set. If `reveal_last` is not set, it
+    /// will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .mask('*')
+    ///     .reveal_last(true)
+    ///     .build();
+    /// ```
+    pub fn reveal_last(mut self, reveal_last: bool) -> Self {
+        self.password.reveal_last = reveal_last;
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -1,8 +1,8 @@
 use ui::{backend::Backend, widgets::Text};
 
-use super::RawSelect;
+use super::{RawSelect, RawSelectIndex};
 use crate::{
-    question::{Choice, Options},
+    question::{shown_text, Choice, Options},
     ListItem,
 };
 
@@ -49,8 +49,7 @@ impl<'a> RawSelectBuilder<'a> {
         RawSelectBuilder {
             opts: Options::new(name),
             raw_select: Default::default(),
-            // It is one indexed for the user
-            choice_count: 1,
+            choice_count: 0,
         }
     }
 
@@ -101,6 +100,57 @@ impl<'a> RawSelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default index for the select
@@ -138,6 +188,33 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// How the choices are labelled, which is what the user types to select them.
+    ///
+    /// If `index` is not set, it will default to [`RawSelectIndex::OneBased`].
+    ///
+    /// # Panics
+    ///
+    /// [`build`] will panic if there are fewer [`Custom`] labels than choices, or if any of the
+    /// labels are empty, contain whitespace or are repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::RawSelectIndex, Question};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .choices(vec!["Light", "Dark"])
+    ///     .index(RawSelectIndex::Alphabetic)
+    ///     .build();
+    /// ```
+    ///
+    /// [`build`]: RawSelectBuilder::build
+    /// [`Custom`]: RawSelectIndex::Custom
+    pub fn index(mut self, index: RawSelectIndex) -> Self {
+        self.raw_select.index = index;
+        self
+    }
+
     /// The maximum height that can be taken by the list
     ///
     /// If the total height exceeds the page size, the list will be scrollable.
@@ -182,6 +259,97 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// Whether `PageUp` and `PageDown` should jump to the previous and next section instead of by
+    /// a page.
+    ///
+    /// Sections are groups of choices delimited by [`Separator`]s. When this is enabled, `[` and
+    /// `]` can also be used to jump to the previous and next section.
+    ///
+    /// If `jump_by_section` is not set, it will default to `false`.
+    ///
+    /// [`Separator`]: crate::question::Choice::Separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .jump_by_section(true)
+    ///     .build();
+    /// ```
+    pub fn jump_by_section(mut self, jump_by_section: bool) -> Self {
+        self.raw_select.choices.set_jump_by_section(jump_by_section);
+        self
+    }
+
+    /// Whether typing should hover the first choice which starts with what was typed.
+    ///
+    /// See [`SelectBuilder::jump_to_prefix`] for more information. Characters which can be part
+    /// of an [index](Self::index) are typed into the index instead, so this is most useful with
+    /// the default numeric indices.
+    ///
+    /// If `jump_to_prefix` is not set, it will default to `false`.
+    ///
+    /// [`SelectBuilder::jump_to_prefix`]: crate::question::SelectBuilder::jump_to_prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("country")
+    ///     .choices(vec!["Argentina", "Brazil", "Canada", "Chile"])
+    ///     .jump_to_prefix(true)
+    ///     .build();
+    /// ```
+    pub fn jump_to_prefix(mut self, jump_to_prefix: bool) -> Self {
+        self.raw_select.choices.set_jump_to_prefix(jump_to_prefix);
+        self
+    }
+
+    /// Whether to capture the mouse while the prompt is active.
+    ///
+    /// When enabled, scrolling moves the cursor and clicking on a choice hovers it. Clicking on
+    /// the hovered choice selects it. While the mouse is captured, the terminal cannot be used to
+    /// select text.
+    ///
+    /// If `mouse_capture` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .mouse_capture(true)
+    ///     .build();
+    /// ```
+    pub fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.raw_select.choices.set_mouse_capture(mouse_capture);
+        self
+    }
+
+    /// Whether to truncate the answer on the finished line so that it fits in the terminal.
+    ///
+    /// When enabled, the answer is cut short with `...` instead of wrapping onto more lines.
+    ///
+    /// If `truncate_answer` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .truncate_answer(false)
+    ///     .build();
+    /// ```
+    pub fn truncate_answer(mut self, truncate_answer: bool) -> Self {
+        self.raw_select.choices.set_truncate_answer(truncate_answer);
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`raw_select`] for more information.
@@ -207,6 +375,62 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with the given text, and a value which is given back in the
+    /// [`ListItem`] if it is selected.
+    ///
+    /// This is useful to find out what was selected without relying on the text or the index of
+    /// the choice.
+    ///
+    /// See [`raw_select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`ListItem`]: crate::ListItem
+    /// [`raw_select`]: crate::question::Question::raw_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .choice_with_value("Order a Pizza", "order-pizza")
+    ///     .build();
+    /// ```
+    pub fn choice_with_value<I: Into<String>, V: Into<String>>(self, text: I, value: V) -> Self {
+        let mut this = self.choice(text);
+        let index = this.raw_select.choices.len() - 1;
+        this.raw_select.choices.set_value(index, value.into());
+        this
+    }
+
+    /// Inserts a [`Disabled`] choice with the given text, which is shown with the reason it cannot be
+    /// picked. It is not given a label, so it does not use up an index.
+    ///
+    /// See [`raw_select`] for more information.
+    ///
+    /// [`Disabled`]: crate::question::Choice::Disabled
+    /// [`raw_select`]: crate::question::Question::raw_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .choice("Order a Pizza")
+    ///     .disabled_choice("Make a reservation", "Fully booked tonight")
+    ///     .build();
+    /// ```
+    pub fn disabled_choice<I: Into<String>, R: Into<String>>(mut self, text: I, reason: R) -> Self {
+        let reason = reason.into();
+        // Disabled choices are not given a label, so the index is never used
+        self.raw_select.choices.choices.push(Choice::Disabled {
+            text: (self.choice_count, shown_text(text.into(), Some(&reason))),
+            reason,
+        });
+        self
+    }
+
     /// Inserts a [`Separator`] with the given text.
     ///
     /// See [`raw_select`] for more information.
@@ -288,9 +512,12 @@ impl<'a> RawSelectBuilder<'a> {
             .choices
             .choices
             .extend(choices.into_iter().map(|choice| {
-                choice.into().map(|c| {
-                    let choice = (*choice_count, Text::new(c));
-                    *choice_count += 1;
+                choice.into().map_with_reason(|c, reason| {
+                    let choice = (*choice_count, shown_text(c, reason));
+                    // Disabled choices are not given a label
+                    if reason.is_none() {
+                        *choice_count += 1;
+                    }
                     choice
                 })
             }));
@@ -316,19 +543,30 @@ impl<'a> RawSelectBuilder<'a> {
     ///
     /// [`Question`]: crate::question::Question
     pub fn build(mut self) -> crate::question::Question<'a> {
-        let num_choices = self
+        if let RawSelectIndex::Custom(ref labels) = self.raw_select.index {
+            assert!(
+                labels.len() >= self.choice_count,
+                "there must be a label for every choice"
+            );
+
+            for (i, label) in labels.iter().enumerate() {
+                assert!(
+                    !label.is_empty() && !label.contains(char::is_whitespace),
+                    "labels cannot be empty or contain whitespace"
+                );
+                assert!(!labels[..i].contains(label), "labels must be unique");
+            }
+        }
+
+        let index = &self.raw_select.index;
+        self.raw_select.labels = (0..self.choice_count).map(|n| index.label(n)).collect();
+        self.raw_select.max_index_width = self
             .raw_select
-            .choices
-            .choices
+            .labels
             .iter()
-            .rfind(|c| c.is_choice())
-            .and_then(|c| match c {
-                Choice::Choice((i, _)) => Some(*i),
-                _ => None,
-            })
-            .unwrap_or(0);
-
-        self.raw_select.max_index_width = (num_choices as f64).log10() as u16 + 1;
+            .map(|label| ui::layout::display_width(label))
+            .max()
+            .unwrap_or(1);
 
         crate::question::Question::new(
             self.opts,
@@ -2,13 +2,13 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::{Color, Stylize},
+    events::{KeyEvent, MouseEvent},
+    style::Color,
     widgets::{self, List, Text},
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Transform};
+use super::{describe_hovered, write_answer, Choice, Transform};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::RawSelectBuilder;
@@ -18,11 +18,77 @@ mod builder;
 #[cfg(test)]
 mod tests;
 
+/// How the choices of a [`raw_select`] are labelled, which is what the user types to select
+/// them.
+///
+/// [`raw_select`]: crate::question::Question::raw_select
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RawSelectIndex {
+    /// Numbers starting from 1. This is the default.
+    OneBased,
+    /// Numbers starting from 0.
+    ZeroBased,
+    /// Lowercase letters, which continue with `aa`, `ab` and so on after `z`. They can also be
+    /// typed in uppercase.
+    Alphabetic,
+    /// The given labels, in the order of the choices. They cannot contain whitespace.
+    Custom(Vec<String>),
+}
+
+impl Default for RawSelectIndex {
+    fn default() -> Self {
+        RawSelectIndex::OneBased
+    }
+}
+
+impl RawSelectIndex {
+    /// The label of the choice with the given 0-based position, ignoring separators.
+    fn label(&self, n: usize) -> String {
+        match self {
+            RawSelectIndex::OneBased => (n + 1).to_string(),
+            RawSelectIndex::ZeroBased => n.to_string(),
+            RawSelectIndex::Alphabetic => {
+                let mut label = Vec::new();
+                let mut n = n + 1;
+
+                while n > 0 {
+                    n -= 1;
+                    label.push(b'a' + (n % 26) as u8);
+                    n /= 26;
+                }
+
+                label.iter().rev().map(|&c| c as char).collect()
+            }
+            RawSelectIndex::Custom(labels) => labels[n].clone(),
+        }
+    }
+
+    fn filter_map(&self) -> widgets::FilterMapChar {
+        match self {
+            RawSelectIndex::OneBased | RawSelectIndex::ZeroBased => {
+                |c| if c.is_ascii_digit() { Some(c) } else { None }
+            }
+            RawSelectIndex::Alphabetic => |c| {
+                if c.is_ascii_alphabetic() {
+                    Some(c.to_ascii_lowercase())
+                } else {
+                    None
+                }
+            },
+            RawSelectIndex::Custom(_) => |c| if c.is_whitespace() { None } else { Some(c) },
+        }
+    }
+}
+
 // Kind of a bad name
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct RawSelect<'a> {
+    /// The choices along with their position, ignoring separators
     choices: super::ChoiceList<(usize, Text<String>)>,
     transform: Transform<'a, ListItem>,
+    index: RawSelectIndex,
+    /// The label of each choice, by its position
+    labels: Vec<String>,
     max_index_width: u16,
 }
 
@@ -33,18 +99,22 @@ struct RawSelectPrompt<'a> {
 }
 
 impl RawSelectPrompt<'_> {
+    fn set_input_to_hovered(&mut self) {
+        let at = self.select.get_at();
+        let index = self.select.list.choices[at].as_ref().unwrap_choice().0;
+        self.input.set_value(self.select.list.labels[index].clone());
+    }
+
     fn finish_index(self, index: usize) -> ListItem {
+        let mut choices = self.select.into_inner().choices;
+        let section = choices.section_at(index);
+        let value = choices.take_value(index);
+
         ListItem {
             index,
-            text: self
-                .select
-                .into_inner()
-                .choices
-                .choices
-                .swap_remove(index)
-                .unwrap_choice()
-                .1
-                .text,
+            text: choices.choices.swap_remove(index).unwrap_choice().1.text,
+            section,
+            value,
         }
     }
 }
@@ -54,13 +124,31 @@ impl Prompt for RawSelectPrompt<'_> {
     type Output = ListItem;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if self.select.get_at() >= self.select.list.len() {
+        if !self.select.has_selectable() {
+            Err("There are no choices to select")
+        } else if self.select.get_at() >= self.select.list.len() {
             Err("Please enter a valid choice")
         } else {
             Ok(Validation::Finish)
         }
     }
 
+    fn describe(&mut self) -> Option<String> {
+        Some(describe_hovered("Selected", &self.select, |list, at| {
+            let (index, text) = list.choices[at].as_ref().unwrap_choice();
+            format!("{}. {}", list.labels[*index], text.text)
+        }))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        (*err).to_owned()
+    }
+
+    /// Clicking on the hovered choice selects it.
+    fn is_submit_click(&self, mouse: MouseEvent) -> bool {
+        self.select.is_hovered_click(mouse)
+    }
+
     fn finish(self) -> Self::Output {
         let index = self.select.get_at();
         self.finish_index(index)
@@ -87,25 +175,28 @@ impl Widget for RawSelectPrompt<'_> {
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         if self.input.handle_key(key) {
-            if let Ok(n) = self.input.value().parse::<usize>() {
-                if n <= self.select.list.len() && n > 0 {
-                    let pos = self.select.list.choices.choices[(n - 1)..]
-                        .iter()
-                        .position(|choice| matches!(choice, Choice::Choice((i, _)) if *i == n));
-
-                    if let Some(pos) = pos {
-                        self.select.set_at(pos + n - 1);
-                        return true;
-                    }
-                }
+            let value = self.input.value();
+            let list = &self.select.list;
+            let pos = list.choices.choices.iter().position(
+                |choice| matches!(choice, Choice::Choice((i, _)) if list.labels[*i] == value),
+            );
+
+            match pos {
+                Some(pos) => self.select.set_at(pos),
+                None => self.select.set_at(self.select.list.len() + 1),
             }
-
-            self.select.set_at(self.select.list.len() + 1);
             true
         } else if self.select.handle_key(key) {
-            let at = self.select.get_at();
-            let index = self.select.list.choices[at].as_ref().unwrap_choice().0;
-            self.input.set_value(index.to_string());
+            self.set_input_to_hovered();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.select.handle_mouse(mouse) {
+            self.set_input_to_hovered();
             true
         } else {
             false
@@ -134,15 +225,16 @@ impl widgets::List for RawSelect<'_> {
         match &mut self.choices[index] {
             &mut Choice::Choice((index, ref mut text)) => {
                 if hovered {
-                    b.set_fg(Color::Cyan)?;
+                    b.set_fg(ui::style::theme().highlight)?;
                 }
 
-                write!(
-                    b,
-                    "  {:>width$}. ",
-                    index,
-                    width = self.max_index_width as usize
-                )?;
+                // The labels are padded by their width, since `{:>width$}` pads by the number of
+                // characters
+                let label = &self.labels[index];
+                let pad = self
+                    .max_index_width
+                    .saturating_sub(ui::layout::display_width(label));
+                write!(b, "  {:pad$}{}. ", "", label, pad = pad as usize)?;
 
                 layout.offset_x += self.max_index_width + 4;
                 text.render(&mut layout, b)?;
@@ -151,8 +243,26 @@ impl widgets::List for RawSelect<'_> {
                     b.set_fg(Color::Reset)?;
                 }
             }
+            Choice::Disabled {
+                text: (_, ref mut text),
+                ..
+            } => {
+                // Disabled choices are not given a label, but are lined up with the other choices
+                b.set_fg(ui::style::theme().hint)?;
+                write!(
+                    b,
+                    "{:width$}",
+                    "",
+                    width = self.max_index_width as usize + 4
+                )?;
+
+                layout.offset_x += self.max_index_width + 4;
+                text.render(&mut layout, b)?;
+
+                b.set_fg(Color::Reset)?;
+            }
             separator => {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(ui::style::theme().hint)?;
                 b.write_all(b"  ")?;
                 super::get_sep_str(separator).render(&mut layout.with_line_offset(2), b)?;
                 b.set_fg(Color::Reset)?;
@@ -163,12 +273,16 @@ impl widgets::List for RawSelect<'_> {
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        !self.choices[index].is_separator()
+        self.choices[index].is_choice()
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
         match self.choices[index] {
-            Choice::Choice((_, ref mut c)) => {
+            Choice::Choice((_, ref mut c))
+            | Choice::Disabled {
+                text: (_, ref mut c),
+                ..
+            } => {
                 layout.offset_x += self.max_index_width + 4;
                 c.height(&mut layout)
             }
@@ -187,9 +301,33 @@ impl widgets::List for RawSelect<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn jump_by_section(&self) -> bool {
+        self.choices.jump_by_section()
+    }
+
+    fn jump_to_prefix(&self) -> bool {
+        self.choices.jump_to_prefix()
+    }
+
+    fn filter_text(&self, index: usize) -> Option<&str> {
+        match self.choices[index] {
+            Choice::Choice((_, ref text)) => Some(&text.text),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> RawSelect<'a> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::ListItem(item) = answer {
+            // The choices may have changed since the answer was given
+            if item.index < self.choices.len() && self.choices[item.index].is_choice() {
+                self.choices.set_default(item.index);
+            }
+        }
+    }
+
     fn into_prompt(self, message: &'a str) -> RawSelectPrompt<'a> {
         let mut select = widgets::Select::new(self);
 
@@ -198,38 +336,61 @@ impl<'a> RawSelect<'a> {
         }
 
         RawSelectPrompt {
-            input: widgets::StringInput::with_filter_map(|c| {
-                if c.is_ascii_digit() {
-                    Some(c)
-                } else {
-                    None
-                }
-            }),
+            input: widgets::StringInput::with_filter_map(select.list.index.filter_map()),
             select,
             prompt: widgets::Prompt::new(message),
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let mouse_capture = self.choices.mouse_capture();
+        let truncate_answer = self.choices.truncate_answer();
 
-        let ans = ui::Input::new(self.into_prompt(&message), b)
-            .on_esc(on_esc)
-            .run(events)?;
+        let input = ui::Input::new(self.into_prompt(&message), b).mouse_capture(mouse_capture);
+        let ans = driver.run(input).await?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
+        Self::write_finished(transform, message, ans, truncate_answer, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans
+            .as_list_item()
+            .expect("answer must be a list item")
+            .clone();
+        let transform = self.transform.take();
+        let truncate_answer = self.choices.truncate_answer();
+
+        Self::write_finished(transform, message, Some(ans), truncate_answer, answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, ListItem>,
+        message: String,
+        ans: Option<ListItem>,
+        truncate_answer: bool,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            let text = ans
+                .text
                 .lines()
                 .next()
-                .expect("There must be at least one line in a `str`")
-                .cyan()
-        )?)
+                .expect("There must be at least one line in a `str`");
+            write_answer(&message, text, truncate_answer, b)?;
+        })
     }
 }
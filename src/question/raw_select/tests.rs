@@ -126,3 +126,101 @@ fn test_cursor_pos() {
         assert_eq!(select.cursor_pos(layout), (line_offset, 12));
     }
 }
+
+#[test]
+fn test_index() {
+    assert_eq!(RawSelectIndex::Alphabetic.label(0), "a");
+    assert_eq!(RawSelectIndex::Alphabetic.label(25), "z");
+    assert_eq!(RawSelectIndex::Alphabetic.label(26), "aa");
+    assert_eq!(RawSelectIndex::Alphabetic.label(26 * 27), "aaa");
+    assert_eq!(RawSelectIndex::ZeroBased.label(3), "3");
+
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let indices = vec![
+        (RawSelectIndex::ZeroBased, "5", 8),
+        (RawSelectIndex::Alphabetic, "E", 7),
+        (
+            RawSelectIndex::Custom((0..7).map(|i| format!("c{}", i)).collect()),
+            "c4",
+            7,
+        ),
+        // Wide labels are lined up by their width
+        (
+            RawSelectIndex::Custom(
+                ["一", "b", "二三", "d", "四", "f", "g"]
+                    .iter()
+                    .map(|&l| l.to_owned())
+                    .collect(),
+            ),
+            "二三",
+            4,
+        ),
+    ];
+
+    for (index, typed, at) in indices {
+        let mut raw_select = unwrap_select(
+            RawSelectBuilder::new("name".into())
+                .choices(choices(10))
+                .index(index),
+        )
+        .into_prompt("message");
+
+        for c in typed.chars() {
+            assert!(raw_select.handle_key(KeyCode::Char(c).into()));
+        }
+        assert_eq!(raw_select.select.get_at(), at);
+
+        backend.reset_with_layout(base_layout);
+        assert!(raw_select
+            .render(&mut base_layout.clone(), &mut backend)
+            .is_ok());
+        ui::assert_backend_snapshot!(backend);
+    }
+}
+
+#[test]
+#[should_panic(expected = "there must be a label for every choice")]
+fn test_too_few_labels() {
+    RawSelectBuilder::new("name".into())
+        .choices(vec!["a", "b"])
+        .index(RawSelectIndex::Custom(vec!["x".into()]))
+        .build();
+}
+
+#[test]
+fn test_disabled_choices() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let mut raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .choice("Pizza")
+            .disabled_choice("Sushi", "Closed today")
+            .choices(vec![
+                Choice::Disabled {
+                    text: "Tacos".into(),
+                    reason: "Sold out".into(),
+                },
+                "Burgers".into(),
+            ]),
+    )
+    .into_prompt("message");
+
+    // Disabled choices are not given a label
+    assert_eq!(raw_select.select.list.labels, ["1", "2"]);
+    assert!(raw_select.handle_key(KeyCode::Char('2').into()));
+    assert_eq!(raw_select.select.get_at(), 3);
+
+    assert!(raw_select
+        .render(&mut base_layout.clone(), &mut backend)
+        .is_ok());
+    ui::assert_backend_snapshot!(backend);
+
+    let item = raw_select.finish();
+    assert_eq!(item.index, 3);
+    assert_eq!(item.text, "Burgers");
+}
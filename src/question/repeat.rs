@@ -0,0 +1,147 @@
+use ui::{backend::Backend, events::EventIterator, style::Stylize};
+
+use super::{Asked, Prompt, Question};
+use crate::{Answer, Answers};
+
+/// A group of questions which is asked again until the user is done, which is added to a
+/// [`PromptModule`] with [`PromptModule::repeat`].
+///
+/// The header is shown before the questions are asked for the first time. Every time all the
+/// questions have been asked, `should_continue` is given their answers, and decides whether they
+/// are asked once more. The answers of each time are collected in an [`Answer::List`], which is
+/// stored under the name of the group.
+///
+/// The questions are created again by `questions` each time they are asked. Like in a
+/// [`Section`], they are named under the name of the group while they are asked, so a question
+/// named `version` after a question named `name` in a group named `dependencies` can refer to the
+/// earlier answer as `dependencies.name`. Only the answers given in the same repetition can be
+/// referred to this way. The answers in the [`Answer::List`] and the ones given to
+/// `should_continue` use the names of the questions.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{PromptModule, Question, Repeat};
+///
+/// let module = PromptModule::new(vec![Question::input("name").build()]).repeat(Repeat::new(
+///     "dependencies",
+///     "Dependencies",
+///     || {
+///         vec![
+///             Question::input("name").build(),
+///             Question::input("version").build(),
+///             Question::confirm("another")
+///                 .message("Add another dependency?")
+///                 .build(),
+///         ]
+///     },
+///     |answers| answers["another"].as_bool().unwrap_or(false),
+/// ));
+/// ```
+///
+/// [`Section`]: crate::Section
+/// [`PromptModule`]: crate::PromptModule
+/// [`PromptModule::repeat`]: crate::PromptModule::repeat
+/// [`Answer::List`]: crate::Answer::List
+#[derive(Debug)]
+pub struct Repeat<'a> {
+    header: String,
+    prompt: RepeatPrompt<'a>,
+}
+
+impl<'a> Repeat<'a> {
+    /// Creates a new `Repeat` with the given name, header, function creating the questions and
+    /// function deciding whether to ask them again.
+    pub fn new<N, H, F, I, C>(name: N, header: H, questions: F, should_continue: C) -> Self
+    where
+        N: Into<String>,
+        H: Into<String>,
+        F: Fn() -> I + 'a,
+        I: IntoIterator<Item = Question<'a>>,
+        C: FnMut(&Answers) -> bool + 'a,
+    {
+        Self {
+            header: header.into(),
+            prompt: RepeatPrompt {
+                name: name.into(),
+                questions: Box::new(move || questions().into_iter().collect()),
+                should_continue: Box::new(should_continue),
+            },
+        }
+    }
+
+    /// The single question which asks the whole group, using the header as its message.
+    pub(crate) fn into_question(self) -> Question<'a> {
+        Question::custom(self.prompt.name.clone(), self.prompt)
+            .message(self.header)
+            .build()
+    }
+}
+
+struct RepeatPrompt<'a> {
+    name: String,
+    questions: Box<dyn Fn() -> Vec<Question<'a>> + 'a>,
+    should_continue: Box<dyn FnMut(&Answers) -> bool + 'a>,
+}
+
+impl std::fmt::Debug for RepeatPrompt<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepeatPrompt")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Prompt for RepeatPrompt<'_> {
+    fn ask(
+        mut self,
+        message: String,
+        answers: &Answers,
+        mut backend: &mut dyn Backend,
+        mut events: &mut dyn EventIterator,
+    ) -> ui::Result<Option<Answer>> {
+        backend.write_styled(&message.as_str().bold().underlined())?;
+        backend.write_all(b"\n")?;
+        backend.flush()?;
+
+        let mut list = Vec::new();
+
+        loop {
+            let mut all_answers = answers.clone();
+            let mut group = Answers::default();
+
+            for mut question in (self.questions)() {
+                let name = std::mem::take(&mut question.opts.name);
+                question.opts.name = format!("{}.{}", self.name, name);
+
+                if let Asked::Answered(full_name, answer) =
+                    question.ask(&all_answers, &mut backend, &mut events, None)?
+                {
+                    all_answers.insert(full_name, answer.clone());
+                    group.insert(name, answer);
+                }
+            }
+
+            let more = (self.should_continue)(&group);
+            list.push(group);
+
+            if !more {
+                return Ok(Some(Answer::List(list)));
+            }
+        }
+    }
+
+    /// Writes the number of times the questions were answered.
+    fn write_answer(
+        &self,
+        answer: &Answer,
+        _: &Answers,
+        backend: &mut dyn Backend,
+    ) -> ui::Result<()> {
+        if let Answer::List(list) = answer {
+            backend.write_styled(&list.len().with(ui::style::theme().highlight))?;
+        }
+
+        Ok(())
+    }
+}
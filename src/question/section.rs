@@ -0,0 +1,126 @@
+use std::{cell::Cell, rc::Rc};
+
+use ui::{backend::Backend, style::Stylize};
+
+use super::{options::Getter, Question};
+use crate::Answers;
+
+/// A group of questions shown under a header, which is added to a [`PromptModule`] with
+/// [`PromptModule::section`].
+///
+/// The answers to the questions are stored under the name of the section, so a question named
+/// `host` in a section named `network` is answered as `network.host`. This is also the name that
+/// must be used to refer to the answer in later questions, or in [`PromptModule::with_defaults`].
+/// The answers of a section can be grouped into an [`Answer::Map`] with [`Answers::into_nested`].
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{PromptModule, Question, Section};
+///
+/// let module = PromptModule::new(vec![Question::confirm("proxy").build()]).section(
+///     Section::new(
+///         "network",
+///         "Network settings",
+///         vec![
+///             Question::input("host").build(),
+///             Question::int("port").build(),
+///         ],
+///     )
+///     .when(|answers: &requestty::Answers| answers["proxy"].as_bool().unwrap()),
+/// );
+/// ```
+///
+/// [`PromptModule`]: crate::PromptModule
+/// [`PromptModule::section`]: crate::PromptModule::section
+/// [`PromptModule::with_defaults`]: crate::PromptModule::with_defaults
+/// [`Answer::Map`]: crate::Answer::Map
+#[derive(Debug)]
+pub struct Section<'a> {
+    name: String,
+    header: String,
+    questions: Vec<Question<'a>>,
+    when: Getter<'a, bool>,
+}
+
+impl<'a> Section<'a> {
+    /// Creates a new `Section` with the given name, header and questions.
+    pub fn new<N, H, I>(name: N, header: H, questions: I) -> Self
+    where
+        N: Into<String>,
+        H: Into<String>,
+        I: IntoIterator<Item = Question<'a>>,
+    {
+        Self {
+            name: name.into(),
+            header: header.into(),
+            questions: questions.into_iter().collect(),
+            when: true.into(),
+        }
+    }
+
+    /// Whether to ask the questions of the section (`true`) or not (`false`).
+    ///
+    /// It can be either a [`bool`] or a [`FnOnce`] that returns a [`bool`]. If it is a function, it
+    /// is passed all the previous [`Answers`], and will be called right before the first question
+    /// of the section is prompted. If it is `false`, the header is not shown and all the questions
+    /// are skipped, regardless of their own `when`.
+    ///
+    /// If it is not given, it defaults to `true`.
+    pub fn when<W>(mut self, when: W) -> Self
+    where
+        W: Into<Getter<'a, bool>>,
+    {
+        self.when = when.into();
+        self
+    }
+
+    /// The questions of the section, named under the section, with the first one starting it.
+    pub(crate) fn into_questions(self) -> std::vec::IntoIter<Question<'a>> {
+        let active = Rc::new(Cell::new(false));
+        let mut start = Some(SectionStart {
+            header: self.header,
+            when: self.when,
+            active: active.clone(),
+        });
+
+        let mut questions = self.questions;
+
+        for question in &mut questions {
+            let opts = &mut question.opts;
+            opts.name = format!("{}.{}", self.name, opts.name);
+            opts.section_start = start.take();
+
+            let when = std::mem::replace(&mut opts.when, true.into());
+            let active = active.clone();
+            opts.when = Getter::from(move |answers: &Answers| active.get() && when.get(answers));
+        }
+
+        questions.into_iter()
+    }
+}
+
+/// Carried by the first question of a section to show the header before it is asked.
+#[derive(Debug, Clone)]
+pub(crate) struct SectionStart<'a> {
+    header: String,
+    when: Getter<'a, bool>,
+    /// Whether the questions of the section should be asked, shared by all of them
+    active: Rc<Cell<bool>>,
+}
+
+impl SectionStart<'_> {
+    /// Decides whether the section is asked, and writes the header if it is.
+    pub(crate) fn begin<B: Backend>(self, answers: &Answers, b: &mut B) -> ui::Result<()> {
+        let active = self.when.get(answers);
+        self.active.set(active);
+
+        if active {
+            b.write_styled(&self.header.as_str().bold().underlined())?;
+            b.write_all(b"\n")?;
+            b.flush()?;
+        }
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use ui::{backend::Backend, widgets::Text};
 
 use super::Select;
 use crate::{
-    question::{Choice, Options},
+    question::{dynamic_list::DynamicChoices, shown_text, Choice, DynamicList, Options},
     ListItem,
 };
 
@@ -98,6 +98,57 @@ impl<'a> SelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let select = Question::select("theme")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default index for the select
@@ -179,6 +230,165 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Whether `PageUp` and `PageDown` should jump to the previous and next section instead of by
+    /// a page.
+    ///
+    /// Sections are groups of choices delimited by [`Separator`]s. When this is enabled, `[` and
+    /// `]` can also be used to jump to the previous and next section.
+    ///
+    /// If `jump_by_section` is not set, it will default to `false`.
+    ///
+    /// [`Separator`]: crate::question::Choice::Separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .jump_by_section(true)
+    ///     .build();
+    /// ```
+    pub fn jump_by_section(mut self, jump_by_section: bool) -> Self {
+        self.select.choices.set_jump_by_section(jump_by_section);
+        self
+    }
+
+    /// Whether to capture the mouse while the prompt is active.
+    ///
+    /// When enabled, scrolling moves the cursor and clicking on a choice hovers it. Clicking on
+    /// the hovered choice selects it. While the mouse is captured, the terminal cannot be used to
+    /// select text.
+    ///
+    /// If `mouse_capture` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .mouse_capture(true)
+    ///     .build();
+    /// ```
+    pub fn mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.select.choices.set_mouse_capture(mouse_capture);
+        self
+    }
+
+    /// Whether to truncate the answer on the finished line so that it fits in the terminal.
+    ///
+    /// When enabled, the answer is cut short with `...` instead of wrapping onto more lines.
+    ///
+    /// If `truncate_answer` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .truncate_answer(false)
+    ///     .build();
+    /// ```
+    pub fn truncate_answer(mut self, truncate_answer: bool) -> Self {
+        self.select.choices.set_truncate_answer(truncate_answer);
+        self
+    }
+
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// When enabled, typing narrows down the choices to those which fuzzy match what was typed,
+    /// i.e. those which contain the typed characters in the same order, ignoring case.
+    /// `Backspace` removes the last typed character. Separators are hidden while filtering.
+    ///
+    /// If `filterable` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.select.choices.set_filterable(filterable);
+        self
+    }
+
+    /// Whether typing should hover the first choice which starts with what was typed.
+    ///
+    /// Characters typed within a second of each other are looked up together, and typing the same
+    /// character again moves to the next choice starting with it, like the list boxes of GUIs.
+    /// While this is enabled, `j` and `k` cannot be used to move up and down. It has no effect if
+    /// the select is [`filterable`](Self::filterable).
+    ///
+    /// If `jump_to_prefix` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("country")
+    ///     .choices(vec!["Argentina", "Brazil", "Canada", "Chile"])
+    ///     .jump_to_prefix(true)
+    ///     .build();
+    /// ```
+    pub fn jump_to_prefix(mut self, jump_to_prefix: bool) -> Self {
+        self.select.choices.set_jump_to_prefix(jump_to_prefix);
+        self
+    }
+
+    /// The number of columns to lay the choices out in.
+    ///
+    /// When there is more than one column, the choices are laid out in a grid similar to shell
+    /// completions, which is useful for long lists of short choices. Each choice takes a single
+    /// line, and `Left` and `Right` move between the columns. If `columns` is 0, the most columns
+    /// in which every choice fits on a single line are used. Fewer columns are used if the
+    /// terminal is too narrow.
+    ///
+    /// If `columns` is not set, it will default to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .columns(0)
+    ///     .build();
+    /// ```
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.select.choices.set_columns(columns);
+        self
+    }
+
+    /// Whether to show the full text of the hovered choice below the list.
+    ///
+    /// When the choices are laid out in more than one [column](Self::columns), each choice is cut
+    /// off after a single line. With `hover_detail`, the full text of the hovered choice is shown
+    /// in a dimmed line below the list, wrapping if needed, which updates as the user moves.
+    ///
+    /// If `hover_detail` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .columns(3)
+    ///     .hover_detail(true)
+    ///     .build();
+    /// ```
+    pub fn hover_detail(mut self, hover_detail: bool) -> Self {
+        self.select.choices.set_hover_detail(hover_detail);
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`select`] for more information.
@@ -203,6 +413,94 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with the given text, and a value which is given back in the
+    /// [`ListItem`] if it is selected.
+    ///
+    /// This is useful to find out what was selected without relying on the text or the index of
+    /// the choice.
+    ///
+    /// See [`select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`ListItem`]: crate::ListItem
+    /// [`select`]: crate::question::Question::select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choice_with_value("Order a Pizza", "order-pizza")
+    ///     .build();
+    /// ```
+    pub fn choice_with_value<I: Into<String>, V: Into<String>>(self, text: I, value: V) -> Self {
+        let mut this = self.choice(text);
+        let index = this.select.choices.len() - 1;
+        this.select.choices.set_value(index, value.into());
+        this
+    }
+
+    /// Inserts a [`Choice`] with the given text, and a description which is shown below the list
+    /// while the choice is hovered.
+    ///
+    /// The description is shown in a dimmed colour, wrapping if needed, and updates as the user
+    /// moves. It is shown instead of the [`hover_detail`](Self::hover_detail) of the choice.
+    ///
+    /// See [`select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`select`]: crate::question::Question::select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choice_with_description("Order a Pizza", "Delivered in 30 minutes")
+    ///     .build();
+    /// ```
+    pub fn choice_with_description<I: Into<String>, D: Into<String>>(
+        self,
+        text: I,
+        description: D,
+    ) -> Self {
+        let mut this = self.choice(text);
+        let index = this.select.choices.len() - 1;
+        this.select
+            .choices
+            .set_description(index, description.into());
+        this
+    }
+
+    /// Inserts a [`Disabled`] choice with the given text, which is shown with the reason it cannot be
+    /// picked.
+    ///
+    /// See [`select`] for more information.
+    ///
+    /// [`Disabled`]: crate::question::Choice::Disabled
+    /// [`select`]: crate::question::Question::select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choice("Order a Pizza")
+    ///     .disabled_choice("Make a reservation", "Fully booked tonight")
+    ///     .build();
+    /// ```
+    pub fn disabled_choice<I: Into<String>, R: Into<String>>(mut self, text: I, reason: R) -> Self {
+        let reason = reason.into();
+        self.select.choices.choices.push(Choice::Disabled {
+            text: shown_text(text.into(), Some(&reason)),
+            reason,
+        });
+        self
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`select`] for more information.
@@ -279,8 +577,57 @@ impl<'a> SelectBuilder<'a> {
         self.select.choices.choices.extend(
             choices
                 .into_iter()
-                .map(|choice| choice.into().map(Text::new)),
+                .map(|choice| choice.into().map_with_reason(shown_text)),
+        );
+        self
+    }
+
+    /// Inserts the choices of a [`DynamicList`], which are only fetched once they are shown.
+    ///
+    /// This is useful when there are too many choices to load all of them before asking, such as
+    /// the rows of a database. Filtering and [`columns`](Self::columns) are disabled for a list
+    /// with dynamic choices, and each choice is given a single line. Other choices can still be
+    /// inserted before and after the dynamic ones, but the indices in the [`ListItem`] count all
+    /// of the choices.
+    ///
+    /// See [`select`] and [`DynamicList`] for more information.
+    ///
+    /// [`select`]: crate::question::Question::select
+    ///
+    /// # Panics
+    ///
+    /// This will panic if a `DynamicList` was already inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Range;
+    ///
+    /// use requestty::{question::DynamicList, Question};
+    ///
+    /// struct Customers;
+    ///
+    /// impl DynamicList for Customers {
+    ///     fn len(&self) -> usize {
+    ///         10_000
+    ///     }
+    ///
+    ///     fn fetch(&mut self, range: Range<usize>) -> Vec<String> {
+    ///         range.map(|i| format!("Customer {}", i)).collect()
+    ///     }
+    /// }
+    ///
+    /// let select = Question::select("customer")
+    ///     .dynamic_choices(Customers)
+    ///     .build();
+    /// ```
+    pub fn dynamic_choices<L: DynamicList + 'a>(mut self, list: L) -> Self {
+        assert!(
+            self.select.dynamic.is_none(),
+            "only one `DynamicList` can be inserted"
         );
+
+        self.select.dynamic = Some(DynamicChoices::new(list, &mut self.select.choices));
         self
     }
 
@@ -304,7 +651,7 @@ impl<'a> SelectBuilder<'a> {
     /// [`Question`]: crate::question::Question
     pub fn build(self) -> crate::question::Question<'a> {
         if let Some(default) = self.select.choices.default() {
-            if self.select.choices[default].is_separator() {
+            if !self.select.choices[default].is_choice() {
                 panic!("Invalid default '{}' is not a `Choice`", default);
             }
         }
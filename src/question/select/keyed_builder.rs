@@ -0,0 +1,420 @@
+use std::{any::Any, marker::PhantomData};
+
+use ui::{backend::Backend, widgets::Text};
+
+use super::{ChoiceKeys, Select};
+use crate::{
+    question::{shown_text, Choice, Options},
+    CustomAnswer, ListItem,
+};
+
+/// The builder for a [`custom_keyed_select`] prompt.
+///
+/// This is a [`select`] where every choice is given a value of type `V`. The value of the chosen
+/// choice is given back as an [`Answer::Custom`], instead of a [`ListItem`], so there is no need
+/// to match the index or text of the choice back to a value.
+///
+/// The values are compared to find the choice of a previous answer, such as when it is used as the
+/// default, so they must implement [`PartialEq`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Env {
+///     Staging,
+///     Production,
+/// }
+///
+/// let select = Question::custom_keyed_select("env")
+///     .message("Where do you want to deploy?")
+///     .choice_value("Staging", Env::Staging)
+///     .choice_value("Production", Env::Production)
+///     .build();
+///
+/// // Once answered, the value is taken out of the answer with
+/// // `answer.as_custom::<Env>()`
+/// ```
+///
+/// [`Answer::Custom`]: crate::Answer::Custom
+/// [`custom_keyed_select`]: crate::question::Question::custom_keyed_select
+/// [`select`]: crate::question::Question::select
+#[derive(Debug)]
+pub struct KeyedSelectBuilder<'a, V> {
+    opts: Options<'a>,
+    select: Select<'a>,
+    _value: PhantomData<fn() -> V>,
+}
+
+impl<'a, V: Any + PartialEq + Send + Sync> KeyedSelectBuilder<'a, V> {
+    pub(crate) fn new(name: String) -> Self {
+        KeyedSelectBuilder {
+            opts: Options::new(name),
+            select: Select {
+                keys: Some(ChoiceKeys {
+                    keys: Vec::new(),
+                    eq: |a, b| a.downcast_ref::<V>() == b.downcast_ref::<V>(),
+                }),
+                ..Default::default()
+            },
+            _value: PhantomData,
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .message("Which size do you want?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("customise") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers, OnEsc};
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set a default index for the select
+    ///
+    /// The given index will be hovered in the beginning. Separators and disabled choices are
+    /// counted in the index.
+    ///
+    /// If `default` is unspecified, the first choice will be hovered.
+    ///
+    /// # Panics
+    ///
+    /// If the default given is not a choice with a value, it will cause a panic on [`build`]
+    ///
+    /// [`build`]: Self::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .choice_value("Medium", 12)
+    ///     .default(1)
+    ///     .build();
+    /// ```
+    pub fn default(mut self, default: usize) -> Self {
+        self.select.choices.set_default(default);
+        self
+    }
+
+    /// The maximum height that can be taken by the list
+    ///
+    /// If the total height exceeds the page size, the list will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.select.choices.set_page_size(page_size);
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last element.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.select.choices.set_should_loop(should_loop);
+        self
+    }
+
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// See [`SelectBuilder::filterable`] for more information.
+    ///
+    /// [`SelectBuilder::filterable`]: crate::question::SelectBuilder::filterable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.select.choices.set_filterable(filterable);
+        self
+    }
+
+    /// Inserts a choice with the given text, and the value which is given back if it is chosen.
+    ///
+    /// See [`custom_keyed_select`] for more information.
+    ///
+    /// [`custom_keyed_select`]: crate::question::Question::custom_keyed_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .build();
+    /// ```
+    pub fn choice_value<I: Into<String>>(mut self, text: I, value: V) -> Self {
+        self.push(Choice::Choice(Text::new(text.into())), Some(value));
+        self
+    }
+
+    /// Inserts a [`Disabled`] choice with the given text, which is shown with the reason it cannot be
+    /// picked.
+    ///
+    /// See [`custom_keyed_select`] for more information.
+    ///
+    /// [`Disabled`]: crate::question::Choice::Disabled
+    /// [`custom_keyed_select`]: crate::question::Question::custom_keyed_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .disabled_choice("Large", "Sold out")
+    ///     .build();
+    /// ```
+    pub fn disabled_choice<I: Into<String>, R: Into<String>>(mut self, text: I, reason: R) -> Self {
+        let reason = reason.into();
+        self.push(
+            Choice::Disabled {
+                text: shown_text(text.into(), Some(&reason)),
+                reason,
+            },
+            None,
+        );
+        self
+    }
+
+    /// Inserts a [`Separator`] with the given text
+    ///
+    /// See [`custom_keyed_select`] for more information.
+    ///
+    /// [`Separator`]: crate::question::Choice::Separator
+    /// [`custom_keyed_select`]: crate::question::Question::custom_keyed_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .separator("-- custom separator text --")
+    ///     .build();
+    /// ```
+    pub fn separator<I: Into<String>>(mut self, text: I) -> Self {
+        self.push(Choice::Separator(text.into()), None);
+        self
+    }
+
+    /// Inserts a [`DefaultSeparator`]
+    ///
+    /// See [`custom_keyed_select`] for more information.
+    ///
+    /// [`DefaultSeparator`]: crate::question::Choice::DefaultSeparator
+    /// [`custom_keyed_select`]: crate::question::Question::custom_keyed_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .default_separator()
+    ///     .build();
+    /// ```
+    pub fn default_separator(mut self) -> Self {
+        self.push(Choice::DefaultSeparator, None);
+        self
+    }
+
+    fn push(&mut self, choice: Choice<Text<String>>, value: Option<V>) {
+        self.select.choices.choices.push(choice);
+        self.select
+            .keys
+            .as_mut()
+            .expect("a keyed select has keys")
+            .keys
+            .push(value.map(CustomAnswer::new));
+    }
+
+    crate::impl_transform_builder! {
+    /// The [`ListItem`] given is that of the chosen choice, rather than its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::custom_keyed_select("size")
+    ///     .choice_value("Small", 8)
+    ///     .transform(|choice, previous_answers, backend| {
+    ///         write!(backend, "({}) {}", choice.index, choice.text)
+    ///     })
+    ///     .build();
+    /// ```
+    ListItem; select
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> crate::question::Question<'a> {
+        if let Some(default) = self.select.choices.default() {
+            if !self.select.choices[default].is_choice() {
+                panic!("Invalid default '{}' is not a `Choice`", default);
+            }
+        }
+
+        crate::question::Question::new(
+            self.opts,
+            crate::question::QuestionKind::Select(self.select),
+        )
+    }
+}
+
+impl<'a, V: Any + PartialEq + Send + Sync> From<KeyedSelectBuilder<'a, V>>
+    for crate::question::Question<'a>
+{
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: KeyedSelectBuilder<'a, V>) -> Self {
+        builder.build()
+    }
+}
@@ -1,46 +1,60 @@
-use std::io;
+use std::{borrow::Cow, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Stylize,
+    events::{KeyEvent, MouseEvent},
+    layout::RenderRegion,
     widgets::{self, Text},
-    Prompt, Widget,
+    Prompt, Validation, Widget,
 };
 
-use super::Transform;
-use crate::{Answer, Answers, ListItem};
+use super::{describe_hovered, dynamic_list::DynamicChoices, filter_hint, write_answer, Transform};
+use crate::{Answer, Answers, CustomAnswer, ListItem};
 
 pub use builder::SelectBuilder;
+pub use keyed_builder::KeyedSelectBuilder;
 
 mod builder;
+mod keyed_builder;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(super) struct Select<'a> {
     choices: super::ChoiceList<Text<String>>,
+    dynamic: Option<DynamicChoices<'a>>,
+    keys: Option<ChoiceKeys>,
     transform: Transform<'a, ListItem>,
 }
 
+/// The values of the choices of a [`KeyedSelectBuilder`], which are given as the answer.
+#[derive(Debug, Clone)]
+struct ChoiceKeys {
+    // note: this is indexed the same as choices
+    keys: Vec<Option<CustomAnswer>>,
+    /// Compares the values, since answers may come from another instance of the question
+    eq: fn(&CustomAnswer, &CustomAnswer) -> bool,
+}
+
+const FILTER_HINT: &str = "Type to filter";
+
 struct SelectPrompt<'a> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<&'a str, Cow<'static, str>>,
     select: widgets::Select<Select<'a>>,
 }
 
 impl SelectPrompt<'_> {
     fn finish_index(self, index: usize) -> ListItem {
+        let mut choices = self.select.into_inner().choices;
+        let section = choices.section_at(index);
+        let value = choices.take_value(index);
+
         ListItem {
             index,
-            text: self
-                .select
-                .into_inner()
-                .choices
-                .choices
-                .swap_remove(index)
-                .unwrap_choice()
-                .text,
+            text: choices.choices.swap_remove(index).unwrap_choice().text,
+            section,
+            value,
         }
     }
 }
@@ -49,6 +63,35 @@ impl Prompt for SelectPrompt<'_> {
     type ValidateErr = &'static str;
     type Output = ListItem;
 
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if self.select.has_selectable() {
+            Ok(Validation::Finish)
+        } else if !self.select.filter().is_empty() {
+            Err("There are no choices matching the filter")
+        } else {
+            Err("There are no choices to select")
+        }
+    }
+
+    fn describe(&mut self) -> Option<String> {
+        Some(describe_hovered("Selected", &self.select, |list, at| {
+            let text = &list.choices[at].as_ref().unwrap_choice().text;
+            match list.choices.description(at) {
+                Some(description) => format!("{} ({})", text, description),
+                None => text.clone(),
+            }
+        }))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        (*err).to_owned()
+    }
+
+    /// Clicking on the hovered choice selects it.
+    fn is_submit_click(&self, mouse: MouseEvent) -> bool {
+        self.select.is_hovered_click(mouse)
+    }
+
     fn finish(self) -> Self::Output {
         let index = self.select.get_at();
         self.finish_index(index)
@@ -69,8 +112,27 @@ impl Widget for SelectPrompt<'_> {
         self.select.cursor_pos(layout)
     }
 
+    fn focused_rows(&mut self, layout: ui::layout::Layout) -> std::ops::Range<u16> {
+        self.select.focused_rows(layout)
+    }
+
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        self.select.handle_key(key)
+        let filter_len = self.select.filter().len();
+
+        if !self.select.handle_key(key) {
+            return false;
+        }
+
+        if self.select.filter().len() != filter_len {
+            self.prompt
+                .set_hint(Some(filter_hint(self.select.filter(), FILTER_HINT)));
+        }
+
+        true
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        self.select.handle_mouse(mouse)
     }
 }
 
@@ -79,9 +141,14 @@ impl widgets::List for Select<'_> {
         &mut self,
         index: usize,
         hovered: bool,
-        layout: ui::layout::Layout,
+        mut layout: ui::layout::Layout,
         backend: &mut B,
     ) -> io::Result<()> {
+        if self.is_dynamic(index) {
+            // Only the first line of a dynamic choice is shown, since it is given a single line
+            layout.render_region = RenderRegion::Top;
+        }
+
         self.choices.render_item(index, hovered, layout, backend)
     }
 
@@ -89,8 +156,19 @@ impl widgets::List for Select<'_> {
         self.choices.is_selectable(index)
     }
 
+    fn load_range(&mut self, indices: std::ops::Range<usize>) {
+        if let Some(ref mut dynamic) = self.dynamic {
+            dynamic.load(indices, &mut self.choices);
+        }
+    }
+
     fn height_at(&mut self, index: usize, layout: ui::layout::Layout) -> u16 {
-        self.choices.height_at(index, layout)
+        // The heights are measured before the dynamic choices are fetched
+        if self.is_dynamic(index) {
+            1
+        } else {
+            self.choices.height_at(index, layout)
+        }
     }
 
     fn len(&self) -> usize {
@@ -104,41 +182,170 @@ impl widgets::List for Select<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn jump_by_section(&self) -> bool {
+        self.choices.jump_by_section()
+    }
+
+    fn columns(&self) -> usize {
+        self.choices.columns()
+    }
+
+    fn filterable(&self) -> bool {
+        self.choices.filterable()
+    }
+
+    fn jump_to_prefix(&self) -> bool {
+        self.choices.jump_to_prefix()
+    }
+
+    fn filter_text(&self, index: usize) -> Option<&str> {
+        self.choices.filter_text(index)
+    }
+
+    fn hover_detail(&self, index: usize) -> Option<&str> {
+        self.choices.hover_detail_text(index)
+    }
+
+    fn description(&self, index: usize) -> Option<&str> {
+        self.choices.description(index)
+    }
 }
 
 impl<'a> Select<'a> {
-    fn into_prompt(self, message: &'a str) -> SelectPrompt<'a> {
+    fn is_dynamic(&self, index: usize) -> bool {
+        matches!(self.dynamic, Some(ref dynamic) if dynamic.contains(index))
+    }
+
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        match answer {
+            // The choices may have changed since the answer was given
+            Answer::ListItem(item)
+                if self.keys.is_none()
+                    && item.index < self.choices.len()
+                    && self.choices[item.index].is_choice() =>
+            {
+                self.choices.set_default(item.index);
+            }
+            Answer::Custom(key) => {
+                if let Some(index) = self.index_of_key(key) {
+                    self.choices.set_default(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The index of the choice with the given key, if this is a keyed select.
+    fn index_of_key(&self, key: &CustomAnswer) -> Option<usize> {
+        let keys = self.keys.as_ref()?;
+        keys.keys
+            .iter()
+            .position(|k| matches!(k, Some(k) if (keys.eq)(k, key)))
+    }
+
+    /// Replaces a list item answered by a keyed select with the key of its choice.
+    fn into_keyed_answer(keys: Option<ChoiceKeys>, ans: Answer) -> Answer {
+        match (keys, ans) {
+            (Some(mut keys), Answer::ListItem(item)) => Answer::Custom(
+                keys.keys[item.index]
+                    .take()
+                    .expect("every choice of a keyed select has a key"),
+            ),
+            (_, ans) => ans,
+        }
+    }
+
+    fn into_prompt(mut self, message: &'a str) -> SelectPrompt<'a> {
+        if self.dynamic.is_some() {
+            // Filtering and laying out the choices in columns need all of the choices
+            self.choices.set_filterable(false);
+            self.choices.set_columns(1);
+        }
+
+        let hint = if self.choices.filterable() {
+            Some(Cow::Borrowed(FILTER_HINT))
+        } else {
+            None
+        };
+
         let mut select = widgets::Select::new(self);
         if let Some(default) = select.list.choices.default() {
             select.set_at(default);
         }
 
         SelectPrompt {
-            prompt: widgets::Prompt::new(message),
+            prompt: widgets::Prompt::new(message).with_optional_hint(hint),
             select,
         }
     }
 
-    pub(crate) fn ask<B: Backend, E: EventIterator>(
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
         answers: &Answers,
         b: &mut B,
-        events: &mut E,
+        driver: &mut D,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
-        let ans = ui::Input::new(self.into_prompt(&message), b)
+        let choice_keys = self.keys.take();
+        let mouse_capture = self.choices.mouse_capture();
+        let truncate_answer = self.choices.truncate_answer();
+        let input = ui::Input::new(self.into_prompt(&message), b)
             .hide_cursor()
-            .on_esc(on_esc)
-            .run(events)?;
+            .mouse_capture(mouse_capture);
+        let ans = driver.run(input).await?;
+
+        let ans = Self::write_finished(transform, message, ans, truncate_answer, answers, b)?;
+        Ok(ans.map(|ans| Self::into_keyed_answer(choice_keys, ans)))
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = match ans {
+            Answer::Custom(key) if self.keys.is_some() => {
+                let index = self
+                    .index_of_key(key)
+                    .expect("answer must be the key of a choice");
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
+                ListItem {
+                    index,
+                    text: self.choices[index].as_ref().unwrap_choice().text.clone(),
+                    section: self.choices.section_at(index),
+                    value: None,
+                }
+            }
+            ans => ans
+                .as_list_item()
+                .expect("answer must be a list item")
+                .clone(),
+        };
+        let transform = self.transform.take();
+        let truncate_answer = self.choices.truncate_answer();
+
+        Self::write_finished(transform, message, Some(ans), truncate_answer, answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, ListItem>,
+        message: String,
+        ans: Option<ListItem>,
+        truncate_answer: bool,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            let text = ans
+                .text
                 .lines()
                 .next()
-                .expect("There must be at least one line in a `str`")
-                .cyan()
-        )?)
+                .expect("There must be at least one line in a `str`");
+            write_answer(&message, text, truncate_answer, b)?;
+        })
     }
 }
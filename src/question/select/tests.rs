@@ -1,8 +1,10 @@
+use std::{cell::RefCell, ops::Range};
+
 use rand::prelude::*;
 use rand_chacha::ChaCha12Rng;
 use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
 
-use crate::question::{Choice, Question, QuestionKind};
+use crate::question::{Choice, DynamicList, Question, QuestionKind};
 
 use super::*;
 
@@ -128,3 +130,129 @@ test_select!(pagination {
         );
     height = 16;
 });
+
+#[test]
+fn test_filterable() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut backend = TestBackend::new(size);
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(choices(20))
+            .filterable(true),
+    )
+    .into_prompt("message");
+
+    assert!(select.handle_key(KeyCode::Char('c').into()));
+    assert!(select.handle_key(KeyCode::Char('1').into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+
+    let mut layout = base_layout;
+    backend.reset_with_layout(layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+
+    assert!(select.handle_key(KeyCode::Char('z').into()));
+    assert_eq!(
+        select.validate(),
+        Err("There are no choices matching the filter")
+    );
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert!(select.validate().is_ok());
+
+    let at = select.select.get_at();
+    let item = select.finish();
+    assert_eq!(item.index, at);
+    assert!(item.text.starts_with("Choice 1"));
+}
+
+/// A list of numbered items which records the ranges that are fetched
+struct Items<'f>(&'f RefCell<Vec<Range<usize>>>);
+
+impl DynamicList for Items<'_> {
+    fn len(&self) -> usize {
+        10_000
+    }
+
+    fn fetch(&mut self, range: Range<usize>) -> Vec<String> {
+        self.0.borrow_mut().push(range.clone());
+        range.map(|i| format!("Item {}", i)).collect()
+    }
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_dynamic_choices() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut backend = TestBackend::new(size);
+    let fetched = RefCell::new(Vec::new());
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .separator("Items")
+            .dynamic_choices(Items(&fetched))
+            .filterable(true),
+    )
+    .into_prompt("message");
+
+    // Nothing is fetched until the choices are shown, and they cannot be filtered
+    assert!(fetched.borrow().is_empty());
+    assert_eq!(select.select.list.choices.len(), 10_001);
+    assert!(!select.select.list.choices.filterable());
+
+    let mut layout = base_layout;
+    backend.reset_with_layout(layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+    assert_eq!(*fetched.borrow(), [0..13]);
+
+    // Only the choices which were not shown before are fetched
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert!(select.handle_key(KeyCode::Up.into()));
+    let mut layout = base_layout;
+    backend.reset_with_layout(layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+    assert_eq!(*fetched.borrow(), [0..13, 13..14]);
+
+    let at = select.select.get_at();
+    let item = select.finish();
+    assert_eq!(item.index, at);
+    assert_eq!(item.text, format!("Item {}", at - 1));
+    assert_eq!(item.section.as_deref(), Some("Items"));
+}
+
+#[test]
+fn test_disabled_choices() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choice("Pizza")
+            .disabled_choice("Sushi", "Closed today")
+            .choices(vec![
+                Choice::Disabled {
+                    text: "Tacos".into(),
+                    reason: "Sold out".into(),
+                },
+                "Burgers".into(),
+            ]),
+    )
+    .into_prompt("message");
+
+    // The disabled choices are skipped over
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.select.get_at(), 3);
+
+    let mut layout = base_layout;
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.select.get_at(), 0);
+
+    let item = select.finish();
+    assert_eq!(item.index, 0);
+    assert_eq!(item.text, "Pizza");
+}
@@ -0,0 +1,461 @@
+use std::{io, ops::RangeInclusive};
+
+use ui::{backend::Backend, events::KeyEvent, style::Stylize, widgets, Prompt, Validation, Widget};
+
+use super::{Options, TransformByVal as Transform, ValidateByVal as Validate, ValidationMessage};
+use crate::{Answer, Answers};
+
+#[derive(Debug, Clone)]
+pub(super) struct Slider<'a> {
+    range: RangeInclusive<i64>,
+    step: i64,
+    default: Option<i64>,
+    validate: Validate<'a, i64>,
+    transform: Transform<'a, i64>,
+}
+
+impl Default for Slider<'_> {
+    fn default() -> Self {
+        Self {
+            range: 0..=100,
+            step: 1,
+            default: None,
+            validate: Validate::None,
+            transform: Transform::None,
+        }
+    }
+}
+
+struct SliderPrompt<'a, 's> {
+    prompt: widgets::Prompt<&'a str>,
+    slider: Slider<'s>,
+    input: widgets::Slider,
+    answers: &'a Answers,
+}
+
+impl Widget for SliderPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.prompt.height(layout) + self.input.height(layout) - 1
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input.handle_key(key)
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.input
+            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+    }
+}
+
+impl Prompt for SliderPrompt<'_, '_> {
+    type ValidateErr = ValidationMessage;
+    type Output = i64;
+
+    fn severity(&self, err: &Self::ValidateErr) -> ui::Severity {
+        err.severity
+    }
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Validate::Sync(ref mut validate) = self.slider.validate {
+            validate(self.input.value(), self.answers).into_result()?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn describe(&mut self) -> Option<String> {
+        Some(format!(
+            "Value: {} (from {} to {})",
+            self.input.value(),
+            self.slider.range.start(),
+            self.slider.range.end()
+        ))
+    }
+
+    fn describe_error(&self, err: &Self::ValidateErr) -> String {
+        err.as_str().to_owned()
+    }
+
+    fn finish(self) -> Self::Output {
+        self.input.value()
+    }
+}
+
+impl<'s> Slider<'s> {
+    pub(super) fn set_default_answer(&mut self, answer: &Answer) {
+        if let Answer::Int(default) = *answer {
+            self.default = Some(default);
+        }
+    }
+
+    fn into_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> SliderPrompt<'a, 's> {
+        let mut input =
+            widgets::Slider::new(*self.range.start(), *self.range.end()).with_step(self.step);
+
+        if let Some(default) = self.default {
+            input.set_value(default);
+        }
+
+        SliderPrompt {
+            prompt: widgets::Prompt::new(message),
+            slider: self,
+            input,
+            answers,
+        }
+    }
+
+    pub(crate) async fn ask<B: Backend, D: super::Driver>(
+        mut self,
+        message: String,
+        answers: &Answers,
+        b: &mut B,
+        driver: &mut D,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let input = ui::Input::new(self.into_prompt(&message, answers), b).hide_cursor();
+        let ans = driver.run(input).await?;
+
+        Self::write_finished(transform, message, ans, answers, b)
+    }
+
+    pub(crate) fn render_finished<B: Backend>(
+        mut self,
+        message: String,
+        ans: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let ans = ans.as_int().expect("answer must be an int");
+        let transform = self.transform.take();
+
+        Self::write_finished(transform, message, Some(ans), answers, b).map(|_| ())
+    }
+
+    fn write_finished<B: Backend>(
+        transform: Transform<'_, i64>,
+        message: String,
+        ans: Option<i64>,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<Option<Answer>> {
+        crate::write_final!(transform, message, ans, answers, b, |ans| {
+            b.write_styled(&ans.to_string().with(ui::style::theme().highlight))?;
+        })
+    }
+}
+
+/// The builder for a [`slider`] prompt.
+///
+/// The value is adjusted with `Left` and `Right` by the [`step`], with `PageDown` and `PageUp` by a
+/// tenth of the [`range`], and `Home` and `End` go to the ends of the range.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let slider = Question::slider("volume")
+///     .message("How loud should the music be?")
+///     .range(0..=100)
+///     .step(5)
+///     .build();
+/// ```
+///
+/// [`slider`]: crate::question::Question::slider
+/// [`step`]: SliderBuilder::step
+/// [`range`]: SliderBuilder::range
+#[derive(Debug)]
+pub struct SliderBuilder<'a> {
+    opts: Options<'a>,
+    slider: Slider<'a>,
+}
+
+impl<'a> SliderBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        SliderBuilder {
+            opts: Options::new(name),
+            slider: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .message("How loud should the music be?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("music") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+
+    theme
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Color, Theme}, Question};
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .theme(Theme {
+    ///         highlight: Color::Magenta,
+    ///         ..Theme::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    before_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .before_ask(|previous_answers| {
+    ///         println!("{} questions answered so far", previous_answers.len());
+    ///     })
+    ///     .build();
+    /// ```
+
+    after_ask
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .after_ask(|previous_answers, answer| {
+    ///         println!("Answered with {:?}", answer);
+    ///     })
+    ///     .build();
+    /// ```
+
+    hide_final
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .hide_final(true)
+    ///     .build();
+    /// ```
+    }
+
+    /// The range of values that can be picked, including both ends.
+    ///
+    /// If `range` is not set, it will default to `0..=100`.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .range(0..=11)
+    ///     .build();
+    /// ```
+    pub fn range(mut self, range: RangeInclusive<i64>) -> Self {
+        assert!(!range.is_empty(), "range cannot be empty");
+
+        self.slider.range = range;
+        self
+    }
+
+    /// The amount the value changes by when `Left` or `Right` is pressed.
+    ///
+    /// If `step` is not set, it will default to 1.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `step` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .step(5)
+    ///     .build();
+    /// ```
+    pub fn step(mut self, step: i64) -> Self {
+        assert!(step > 0, "step must be positive");
+
+        self.slider.step = step;
+        self
+    }
+
+    /// Set the value the slider starts at. It is clamped to the [`range`].
+    ///
+    /// If `default` is not set, the slider starts at the start of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .default(50)
+    ///     .build();
+    /// ```
+    ///
+    /// [`range`]: SliderBuilder::range
+    pub fn default(mut self, default: i64) -> Self {
+        self.slider.default = Some(default);
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .validate(|volume, previous_answers| if volume > 0 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("The music cannot be muted".to_owned())
+    ///     })
+    ///     .build();
+    /// ```
+    by val i64; slider
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .transform(|volume, previous_answers, backend| {
+    ///         write!(backend, "{}%", volume)
+    ///     })
+    ///     .build();
+    /// ```
+    by val i64; slider
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(self.opts, super::QuestionKind::Slider(self.slider))
+    }
+}
+
+impl<'a> From<SliderBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: SliderBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = Slider {
+            step: 10,
+            default: Some(30),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        let mut layout = base_layout;
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_line_offset(50));
+        ui::assert_backend_snapshot!(backend);
+
+        assert!(prompt.handle_key(KeyCode::Right.into()));
+        assert_eq!(prompt.input.value(), 40);
+
+        layout = base_layout;
+        backend.reset_with_layout(layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        ui::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_height() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = Slider::default().into_prompt("message", &answers);
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 1);
+        assert_eq!(layout, base_layout.with_line_offset(49));
+
+        // The prompt takes up the entire first line, so the slider is moved to the next one
+        let message = "m".repeat(40);
+        let mut prompt = Slider::default().into_prompt(&message, &answers);
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 2);
+        assert_eq!(layout, base_layout.with_line_offset(32).with_offset(0, 1));
+    }
+}
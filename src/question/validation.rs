@@ -0,0 +1,152 @@
+use std::io;
+
+use ui::{
+    backend::Backend, events::KeyEvent, layout::Layout, style::Color, widgets, Severity, Widget,
+};
+
+/// The result of validating an answer with `validate_with_severity`.
+///
+/// Only [`Error`](ValidationResult::Error) prevents the answer from being submitted. Warnings and
+/// information are shown below the question, and the answer is accepted if the user submits it
+/// again without changing it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ValidationResult {
+    /// The answer is valid.
+    Valid,
+    /// The answer is invalid, and cannot be submitted until it is changed.
+    Error(String),
+    /// The answer may be wrong, but can still be submitted.
+    Warning(String),
+    /// Information about the answer, which can still be submitted.
+    Info(String),
+}
+
+impl From<Result<(), String>> for ValidationResult {
+    fn from(res: Result<(), String>) -> Self {
+        match res {
+            Ok(()) => ValidationResult::Valid,
+            Err(e) => ValidationResult::Error(e),
+        }
+    }
+}
+
+impl ValidationResult {
+    pub(super) fn into_result(self) -> Result<(), ValidationMessage> {
+        let (message, severity) = match self {
+            ValidationResult::Valid => return Ok(()),
+            ValidationResult::Error(message) => (message, Severity::Error),
+            ValidationResult::Warning(message) => (message, Severity::Warning),
+            ValidationResult::Info(message) => (message, Severity::Info),
+        };
+
+        Err(ValidationMessage {
+            text: widgets::Text::new(message),
+            severity,
+        })
+    }
+}
+
+/// The message shown by a prompt when validation fails, along with its severity.
+#[derive(Debug)]
+pub(super) struct ValidationMessage {
+    text: widgets::Text<String>,
+    pub(super) severity: Severity,
+}
+
+impl ValidationMessage {
+    /// The plain text of the message.
+    pub(super) fn as_str(&self) -> &str {
+        &self.text.text
+    }
+}
+
+impl From<widgets::Text<String>> for ValidationMessage {
+    fn from(text: widgets::Text<String>) -> Self {
+        Self {
+            text,
+            severity: Severity::Error,
+        }
+    }
+}
+
+impl From<String> for ValidationMessage {
+    fn from(message: String) -> Self {
+        widgets::Text::new(message).into()
+    }
+}
+
+impl Widget for ValidationMessage {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        self.text.render(layout, backend)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.text.height(layout)
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        self.text.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.text.handle_key(key)
+    }
+}
+
+/// The result of validating an answer on every key press.
+///
+/// An invalid answer is shown in the error colour, and the message returned by the validation (if
+/// any) is shown beneath the input until it is valid again.
+#[derive(Debug, Default)]
+pub(super) struct KeyValidation {
+    error: Option<widgets::Text<String>>,
+}
+
+impl KeyValidation {
+    pub(super) fn set(&mut self, res: Result<(), String>) {
+        self.error = res.err().map(widgets::Text::new);
+    }
+
+    pub(super) fn set_valid(&mut self) {
+        self.error = None;
+    }
+
+    pub(super) fn set_invalid(&mut self) {
+        self.set(Err(String::new()));
+    }
+
+    pub(super) fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    fn message(&mut self) -> Option<&mut widgets::Text<String>> {
+        self.error.as_mut().filter(|text| !text.text.is_empty())
+    }
+
+    /// Renders the message on the lines after the input, if there is one.
+    pub(super) fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        if let Some(message) = self.message() {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+            b.set_fg(ui::style::theme().error)?;
+            message.render(layout, b)?;
+            b.set_fg(Color::Reset)?;
+        }
+
+        Ok(())
+    }
+
+    /// The number of lines taken by the message after the input.
+    pub(super) fn height(&mut self, layout: &mut Layout) -> u16 {
+        match self.message() {
+            Some(message) => {
+                layout.line_offset = 0;
+                layout.offset_y += 1;
+                message.height(layout)
+            }
+            None => 0,
+        }
+    }
+}
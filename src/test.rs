@@ -0,0 +1,219 @@
+//! Utilities to test whole prompts without a terminal.
+//!
+//! The [`Driver`] runs a [`PromptModule`] against a scripted sequence of keys on a [`TestBackend`],
+//! capturing every frame that is shown along the way. This makes it possible to snapshot whole
+//! flows of questions, rather than single questions or widgets.
+
+use std::{collections::VecDeque, fmt, io};
+
+use ui::{
+    backend::{Backend, ClearType, MoveDirection, Size, TestBackend},
+    events::{EventIterator, KeyCode, KeyEvent},
+    style::{Attributes, Color, ColorSupport},
+};
+
+use crate::{Answers, PromptModule, Question};
+
+/// Runs prompts against a scripted sequence of keys, capturing each frame that is rendered.
+///
+/// The keys are given with [`key`](Driver::key), [`keys`](Driver::keys) and
+/// [`text`](Driver::text), and are given to the questions in the order they were added. Every time
+/// a question flushes what it has rendered, a copy of the [`TestBackend`] is taken as a frame,
+/// unless it looks the same as the previous frame.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{prompt::events::KeyCode, test::Driver, PromptModule, Question};
+///
+/// let mut driver = Driver::new((50, 10).into())
+///     .text("Ferris")
+///     .key(KeyCode::Enter)
+///     .text("y")
+///     .key(KeyCode::Enter);
+///
+/// let answers = driver
+///     .run(PromptModule::new(vec![
+///         Question::input("name").build(),
+///         Question::confirm("crab").build(),
+///     ]))
+///     .unwrap();
+///
+/// assert_eq!(answers["name"].as_string(), Some("Ferris"));
+/// assert_eq!(answers["crab"].as_bool(), Some(true));
+/// assert!(driver.remaining_keys().is_empty());
+///
+/// // Each frame can be snapshot, for example with `insta::assert_display_snapshot!(frame)`
+/// for frame in driver.frames() {
+///     println!("{}", frame);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Driver {
+    backend: RecordingBackend,
+    keys: VecDeque<KeyEvent>,
+}
+
+impl Driver {
+    /// Creates a new `Driver` with a terminal of the given size, and no keys.
+    pub fn new(size: Size) -> Self {
+        Self {
+            backend: RecordingBackend {
+                backend: TestBackend::new(size),
+                frames: Vec::new(),
+            },
+            keys: VecDeque::new(),
+        }
+    }
+
+    /// Adds a key to the end of the script.
+    pub fn key<K: Into<KeyEvent>>(mut self, key: K) -> Self {
+        self.keys.push_back(key.into());
+        self
+    }
+
+    /// Adds the keys to the end of the script.
+    pub fn keys<I>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyEvent>,
+    {
+        self.keys.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a key for every character of `text` to the end of the script, as if it was typed.
+    pub fn text(mut self, text: &str) -> Self {
+        self.keys
+            .extend(text.chars().map(|c| KeyEvent::from(KeyCode::Char(c))));
+        self
+    }
+
+    /// Prompts all the questions of `module`, returning their answers.
+    ///
+    /// If the keys run out while a question still needs one, an [`io::Error`] of the kind
+    /// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) is returned. Keys which are left over
+    /// once all the questions are answered can be found with
+    /// [`remaining_keys`](Driver::remaining_keys), and are given to the next call to `run`.
+    pub fn run<'a, Q>(&mut self, module: PromptModule<Q>) -> crate::Result<Answers>
+    where
+        Q: Iterator<Item = Question<'a>>,
+    {
+        module.prompt_all_with(&mut self.backend, &mut ScriptedEvents(&mut self.keys))
+    }
+
+    /// The frames captured so far, in the order they were shown.
+    pub fn frames(&self) -> &[TestBackend] {
+        &self.backend.frames
+    }
+
+    /// The backend as it is now, which is what the terminal would show after the last question.
+    pub fn backend(&self) -> &TestBackend {
+        &self.backend.backend
+    }
+
+    /// The keys of the script which have not been given to a question yet.
+    pub fn remaining_keys(&self) -> Vec<KeyEvent> {
+        self.keys.iter().copied().collect()
+    }
+}
+
+/// Gives the keys of a [`Driver`] one by one, failing once they run out.
+struct ScriptedEvents<'d>(&'d mut VecDeque<KeyEvent>);
+
+impl EventIterator for ScriptedEvents<'_> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        self.0.pop_front().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the scripted keys ran out, but another one was requested",
+            )
+        })
+    }
+}
+
+/// A [`TestBackend`] which keeps a copy of itself every time it is flushed.
+#[derive(Debug, Clone)]
+struct RecordingBackend {
+    backend: TestBackend,
+    frames: Vec<TestBackend>,
+}
+
+impl io::Write for RecordingBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.backend.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.frames.last() != Some(&self.backend) {
+            self.frames.push(self.backend.clone());
+        }
+        Ok(())
+    }
+}
+
+impl Backend for RecordingBackend {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.disable_raw_mode()
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.backend.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.backend.show_cursor()
+    }
+
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        self.backend.get_cursor_pos()
+    }
+
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.backend.move_cursor_to(x, y)
+    }
+
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        self.backend.move_cursor(direction)
+    }
+
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        self.backend.scroll(dist)
+    }
+
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        self.backend.set_attributes(attributes)
+    }
+
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        self.backend.set_fg(color)
+    }
+
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        self.backend.set_bg(color)
+    }
+
+    fn write_hyperlink(&mut self, url: &str, text: &dyn fmt::Display) -> io::Result<()> {
+        self.backend.write_hyperlink(url, text)
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.backend.clear(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.backend.size()
+    }
+
+    fn supports_colors(&self) -> ColorSupport {
+        self.backend.supports_colors()
+    }
+
+    fn supports_unicode(&self) -> bool {
+        self.backend.supports_unicode()
+    }
+}
@@ -0,0 +1,71 @@
+//! Validators for common checks, which can be given to `validate`.
+//!
+//! # Examples
+//!
+//! ```
+//! use requestty::{validators, Question};
+//!
+//! let nickname = Question::input("nickname")
+//!     .validate(validators::unique_among(&["first_name", "last_name"]))
+//!     .build();
+//! ```
+
+use crate::{Answer, Answers};
+
+/// Checks that the answer differs from the answers to the questions with the given names.
+///
+/// Only string answers, like those of [`input`] and [`password`], are compared. Questions that have
+/// not been answered are ignored. The error names the first question that has the same answer.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{validators, Question};
+///
+/// let backup_dir = Question::input("backup_dir")
+///     .validate(validators::unique_among(&["source_dir"]))
+///     .build();
+/// ```
+///
+/// [`input`]: crate::Question::input
+/// [`password`]: crate::Question::password
+pub fn unique_among(names: &[&str]) -> impl FnMut(&str, &Answers) -> Result<(), String> {
+    let names: Vec<String> = names.iter().map(|&name| name.to_owned()).collect();
+
+    move |answer, previous_answers| {
+        let conflict = names
+            .iter()
+            .find(|name| match previous_answers.get(name.as_str()) {
+                Some(Answer::String(previous)) => previous == answer,
+                _ => false,
+            });
+
+        match conflict {
+            Some(name) => Err(format!("Must be different from the answer to '{}'", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_among() {
+        let mut answers = Answers::default();
+        answers.insert("first".into(), Answer::String("foo".into()));
+        answers.insert("second".into(), Answer::String("bar".into()));
+        answers.insert("number".into(), Answer::Int(3));
+
+        let mut validate = unique_among(&["first", "second", "number", "missing"]);
+
+        assert_eq!(validate("baz", &answers), Ok(()));
+        assert_eq!(validate("3", &answers), Ok(()));
+        assert_eq!(
+            validate("bar", &answers),
+            Err("Must be different from the answer to 'second'".to_owned())
+        );
+        assert_eq!(unique_among(&["first"])("bar", &answers), Ok(()));
+    }
+}
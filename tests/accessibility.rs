@@ -0,0 +1,57 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+#[test]
+fn test_accessible() {
+    requestty::accessibility::set(true);
+
+    let questions = vec![
+        Question::input("name")
+            .message("name")
+            .validate(|name, _| {
+                if name.len() > 1 {
+                    Ok(())
+                } else {
+                    Err("Name is too short".into())
+                }
+            })
+            .build(),
+        Question::password("password").message("password").build(),
+        Question::select("colour")
+            .message("colour")
+            .choices(vec![
+                "Red".into(),
+                requestty::DefaultSeparator,
+                "Blue".into(),
+            ])
+            .build(),
+        Question::multi_select("toppings")
+            .message("toppings")
+            .choices(vec!["Cheese", "Olives", "Onions"])
+            .build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((60, 25).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('p').into(),
+        KeyCode::Char('w').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers["name"], Answer::String("ab".into()));
+    assert_eq!(answers["colour"].as_list_item().unwrap().index, 2);
+    assert_eq!(answers["toppings"].as_list_items().unwrap().len(), 1);
+
+    ui::assert_backend_snapshot!(backend);
+}
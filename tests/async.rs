@@ -0,0 +1,185 @@
+use std::{
+    future::Future,
+    io,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread,
+};
+
+use requestty::{
+    prompt::{events::AsyncEventIterator, *},
+    Answers, Question,
+};
+use ui::events::{KeyCode, KeyEvent, TestEvents};
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Returns `Poll::Pending` before every event, to make sure the prompts wait for events properly.
+struct PendingEvents<E> {
+    events: E,
+    pending: bool,
+}
+
+impl<E> PendingEvents<E> {
+    fn new(events: E) -> Self {
+        Self {
+            events,
+            pending: true,
+        }
+    }
+}
+
+impl<E: AsyncEventIterator> AsyncEventIterator for PendingEvents<E> {
+    fn poll_next_event(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<KeyEvent>> {
+        if self.pending {
+            self.pending = false;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            self.pending = true;
+            self.events.poll_next_event(cx)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CharPrompt;
+
+impl Prompt for CharPrompt {
+    fn ask(
+        self,
+        _: String,
+        _: &Answers,
+        _: &mut dyn Backend,
+        events: &mut dyn EventIterator,
+    ) -> requestty::Result<Option<Answer>> {
+        match events.next_event()?.code {
+            KeyCode::Char(c) => Ok(Some(Answer::String(c.to_string()))),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn questions() -> Vec<Question<'static>> {
+    vec![
+        Question::input("name").build(),
+        Question::confirm("confirm").build(),
+        Question::select("select")
+            .choices(vec!["first", "second", "third"])
+            .build(),
+        Question::custom("custom", CharPrompt).build(),
+    ]
+}
+
+fn keys() -> Vec<KeyEvent> {
+    vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('c').into(),
+    ]
+}
+
+#[test]
+fn test_prompt_all_async() {
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = PendingEvents::new(TestEvents::new(keys()));
+
+    let answers = block_on(
+        requestty::PromptModule::new(questions()).prompt_all_async_with(&mut backend, &mut events),
+    )
+    .unwrap();
+
+    assert_eq!(answers["name"].as_string(), Some("ab"));
+    assert_eq!(answers["confirm"].as_bool(), Some(true));
+    assert_eq!(answers["select"].as_list_item().unwrap().index, 1);
+    assert_eq!(answers["custom"].as_string(), Some("c"));
+}
+
+#[test]
+fn test_same_as_sync() {
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let sync_answers = requestty::PromptModule::new(questions())
+        .prompt_all_with(&mut backend, &mut TestEvents::new(keys()))
+        .unwrap();
+
+    let mut async_backend = ui::backend::TestBackend::new((50, 20).into());
+    let async_answers = block_on(
+        requestty::PromptModule::new(questions())
+            .prompt_all_async_with(&mut async_backend, &mut TestEvents::new(keys())),
+    )
+    .unwrap();
+
+    assert_eq!(sync_answers, async_answers);
+    assert_eq!(backend, async_backend);
+}
+
+#[test]
+fn test_prompt_async() {
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('n').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let mut module = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("skipped").when(false).build(),
+        Question::confirm("confirm").build(),
+    ]);
+
+    let answer = block_on(module.prompt_async_with(&mut backend, &mut events))
+        .unwrap()
+        .cloned();
+    assert_eq!(answer, Some(Answer::String("a".into())));
+
+    let answer = block_on(module.prompt_async_with(&mut backend, &mut events))
+        .unwrap()
+        .cloned();
+    assert_eq!(answer, Some(Answer::Bool(false)));
+
+    assert!(
+        block_on(module.prompt_async_with(&mut backend, &mut events))
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_eof() {
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+
+    let res = block_on(
+        requestty::PromptModule::new(questions()).prompt_all_async_with(
+            &mut backend,
+            &mut TestEvents::new(Some(KeyCode::Null.into())),
+        ),
+    );
+
+    assert!(matches!(res, Err(requestty::ErrorKind::Eof)));
+}
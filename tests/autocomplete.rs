@@ -0,0 +1,86 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+fn countries(input: &str, _: &requestty::Answers) -> Vec<String> {
+    ["Australia", "Austria", "Brazil", "Canada"]
+        .iter()
+        .filter(|country| country.to_lowercase().starts_with(&input.to_lowercase()))
+        .map(|country| country.to_string())
+        .collect()
+}
+
+#[test]
+fn test_submit_suggestion() {
+    let prompt = Question::autocomplete("country", countries).message("message");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Char('u').into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("Austria".into()));
+}
+
+#[test]
+fn test_submit_text() {
+    let prompt = Question::autocomplete("country", countries)
+        .message("message")
+        .initial_value("Denm")
+        .filter(|country, _| country.to_uppercase());
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Char('r').into(),
+        KeyCode::Char('k').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("DENMARK".into()));
+}
+
+#[test]
+fn test_debounce() {
+    let prompt = Question::autocomplete("country", countries)
+        .message("message")
+        .debounce(std::time::Duration::from_secs(3600));
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    // The first `Enter` only updates the suggestions for "c"
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("Canada".into()));
+}
+
+#[test]
+fn test_validate() {
+    let prompt = Question::autocomplete("country", countries)
+        .message("message")
+        .validate(|country, _| {
+            if country.starts_with('B') {
+                Ok(())
+            } else {
+                Err("Please pick a country starting with B".into())
+            }
+        });
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("Brazil".into()));
+}
@@ -0,0 +1,218 @@
+use std::sync::{Mutex, MutexGuard};
+
+use once_cell::sync::Lazy;
+use requestty::{config::Config, symbols, Answer, ErrorKind, OnEsc, PromptModule, Question};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, KeyEvent, KeyModifiers, TestEvents},
+};
+
+mod helpers;
+
+static LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Keeps tests which apply a config from running at the same time, and restores the defaults when
+/// dropped.
+struct Defaults {
+    symbols: symbols::SymbolSet,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Defaults {
+    fn lock() -> Self {
+        // A test which failed still restores the defaults
+        let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        Defaults {
+            symbols: symbols::current(),
+            _lock: lock,
+        }
+    }
+}
+
+impl Drop for Defaults {
+    fn drop(&mut self) {
+        Config {
+            symbols: Some(self.symbols.clone()),
+            on_esc: Some(OnEsc::Ignore),
+            hide_cursor: Some(false),
+            back_key: Some(KeyCode::BackTab.into()),
+            ..Config::default()
+        }
+        .apply();
+    }
+}
+
+#[test]
+fn test_apply() {
+    let _defaults = Defaults::lock();
+    let back_key = KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL);
+
+    Config {
+        on_esc: Some(OnEsc::Terminate),
+        back_key: Some(back_key),
+        ..Config::default()
+    }
+    .apply();
+
+    let mut backend = TestBackend::new((50, 10).into());
+
+    let mut events = TestEvents::new(Some(KeyCode::Esc.into()));
+    let err =
+        requestty::prompt_one_with(Question::input("name"), &mut backend, &mut events).unwrap_err();
+    assert!(matches!(err, ErrorKind::Aborted));
+
+    // Options set on the question take precedence
+    let mut events = TestEvents::new(vec![KeyCode::Esc.into(), KeyCode::Enter.into()]);
+    let question = Question::input("name").on_esc(OnEsc::Ignore);
+    assert!(requestty::prompt_one_with(question, &mut backend, &mut events).is_ok());
+
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        back_key,
+        KeyCode::Backspace.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+    let answers = PromptModule::new(vec![
+        Question::input("a").build(),
+        Question::input("b").default("c").build(),
+    ])
+    .allow_back(true)
+    .prompt_all_with(&mut backend, &mut events)
+    .unwrap();
+    assert_eq!(answers["a"], Answer::String("b".into()));
+}
+
+#[test]
+fn test_hide_cursor() {
+    let _defaults = Defaults::lock();
+
+    Config {
+        hide_cursor: Some(true),
+        ..Config::default()
+    }
+    .apply();
+
+    // The cursor is not shown after the typed text
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 10).into());
+    let mut events = TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+    let answer = requestty::prompt_one_with(Question::input("name"), &mut backend, &mut events);
+    assert_eq!(answer.unwrap(), Answer::String("a".into()));
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_from_toml() {
+    use requestty::prompt::style;
+
+    assert_eq!(Config::from_toml("").unwrap(), Config::default());
+
+    let config = Config::from_toml(
+        r##"
+        page_size = 7
+        should_loop = false
+        hide_cursor = true
+        unknown = "ignored"
+
+        [symbols]
+        set = "unicode"
+        pointer = ">"
+
+        [theme]
+        prefix = "light_magenta"
+        highlight = 208
+        hint = "#8000ff"
+
+        [keybindings]
+        on_esc = "skip_question"
+        back = "ctrl+shift+tab"
+        "##,
+    )
+    .unwrap();
+
+    assert_eq!(config.page_size, Some(7));
+    assert_eq!(config.should_loop, Some(false));
+    assert_eq!(config.hide_cursor, Some(true));
+    assert_eq!(
+        config.symbols,
+        Some(symbols::SymbolSet {
+            pointer: '>',
+            ..symbols::UNICODE
+        })
+    );
+    assert_eq!(
+        config.theme,
+        Some(style::Theme {
+            prefix: style::Color::LightMagenta,
+            highlight: style::Color::Ansi(208),
+            hint: style::Color::Rgb(0x80, 0x00, 0xff),
+            ..style::Theme::default()
+        })
+    );
+    assert_eq!(config.on_esc, Some(OnEsc::SkipQuestion));
+    assert_eq!(
+        config.back_key,
+        Some(KeyEvent::new(KeyCode::BackTab, KeyModifiers::CONTROL))
+    );
+
+    for (key, expected) in [
+        ("esc", KeyEvent::from(KeyCode::Esc)),
+        ("f5", KeyCode::F(5).into()),
+        (
+            "alt+page_up",
+            KeyEvent::new(KeyCode::PageUp, KeyModifiers::ALT),
+        ),
+        (
+            "ctrl+B",
+            KeyEvent::new(KeyCode::Char('B'), KeyModifiers::CONTROL),
+        ),
+        ("f", KeyCode::Char('f').into()),
+    ] {
+        let config = Config::from_toml(&format!("[keybindings]\nback = \"{}\"", key)).unwrap();
+        assert_eq!(config.back_key, Some(expected), "{}", key);
+    }
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_symbols_base() {
+    let _defaults = Defaults::lock();
+    symbols::set(symbols::ASCII);
+
+    // The symbols are not overridden on top of the current set
+    let config = Config::from_toml("[symbols]\npointer = \">\"").unwrap();
+    assert_eq!(
+        config.symbols,
+        Some(symbols::SymbolSet {
+            pointer: '>',
+            ..symbols::UNICODE
+        })
+    );
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_invalid() {
+    for config in [
+        "page_size = 4",
+        "page_size = \"10\"",
+        "should_loop = 1",
+        "hide_cursor = \"yes\"",
+        "symbols = \"ascii\"",
+        "[symbols]\nset = \"emoji\"",
+        "[symbols]\npointer = \"->\"",
+        "theme = \"dark\"",
+        "[theme]\nhint = \"purple\"",
+        "[theme]\nhint = 256",
+        "[theme]\nhint = \"#80ff\"",
+        "[keybindings]\non_esc = \"back\"",
+        "[keybindings]\nback = \"hyper+b\"",
+        "[keybindings]\nback = \"ctrl+\"",
+        "page_size = ",
+    ] {
+        assert!(Config::from_toml(config).is_err(), "{}", config);
+    }
+}
@@ -125,3 +125,106 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_words() {
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('Y').into(),
+        KeyCode::Char('e').into(),
+        KeyCode::Tab.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(
+        Question::confirm("name").message("message").build(),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::Bool(true));
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('n').into(),
+        KeyCode::Left.into(),
+        KeyCode::Char('x').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Right.into(),
+        KeyCode::Char('O').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(
+        Question::confirm("name")
+            .message("message")
+            .default(true)
+            .build(),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::Bool(false));
+}
+
+#[test]
+fn test_allow_abstain() {
+    let size = (50, 20).into();
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('x').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(
+        Question::confirm("name")
+            .message("message")
+            .allow_abstain(true)
+            .build(),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::OptionBool(None));
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyCode::Esc.into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(
+        Question::confirm("name")
+            .message("message")
+            .default(true)
+            .allow_abstain(true)
+            .build(),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::OptionBool(None));
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(
+        Question::confirm("name")
+            .message("message")
+            .default(true)
+            .allow_abstain(true)
+            .build(),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::OptionBool(Some(true)));
+}
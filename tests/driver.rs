@@ -0,0 +1,50 @@
+use requestty::{prompt::events::KeyCode, test::Driver, PromptModule, Question};
+use ui::backend::TestBackend;
+
+#[test]
+fn test_driver() {
+    let questions = || {
+        PromptModule::new(vec![
+            Question::input("name").message("Name").build(),
+            Question::select("colour")
+                .message("Colour")
+                .choices(vec!["red", "green", "blue"])
+                .build(),
+        ])
+    };
+
+    let mut driver = Driver::new((30, 10).into())
+        .text("ab")
+        .keys(vec![KeyCode::Backspace, KeyCode::Enter, KeyCode::Down])
+        .key(KeyCode::Enter)
+        .key(KeyCode::Esc);
+
+    let answers = driver.run(questions()).unwrap();
+
+    assert_eq!(answers["name"].as_string(), Some("a"));
+    assert_eq!(answers["colour"].as_list_item().unwrap().index, 1);
+    assert_eq!(driver.remaining_keys(), [KeyCode::Esc.into()]);
+
+    let frames = driver.frames();
+    // Consecutive frames are never the same
+    assert!(frames.windows(2).all(|frames| frames[0] != frames[1]));
+    assert!(frames.iter().any(|frame| frame.find_text("ab").is_some()));
+    assert!(frames
+        .iter()
+        .any(|frame| frame.find_text("green").is_some()));
+    assert_eq!(driver.backend(), frames.last().unwrap());
+    // The symbols depend on the terminal, so only the text is checked
+    assert!(driver.backend().row_text(0).trim_end().ends_with(" a"));
+    assert!(driver.backend().row_text(1).trim_end().ends_with(" green"));
+
+    // The keys ran out while the input was still being asked
+    let mut driver = Driver::new((30, 10).into()).text("a");
+    let err = driver.run(questions()).unwrap_err();
+    assert!(
+        matches!(err, requestty::ErrorKind::IoError(ref e) if e.kind() == std::io::ErrorKind::UnexpectedEof)
+    );
+    assert_ne!(
+        driver.frames().last(),
+        Some(&TestBackend::new((30, 10).into()))
+    );
+}
@@ -0,0 +1,57 @@
+#![cfg(unix)]
+
+use std::process::Command;
+
+use requestty::{Answer, Question};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, TestEvents},
+};
+
+/// An editor which adds a line to the file every time it is opened.
+fn append_editor() -> Command {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg("printf 'line\\n' >> \"$0\"");
+    command
+}
+
+#[test]
+fn test_reopen_on_error() {
+    let prompt = Question::editor("name")
+        .editor(append_editor())
+        .reopen_on_error(true)
+        .validate(|s, _| {
+            assert!(!s.starts_with('#'), "the error should be removed");
+
+            if s.lines().count() >= 3 {
+                Ok(())
+            } else {
+                Err("At least 3 lines\nare required".into())
+            }
+        });
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("line\nline\nline\n".into()));
+}
+
+#[test]
+fn test_no_reopen_on_error() {
+    let prompt = Question::editor("name")
+        .editor(append_editor())
+        .validate(|s, _| {
+            if s.lines().count() >= 2 {
+                Ok(())
+            } else {
+                Err("At least 2 lines are required".into())
+            }
+        });
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("line\nline\n".into()));
+}
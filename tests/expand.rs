@@ -36,7 +36,7 @@ fn test_tranform() {
         .try_into_expand_item()
         .unwrap();
 
-    assert_eq!(ans.key, 'b');
+    assert_eq!(ans.key, "b");
 }
 
 #[test]
@@ -63,7 +63,7 @@ fn test_default() {
         .try_into_expand_item()
         .unwrap();
 
-    assert_eq!(ans.key, 'd');
+    assert_eq!(ans.key, "d");
 
     let expand = Question::expand("name")
         .message("message")
@@ -82,7 +82,7 @@ fn test_default() {
         .try_into_expand_item()
         .unwrap();
 
-    assert_eq!(ans.key, 'd');
+    assert_eq!(ans.key, "d");
 }
 
 #[test]
@@ -125,3 +125,32 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_multi_char_keys() {
+    let size = (50, 20).into();
+
+    let expand = Question::expand("name")
+        .message("message")
+        .choice('a', "Choice A")
+        .choices(vec![("ba", "Choice BA"), ("bb", "Choice BB")])
+        .default("bb");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('h').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(expand, &mut backend, &mut events)
+        .unwrap()
+        .try_into_expand_item()
+        .unwrap();
+
+    assert_eq!(ans.key, "ba");
+}
@@ -174,3 +174,20 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_initial_value() {
+    let prompt = Question::float("name")
+        .message("message")
+        .initial_value(4.5);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Backspace.into(),
+        KeyCode::Char('2').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Float(4.2));
+}
@@ -78,6 +78,10 @@ impl backend::Backend for SnapshotOnFlushBackend {
         self.backend.set_bg(color)
     }
 
+    fn write_hyperlink(&mut self, url: &str, text: &dyn std::fmt::Display) -> io::Result<()> {
+        self.backend.write_hyperlink(url, text)
+    }
+
     fn clear(&mut self, clear_type: backend::ClearType) -> io::Result<()> {
         self.backend.clear(clear_type)
     }
@@ -1,4 +1,7 @@
-use requestty::{question::Completions, Answer, Question};
+use requestty::{
+    question::{Completions, ValidationResult},
+    Answer, Question,
+};
 use ui::{
     events::{KeyCode, TestEvents},
     style::Color,
@@ -57,6 +60,56 @@ fn test_validate_on_key() {
     assert_eq!(ans, Answer::String("str".into()));
 }
 
+#[test]
+fn test_validate_on_key_with_message() {
+    let prompt = Question::input("name")
+        .message("message")
+        .validate_on_key_with_message(|s, _| {
+            if s.len() > 2 {
+                Ok(())
+            } else {
+                Err("Too short".into())
+            }
+        });
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('s').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::Char('r').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("str".into()));
+}
+
+#[test]
+fn test_validate_with_severity() {
+    let prompt = Question::input("name")
+        .message("message")
+        .validate_with_severity(|s, _| {
+            if s.is_empty() {
+                ValidationResult::Error("The string must not be empty".into())
+            } else if s.len() < 3 {
+                ValidationResult::Warning("The string is shorter than 3 characters".into())
+            } else {
+                ValidationResult::Valid
+            }
+        });
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("s".into()));
+}
+
 #[test]
 fn test_filter() {
     let prompt = Question::input("name")
@@ -144,6 +197,94 @@ fn test_default() {
     assert_eq!(ans, Answer::String("default".into()));
 }
 
+#[test]
+fn test_placeholder() {
+    let prompt = Question::input("name")
+        .message("message")
+        .placeholder("placeholder");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('s').into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("".into()));
+
+    // the default is shown instead of the placeholder
+    let prompt = Question::input("name")
+        .message("message")
+        .default("default")
+        .placeholder("placeholder");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("default".into()));
+}
+
+#[test]
+fn test_initial_value() {
+    let prompt = Question::input("name")
+        .message("message")
+        .initial_value("initial");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Backspace.into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("initias".into()));
+
+    // clearing the initial value falls back to the default
+    let prompt = Question::input("name")
+        .message("message")
+        .default("default")
+        .initial_value("in");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Backspace.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("default".into()));
+}
+
+#[test]
+fn test_history() {
+    let history = requestty::question::History::new();
+
+    let questions = vec![
+        Question::input("first").history(history.clone()).build(),
+        Question::input("second").history(history.clone()).build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('l').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Up.into(),
+        KeyCode::Char('!').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers["first"], Answer::String("ls".into()));
+    assert_eq!(answers["second"], Answer::String("ls!".into()));
+    assert_eq!(history.entries(), ["ls", "ls!"]);
+}
+
 #[test]
 fn test_auto_complete() {
     let prompt = Question::input("name")
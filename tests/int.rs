@@ -63,6 +63,30 @@ fn test_validate_on_key() {
     assert_eq!(ans, Answer::Int(32));
 }
 
+#[test]
+fn test_validate_on_key_with_message() {
+    let prompt = Question::int("name")
+        .message("message")
+        .validate_on_key_with_message(|i, _| {
+            if i > 3 {
+                Ok(())
+            } else {
+                Err("The number must be more than 3".into())
+            }
+        });
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('2').into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('5').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(5));
+}
+
 #[test]
 fn test_filter() {
     let prompt = Question::int("name")
@@ -170,3 +194,21 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_initial_value() {
+    let prompt = Question::int("name")
+        .message("message")
+        .validate_on_key(|i, _| i > 50)
+        .initial_value(42);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('0').into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(421));
+}
@@ -50,7 +50,20 @@ fn test_duplicate() {
 fn test_unknown() {
     let t = Runner::new("unknown");
     t.compile_fail("kind");
+    t.compile_fail("kind_suggestion");
     t.compile_fail("option");
+    t.compile_fail("option_suggestion");
+    t.compile_fail("option_other_kind");
+}
+
+#[test]
+#[ignore = "proc-macro test"]
+fn test_lit_type() {
+    let t = Runner::new("lit_type");
+    t.compile_fail("default");
+    t.compile_fail("page_size");
+    t.compile_fail("mask");
+    t.compile_fail("when");
 }
 
 #[test]
@@ -59,6 +72,7 @@ fn test_missing() {
     let t = Runner::new("missing");
     t.compile_fail("name");
     t.compile_fail("prompt");
+    t.compile_fail("plugin");
 }
 
 #[test]
@@ -246,6 +260,16 @@ fn test_custom_prompt() {
     t.compile_fail("mask");
     t.compile_fail("extension");
     t.compile_fail("editor");
+    t.compile_fail("plugin");
+}
+
+#[test]
+#[ignore = "proc-macro test"]
+fn test_plugin() {
+    let t = Runner::new("plugin");
+
+    t.pass("valid");
+    t.compile_fail("prompt");
 }
 
 #[test]
@@ -263,3 +287,13 @@ fn test_raw_select() {
     t.compile_fail("editor");
     t.compile_fail("prompt");
 }
+
+#[test]
+#[ignore = "proc-macro test"]
+fn test_from_answers() {
+    let t = Runner::new("from_answers");
+
+    t.pass("valid");
+    t.compile_fail("tuple_struct");
+    t.compile_fail("unknown_option");
+}
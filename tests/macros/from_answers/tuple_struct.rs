@@ -0,0 +1,4 @@
+#[derive(requestty::FromAnswers)]
+struct Config(String);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(requestty::FromAnswers)]
+struct Config {
+    #[answer(rename = "other")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[derive(requestty::FromAnswers)]
+struct Config<T: requestty::FromAnswer> {
+    name: String,
+    #[answer(name = "network.port")]
+    port: Option<i64>,
+    extra: T,
+    colors: Vec<String>,
+}
+
+fn main() {
+    let _ = <Config<bool> as requestty::FromAnswers>::from_answers(&Default::default());
+}
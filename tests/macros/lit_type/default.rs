@@ -0,0 +1,6 @@
+fn main() {
+    let q = requestty::questions![Confirm {
+        name: "name",
+        default: "yes",
+    }];
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let q = requestty::questions![Password {
+        name: "name",
+        mask: "*",
+    }];
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let q = requestty::questions![Select {
+        name: "name",
+        page_size: "10",
+    }];
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let q = requestty::questions![Input {
+        name: "name",
+        when: 1,
+    }];
+}
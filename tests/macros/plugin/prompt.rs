@@ -0,0 +1,3 @@
+fn main() {
+    requestty::questions![Plugin { prompt: todo!() }];
+}
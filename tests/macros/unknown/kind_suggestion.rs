@@ -0,0 +1,3 @@
+fn main() {
+    let q = requestty::questions![Multiselect {}];
+}
@@ -167,3 +167,97 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_section() {
+    let multi_select = requestty::Question::multi_select("name")
+        .message("multi select")
+        .choice("Choice 0")
+        .separator("Section 1")
+        .choice("Choice 2")
+        .choice("Choice 3")
+        .default_separator()
+        .choice("Choice 5");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| (item.index, item.section))
+        .collect();
+
+    assert_eq!(
+        ans,
+        vec![(0, None), (3, Some("Section 1".into())), (5, None)]
+    );
+}
+
+#[test]
+fn test_value() {
+    let multi_select = requestty::Question::multi_select("name")
+        .message("multi select")
+        .choice_with_value("Choice 0", "zero")
+        .separator("Section 1")
+        .choice("Choice 2")
+        .choice_with_value("Choice 3", "three");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| (item.index, item.value))
+        .collect();
+
+    assert_eq!(
+        ans,
+        vec![
+            (0, Some("zero".into())),
+            (2, None),
+            (3, Some("three".into()))
+        ]
+    );
+}
+
+#[test]
+fn test_description() {
+    let multi_select = requestty::Question::multi_select("name")
+        .message("multi select")
+        .choice_with_description("Choice 0", "The first choice")
+        .choice("Choice 1")
+        .choice_with_description("Choice 2", "The last choice");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.index)
+        .collect();
+
+    assert_eq!(ans, vec![0]);
+}
@@ -0,0 +1,43 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, KeyEvent, KeyModifiers, TestEvents};
+
+#[test]
+fn test_submit() {
+    let prompt = Question::multiline_input("name").message("message");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("a\nb".into()));
+}
+
+#[test]
+fn test_validate() {
+    let prompt = Question::multiline_input("name")
+        .message("message")
+        .default("a")
+        .validate(|s, _| {
+            if s.lines().count() > 1 {
+                Ok(())
+            } else {
+                Err("There must be more than one line".into())
+            }
+        });
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("a\nb".into()));
+}
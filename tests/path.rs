@@ -0,0 +1,48 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_complete() {
+    let prompt = Question::path("file")
+        .message("file")
+        .must_exist(true)
+        .extensions(["rs"]);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('t').into(),
+        KeyCode::Char('e').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Tab.into(),
+        KeyCode::Char('h').into(),
+        KeyCode::Tab.into(),
+        KeyCode::Tab.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("tests/helpers/mod.rs".into()));
+}
+
+#[test]
+fn test_validate() {
+    let prompt = Question::path("file")
+        .message("file")
+        .initial_value("Cargo.tom")
+        .must_exist(true)
+        .extensions(["toml"]);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Char('l').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("Cargo.toml".into()));
+}
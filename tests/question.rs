@@ -1,4 +1,6 @@
-use requestty::{prompt::*, question::CustomPromptBuilder, Question};
+use requestty::{prompt::*, question::CustomPromptBuilder, ListItem, Question, Repeat, Section};
+
+mod helpers;
 
 #[derive(Debug)]
 struct Validate<'a> {
@@ -82,6 +84,269 @@ fn test_when() {
     assert!(!prompted_1);
 }
 
+#[test]
+fn test_record_skipped() {
+    let mut prompted = false;
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("when").when(false).build(),
+        Question::input("esc")
+            .on_esc(requestty::OnEsc::SkipQuestion)
+            .build(),
+        custom_prompt("answered", "answered:", &mut prompted).build(),
+        Question::input("answered").when(false).build(),
+    ])
+    .record_skipped(true)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut ui::events::TestEvents::new(Some(ui::events::KeyCode::Esc.into())),
+    )
+    .unwrap();
+
+    assert!(prompted);
+    assert_eq!(answers.len(), 3);
+    assert_eq!(answers["when"], Answer::Skipped);
+    assert_eq!(answers["esc"], Answer::Skipped);
+    assert_eq!(answers["answered"], Answer::Int(0));
+
+    let answers = requestty::PromptModule::new(vec![Question::input("when").when(false).build()])
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut ui::events::TestEvents::empty(),
+        )
+        .unwrap();
+
+    assert!(answers.is_empty());
+}
+
+#[test]
+fn test_prompt_each() {
+    let questions = || {
+        vec![
+            Question::input("first").build(),
+            Question::input("skipped").when(false).build(),
+            Question::confirm("second").build(),
+        ]
+    };
+    let events = || {
+        ui::events::TestEvents::new(vec![
+            ui::events::KeyCode::Char('a').into(),
+            ui::events::KeyCode::Enter.into(),
+            ui::events::KeyCode::Char('y').into(),
+            ui::events::KeyCode::Enter.into(),
+        ])
+    };
+
+    let mut seen = Vec::new();
+    let answers = requestty::PromptModule::new(questions())
+        .prompt_each_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut events(),
+            |name, answer, answers| {
+                assert_eq!(answers.get(name), Some(answer));
+                seen.push((name.to_owned(), answers.len()));
+            },
+        )
+        .unwrap();
+
+    assert_eq!(seen, [("first".to_owned(), 1), ("second".to_owned(), 2)]);
+    assert_eq!(answers["first"], Answer::String("a".into()));
+    assert_eq!(answers["second"], Answer::Bool(true));
+
+    let mut module = requestty::PromptModule::new(questions());
+    let yielded: Vec<_> = module
+        .prompt_iter_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut events(),
+        )
+        .collect::<requestty::Result<_>>()
+        .unwrap();
+
+    assert_eq!(
+        yielded,
+        [
+            ("first".to_owned(), Answer::String("a".into())),
+            ("second".to_owned(), Answer::Bool(true))
+        ]
+    );
+    assert_eq!(module.into_answers(), answers);
+
+    // the iterator stops after an error
+    let mut module = requestty::PromptModule::new(questions());
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(Some(ui::events::KeyEvent::new(
+        ui::events::KeyCode::Char('c'),
+        ui::events::KeyModifiers::CONTROL,
+    )));
+    let mut iter = module.prompt_iter_with(&mut backend, &mut events);
+
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_non_interactive() {
+    let preset: Answers = vec![("name".to_owned(), Answer::String("preset".into()))]
+        .into_iter()
+        .collect();
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("name").ask_if_answered(true).build(),
+        Question::input("when").when(false).build(),
+    ])
+    .with_answers(preset.clone())
+    .record_skipped(true)
+    .interactive(false)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert_eq!(answers.len(), 2);
+    assert_eq!(answers["name"], Answer::String("preset".into()));
+    assert_eq!(answers["when"], Answer::Skipped);
+
+    let err = requestty::prompt_with_answers(
+        vec![
+            Question::input("name").build(),
+            Question::confirm("missing").build(),
+        ],
+        preset,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, requestty::ErrorKind::MissingAnswer(ref name) if name == "missing"));
+    assert_eq!(
+        err.to_string(),
+        "No answer was given for 'missing' in non-interactive mode"
+    );
+}
+
+#[test]
+fn test_with_defaults() {
+    let defaults: Answers = vec![
+        ("input".to_owned(), Answer::String("input".into())),
+        ("int".to_owned(), Answer::Int(3)),
+        ("confirm".to_owned(), Answer::Bool(true)),
+        ("select".to_owned(), Answer::ListItem((2, "c").into())),
+        ("removed".to_owned(), Answer::ListItem((5, "f").into())),
+        (
+            "multi_select".to_owned(),
+            Answer::ListItems(vec![(0, "a").into(), (2, "c").into()]),
+        ),
+        ("expand".to_owned(), Answer::ExpandItem(('b', "b").into())),
+        ("wrong_type".to_owned(), Answer::Int(3)),
+    ]
+    .into_iter()
+    .collect();
+
+    let choices = || vec!["a", "b", "c"];
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("input").build(),
+        Question::int("int").build(),
+        Question::confirm("confirm").build(),
+        Question::select("select").choices(choices()).build(),
+        Question::select("removed").choices(choices()).build(),
+        Question::multi_select("multi_select")
+            .choices(choices())
+            .build(),
+        Question::expand("expand")
+            .choice('a', "a")
+            .choice('b', "b")
+            .build(),
+        Question::input("wrong_type").default("default").build(),
+    ])
+    .with_defaults(defaults)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut ui::events::TestEvents::new(vec![ui::events::KeyCode::Enter.into(); 8]),
+    )
+    .unwrap();
+
+    assert_eq!(answers["input"], Answer::String("input".into()));
+    assert_eq!(answers["int"], Answer::Int(3));
+    assert_eq!(answers["confirm"], Answer::Bool(true));
+    assert_eq!(answers["select"].as_list_item().unwrap().index, 2);
+    assert_eq!(answers["removed"].as_list_item().unwrap().index, 0);
+    assert_eq!(
+        answers["multi_select"]
+            .as_list_items()
+            .unwrap()
+            .iter()
+            .map(|item| item.index)
+            .collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+    assert_eq!(answers["expand"].as_expand_item().unwrap().key, "b");
+    assert_eq!(answers["wrong_type"], Answer::String("default".into()));
+}
+
+/// Counts the number of times the keys typed ahead are discarded.
+struct CountDiscards<E> {
+    events: E,
+    discards: usize,
+}
+
+impl<E: EventIterator> EventIterator for CountDiscards<E> {
+    fn next_event(&mut self) -> std::io::Result<ui::events::KeyEvent> {
+        self.events.next_event()
+    }
+
+    fn discard_pending(&mut self) -> std::io::Result<()> {
+        self.discards += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_type_ahead() {
+    let questions = || {
+        vec![
+            Question::input("input_1").build(),
+            Question::input("input_2").build(),
+        ]
+    };
+    let events = || CountDiscards {
+        events: ui::events::TestEvents::new(vec![ui::events::KeyCode::Enter.into(); 2]),
+        discards: 0,
+    };
+
+    let mut type_ahead = events();
+    requestty::PromptModule::new(questions())
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut type_ahead,
+        )
+        .unwrap();
+    assert_eq!(type_ahead.discards, 0);
+
+    let mut no_type_ahead = events();
+    requestty::PromptModule::new(questions())
+        .type_ahead(false)
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut no_type_ahead,
+        )
+        .unwrap();
+    assert_eq!(no_type_ahead.discards, 2);
+
+    let mut error = CountDiscards {
+        events: ui::events::TestEvents::new(vec![ui::events::KeyEvent::new(
+            ui::events::KeyCode::Char('c'),
+            ui::events::KeyModifiers::CONTROL,
+        )]),
+        discards: 0,
+    };
+    let res = requestty::PromptModule::new(questions()).prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut error,
+    );
+    assert!(matches!(res, Err(requestty::ErrorKind::Interrupted)));
+    assert_eq!(error.discards, 1);
+}
+
 #[test]
 fn test_message() {
     let mut prompted_0 = false;
@@ -97,3 +362,620 @@ fn test_message() {
     assert!(prompted_0);
     assert!(prompted_1);
 }
+
+#[test]
+fn test_numbered() {
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+    let mut prompted_2 = false;
+
+    requestty::PromptModule::new(vec![
+        custom_prompt("name-0", "1. message", &mut prompted_0)
+            .message("message")
+            .build(),
+        custom_prompt("name-1", "message", &mut prompted_1)
+            .when(false)
+            .build(),
+        custom_prompt("name-2", "3. name-2:", &mut prompted_2).build(),
+    ])
+    .numbered(true)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert!(prompted_0);
+    assert!(!prompted_1);
+    assert!(prompted_2);
+
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+
+    requestty::PromptModule::new(vec![
+        custom_prompt("name-0", "[1/2] message", &mut prompted_0)
+            .message("message")
+            .build(),
+        custom_prompt("name-1", "[2/2] message", &mut prompted_1)
+            .message(|_: &Answers| "message".to_owned())
+            .build(),
+    ])
+    .numbered_with(|number, total, message| format!("[{}/{}] {}", number, total.unwrap(), message))
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert!(prompted_0);
+    assert!(prompted_1);
+
+    let mut prompted = false;
+
+    requestty::PromptModule::new(
+        vec![custom_prompt("name", "[1/?] message", &mut prompted)
+            .message("message")
+            .build()]
+        .into_iter()
+        .chain(std::iter::from_fn(|| None)),
+    )
+    .numbered_with(|number, total, message| {
+        assert_eq!(total, None);
+        format!("[{}/?] {}", number, message)
+    })
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert!(prompted);
+}
+
+#[test]
+fn test_allow_back() {
+    use ui::events::KeyCode;
+
+    let questions = || {
+        vec![
+            Question::input("a").build(),
+            Question::int("b").build(),
+            Question::confirm("c").build(),
+        ]
+    };
+
+    let events = vec![
+        // Going back during the first question asks it again
+        KeyCode::Char('x').into(),
+        KeyCode::BackTab.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('1').into(),
+        KeyCode::Enter.into(),
+        // Goes back to `b`, and then to `a`, which use the previous answers as defaults
+        KeyCode::BackTab.into(),
+        KeyCode::BackTab.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+    ];
+
+    let answers = requestty::PromptModule::new(questions())
+        .allow_back(true)
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut ui::events::TestEvents::new(events),
+        )
+        .unwrap();
+
+    assert_eq!(answers["a"], Answer::String("y".into()));
+    assert_eq!(answers["b"], Answer::Int(1));
+    assert_eq!(answers["c"], Answer::Bool(true));
+
+    // The key is given to the questions if going back is not allowed
+    let answers = requestty::PromptModule::new(questions())
+        .back_key(KeyCode::Char('x').into())
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut ui::events::TestEvents::new(vec![
+                KeyCode::Char('x').into(),
+                KeyCode::Enter.into(),
+                KeyCode::Char('1').into(),
+                KeyCode::Enter.into(),
+                KeyCode::Char('y').into(),
+                KeyCode::Enter.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(answers["a"], Answer::String("x".into()));
+}
+
+#[test]
+fn test_allow_back_section() {
+    use ui::events::KeyCode;
+
+    let answers = requestty::PromptModule::new(vec![Question::input("a").build()])
+        .section(Section::new(
+            "network",
+            "Network settings",
+            vec![
+                Question::input("port").when(false).build(),
+                Question::input("host").build(),
+            ],
+        ))
+        .record_skipped(true)
+        .allow_back(true)
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut ui::events::TestEvents::new(vec![
+                KeyCode::Char('x').into(),
+                KeyCode::Enter.into(),
+                // Goes back past the skipped question, which is skipped again
+                KeyCode::BackTab.into(),
+                KeyCode::Backspace.into(),
+                KeyCode::Char('y').into(),
+                KeyCode::Enter.into(),
+                KeyCode::Char('z').into(),
+                KeyCode::Enter.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(answers.len(), 3);
+    assert_eq!(answers["a"], Answer::String("y".into()));
+    assert_eq!(answers["network.port"], Answer::Skipped);
+    assert_eq!(answers["network.host"], Answer::String("z".into()));
+}
+
+#[test]
+fn test_allow_back_custom() {
+    use ui::events::KeyCode;
+
+    let mut prompted = false;
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("a").build(),
+        custom_prompt("custom", "custom:", &mut prompted).build(),
+        Question::input("b").build(),
+    ])
+    .allow_back(true)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut ui::events::TestEvents::new(vec![
+            KeyCode::Char('x').into(),
+            KeyCode::Enter.into(),
+            // The custom prompt cannot be asked again, so `b` is asked again instead
+            KeyCode::BackTab.into(),
+            KeyCode::Char('y').into(),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert!(prompted);
+    assert_eq!(answers["a"], Answer::String("x".into()));
+    assert_eq!(answers["b"], Answer::String("y".into()));
+}
+
+#[test]
+fn test_section() {
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+    let mut prompted_2 = false;
+
+    let answers =
+        requestty::PromptModule::new(vec![custom_prompt("name", "message", &mut prompted_0)
+            .message("message")
+            .build()])
+        .section(Section::new(
+            "network",
+            "Network settings",
+            vec![
+                custom_prompt("host", "network.host:", &mut prompted_1).build(),
+                Question::input("port").when(false).build(),
+            ],
+        ))
+        .section(
+            Section::new(
+                "storage",
+                "Storage settings",
+                vec![custom_prompt("directory", "storage.directory:", &mut prompted_2).build()],
+            )
+            .when(|answers: &Answers| answers["network.host"] == Answer::Int(1)),
+        )
+        .record_skipped(true)
+        .prompt_all_with(
+            &mut helpers::SnapshotOnFlushBackend::new((50, 20).into()),
+            &mut ui::events::TestEvents::empty(),
+        )
+        .unwrap();
+
+    assert!(prompted_0);
+    assert!(prompted_1);
+    assert!(!prompted_2);
+    assert_eq!(answers.len(), 4);
+    assert_eq!(answers["network.host"], Answer::Int(0));
+    assert_eq!(answers["network.port"], Answer::Skipped);
+    assert_eq!(answers["storage.directory"], Answer::Skipped);
+}
+
+#[test]
+fn test_repeat() {
+    let mut repetitions = 0;
+
+    let answers = requestty::PromptModule::new(vec![Question::input("name").build()])
+        .repeat(Repeat::new(
+            "dependencies",
+            "Dependencies",
+            || {
+                vec![
+                    Question::input("name")
+                        .when(|answers: &Answers| answers.contains_key("name"))
+                        .build(),
+                    Question::int("version")
+                        .when(|answers: &Answers| !answers.contains_key("dependencies.name"))
+                        .build(),
+                    Question::confirm("another").build(),
+                ]
+            },
+            |answers| {
+                repetitions += 1;
+                answers["another"] == Answer::Bool(true)
+            },
+        ))
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut ui::events::TestEvents::new(vec![
+                ui::events::KeyCode::Char('a').into(),
+                ui::events::KeyCode::Enter.into(),
+                ui::events::KeyCode::Char('b').into(),
+                ui::events::KeyCode::Enter.into(),
+                ui::events::KeyCode::Char('y').into(),
+                ui::events::KeyCode::Enter.into(),
+                ui::events::KeyCode::Char('c').into(),
+                ui::events::KeyCode::Enter.into(),
+                ui::events::KeyCode::Char('n').into(),
+                ui::events::KeyCode::Enter.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(repetitions, 2);
+    assert_eq!(answers.len(), 2);
+
+    let list = answers["dependencies"].as_list().unwrap();
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0]["name"], Answer::String("b".into()));
+    assert_eq!(list[0]["another"], Answer::Bool(true));
+    assert_eq!(list[1]["name"], Answer::String("c".into()));
+    assert_eq!(list[1]["another"], Answer::Bool(false));
+    assert!(!list[1].contains_key("version"));
+}
+
+#[test]
+fn test_then() {
+    let mut generated = Vec::new();
+
+    let answers = requestty::PromptModule::new(vec![Question::multi_select("languages")
+        .choices(vec!["Rust", "Python", "Go"])
+        .build()])
+    .then(|answers| {
+        answers["languages"]
+            .as_list_items()
+            .unwrap()
+            .iter()
+            .map(|item| Question::input(item.text.clone()).build())
+            .collect()
+    })
+    .then(|answers| {
+        generated.extend(answers.keys().cloned());
+        Vec::new()
+    })
+    .then(|_| vec![Question::confirm("done").build()])
+    .numbered(true)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut ui::events::TestEvents::new(vec![
+            ui::events::KeyCode::Char(' ').into(),
+            ui::events::KeyCode::Down.into(),
+            ui::events::KeyCode::Down.into(),
+            ui::events::KeyCode::Char(' ').into(),
+            ui::events::KeyCode::Enter.into(),
+            ui::events::KeyCode::Char('a').into(),
+            ui::events::KeyCode::Enter.into(),
+            ui::events::KeyCode::Char('b').into(),
+            ui::events::KeyCode::Enter.into(),
+            ui::events::KeyCode::Char('y').into(),
+            ui::events::KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    generated.sort();
+    assert_eq!(generated, ["Go", "Rust", "languages"]);
+    assert_eq!(answers.len(), 4);
+    assert_eq!(answers["Rust"], Answer::String("a".into()));
+    assert_eq!(answers["Go"], Answer::String("b".into()));
+    assert_eq!(answers["done"], Answer::Bool(true));
+}
+
+#[test]
+#[should_panic(expected = "questions cannot be added after `then`")]
+fn test_section_after_then() {
+    let _ = requestty::PromptModule::new(vec![Question::input("name").build()])
+        .then(|_| Vec::new())
+        .section(Section::new("network", "Network settings", Vec::new()));
+}
+
+#[test]
+fn test_render_finished() {
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let answers = Answers::default();
+
+    Question::input("name")
+        .message("message")
+        .transform(|s, _, b| b.write_all(s.to_uppercase().as_bytes()))
+        .build()
+        .render_finished(&Answer::String("input".into()), &answers, &mut backend)
+        .unwrap();
+
+    Question::select("name")
+        .choices(vec!["foo", "bar"])
+        .build()
+        .render_finished(
+            &Answer::ListItem(ListItem {
+                index: 1,
+                text: "bar".into(),
+                section: None,
+                value: None,
+            }),
+            &answers,
+            &mut backend,
+        )
+        .unwrap();
+
+    let mut prompted = false;
+    custom_prompt("name", "message", &mut prompted)
+        .message("message")
+        .build()
+        .render_finished(&Answer::Int(0), &answers, &mut backend)
+        .unwrap();
+
+    assert!(!prompted);
+}
+
+#[test]
+fn test_theme() {
+    let theme = style::Theme {
+        prefix: style::Color::Blue,
+        highlight: style::Color::Magenta,
+        hint: style::Color::Yellow,
+        error: style::Color::LightRed,
+        warning: style::Color::LightYellow,
+        selected: style::Color::White,
+    };
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        ui::events::KeyCode::Char(' ').into(),
+        ui::events::KeyCode::Down.into(),
+        ui::events::KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(
+        Question::multi_select("name")
+            .message("message")
+            .choices(vec!["foo", "bar"])
+            .theme(theme)
+            .build(),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans.as_list_items().unwrap().len(), 1);
+    assert_eq!(style::theme(), style::Theme::default());
+}
+
+#[test]
+fn test_before_after_ask() {
+    let calls = std::cell::RefCell::new(Vec::new());
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+
+    prompt_all(vec![
+        custom_prompt("name-0", "message", &mut prompted_0)
+            .message(|_: &Answers| {
+                calls.borrow_mut().push("message".to_owned());
+                "message".to_owned()
+            })
+            .before_ask(|answers| calls.borrow_mut().push(format!("before {}", answers.len())))
+            .after_ask(|answers, answer| {
+                calls
+                    .borrow_mut()
+                    .push(format!("after {} {:?}", answers.len(), answer))
+            })
+            .build(),
+        custom_prompt("name-1", "message", &mut prompted_1)
+            .when(false)
+            .before_ask(|_| calls.borrow_mut().push("skipped".to_owned()))
+            .after_ask(|_, _| calls.borrow_mut().push("skipped".to_owned()))
+            .build(),
+    ]);
+
+    assert!(prompted_0);
+    assert!(!prompted_1);
+    assert_eq!(*calls.borrow(), ["before 0", "message", "after 0 Int(0)"]);
+}
+
+#[test]
+fn test_hide_final() {
+    use ui::events::KeyCode;
+
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Esc.into(),
+    ]);
+
+    let answers = requestty::prompt_with(
+        vec![
+            Question::input("name").hide_final(true).build(),
+            Question::confirm("shown").build(),
+            Question::input("skipped")
+                .on_esc(requestty::OnEsc::SkipQuestion)
+                .hide_final(true)
+                .build(),
+        ],
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(answers["name"].as_string(), Some("a"));
+    // Only the question which is not hidden is left on the terminal
+    assert_eq!(backend.find_text("name"), None);
+    assert_eq!(backend.find_text("skipped"), None);
+    assert_eq!(backend.find_text("shown"), Some((2, 0)));
+    assert_eq!(backend.cursor(), (0, 1));
+
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    requestty::PromptModule::new(vec![
+        Question::confirm("first").build(),
+        Question::input("last").build(),
+    ])
+    .quiet()
+    .prompt_all_with(&mut backend, &mut events)
+    .unwrap();
+
+    assert_eq!(backend.cursor(), (0, 0));
+    assert!((0..10).all(|row| backend.row_text(row).trim().is_empty()));
+}
+
+#[derive(Debug)]
+struct Native;
+
+impl Prompt for Native {
+    fn ask(
+        self,
+        message: String,
+        _: &Answers,
+        mut backend: &mut dyn Backend,
+        _: &mut dyn EventIterator,
+    ) -> requestty::Result<Option<Answer>> {
+        let mut layout = layout::Layout::new(0, backend.size()?);
+        widgets::Prompt::new(&*message)
+            .with_hint("hint")
+            .with_delim(widgets::Delimiter::SquareBracket)
+            .render(&mut layout, &mut backend)?;
+        backend.write_all(b"\n")?;
+
+        write_finished(&message, Some("answer"), backend)?;
+        Ok(Some(Answer::String("answer".into())))
+    }
+}
+
+#[test]
+fn test_write_finished() {
+    let ans = requestty::prompt_one_with(
+        Question::custom("name", Native).message("message"),
+        &mut helpers::SnapshotOnFlushBackend::new((50, 5).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::String("answer".into()));
+}
+
+#[derive(Debug, PartialEq)]
+struct Colour(u8, u8, u8);
+
+#[derive(Debug)]
+struct ColourPicker;
+
+impl Prompt for ColourPicker {
+    fn ask(
+        self,
+        _: String,
+        _: &Answers,
+        _: &mut dyn Backend,
+        _: &mut dyn EventIterator,
+    ) -> requestty::Result<Option<Answer>> {
+        Ok(Some(
+            requestty::CustomAnswer::new(Colour(255, 128, 0)).into(),
+        ))
+    }
+}
+
+#[test]
+fn test_custom_answer() {
+    let answers = requestty::prompt_with(
+        vec![Question::custom("colour", ColourPicker).build()],
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    let answer = &answers["colour"];
+    assert!(answer.is_custom());
+    assert_eq!(answer.as_custom::<Colour>(), Some(&Colour(255, 128, 0)));
+    assert_eq!(answer.as_custom::<String>(), None);
+    // clones share the data
+    assert_eq!(answer.clone(), *answer);
+    assert_ne!(
+        Answer::from(requestty::CustomAnswer::new(Colour(255, 128, 0))),
+        *answer
+    );
+
+    let custom = answer.clone().try_into_custom().unwrap();
+    assert_eq!(*custom.downcast::<Colour>().unwrap(), Colour(255, 128, 0));
+}
+
+#[test]
+fn test_prompt_one_with_initial() {
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+    let esc = || vec![ui::events::KeyCode::Esc.into()];
+
+    let abandoned = requestty::prompt_one_with_initial(
+        Question::int("age").on_esc(requestty::OnEsc::Terminate),
+        Some(&Answer::String("4".into())),
+        &mut backend,
+        &mut ui::events::TestEvents::new(
+            std::iter::once(ui::events::KeyCode::Char('2').into()).chain(esc()),
+        ),
+    )
+    .unwrap_err();
+    assert!(matches!(abandoned.error, requestty::ErrorKind::Aborted));
+    assert_eq!(abandoned.partial, Some(Answer::String("42".into())));
+
+    let answer = requestty::prompt_one_with_initial(
+        Question::int("age"),
+        abandoned.partial.as_ref(),
+        &mut backend,
+        &mut ui::events::TestEvents::new(Some(ui::events::KeyCode::Enter.into())),
+    )
+    .unwrap();
+    assert_eq!(answer, Answer::Int(42));
+
+    // Questions which are not text based have no partial answer
+    let abandoned = requestty::prompt_one_with_initial(
+        Question::confirm("sure").on_esc(requestty::OnEsc::Terminate),
+        None,
+        &mut backend,
+        &mut ui::events::TestEvents::new(esc()),
+    )
+    .unwrap_err();
+    assert!(matches!(abandoned.error, requestty::ErrorKind::Aborted));
+    assert_eq!(abandoned.partial, None);
+}
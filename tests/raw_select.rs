@@ -141,3 +141,46 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_value() {
+    let raw_select = Question::raw_select("name")
+        .message("raw select")
+        .choice("Choice 0")
+        .separator("Section 1")
+        .choice_with_value("Choice 2", "two");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Char('2').into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(raw_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 2);
+    assert_eq!(ans.value.as_deref(), Some("two"));
+}
+
+#[test]
+fn test_jump_to_prefix() {
+    let raw_select = Question::raw_select("name")
+        .message("raw select")
+        .choices(vec!["Argentina", "Brazil", "Canada", "Chile"])
+        .jump_to_prefix(true);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('c').into(),
+        KeyCode::Char('h').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(raw_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "Chile");
+}
@@ -87,3 +87,260 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_section() {
+    fn test(events: Vec<KeyEvent>, index: usize, section: Option<&str>) {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .choice("Choice 0")
+            .separator("Section 1")
+            .choice("Choice 2")
+            .default_separator()
+            .choice("Choice 4");
+
+        let mut backend = ui::backend::TestBackend::new((50, 20).into());
+        let mut events = TestEvents::new(events);
+
+        let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+        assert_eq!(ans.index, index);
+        assert_eq!(ans.section.as_deref(), section);
+    }
+
+    test(vec![KeyCode::Enter.into()], 0, None);
+    test(
+        vec![KeyCode::Down.into(), KeyCode::Enter.into()],
+        2,
+        Some("Section 1"),
+    );
+    test(vec![KeyCode::End.into(), KeyCode::Enter.into()], 4, None);
+}
+
+#[test]
+fn test_value() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choice_with_value("Choice 0", "zero")
+        .separator("Section 1")
+        .choice("Choice 2")
+        .choice_with_value("Choice 3", "three");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::End.into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.value.as_deref(), Some("three"));
+}
+
+#[test]
+fn test_columns() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .columns(0)
+        .choices((0..20).map(|i| format!("Choice {}", i)));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Right),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 4);
+}
+
+#[test]
+fn test_hover_detail() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .columns(2)
+        .hover_detail(true)
+        .choices((0..4).map(|i| format!("Choice {} with a description that is too long", i)));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Right),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+}
+
+#[test]
+fn test_description() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choice_with_description("Choice 0", "The first choice")
+        .choice("Choice 1")
+        .choice_with_description("Choice 2", "The last choice");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 2);
+}
+
+#[test]
+fn test_truncate_answer() {
+    let long_choice = "A choice which is too long to fit on the finished line";
+    let size = (50, 20).into();
+
+    for &truncate_answer in &[true, false] {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .truncate_answer(truncate_answer)
+            .choices(vec![long_choice, "Short choice"]);
+
+        let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+        let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+        let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+        // only the finished line is truncated
+        assert_eq!(ans.text, long_choice);
+    }
+}
+
+#[test]
+fn test_custom_keyed_select() {
+    #[derive(Debug, PartialEq)]
+    enum Env {
+        Staging,
+        Production,
+    }
+
+    let select = || {
+        requestty::Question::custom_keyed_select("env")
+            .message("select")
+            .choice_value("Staging", Env::Staging)
+            .separator("Live")
+            .disabled_choice("Canary", "Paused")
+            .choice_value("Production", Env::Production)
+    };
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Down.into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select(), &mut backend, &mut events).unwrap();
+    assert_eq!(ans.as_custom::<Env>(), Some(&Env::Production));
+    ui::assert_backend_snapshot!(backend);
+
+    // The values are compared to use a previous answer as the default
+    let mut defaults = requestty::Answers::default();
+    defaults.insert("env".into(), ans);
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into()]);
+    let answers = requestty::PromptModule::new(vec![select().build()])
+        .with_defaults(defaults)
+        .prompt_all_with(&mut backend, &mut events)
+        .unwrap();
+    assert_eq!(answers["env"].as_custom::<Env>(), Some(&Env::Production));
+}
+
+#[test]
+fn test_jump_to_prefix() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["Argentina", "Brazil", "Canada", "Chile"])
+        .jump_to_prefix(true);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('c')),
+        KeyCode::Char('c').into(),
+        // `j` is not used to move down
+        KeyCode::Char('j').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+}
+
+#[test]
+fn test_mouse() {
+    use ui::events::{Event, EventIterator, MouseButton, MouseEvent, MouseEventKind};
+
+    struct Events(std::vec::IntoIter<Event>);
+
+    impl EventIterator for Events {
+        fn next_event(&mut self) -> std::io::Result<KeyEvent> {
+            loop {
+                if let Event::Key(key) = self.next_any_event()? {
+                    return Ok(key);
+                }
+            }
+        }
+
+        fn next_any_event(&mut self) -> std::io::Result<Event> {
+            Ok(self
+                .0
+                .next()
+                .expect("Events ran out, but another one was requested"))
+        }
+    }
+
+    fn click(row: u16) -> Event {
+        Event::Mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            2,
+            row,
+        ))
+    }
+
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["Choice 0", "Choice 1", "Choice 2"])
+        .mouse_capture(true);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    // The first click hovers the choice, and clicking it again selects it
+    let mut events = Events(vec![click(3), click(3)].into_iter());
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 2);
+    assert_eq!(ans.text, "Choice 2");
+    assert!(events.0.next().is_none());
+}
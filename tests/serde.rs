@@ -0,0 +1,90 @@
+use requestty::{Answer, Answers, CustomAnswer, ExpandItem, ListItem};
+
+fn answers() -> Answers {
+    vec![
+        ("name".to_owned(), Answer::String("requestty".into())),
+        ("age".to_owned(), Answer::Int(3)),
+        ("version".to_owned(), Answer::Float(0.5)),
+        ("confirm".to_owned(), Answer::Bool(true)),
+        (
+            "select".to_owned(),
+            Answer::ListItem(ListItem {
+                index: 2,
+                text: "third".into(),
+                section: Some("section".into()),
+                value: Some("3".into()),
+            }),
+        ),
+        (
+            "expand".to_owned(),
+            Answer::ExpandItem(ExpandItem {
+                key: "y".into(),
+                text: "yes".into(),
+            }),
+        ),
+        (
+            "multi_select".to_owned(),
+            Answer::ListItems(vec![(0, "first").into(), (2, "third").into()]),
+        ),
+        ("skipped".to_owned(), Answer::Skipped),
+        (
+            "database".to_owned(),
+            Answer::Map(
+                vec![("port".to_owned(), Answer::Int(5432))]
+                    .into_iter()
+                    .collect(),
+            ),
+        ),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_serialize() {
+    let json = serde_json::to_value(answers()).unwrap();
+
+    assert_eq!(json["name"], serde_json::json!({ "String": "requestty" }));
+    assert_eq!(json["age"], serde_json::json!({ "Int": 3 }));
+    assert_eq!(
+        json["select"],
+        serde_json::json!({
+            "ListItem": {
+                "index": 2,
+                "text": "third",
+                "section": "section",
+                "value": "3",
+            }
+        })
+    );
+    assert_eq!(
+        json["expand"],
+        serde_json::json!({ "ExpandItem": { "key": "y", "text": "yes" } })
+    );
+    assert_eq!(json["skipped"], serde_json::json!("Skipped"));
+    assert_eq!(
+        json["database"],
+        serde_json::json!({ "Map": { "port": { "Int": 5432 } } })
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let answers = answers();
+    let json = serde_json::to_string(&answers).unwrap();
+
+    assert_eq!(serde_json::from_str::<Answers>(&json).unwrap(), answers);
+}
+
+#[test]
+fn test_deserialize_missing_optional() {
+    let item: ListItem = serde_json::from_str(r#"{ "index": 0, "text": "first" }"#).unwrap();
+
+    assert_eq!(item, (0, "first").into());
+}
+
+#[test]
+fn test_serialize_custom() {
+    let answer = Answer::Custom(CustomAnswer::new(3u8));
+    assert!(serde_json::to_value(&answer).is_err());
+}
@@ -0,0 +1,100 @@
+use requestty::Question;
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, KeyEvent, KeyModifiers, TestEvents},
+};
+
+/// Asks the question at all of [`TestBackend::SIZES`], stopping with `Ctrl+C` after the given keys
+/// so that the prompt is left on the screen.
+fn render_at_sizes<'a>(question: impl Fn() -> Question<'a>, keys: &[KeyEvent]) -> String {
+    TestBackend::render_at_sizes(&TestBackend::SIZES, |backend| {
+        let mut events = TestEvents::new(keys.iter().copied().chain(Some(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        ))));
+
+        let res = requestty::prompt_one_with(question(), backend, &mut events);
+        assert!(matches!(res, Err(requestty::ErrorKind::Interrupted)));
+    })
+}
+
+#[test]
+fn test_input() {
+    let keys: Vec<KeyEvent> = "hello world"
+        .chars()
+        .map(|c| KeyCode::Char(c).into())
+        .collect();
+
+    let rendered = render_at_sizes(
+        || {
+            Question::input("name")
+                .message("What is the name of the package you are creating?")
+                .default("requestty")
+                .build()
+        },
+        &keys,
+    );
+
+    ui::assert_backend_snapshot!(rendered);
+}
+
+#[test]
+fn test_select() {
+    let rendered = render_at_sizes(
+        || {
+            Question::select("size")
+                .message("Which size would you like?")
+                .choices((0..20).map(|i| format!("Choice {} with a somewhat long name", i)))
+                .build()
+        },
+        &[
+            KeyCode::Down.into(),
+            KeyCode::Down.into(),
+            KeyCode::PageDown.into(),
+        ],
+    );
+
+    ui::assert_backend_snapshot!(rendered);
+}
+
+#[test]
+fn test_multi_select() {
+    let rendered = render_at_sizes(
+        || {
+            Question::multi_select("toppings")
+                .message("Which toppings would you like?")
+                .choices(vec![
+                    "Pepperoni",
+                    "Mushrooms",
+                    "Onions",
+                    "Olives",
+                    "Pineapple",
+                ])
+                .separator(" = Extras = ")
+                .choices(vec!["Extra cheese", "Extra sauce"])
+                .build()
+        },
+        &[KeyCode::Char(' ').into(), KeyCode::Down.into()],
+    );
+
+    ui::assert_backend_snapshot!(rendered);
+}
+
+#[test]
+fn test_expand() {
+    let rendered = render_at_sizes(
+        || {
+            Question::expand("overwrite")
+                .message("Conflict on `file.rs`")
+                .choices(vec![
+                    ('y', "Overwrite"),
+                    ('a', "Overwrite this one and all next"),
+                    ('d', "Show diff"),
+                ])
+                .build()
+        },
+        &[KeyCode::Char('h').into(), KeyCode::Enter.into()],
+    );
+
+    ui::assert_backend_snapshot!(rendered);
+}
@@ -0,0 +1,50 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_adjust() {
+    let prompt = Question::slider("volume")
+        .message("volume")
+        .range(0..=100)
+        .step(5)
+        .default(50);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Right.into(),
+        KeyCode::Right.into(),
+        KeyCode::PageDown.into(),
+        KeyCode::Left.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(45));
+}
+
+#[test]
+fn test_validate() {
+    let prompt = Question::slider("volume")
+        .message("volume")
+        .range(-10..=10)
+        .validate(|volume, _| {
+            if volume > 0 {
+                Ok(())
+            } else {
+                Err("The volume must be positive".into())
+            }
+        });
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::End.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(10));
+}
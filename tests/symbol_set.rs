@@ -30,3 +30,24 @@ fn test_validate() {
 
     requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
 }
+
+#[test]
+fn test_select_separator() {
+    requestty::symbols::set(requestty::symbols::ASCII);
+
+    let prompt = Question::select("name").message("message").choices(vec![
+        "foo".into(),
+        requestty::question::Choice::DefaultSeparator,
+        "bar".into(),
+    ]);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Down.into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 2);
+}